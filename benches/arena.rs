@@ -0,0 +1,93 @@
+// Compares the Box-linked AVLTree with the arena-backed variant on the same workloads. Run with
+// `cargo bench --bench arena`; the numbers are wall-clock medians, so compare them on one machine.
+
+mod common;
+
+use std::{hint::black_box, time::Duration};
+
+use common::{median, scattered};
+use dsa_rust::tree::{arena_avl::ArenaAVLTree, avl::AVLTree};
+
+const KEYS: u32 = 100_000;
+
+fn report(name: &str, boxed: Duration, arena: Duration) {
+    println!(
+        "{:<8} box {:>10.2?}  arena {:>10.2?}  ({:.2}x)",
+        name,
+        boxed,
+        arena,
+        boxed.as_secs_f64() / arena.as_secs_f64()
+    );
+}
+
+fn main() {
+    let keys = scattered(KEYS);
+
+    let boxed = median(|| (), |_| AVLTree::from(keys.iter().copied()));
+    let arena = median(|| (), |_| ArenaAVLTree::from(keys.iter().copied()));
+    report("build", boxed, arena);
+
+    let boxed_tree = AVLTree::from(keys.iter().copied());
+    let arena_tree = ArenaAVLTree::from(keys.iter().copied());
+    let boxed = median(
+        || (),
+        |_| {
+            keys.iter()
+                .for_each(|key| assert!(boxed_tree.contains(black_box(key))))
+        },
+    );
+    let arena = median(
+        || (),
+        |_| {
+            keys.iter()
+                .for_each(|key| assert!(arena_tree.contains(black_box(key))))
+        },
+    );
+    report("lookup", boxed, arena);
+
+    let boxed = median(
+        || boxed_tree.clone(),
+        |mut tree| keys.iter().for_each(|key| assert!(tree.remove(key))),
+    );
+    let arena = median(
+        || arena_tree.clone(),
+        |mut tree| keys.iter().for_each(|key| assert!(tree.remove(key))),
+    );
+    report("remove", boxed, arena);
+
+    let boxed = median(
+        || boxed_tree.clone(),
+        |mut tree| {
+            tree.clear();
+            tree
+        },
+    );
+    let arena = median(
+        || arena_tree.clone(),
+        |mut tree| {
+            tree.clear();
+            tree
+        },
+    );
+    report("clear", boxed, arena);
+
+    // refilling a cleared arena reuses its slots instead of going back to the allocator
+    let refill = |mut tree: AVLTree<u32>| {
+        tree.extend(keys.iter().copied());
+        tree
+    };
+    let boxed = median(AVLTree::new, refill);
+    let arena = median(
+        || {
+            let mut tree = ArenaAVLTree::with_capacity(keys.len());
+            tree.extend(keys.iter().copied());
+            tree.clear();
+            tree
+        },
+        |mut tree| {
+            tree.extend(keys.iter().copied());
+            tree
+        },
+    );
+    report("refill", boxed, arena);
+}
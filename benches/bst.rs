@@ -0,0 +1,36 @@
+// Shows what balancing buys: the same keys go into the unbalanced BST and the AVLTree, once in
+// sorted order and once scattered. Run with `cargo bench --bench bst`. Sorted input turns the BST
+// into a list, so its build time grows with the square of n while every other column stays near
+// n log n; the sizes are kept small for that reason.
+
+mod common;
+
+use std::time::Duration;
+
+use common::{median, scattered};
+use dsa_rust::tree::{avl::AVLTree, bst::BST};
+
+const SIZES: [u32; 4] = [1_000, 2_000, 4_000, 8_000];
+
+fn build(keys: &[u32]) -> (Duration, Duration, usize) {
+    let bst = median(|| (), |_| BST::from(keys.iter().copied()));
+    let avl = median(|| (), |_| AVLTree::from(keys.iter().copied()));
+    (bst, avl, BST::from(keys.iter().copied()).height())
+}
+
+fn main() {
+    println!(
+        "{:<8}{:<11}{:>14}{:>14}{:>12}",
+        "n", "input", "BST", "AVLTree", "BST height"
+    );
+    for n in SIZES {
+        let sorted: Vec<u32> = (0..n).collect();
+        for (input, keys) in [("sorted", sorted), ("scattered", scattered(n))] {
+            let (bst, avl, height) = build(&keys);
+            println!(
+                "{:<8}{:<11}{:>14.2?}{:>14.2?}{:>12}",
+                n, input, bst, avl, height
+            );
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// Times the crate's collections against their std counterparts on the same generated keys and
+// prints one table. Run with `cargo bench --bench collections`; each cell is the median of
+// several rounds over the whole workload, so only compare numbers taken on one machine.
+//
+// push fills the structure (insert for the ordered ones), pop empties it from the front or top,
+// and search looks up a fixed sample of present keys.
+
+mod common;
+
+use std::{
+    collections::{BTreeSet, BinaryHeap as StdBinaryHeap, VecDeque},
+    hint::black_box,
+    time::Duration,
+};
+
+use common::{median, scattered};
+use dsa_rust::{
+    linked_list::{doubly, singly, unrolled::UnrolledList},
+    tree::{avl::AVLTree, heap::BinaryHeap},
+};
+
+const KEYS: u32 = 100_000;
+// the lists and heaps scan for every probe, so the sample is kept small
+const PROBES: usize = 200;
+
+struct Row {
+    name: &'static str,
+    push: Duration,
+    pop: Duration,
+    search: Duration,
+}
+
+fn sequence<T, P, Q, S>(name: &'static str, keys: &[u32], push: P, pop: Q, search: S) -> Row
+where
+    T: Default,
+    P: Fn(&mut T, u32),
+    Q: Fn(&mut T) -> Option<u32>,
+    S: Fn(&T, &u32) -> bool,
+{
+    let fill = || {
+        let mut collection = T::default();
+        keys.iter().for_each(|&key| push(&mut collection, key));
+        collection
+    };
+    let full = fill();
+    let probes: Vec<u32> = keys.iter().step_by(keys.len() / PROBES).copied().collect();
+    Row {
+        name,
+        push: median(|| (), |_| fill()),
+        pop: median(fill, |mut collection| {
+            while black_box(pop(&mut collection)).is_some() {}
+            collection
+        }),
+        search: median(
+            || (),
+            |_| {
+                probes
+                    .iter()
+                    .for_each(|key| assert!(search(&full, black_box(key))))
+            },
+        ),
+    }
+}
+
+fn main() {
+    let keys = scattered(KEYS);
+    let rows = [
+        sequence::<Vec<u32>, _, _, _>("std Vec", &keys, Vec::push, Vec::pop, |vec, key| {
+            vec.contains(key)
+        }),
+        sequence::<VecDeque<u32>, _, _, _>(
+            "std VecDeque",
+            &keys,
+            VecDeque::push_back,
+            VecDeque::pop_front,
+            VecDeque::contains,
+        ),
+        sequence::<singly::LinkedList<u32>, _, _, _>(
+            "singly list",
+            &keys,
+            singly::LinkedList::push_tail,
+            singly::LinkedList::pop,
+            singly::LinkedList::contains,
+        ),
+        sequence::<doubly::LinkedList<u32>, _, _, _>(
+            "doubly list",
+            &keys,
+            doubly::LinkedList::push_tail,
+            doubly::LinkedList::pop_head,
+            doubly::LinkedList::contains,
+        ),
+        sequence::<UnrolledList<u32, 16>, _, _, _>(
+            "unrolled list",
+            &keys,
+            UnrolledList::push_tail,
+            UnrolledList::pop_head,
+            UnrolledList::contains,
+        ),
+        sequence::<StdBinaryHeap<u32>, _, _, _>(
+            "std BinaryHeap",
+            &keys,
+            StdBinaryHeap::push,
+            StdBinaryHeap::pop,
+            |heap, key| heap.iter().any(|item| item == key),
+        ),
+        sequence::<BinaryHeap<u32>, _, _, _>(
+            "BinaryHeap",
+            &keys,
+            BinaryHeap::offer,
+            BinaryHeap::poll,
+            |heap, key| heap.iter_unsorted().any(|item| item == key),
+        ),
+        sequence::<BTreeSet<u32>, _, _, _>(
+            "std BTreeSet",
+            &keys,
+            |set, key| {
+                set.insert(key);
+            },
+            BTreeSet::pop_first,
+            BTreeSet::contains,
+        ),
+        sequence::<AVLTree<u32>, _, _, _>(
+            "AVLTree",
+            &keys,
+            |tree, key| {
+                tree.insert(key);
+            },
+            |tree| {
+                let first = *tree.first()?;
+                tree.remove(&first);
+                Some(first)
+            },
+            AVLTree::contains,
+        ),
+    ];
+
+    println!("{} keys, {} searches per round", keys.len(), PROBES);
+    println!(
+        "{:<16}{:>14}{:>14}{:>14}",
+        "structure", "push", "pop", "search"
+    );
+    for row in rows {
+        println!(
+            "{:<16}{:>14.2?}{:>14.2?}{:>14.2?}",
+            row.name, row.push, row.pop, row.search
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+pub const ROUNDS: usize = 9;
+
+// a multiplicative hash scatters the keys so inserts land all over a tree
+pub fn scattered(count: u32) -> Vec<u32> {
+    (0..count)
+        .map(|key| key.wrapping_mul(2_654_435_761))
+        .collect()
+}
+
+// runs setup untimed before each round and reports the median time of the measured part; what
+// run returns is dropped after the clock stops, so tearing down a structure is not counted
+pub fn median<S, F, V, R>(mut setup: S, mut run: F) -> Duration
+where
+    S: FnMut() -> V,
+    F: FnMut(V) -> R,
+{
+    let mut times: Vec<Duration> = (0..ROUNDS)
+        .map(|_| {
+            let input = setup();
+            let start = Instant::now();
+            let output = black_box(run(input));
+            let time = start.elapsed();
+            drop(output);
+            time
+        })
+        .collect();
+    times.sort();
+    times[ROUNDS / 2]
+}
@@ -0,0 +1,43 @@
+// Builds trees from already sorted keys three ways: one insert per key, the midpoint-splitting
+// bulk build, and collecting, which notices the order and takes the bulk path. BTreeSet's own
+// collect does the same trick, so it is listed for reference. Run with
+// `cargo bench --bench from_sorted`. The last column collects the same keys scattered, which
+// has to fall back to inserting and should cost about as much as the first.
+
+mod common;
+
+use std::collections::BTreeSet;
+
+use common::{median, scattered};
+use dsa_rust::tree::avl::AVLTree;
+
+const SIZES: [u32; 3] = [10_000, 100_000, 1_000_000];
+
+fn main() {
+    println!(
+        "{:<10}{:>14}{:>14}{:>14}{:>14}{:>10}{:>14}",
+        "n", "inserts", "sorted vec", "collect", "BTreeSet", "speedup", "unsorted"
+    );
+    for n in SIZES {
+        let keys: Vec<u32> = (0..n).collect();
+        let inserts = median(|| (), |_| AVLTree::from(keys.iter().copied()));
+        let bulk = median(|| keys.clone(), AVLTree::from_sorted_vec);
+        let collect = median(|| (), |_| keys.iter().copied().collect::<AVLTree<u32>>());
+        let std = median(|| (), |_| keys.iter().copied().collect::<BTreeSet<u32>>());
+        let shuffled = scattered(n);
+        let unsorted = median(
+            || (),
+            |_| shuffled.iter().copied().collect::<AVLTree<u32>>(),
+        );
+        println!(
+            "{:<10}{:>14.2?}{:>14.2?}{:>14.2?}{:>14.2?}{:>9.1}x{:>14.2?}",
+            n,
+            inserts,
+            bulk,
+            collect,
+            std,
+            inserts.as_secs_f64() / bulk.as_secs_f64(),
+            unsorted
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use core::marker::PhantomData;
+
+use crate::adt::{Queue, Stack};
+
+// a queue from two stacks: offers push onto inbox, polls pop off outbox, and an empty outbox is
+// refilled by popping all of inbox into it, which reverses it into arrival order. an item is
+// pushed and popped at most twice over its whole stay, so with n offers there are at most 4n
+// stack operations in total: O(1) amortized per call, though a single poll can cost O(n)
+pub struct QueueFromStacks<T, S>
+where
+    T: Clone,
+    S: Stack<T>,
+{
+    inbox: S,
+    outbox: S,
+    // Stack has no view of its bottom, so the items head and tail may need from there are kept
+    // aside: the oldest item in inbox, and the newest item overall
+    oldest_in_inbox: Option<T>,
+    newest: Option<T>,
+    _items: PhantomData<T>,
+}
+
+impl<T, S> QueueFromStacks<T, S>
+where
+    T: Clone,
+    S: Stack<T> + Default,
+{
+    pub fn new() -> Self {
+        Self {
+            inbox: S::default(),
+            outbox: S::default(),
+            oldest_in_inbox: None,
+            newest: None,
+            _items: PhantomData,
+        }
+    }
+}
+
+impl<T, S> Default for QueueFromStacks<T, S>
+where
+    T: Clone,
+    S: Stack<T> + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> Queue<T> for QueueFromStacks<T, S>
+where
+    T: Clone,
+    S: Stack<T>,
+{
+    fn is_empty(&self) -> bool {
+        self.inbox.is_empty() && self.outbox.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.inbox.len() + self.outbox.len()
+    }
+    fn offer(&mut self, data: T) {
+        if self.inbox.is_empty() {
+            self.oldest_in_inbox = Some(data.clone());
+        }
+        self.newest = Some(data.clone());
+        self.inbox.push(data);
+    }
+    fn poll(&mut self) -> Option<T> {
+        if self.outbox.is_empty() {
+            while let Some(data) = self.inbox.pop() {
+                self.outbox.push(data);
+            }
+            self.oldest_in_inbox = None;
+        }
+        let data = self.outbox.pop();
+        if self.is_empty() {
+            self.newest = None;
+        }
+        data
+    }
+    fn head(&self) -> Option<T> {
+        self.outbox.peek().or_else(|| self.oldest_in_inbox.clone())
+    }
+    fn tail(&self) -> Option<T> {
+        self.newest.clone()
+    }
+    fn clear(&mut self) {
+        self.inbox.clear();
+        self.outbox.clear();
+        self.oldest_in_inbox = None;
+        self.newest = None;
+    }
+}
+
+// a stack from two queues: push offers onto the live queue in O(1), and pop moves all but the
+// newest item across to the spare queue before taking it, then the two trade places. no
+// amortization helps here, every pop is O(n); peek is O(1) since the top is the live tail
+pub struct StackFromQueues<T, Q>
+where
+    T: Clone,
+    Q: Queue<T>,
+{
+    live: Q,
+    spare: Q,
+    _items: PhantomData<T>,
+}
+
+impl<T, Q> StackFromQueues<T, Q>
+where
+    T: Clone,
+    Q: Queue<T> + Default,
+{
+    pub fn new() -> Self {
+        Self {
+            live: Q::default(),
+            spare: Q::default(),
+            _items: PhantomData,
+        }
+    }
+}
+
+impl<T, Q> Default for StackFromQueues<T, Q>
+where
+    T: Clone,
+    Q: Queue<T> + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Q> Stack<T> for StackFromQueues<T, Q>
+where
+    T: Clone,
+    Q: Queue<T>,
+{
+    fn is_empty(&self) -> bool {
+        self.live.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.live.len()
+    }
+    fn push(&mut self, data: T) {
+        self.live.offer(data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        while self.live.len() > 1 {
+            if let Some(data) = self.live.poll() {
+                self.spare.offer(data);
+            }
+        }
+        let top = self.live.poll();
+        core::mem::swap(&mut self.live, &mut self.spare);
+        top
+    }
+    fn peek(&self) -> Option<T> {
+        self.live.tail()
+    }
+    fn clear(&mut self) {
+        self.live.clear();
+        self.spare.clear();
+    }
+}
@@ -0,0 +1,51 @@
+use core::hash::Hash;
+
+use crate::{
+    adt::Cache,
+    cache::{CacheStats, FifoCache, LfuCache, LruCache},
+};
+
+// every policy exposes the same inherent surface, so the trait only forwards to it
+macro_rules! impl_cache {
+    ($cache:ident) => {
+        impl<K, V> Cache<K, V> for $cache<K, V>
+        where
+            K: Hash + Eq + Clone,
+        {
+            fn is_empty(&self) -> bool {
+                self.is_empty()
+            }
+            fn len(&self) -> usize {
+                self.len()
+            }
+            fn capacity(&self) -> usize {
+                self.capacity()
+            }
+            fn get(&mut self, key: &K) -> Option<&V> {
+                self.get(key)
+            }
+            fn peek(&self, key: &K) -> Option<&V> {
+                self.peek(key)
+            }
+            fn contains(&self, key: &K) -> bool {
+                self.contains(key)
+            }
+            fn put(&mut self, key: K, value: V) -> Option<V> {
+                self.put(key, value)
+            }
+            fn remove(&mut self, key: &K) -> Option<V> {
+                self.remove(key)
+            }
+            fn stats(&self) -> CacheStats {
+                self.stats()
+            }
+            fn clear(&mut self) {
+                self.clear();
+            }
+        }
+    };
+}
+
+impl_cache!(LruCache);
+impl_cache!(LfuCache);
+impl_cache!(FifoCache);
@@ -0,0 +1,111 @@
+use alloc::collections::VecDeque;
+
+use crate::{
+    adt::{BoundedQueue, Deque, LocalStack, Queue, Stack},
+    collections::{RingBuffer, VecStack},
+};
+
+impl<T: Clone> Queue<T> for RingBuffer<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn offer(&mut self, data: T) {
+        if self.try_push(data).is_err() {
+            panic!("offer on a full queue of capacity {}", self.capacity());
+        }
+    }
+    fn poll(&mut self) -> Option<T> {
+        self.pop()
+    }
+    fn head(&self) -> Option<T> {
+        self.front().cloned()
+    }
+    fn tail(&self) -> Option<T> {
+        self.back().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone> BoundedQueue<T> for RingBuffer<T> {
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+    fn try_offer(&mut self, data: T) -> Result<(), T> {
+        self.try_push(data)
+    }
+    fn remaining_capacity(&self) -> usize {
+        self.remaining_capacity()
+    }
+}
+
+impl<T: Clone> Stack<T> for VecStack<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn push(&mut self, data: T) {
+        self.push(data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+    fn peek(&self) -> Option<T> {
+        self.peek().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+impl<T: Clone> LocalStack<T> for VecStack<T> {
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.peek_mut()
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+// alloc's ring-buffer deque, so the trait has a contiguous implementor beside the linked list
+impl<T: Clone> Deque<T> for VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn push_front(&mut self, data: T) {
+        self.push_front(data);
+    }
+    fn push_back(&mut self, data: T) {
+        self.push_back(data);
+    }
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+    fn front(&self) -> Option<T> {
+        self.front().cloned()
+    }
+    fn back(&self) -> Option<T> {
+        self.back().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,55 @@
+use alloc::sync::Arc;
+
+use crate::{
+    adt::{ConcurrentQueue, Stack},
+    concurrent::{MpscQueue, TreiberStack},
+};
+
+#[cfg(feature = "std")]
+impl<T: Send> ConcurrentQueue<T> for crate::concurrent::BlockingQueue<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn offer(&self, data: T) {
+        self.offer(data);
+    }
+    fn poll(&self) -> Option<T> {
+        self.poll()
+    }
+}
+
+impl<T: Send> ConcurrentQueue<T> for MpscQueue<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn offer(&self, data: T) {
+        self.offer(data);
+    }
+    fn poll(&self) -> Option<T> {
+        self.poll()
+    }
+}
+
+// each thread holds its own Arc handle, so the &mut receivers of Stack are per-handle while the
+// stack behind them is shared
+impl<T: Clone + Send + Sync> Stack<T> for Arc<TreiberStack<T>> {
+    fn is_empty(&self) -> bool {
+        TreiberStack::is_empty(self)
+    }
+    fn len(&self) -> usize {
+        TreiberStack::len(self)
+    }
+    fn push(&mut self, data: T) {
+        TreiberStack::push(self, data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        TreiberStack::pop(self)
+    }
+    fn peek(&self) -> Option<T> {
+        TreiberStack::peek(self)
+    }
+    // pops until empty, so items pushed concurrently meanwhile may be cleared too
+    fn clear(&mut self) {
+        while TreiberStack::pop(self).is_some() {}
+    }
+}
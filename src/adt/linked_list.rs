@@ -1,4 +1,7 @@
-use crate::{adt::{Queue, Stack}, linked_list::{doubly, singly}};
+use crate::{
+    adt::{Deque, LocalStack, Queue, Stack},
+    linked_list::{doubly, singly},
+};
 
 impl<T: Clone> Stack<T> for singly::LinkedList<T> {
     fn is_empty(&self) -> bool {
@@ -14,17 +17,25 @@ impl<T: Clone> Stack<T> for singly::LinkedList<T> {
         self.pop()
     }
     fn peek(&self) -> Option<T> {
-        if let Some(head) = &self.head {
-            Some(head.data.clone())
-        } else {
-            None
-        }
+        self.first().cloned()
     }
     fn clear(&mut self) {
         self.clear();
     }
 }
 
+impl<T: Clone> LocalStack<T> for singly::LinkedList<T> {
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
 impl<T: Clone> Queue<T> for singly::LinkedList<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
@@ -39,20 +50,10 @@ impl<T: Clone> Queue<T> for singly::LinkedList<T> {
         self.pop()
     }
     fn head(&self) -> Option<T> {
-        if let Some(head) = &self.head {
-            Some(head.data.clone())
-        } else {
-            None
-        }
+        self.first().cloned()
     }
     fn tail(&self) -> Option<T> {
-        if !self.tail.is_null() {
-            unsafe {
-                Some(Box::from_raw(self.tail).data.clone())
-            }
-        } else {
-            None
-        }
+        self.last().cloned()
     }
     fn clear(&mut self) {
         self.clear();
@@ -73,13 +74,25 @@ impl<T: Clone> Stack<T> for doubly::LinkedList<T> {
         self.pop_head()
     }
     fn peek(&self) -> Option<T> {
-        self.get(0)
+        self.get(0).cloned()
     }
     fn clear(&mut self) {
         self.clear();
     }
 }
 
+impl<T: Clone> LocalStack<T> for doubly::LinkedList<T> {
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.peek_head_mut()
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
 impl<T: Clone> Queue<T> for doubly::LinkedList<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
@@ -94,20 +107,41 @@ impl<T: Clone> Queue<T> for doubly::LinkedList<T> {
         self.pop_head()
     }
     fn head(&self) -> Option<T> {
-        if let Some(head) = self.head.clone() {
-            Some(head.borrow().data.clone())
-        } else {
-            None
-        }
+        self.peek_head().cloned()
     }
     fn tail(&self) -> Option<T> {
-        if let Some(tail) = self.tail.clone() {
-            Some(tail.borrow().data.clone())
-        } else {
-            None
-        }
+        self.peek_tail().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+impl<T: Clone> Deque<T> for doubly::LinkedList<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn push_front(&mut self, data: T) {
+        self.push_head(data);
+    }
+    fn push_back(&mut self, data: T) {
+        self.push_tail(data);
+    }
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_head()
+    }
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_tail()
+    }
+    fn front(&self) -> Option<T> {
+        self.peek_head().cloned()
+    }
+    fn back(&self) -> Option<T> {
+        self.peek_tail().cloned()
     }
     fn clear(&mut self) {
         self.clear();
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,287 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::adt::{Queue, Stack};
+
+// next to every item sits the position of the smallest item at or below it, so the minimum of
+// whatever is left after a pop is already known; equal items keep the lowest one as the minimum
+pub struct MinStack<T>
+where
+    T: Ord,
+{
+    items: Vec<T>,
+    minima: Vec<usize>,
+}
+
+impl<T> MinStack<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            minima: Vec::new(),
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+            minima: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T> MinStack<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn push(&mut self, data: T) {
+        let min = match self.minima.last() {
+            Some(&min) if self.items[min] <= data => min,
+            _ => self.items.len(),
+        };
+        self.items.push(data);
+        self.minima.push(min);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.minima.pop();
+        self.items.pop()
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+    pub fn min(&self) -> Option<&T> {
+        self.minima.last().map(|&min| &self.items[min])
+    }
+    // top to bottom
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.items.iter().rev()
+    }
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.minima.clear();
+    }
+}
+
+impl<T> Default for MinStack<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for MinStack<T>
+where
+    T: Clone + Ord,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            minima: self.minima.clone(),
+        }
+    }
+}
+
+impl<T> Debug for MinStack<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for MinStack<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<T> FromIterator<T> for MinStack<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Self::new();
+        stack.extend(iter);
+        stack
+    }
+}
+
+impl<T> Stack<T> for MinStack<T>
+where
+    T: Clone + Ord,
+{
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn push(&mut self, data: T) {
+        self.push(data);
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+    fn peek(&self) -> Option<T> {
+        self.peek().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+// offers go onto back and polls come off front; when front runs dry, back is poured into it
+// upside down, which puts the oldest item on top. each item is moved at most once, so poll is
+// amortized O(1), and the queue's minimum is the smaller of the two stacks' minima
+pub struct MinQueue<T>
+where
+    T: Ord,
+{
+    front: MinStack<T>,
+    back: MinStack<T>,
+}
+
+impl<T> MinQueue<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            front: MinStack::new(),
+            back: MinStack::new(),
+        }
+    }
+}
+
+impl<T> MinQueue<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+    pub fn offer(&mut self, data: T) {
+        self.back.push(data);
+    }
+    pub fn poll(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            while let Some(data) = self.back.pop() {
+                self.front.push(data);
+            }
+        }
+        self.front.pop()
+    }
+    pub fn head(&self) -> Option<&T> {
+        self.front.peek().or_else(|| self.back.items.first())
+    }
+    pub fn tail(&self) -> Option<&T> {
+        self.back.peek().or_else(|| self.front.items.first())
+    }
+    pub fn min(&self) -> Option<&T> {
+        match (self.front.min(), self.back.min()) {
+            (Some(front), Some(back)) => Some(front.min(back)),
+            (front, back) => front.or(back),
+        }
+    }
+    // oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.front.iter().chain(self.back.items.iter())
+    }
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+    }
+}
+
+impl<T> Default for MinQueue<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for MinQueue<T>
+where
+    T: Clone + Ord,
+{
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<T> Debug for MinQueue<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for MinQueue<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.back.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for MinQueue<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<T> Queue<T> for MinQueue<T>
+where
+    T: Clone + Ord,
+{
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+    fn offer(&mut self, data: T) {
+        self.offer(data);
+    }
+    fn poll(&mut self) -> Option<T> {
+        self.poll()
+    }
+    fn head(&self) -> Option<T> {
+        self.head().cloned()
+    }
+    fn tail(&self) -> Option<T> {
+        self.tail().cloned()
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
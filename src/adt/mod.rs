@@ -1,4 +1,11 @@
+pub mod adapters;
+pub mod cache;
+pub mod collections;
+pub mod concurrent;
 pub mod linked_list;
+pub mod min_stack;
+
+use crate::cache::CacheStats;
 
 pub trait Stack<T: Clone> {
     fn is_empty(&self) -> bool;
@@ -7,6 +14,21 @@ pub trait Stack<T: Clone> {
     fn pop(&mut self) -> Option<T>;
     fn peek(&self) -> Option<T>;
     fn clear(&mut self);
+    // room for that many more pushes without reallocating; node-based stacks allocate on every
+    // push anyway, hence the default of doing nothing
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+}
+
+// a stack with a single owner, which can lend out its items instead of cloning them; the
+// concurrent stack only implements Stack, since another thread could pop whatever it lent
+pub trait LocalStack<T: Clone>: Stack<T> {
+    fn peek_mut(&mut self) -> Option<&mut T>;
+    // top to bottom, the order pops would come out in
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
 }
 
 pub trait Queue<T: Clone> {
@@ -17,4 +39,52 @@ pub trait Queue<T: Clone> {
     fn head(&self) -> Option<T>;
     fn tail(&self) -> Option<T>;
     fn clear(&mut self);
-}
\ No newline at end of file
+}
+
+// a double-ended queue: both ends push and pop, so it can stand in for a Stack or a Queue
+pub trait Deque<T: Clone> {
+    fn is_empty(&self) -> bool;
+    fn len(&self) -> usize;
+    fn push_front(&mut self, data: T);
+    fn push_back(&mut self, data: T);
+    fn pop_front(&mut self) -> Option<T>;
+    fn pop_back(&mut self) -> Option<T>;
+    fn front(&self) -> Option<T>;
+    fn back(&self) -> Option<T>;
+    fn clear(&mut self);
+}
+
+// a queue with a fixed capacity that says no instead of growing; offer on a full one panics,
+// try_offer is the call that lets a producer back off
+pub trait BoundedQueue<T: Clone>: Queue<T> {
+    fn capacity(&self) -> usize;
+    fn try_offer(&mut self, data: T) -> Result<(), T>;
+    fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+    fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
+    }
+}
+
+// a queue shared across threads by reference, so unlike Queue every operation takes &self; there
+// is no head or tail peek, since another thread may take the item before it could be cloned
+pub trait ConcurrentQueue<T>: Send + Sync {
+    fn is_empty(&self) -> bool;
+    fn offer(&self, data: T);
+    fn poll(&self) -> Option<T>;
+}
+
+// a bounded key-value store that decides for itself what to drop when it fills up
+pub trait Cache<K, V> {
+    fn is_empty(&self) -> bool;
+    fn len(&self) -> usize;
+    fn capacity(&self) -> usize;
+    fn get(&mut self, key: &K) -> Option<&V>;
+    fn peek(&self, key: &K) -> Option<&V>;
+    fn contains(&self, key: &K) -> bool;
+    fn put(&mut self, key: K, value: V) -> Option<V>;
+    fn remove(&mut self, key: &K) -> Option<V>;
+    fn stats(&self) -> CacheStats;
+    fn clear(&mut self);
+}
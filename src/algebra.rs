@@ -0,0 +1,261 @@
+/*
+
+    ALGEBRA
+
+    the combining rules range structures are
+    generic over: a semigroup folds two values,
+    a monoid adds an identity, a group an inverse
+
+*/
+
+// combine must be associative: a.combine(&b).combine(&c) == a.combine(&b.combine(&c))
+pub trait Semigroup {
+    fn combine(&self, other: &Self) -> Self;
+}
+
+// identity combines with anything to give that thing back, which is what an empty range folds to
+pub trait Monoid: Semigroup {
+    fn identity() -> Self;
+}
+
+// a.combine(&a.inverse()) is the identity, so a prefix fold can be taken back off a longer one
+pub trait Group: Monoid {
+    fn inverse(&self) -> Self;
+}
+
+// marks a semigroup where a.combine(&a) == a, so folding overlapping blocks counts nothing twice
+pub trait Idempotent: Semigroup {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gcd<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Xor<T>(pub T);
+
+impl<T> Semigroup for Min<T>
+where
+    T: Ord + Clone,
+{
+    fn combine(&self, other: &Self) -> Self {
+        Self(self.0.clone().min(other.0.clone()))
+    }
+}
+
+impl<T> Idempotent for Min<T> where T: Ord + Clone {}
+
+impl<T> Semigroup for Max<T>
+where
+    T: Ord + Clone,
+{
+    fn combine(&self, other: &Self) -> Self {
+        Self(self.0.clone().max(other.0.clone()))
+    }
+}
+
+impl<T> Idempotent for Max<T> where T: Ord + Clone {}
+
+// integer sums wrap, which keeps them a group: a prefix taken off a longer one gives the exact
+// range sum whenever that sum fits, even if some prefix along the way overflowed
+macro_rules! integers {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Sum<$t> {
+            fn combine(&self, other: &Self) -> Self {
+                Self(self.0.wrapping_add(other.0))
+            }
+        }
+        impl Monoid for Sum<$t> {
+            fn identity() -> Self {
+                Self(0)
+            }
+        }
+        impl Group for Sum<$t> {
+            fn inverse(&self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+        impl Monoid for Min<$t> {
+            fn identity() -> Self {
+                Self(<$t>::MAX)
+            }
+        }
+        impl Monoid for Max<$t> {
+            fn identity() -> Self {
+                Self(<$t>::MIN)
+            }
+        }
+        impl Semigroup for Xor<$t> {
+            fn combine(&self, other: &Self) -> Self {
+                Self(self.0 ^ other.0)
+            }
+        }
+        impl Monoid for Xor<$t> {
+            fn identity() -> Self {
+                Self(0)
+            }
+        }
+        impl Group for Xor<$t> {
+            fn inverse(&self) -> Self {
+                *self
+            }
+        }
+    )*};
+}
+
+// gcd(0, x) == x, so 0 is the identity; only unsigned integers, where there is no sign to settle
+macro_rules! unsigned {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Gcd<$t> {
+            fn combine(&self, other: &Self) -> Self {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                Self(a)
+            }
+        }
+        impl Monoid for Gcd<$t> {
+            fn identity() -> Self {
+                Self(0)
+            }
+        }
+        impl Idempotent for Gcd<$t> {}
+    )*};
+}
+
+integers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+// float sums are only approximately associative, so range results can differ from a left to
+// right scan in the last few bits
+macro_rules! floats {
+    ($($t:ty),*) => {$(
+        impl Semigroup for Sum<$t> {
+            fn combine(&self, other: &Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+        impl Monoid for Sum<$t> {
+            fn identity() -> Self {
+                Self(0.0)
+            }
+        }
+        impl Group for Sum<$t> {
+            fn inverse(&self) -> Self {
+                Self(-self.0)
+            }
+        }
+    )*};
+}
+
+floats!(f32, f64);
+
+// an update applied to a whole range at once: act turns the fold of len items into the fold of
+// those items once each is updated. the monoid composes pending updates, a.combine(&b) being a
+// then b, and the identity leaves everything as it was
+pub trait Action<M>: Monoid {
+    fn act(&self, fold: &M, len: usize) -> M;
+}
+
+// the update that does nothing, for range structures only ever updated one item at a time
+impl Semigroup for () {
+    fn combine(&self, _: &Self) -> Self {}
+}
+
+impl Monoid for () {
+    fn identity() -> Self {}
+}
+
+impl<M> Action<M> for ()
+where
+    M: Clone,
+{
+    fn act(&self, fold: &M, _: usize) -> M {
+        fold.clone()
+    }
+}
+
+// adds the same amount to every item in a range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AddEach<T>(pub T);
+
+// overwrites every item in a range with the same value; None is the update still to come
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetEach<T>(pub Option<T>);
+
+impl<T> Semigroup for SetEach<T>
+where
+    T: Clone,
+{
+    fn combine(&self, other: &Self) -> Self {
+        Self(other.0.clone().or_else(|| self.0.clone()))
+    }
+}
+
+impl<T> Monoid for SetEach<T>
+where
+    T: Clone,
+{
+    fn identity() -> Self {
+        Self(None)
+    }
+}
+
+macro_rules! actions {
+    ($($t:ty),*) => {$(
+        impl Semigroup for AddEach<$t> {
+            fn combine(&self, other: &Self) -> Self {
+                Self(self.0.wrapping_add(other.0))
+            }
+        }
+        impl Monoid for AddEach<$t> {
+            fn identity() -> Self {
+                Self(0)
+            }
+        }
+        impl Action<Sum<$t>> for AddEach<$t> {
+            fn act(&self, fold: &Sum<$t>, len: usize) -> Sum<$t> {
+                Sum(fold.0.wrapping_add(self.0.wrapping_mul(len as $t)))
+            }
+        }
+        impl Action<Min<$t>> for AddEach<$t> {
+            fn act(&self, fold: &Min<$t>, _: usize) -> Min<$t> {
+                Min(fold.0.wrapping_add(self.0))
+            }
+        }
+        impl Action<Max<$t>> for AddEach<$t> {
+            fn act(&self, fold: &Max<$t>, _: usize) -> Max<$t> {
+                Max(fold.0.wrapping_add(self.0))
+            }
+        }
+        impl Action<Sum<$t>> for SetEach<$t> {
+            fn act(&self, fold: &Sum<$t>, len: usize) -> Sum<$t> {
+                self.0.map_or(*fold, |value| Sum(value.wrapping_mul(len as $t)))
+            }
+        }
+        impl Action<Min<$t>> for SetEach<$t> {
+            fn act(&self, fold: &Min<$t>, _: usize) -> Min<$t> {
+                self.0.map_or(*fold, Min)
+            }
+        }
+        impl Action<Max<$t>> for SetEach<$t> {
+            fn act(&self, fold: &Max<$t>, _: usize) -> Max<$t> {
+                self.0.map_or(*fold, Max)
+            }
+        }
+    )*};
+}
+
+actions!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
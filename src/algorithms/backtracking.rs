@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::{adt::Stack, linked_list::singly::LinkedList};
+
+// the candidates left to try at one depth of the search; the explicit stack holds one of these
+// per choice made so far, so no search depends on the call stack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<C> {
+    candidates: Vec<C>,
+    next: usize,
+}
+
+// grows a partial solution one choice at a time and yields every complete one, lazily:
+// choices lists the candidates for the next place, or None once the state is complete, and
+// constraint rejects a candidate before anything is built on it
+pub struct Backtrack<C, F, K, S>
+where
+    C: Clone,
+    S: Stack<Frame<C>>,
+{
+    state: Vec<C>,
+    frames: S,
+    choices: F,
+    constraint: K,
+    started: bool,
+}
+
+pub fn backtrack_with<S, C, I, F, K>(
+    state: Vec<C>,
+    choices: F,
+    constraint: K,
+) -> Backtrack<C, F, K, S>
+where
+    S: Stack<Frame<C>> + Default,
+    C: Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(&[C]) -> Option<I>,
+    K: FnMut(&[C], &C) -> bool,
+{
+    Backtrack {
+        state,
+        frames: S::default(),
+        choices,
+        constraint,
+        started: false,
+    }
+}
+
+pub fn backtrack<C, I, F, K>(
+    state: Vec<C>,
+    choices: F,
+    constraint: K,
+) -> Backtrack<C, F, K, LinkedList<Frame<C>>>
+where
+    C: Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(&[C]) -> Option<I>,
+    K: FnMut(&[C], &C) -> bool,
+{
+    backtrack_with(state, choices, constraint)
+}
+
+impl<C, I, F, K, S> Iterator for Backtrack<C, F, K, S>
+where
+    C: Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(&[C]) -> Option<I>,
+    K: FnMut(&[C], &C) -> bool,
+    S: Stack<Frame<C>>,
+{
+    type Item = Vec<C>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            match (self.choices)(&self.state) {
+                Some(candidates) => self.frames.push(Frame {
+                    candidates: candidates.into_iter().collect(),
+                    next: 0,
+                }),
+                None => return Some(self.state.clone()),
+            }
+        }
+        // the top frame is popped, advanced and pushed back, since Stack only hands out copies
+        while let Some(mut frame) = self.frames.pop() {
+            let Some(candidate) = frame.candidates.get(frame.next).cloned() else {
+                // out of candidates at this depth, so undo the choice that led here; the
+                // bottom frame was not reached by a choice
+                if !self.frames.is_empty() {
+                    self.state.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+            self.frames.push(frame);
+            if !(self.constraint)(&self.state, &candidate) {
+                continue;
+            }
+            self.state.push(candidate);
+            match (self.choices)(&self.state) {
+                Some(candidates) => self.frames.push(Frame {
+                    candidates: candidates.into_iter().collect(),
+                    next: 0,
+                }),
+                None => {
+                    let solution = self.state.clone();
+                    self.state.pop();
+                    return Some(solution);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<C, I, F, K, S> FusedIterator for Backtrack<C, F, K, S>
+where
+    C: Clone,
+    I: IntoIterator<Item = C>,
+    F: FnMut(&[C]) -> Option<I>,
+    K: FnMut(&[C], &C) -> bool,
+    S: Stack<Frame<C>>,
+{
+}
+
+// every placement of n queens on an n x n board with none attacking another, as the column of
+// the queen in each row
+pub fn n_queens(n: usize) -> Vec<Vec<usize>> {
+    queens(n).collect()
+}
+
+pub fn queens(n: usize) -> impl Iterator<Item = Vec<usize>> {
+    backtrack(
+        Vec::new(),
+        move |placed: &[usize]| (placed.len() < n).then_some(0..n),
+        |placed: &[usize], &col| {
+            let row = placed.len();
+            placed
+                .iter()
+                .enumerate()
+                .all(|(r, &c)| c != col && row - r != c.abs_diff(col))
+        },
+    )
+}
+
+// positions are what gets permuted, so equal items still give repeated permutations
+pub fn permutations<T>(items: &[T]) -> impl Iterator<Item = Vec<T>> + '_
+where
+    T: Clone,
+{
+    backtrack(
+        Vec::new(),
+        |used: &[usize]| (used.len() < items.len()).then_some(0..items.len()),
+        |used: &[usize], i| !used.contains(i),
+    )
+    .map(|order| order.into_iter().map(|i| items[i].clone()).collect())
+}
+
+// the k-item selections in the order the items appear, each listed once
+pub fn combinations<T>(items: &[T], k: usize) -> impl Iterator<Item = Vec<T>> + '_
+where
+    T: Clone,
+{
+    backtrack(
+        Vec::new(),
+        move |picked: &[usize]| {
+            // past items.len() - still_needed there is no room left to finish the selection
+            let still_needed = k - picked.len();
+            let from = picked.last().map_or(0, |&i| i + 1);
+            (still_needed > 0).then(|| from..(items.len() + 1).saturating_sub(still_needed))
+        },
+        |_: &[usize], _: &usize| true,
+    )
+    .map(|picked| picked.into_iter().map(|i| items[i].clone()).collect())
+}
+
+// all 2^n subsets, built as one include-or-skip decision per item
+pub fn subsets<T>(items: &[T]) -> impl Iterator<Item = Vec<T>> + '_
+where
+    T: Clone,
+{
+    backtrack(
+        Vec::new(),
+        |taken: &[bool]| (taken.len() < items.len()).then_some([false, true]),
+        |_: &[bool], _: &bool| true,
+    )
+    .map(|taken| {
+        items
+            .iter()
+            .zip(taken)
+            .filter(|(_, take)| *take)
+            .map(|(item, _)| item.clone())
+            .collect()
+    })
+}
@@ -0,0 +1,134 @@
+use alloc::{vec, vec::Vec};
+
+use crate::collections::Matrix;
+
+// every solver returns the optimal value together with a solution reaching it, rebuilt by
+// walking the filled table back from its last cell
+
+// fib(93) is the largest that fits in a u64, which also keeps the recursion below shallow
+pub fn fibonacci(n: usize) -> Option<u64> {
+    (n <= 93).then(|| fibonacci_memo(n, &mut vec![None; n + 1]))
+}
+
+// top-down: each value is worked out once and then read back from the memo
+fn fibonacci_memo(n: usize, memo: &mut [Option<u64>]) -> u64 {
+    if let Some(value) = memo[n] {
+        return value;
+    }
+    let value = match n {
+        0 | 1 => n as u64,
+        _ => fibonacci_memo(n - 1, memo) + fibonacci_memo(n - 2, memo),
+    };
+    memo[n] = Some(value);
+    value
+}
+
+// items are (weight, value) pairs; returns the best total value and the indices of the items
+// that make it, ascending. best[i][w] is the most the first i items can be worth within weight w
+pub fn knapsack(items: &[(usize, u64)], capacity: usize) -> (u64, Vec<usize>) {
+    let mut best: Matrix<u64> = Matrix::new(items.len() + 1, capacity + 1);
+    for (i, &(weight, value)) in items.iter().enumerate() {
+        for w in 0..=capacity {
+            let skip = best[(i, w)];
+            best[(i + 1, w)] = match w.checked_sub(weight) {
+                Some(rest) => skip.max(best[(i, rest)] + value),
+                None => skip,
+            };
+        }
+    }
+    let mut chosen = Vec::new();
+    let mut w = capacity;
+    for i in (0..items.len()).rev() {
+        // a changed value means item i had to be taken to reach it
+        if best[(i + 1, w)] != best[(i, w)] {
+            chosen.push(i);
+            w -= items[i].0;
+        }
+    }
+    chosen.reverse();
+    (best[(items.len(), capacity)], chosen)
+}
+
+// length[i][j] is the longest common subsequence of a[i..] and b[j..], filled from the back so
+// the rebuild can walk forward and emit the subsequence in order
+pub fn longest_common_subsequence<T>(a: &[T], b: &[T]) -> (usize, Vec<T>)
+where
+    T: Eq + Clone,
+{
+    let mut length: Matrix<usize> = Matrix::new(a.len() + 1, b.len() + 1);
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            length[(i, j)] = if a[i] == b[j] {
+                length[(i + 1, j + 1)] + 1
+            } else {
+                length[(i + 1, j)].max(length[(i, j + 1)])
+            };
+        }
+    }
+    let mut subsequence = Vec::with_capacity(length[(0, 0)]);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            subsequence.push(a[i].clone());
+            (i, j) = (i + 1, j + 1);
+        } else if length[(i + 1, j)] >= length[(i, j + 1)] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    (length[(0, 0)], subsequence)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    Keep(T),
+    Replace(T, T),
+    Insert(T),
+    Delete(T),
+}
+
+// levenshtein distance, where every edit but Keep costs one, plus a script that turns from into
+// to when applied front to back. cost[i][j] is the distance from from[i..] to to[j..]
+pub fn edit_distance<T>(from: &[T], to: &[T]) -> (usize, Vec<Edit<T>>)
+where
+    T: Eq + Clone,
+{
+    let (n, m) = (from.len(), to.len());
+    let mut cost = Matrix::from_fn(n + 1, m + 1, |i, j| match (i == n, j == m) {
+        (true, _) => m - j,
+        (_, true) => n - i,
+        _ => 0,
+    });
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            cost[(i, j)] = if from[i] == to[j] {
+                cost[(i + 1, j + 1)]
+            } else {
+                1 + cost[(i + 1, j + 1)]
+                    .min(cost[(i + 1, j)])
+                    .min(cost[(i, j + 1)])
+            };
+        }
+    }
+    let mut script = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let edit = if i < n && j < m && from[i] == to[j] {
+            Edit::Keep(from[i].clone())
+        } else if i < n && j < m && cost[(i, j)] == cost[(i + 1, j + 1)] + 1 {
+            Edit::Replace(from[i].clone(), to[j].clone())
+        } else if i < n && cost[(i, j)] == cost[(i + 1, j)] + 1 {
+            Edit::Delete(from[i].clone())
+        } else {
+            Edit::Insert(to[j].clone())
+        };
+        match edit {
+            Edit::Keep(_) | Edit::Replace(..) => (i, j) = (i + 1, j + 1),
+            Edit::Delete(_) => i += 1,
+            Edit::Insert(_) => j += 1,
+        }
+        script.push(edit);
+    }
+    (cost[(0, 0)], script)
+}
@@ -0,0 +1,204 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+use crate::{
+    cache::{CacheStats, LruCache},
+    collections::Matrix,
+    hash::map::HashMap,
+};
+
+// somewhere to keep answers already worked out. a map keeps every one; a bounded cache keeps
+// a few and works the rest out again when asked
+pub trait Memo<A, R> {
+    fn recall(&mut self, arg: &A) -> Option<R>;
+    fn remember(&mut self, arg: A, value: R);
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn evictions(&self) -> usize {
+        0
+    }
+    fn clear(&mut self);
+}
+
+impl<A, R, S> Memo<A, R> for HashMap<A, R, S>
+where
+    A: Hash + Eq,
+    R: Clone,
+    S: BuildHasher,
+{
+    fn recall(&mut self, arg: &A) -> Option<R> {
+        self.get(arg).cloned()
+    }
+    fn remember(&mut self, arg: A, value: R) {
+        self.insert(arg, value);
+    }
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+    fn clear(&mut self) {
+        HashMap::clear(self);
+    }
+}
+
+impl<A, R> Memo<A, R> for LruCache<A, R>
+where
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    fn recall(&mut self, arg: &A) -> Option<R> {
+        self.get(arg).cloned()
+    }
+    fn remember(&mut self, arg: A, value: R) {
+        self.put(arg, value);
+    }
+    fn len(&self) -> usize {
+        LruCache::len(self)
+    }
+    fn evictions(&self) -> usize {
+        self.stats().evictions
+    }
+    fn clear(&mut self) {
+        LruCache::clear(self);
+    }
+}
+
+// a recursive function with its answers kept. f gets a handle to call for its recursive cases
+// in place of calling itself, and every call through the handle, like every call to call,
+// looks in the memo before running f:
+//
+//     let mut fib = Memoized::new(|fib, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+//     fib.call(90);
+//
+// the recursion is real, so its depth is whatever f's is
+pub struct Memoized<F, A, R, M = HashMap<A, R>>
+where
+    M: Memo<A, R>,
+{
+    f: F,
+    memo: M,
+    stats: CacheStats,
+    marker: PhantomData<fn(A) -> R>,
+}
+
+impl<F, A, R> Memoized<F, A, R>
+where
+    F: Fn(&mut dyn FnMut(A) -> R, A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    // every answer kept for as long as this lives
+    pub fn new(f: F) -> Self {
+        Self::with_memo(HashMap::new(), f)
+    }
+}
+
+impl<F, A, R> Memoized<F, A, R, LruCache<A, R>>
+where
+    F: Fn(&mut dyn FnMut(A) -> R, A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    // the capacity most recently used answers kept, which is enough when the recursion only
+    // ever looks a short way back; panics for a capacity of 0
+    pub fn bounded(capacity: usize, f: F) -> Self {
+        Self::with_memo(LruCache::new(capacity), f)
+    }
+}
+
+impl<F, A, R, M> Memoized<F, A, R, M>
+where
+    F: Fn(&mut dyn FnMut(A) -> R, A) -> R,
+    A: Clone,
+    R: Clone,
+    M: Memo<A, R>,
+{
+    pub fn with_memo(memo: M, f: F) -> Self {
+        Self {
+            f,
+            memo,
+            stats: CacheStats::default(),
+            marker: PhantomData,
+        }
+    }
+    pub fn call(&mut self, arg: A) -> R {
+        step(&self.f, &mut self.memo, &mut self.stats, arg)
+    }
+    // hits and misses over every lookup, the recursive ones included; each miss is one run of f
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            evictions: self.memo.evictions(),
+            ..self.stats
+        }
+    }
+    pub fn memo(&self) -> &M {
+        &self.memo
+    }
+    // forgets every answer, leaving the statistics alone
+    pub fn clear(&mut self) {
+        self.memo.clear();
+    }
+}
+
+fn step<F, A, R, M>(f: &F, memo: &mut M, stats: &mut CacheStats, arg: A) -> R
+where
+    F: Fn(&mut dyn FnMut(A) -> R, A) -> R,
+    A: Clone,
+    R: Clone,
+    M: Memo<A, R>,
+{
+    if let Some(value) = memo.recall(&arg) {
+        stats.hits += 1;
+        return value;
+    }
+    stats.misses += 1;
+    let value = f(&mut |arg| step(f, memo, stats, arg), arg.clone());
+    memo.remember(arg, value.clone());
+    value
+}
+
+// fib(n - 1) is asked for before fib(n - 2), and by the time it returns it has just looked at
+// fib(n - 2) and fib(n - 3) and made itself, so three remembered values keep every value to a
+// single run: O(n) time in O(1) memo space. None past fib(93), which overflows a u64
+pub fn fibonacci(n: u64) -> Option<u64> {
+    let mut fib = Memoized::bounded(3, |fib, n: u64| match n {
+        0 | 1 => n,
+        _ => fib(n - 1) + fib(n - 2),
+    });
+    (n <= 93).then(|| fib.call(n))
+}
+
+// paths from the top left cell to the bottom right one moving only right or down, through
+// cells marked true; zero for an empty grid or a closed corner. each cell's count is the sum of
+// the counts from the cells right of and below it, so the memo makes this O(rows * cols)
+// against the exponential number of paths. wraps on overflow, which an open square grid first
+// reaches at 67 cells a side
+pub fn grid_paths(open: &Matrix<bool>) -> u128 {
+    if open.is_empty() {
+        return 0;
+    }
+    let (rows, cols) = (open.rows(), open.cols());
+    let mut paths = Memoized::new(|paths, (row, col): (usize, usize)| {
+        if !open[(row, col)] {
+            return 0;
+        }
+        if (row, col) == (rows - 1, cols - 1) {
+            return 1;
+        }
+        let down = if row + 1 < rows {
+            paths((row + 1, col))
+        } else {
+            0
+        };
+        let right = if col + 1 < cols {
+            paths((row, col + 1))
+        } else {
+            0
+        };
+        u128::wrapping_add(down, right)
+    });
+    paths.call((0, 0))
+}
@@ -0,0 +1,12 @@
+/*
+
+    ALGORITHMS
+
+    problem-solving techniques, each with
+    a few classic problems worked through it
+
+*/
+
+pub mod backtracking;
+pub mod dp;
+pub mod memo;
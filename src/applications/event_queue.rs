@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{
+    collections::slot_map::{Key, SlotMap},
+    tree::heap::{BinaryHeap, HeapHandle},
+};
+
+// an event id is its slot map key, so cancelling an event that already fired is a harmless
+// None rather than hitting whatever was scheduled into the slot since
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId(Key);
+
+// the time, then a sequence number so that simultaneous events fire in the order they were
+// scheduled, then the slot that holds the event
+type Entry<T> = (T, u64, Key);
+
+// a discrete-event simulation clock: time only moves forward, and only as far as the caller
+// lets it, either to the next event or to a chosen time
+pub struct EventQueue<E, T = u64>
+where
+    T: Ord + Copy,
+{
+    // each event sits next to the handle of its heap entry, which is how cancel and reschedule
+    // find the entry without searching
+    events: SlotMap<(E, HeapHandle)>,
+    queue: BinaryHeap<Entry<T>>,
+    now: T,
+    sequence: u64,
+}
+
+impl<E, T> EventQueue<E, T>
+where
+    T: Ord + Copy,
+{
+    pub fn starting_at(now: T) -> Self {
+        Self {
+            events: SlotMap::new(),
+            queue: BinaryHeap::min(),
+            now,
+            sequence: 0,
+        }
+    }
+}
+
+impl<E, T> EventQueue<E, T>
+where
+    T: Ord + Copy + Default,
+{
+    pub fn new() -> Self {
+        Self::starting_at(T::default())
+    }
+}
+
+impl<E, T> EventQueue<E, T>
+where
+    T: Ord + Copy,
+{
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.events.len()
+    }
+    pub fn now(&self) -> T {
+        self.now
+    }
+    pub fn contains(&self, id: EventId) -> bool {
+        self.events.contains_key(id.0)
+    }
+    pub fn get(&self, id: EventId) -> Option<&E> {
+        self.events.get(id.0).map(|(event, _)| event)
+    }
+    pub fn time_of(&self, id: EventId) -> Option<T> {
+        let (_, handle) = self.events.get(id.0)?;
+        self.queue.get_by_handle(*handle).map(|(at, ..)| *at)
+    }
+    // scheduling at the current time is fine, the event fires on the next call that looks
+    pub fn schedule(&mut self, at: T, event: E) -> EventId {
+        assert!(at >= self.now, "cannot schedule an event in the past");
+        let sequence = self.next_sequence();
+        let queue = &mut self.queue;
+        let key = self
+            .events
+            .insert_with_key(|key| (event, queue.offer_with_handle((at, sequence, key))));
+        EventId(key)
+    }
+    pub fn cancel(&mut self, id: EventId) -> Option<E> {
+        let (event, handle) = self.events.remove(id.0)?;
+        self.queue.remove_handle(handle);
+        Some(event)
+    }
+    // moves a pending event in O(log n), returning its old time; it fires after anything already
+    // waiting at the new time
+    pub fn reschedule(&mut self, id: EventId, at: T) -> Option<T> {
+        assert!(at >= self.now, "cannot schedule an event in the past");
+        let &(_, handle) = self.events.get(id.0)?;
+        let sequence = self.next_sequence();
+        self.queue
+            .update(handle, (at, sequence, id.0))
+            .map(|(old, ..)| old)
+    }
+    pub fn peek_time(&self) -> Option<T> {
+        self.queue.peek().map(|(at, ..)| *at)
+    }
+    // fires the earliest event, moving the clock up to its time
+    pub fn next_event(&mut self) -> Option<(T, E)> {
+        let (at, _, key) = self.queue.poll()?;
+        let (event, _) = self
+            .events
+            .remove(key)
+            .expect("every queued entry holds an event");
+        self.now = at;
+        Some((at, event))
+    }
+    // fires everything due by then in time order and leaves the clock at then, even when the
+    // last event was earlier
+    pub fn advance_to(&mut self, then: T) -> Vec<(T, E)> {
+        assert!(then >= self.now, "time only moves forward");
+        let mut fired = Vec::new();
+        while self.peek_time().is_some_and(|at| at <= then) {
+            fired.extend(self.next_event());
+        }
+        self.now = then;
+        fired
+    }
+    // drops every pending event but keeps the clock where it is
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.queue.clear();
+    }
+    fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+}
+
+impl<E, T> Default for EventQueue<E, T>
+where
+    T: Ord + Copy + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, T> Debug for EventQueue<E, T>
+where
+    E: Debug,
+    T: Debug + Ord + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EventQueue")
+            .field("now", &self.now)
+            .field(
+                "pending",
+                &self
+                    .queue
+                    .iter_unsorted()
+                    .map(|(at, _, key)| (at, &self.events[*key].0))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::{adt::Stack, linked_list::singly::LinkedList};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+    // unary minus, told apart from Subtract by having no left operand
+    Negate,
+}
+
+impl Operator {
+    // negation binds looser than ^ so that -2^2 is -(2^2), as on paper
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Add | Self::Subtract => 1,
+            Self::Multiply | Self::Divide => 2,
+            Self::Negate => 3,
+            Self::Power => 4,
+        }
+    }
+    fn right_associative(self) -> bool {
+        matches!(self, Self::Power | Self::Negate)
+    }
+    fn arity(self) -> usize {
+        if self == Self::Negate { 1 } else { 2 }
+    }
+    fn apply(self, lhs: i64, rhs: i64) -> Result<i64, ExprError> {
+        let result = match self {
+            Self::Add => lhs.checked_add(rhs),
+            Self::Subtract => lhs.checked_sub(rhs),
+            Self::Multiply => lhs.checked_mul(rhs),
+            Self::Divide if rhs == 0 => return Err(ExprError::DivisionByZero),
+            Self::Divide => lhs.checked_div(rhs),
+            Self::Power => {
+                let exponent = u32::try_from(rhs).map_err(|_| ExprError::NegativeExponent)?;
+                lhs.checked_pow(exponent)
+            }
+            Self::Negate => rhs.checked_neg(),
+        };
+        result.ok_or(ExprError::Overflow)
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let symbol = match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Power => "^",
+            // postfix needs its own symbol, a bare - would read as subtraction
+            Self::Negate => "neg",
+        };
+        f.write_str(symbol)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Token {
+    Number(i64),
+    Operator(Operator),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{}", value),
+            Self::Operator(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+// positions are byte offsets into the source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprError {
+    UnexpectedChar { at: usize, found: char },
+    UnexpectedToken { at: usize },
+    UnbalancedParen { at: usize },
+    MissingOperand,
+    ExtraOperands { left: usize },
+    DivisionByZero,
+    NegativeExponent,
+    Overflow,
+}
+
+impl Display for ExprError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedChar { at, found } => {
+                write!(f, "unexpected character {:?} at {}", found, at)
+            }
+            Self::UnexpectedToken { at } => write!(f, "token at {} is out of place", at),
+            Self::UnbalancedParen { at } => write!(f, "parenthesis at {} has no partner", at),
+            Self::MissingOperand => write!(f, "an operator is missing an operand"),
+            Self::ExtraOperands { left } => write!(f, "{} values left over with no operator", left),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::NegativeExponent => write!(f, "negative exponents have no integer result"),
+            Self::Overflow => write!(f, "result does not fit in an i64"),
+        }
+    }
+}
+
+impl Error for ExprError {}
+
+// what waits on the operator stack: an operator, or an open paren remembered by position so
+// an unclosed one can be reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pending {
+    Operator(Operator),
+    Paren(usize),
+}
+
+fn closing(open: char) -> Option<char> {
+    match open {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        _ => None,
+    }
+}
+
+// the byte offset of the first bracket without a partner: a closer that matches nothing, or
+// the innermost opener left unclosed; every non-bracket character is ignored
+pub fn unbalanced_at_with<S>(text: &str) -> Option<usize>
+where
+    S: Stack<(char, usize)> + Default,
+{
+    let mut open = S::default();
+    for (at, c) in text.char_indices() {
+        if let Some(close) = closing(c) {
+            open.push((close, at));
+        } else if matches!(c, ')' | ']' | '}') {
+            match open.pop() {
+                Some((expected, _)) if expected == c => {}
+                _ => return Some(at),
+            }
+        }
+    }
+    open.pop().map(|(_, at)| at)
+}
+
+pub fn unbalanced_at(text: &str) -> Option<usize> {
+    unbalanced_at_with::<LinkedList<(char, usize)>>(text)
+}
+
+pub fn is_balanced(text: &str) -> bool {
+    unbalanced_at(text).is_none()
+}
+
+// shunting-yard: numbers go straight to the output, operators wait on the stack until one that
+// binds no tighter arrives, and a closing paren flushes everything back to its opener
+pub fn to_postfix_with<S>(infix: &str) -> Result<Vec<Token>, ExprError>
+where
+    S: Stack<Pending> + Default,
+{
+    let mut output = Vec::new();
+    let mut pending = S::default();
+    // true while the next token has to start an operand: a number, an opener or a unary minus
+    let mut expect_operand = true;
+    let mut chars = infix.char_indices().peekable();
+    while let Some((at, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '0'..='9' => {
+                if !expect_operand {
+                    return Err(ExprError::UnexpectedToken { at });
+                }
+                let mut value = i64::from(c as u8 - b'0');
+                while let Some(&(_, digit @ '0'..='9')) = chars.peek() {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(i64::from(digit as u8 - b'0')))
+                        .ok_or(ExprError::Overflow)?;
+                    chars.next();
+                }
+                output.push(Token::Number(value));
+                expect_operand = false;
+            }
+            '(' => {
+                if !expect_operand {
+                    return Err(ExprError::UnexpectedToken { at });
+                }
+                pending.push(Pending::Paren(at));
+            }
+            ')' => {
+                if expect_operand {
+                    return Err(ExprError::UnexpectedToken { at });
+                }
+                loop {
+                    match pending.pop() {
+                        Some(Pending::Operator(op)) => output.push(Token::Operator(op)),
+                        Some(Pending::Paren(_)) => break,
+                        None => return Err(ExprError::UnbalancedParen { at }),
+                    }
+                }
+            }
+            '-' if expect_operand => pending.push(Pending::Operator(Operator::Negate)),
+            '+' | '-' | '*' | '/' | '^' => {
+                if expect_operand {
+                    return Err(ExprError::UnexpectedToken { at });
+                }
+                let op = match c {
+                    '+' => Operator::Add,
+                    '-' => Operator::Subtract,
+                    '*' => Operator::Multiply,
+                    '/' => Operator::Divide,
+                    _ => Operator::Power,
+                };
+                while let Some(Pending::Operator(top)) = pending.peek()
+                    && (top.precedence() > op.precedence()
+                        || (top.precedence() == op.precedence() && !op.right_associative()))
+                {
+                    pending.pop();
+                    output.push(Token::Operator(top));
+                }
+                pending.push(Pending::Operator(op));
+                expect_operand = true;
+            }
+            found => return Err(ExprError::UnexpectedChar { at, found }),
+        }
+    }
+    if expect_operand {
+        return Err(ExprError::MissingOperand);
+    }
+    while let Some(top) = pending.pop() {
+        match top {
+            Pending::Operator(op) => output.push(Token::Operator(op)),
+            Pending::Paren(at) => return Err(ExprError::UnbalancedParen { at }),
+        }
+    }
+    Ok(output)
+}
+
+pub fn to_postfix(infix: &str) -> Result<Vec<Token>, ExprError> {
+    to_postfix_with::<LinkedList<Pending>>(infix)
+}
+
+pub fn eval_postfix_with<S>(postfix: &[Token]) -> Result<i64, ExprError>
+where
+    S: Stack<i64> + Default,
+{
+    let mut values = S::default();
+    for token in postfix {
+        match *token {
+            Token::Number(value) => values.push(value),
+            Token::Operator(op) => {
+                let rhs = values.pop().ok_or(ExprError::MissingOperand)?;
+                let lhs = match op.arity() {
+                    2 => values.pop().ok_or(ExprError::MissingOperand)?,
+                    _ => 0,
+                };
+                values.push(op.apply(lhs, rhs)?);
+            }
+        }
+    }
+    let result = values.pop().ok_or(ExprError::MissingOperand)?;
+    match values.len() {
+        0 => Ok(result),
+        left => Err(ExprError::ExtraOperands { left }),
+    }
+}
+
+pub fn eval_postfix(postfix: &[Token]) -> Result<i64, ExprError> {
+    eval_postfix_with::<LinkedList<i64>>(postfix)
+}
+
+pub fn evaluate(infix: &str) -> Result<i64, ExprError> {
+    eval_postfix(&to_postfix(infix)?)
+}
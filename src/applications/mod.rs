@@ -0,0 +1,14 @@
+/*
+
+    APPLICATIONS
+
+    classic problems solved on top of the
+    crate's own abstract data types
+
+*/
+
+pub mod event_queue;
+pub mod expr;
+pub mod palindrome;
+pub mod round_robin;
+pub mod scheduler;
@@ -0,0 +1,27 @@
+use crate::{adt::Deque, linked_list::doubly::LinkedList};
+
+// the items go into a deque and then come off both ends in pairs until they disagree or at most
+// one is left over; an empty sequence or a single item reads the same either way
+pub fn is_palindrome_with<D, T, I>(iter: I) -> bool
+where
+    D: Deque<T> + Default,
+    T: Clone + PartialEq,
+    I: IntoIterator<Item = T>,
+{
+    let mut deque = D::default();
+    iter.into_iter().for_each(|item| deque.push_back(item));
+    while deque.len() > 1 {
+        if deque.pop_front() != deque.pop_back() {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn is_palindrome<T, I>(iter: I) -> bool
+where
+    T: Clone + PartialEq,
+    I: IntoIterator<Item = T>,
+{
+    is_palindrome_with::<LinkedList<T>, T, I>(iter)
+}
@@ -0,0 +1,111 @@
+use core::fmt::Debug;
+
+use crate::{adt::Deque, linked_list::doubly::LinkedList};
+
+// tasks wait in a deque, oldest at the front; next takes the front as the current task and puts
+// the previous current task back at the end of the line, so every task gets a turn before any
+// gets a second. as an iterator it never ends while a task is left, so take or remove_current
+// is what stops it
+pub struct RoundRobin<T, D = LinkedList<T>>
+where
+    T: Clone,
+    D: Deque<T>,
+{
+    waiting: D,
+    current: Option<T>,
+}
+
+impl<T> RoundRobin<T>
+where
+    T: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tasks = Self::new();
+        iter.into_iter().for_each(|task| tasks.add(task));
+        tasks
+    }
+}
+
+impl<T, D> Default for RoundRobin<T, D>
+where
+    T: Clone,
+    D: Deque<T> + Default,
+{
+    fn default() -> Self {
+        Self {
+            waiting: D::default(),
+            current: None,
+        }
+    }
+}
+
+impl<T, D> RoundRobin<T, D>
+where
+    T: Clone,
+    D: Deque<T>,
+{
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    // the current task counts, even though it is not waiting
+    pub fn len(&self) -> usize {
+        self.waiting.len() + usize::from(self.current.is_some())
+    }
+    // joins the end of the line, so it runs after every task already waiting
+    pub fn add(&mut self, task: T) {
+        self.waiting.push_back(task);
+    }
+    // the task the last call to next handed out, unless it has since been removed
+    pub fn current(&self) -> Option<&T> {
+        self.current.as_ref()
+    }
+    // the current task is done and leaves the rotation; the next call to next moves on to the
+    // task that was waiting at the front
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current.take()
+    }
+    pub fn clear(&mut self) {
+        self.waiting.clear();
+        self.current = None;
+    }
+}
+
+impl<T, D> Iterator for RoundRobin<T, D>
+where
+    T: Clone,
+    D: Deque<T>,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if let Some(task) = self.current.take() {
+            self.waiting.push_back(task);
+        }
+        self.current = self.waiting.pop_front();
+        self.current.clone()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.is_empty() {
+            true => (0, Some(0)),
+            false => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T, D> Debug for RoundRobin<T, D>
+where
+    T: Clone + Debug,
+    D: Deque<T> + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RoundRobin")
+            .field("current", &self.current)
+            .field("waiting", &self.waiting)
+            .finish()
+    }
+}
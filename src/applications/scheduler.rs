@@ -0,0 +1,139 @@
+use core::{cmp::Ordering, fmt::Debug};
+
+use crate::{
+    collections::slot_map::{Key, SlotMap},
+    tree::heap::IndexedHeap,
+};
+
+// a task id is its slot map key, so an id kept after its task ran or was cancelled can never
+// reach whatever task takes the slot next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaskId(Key);
+
+// what the heap orders: the priority, then a sequence number so that equal priorities run in
+// the order they were scheduled, then the id that peek and run_next hand back
+type Entry<P> = (P, u64, TaskId);
+
+// tasks live in a slot map and their priorities in an indexed heap keyed by slot index, which
+// stays small because the slot map reuses vacated slots
+pub struct Scheduler<P, T>
+where
+    P: Ord,
+{
+    tasks: SlotMap<T>,
+    queue: IndexedHeap<Entry<P>>,
+    sequence: u64,
+}
+
+impl<P, T> Scheduler<P, T>
+where
+    P: Ord,
+{
+    // smallest priority first, which is earliest-deadline-first when priorities are deadlines
+    pub fn new() -> Self {
+        Self::with_order(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)))
+    }
+    pub fn highest_first() -> Self {
+        Self::with_order(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)))
+    }
+    fn with_order(cmp: fn(&Entry<P>, &Entry<P>) -> Ordering) -> Self {
+        Self {
+            tasks: SlotMap::new(),
+            queue: IndexedHeap::new(cmp),
+            sequence: 0,
+        }
+    }
+}
+
+impl<P, T> Scheduler<P, T>
+where
+    P: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.tasks.len()
+    }
+    pub fn contains(&self, id: TaskId) -> bool {
+        self.tasks.contains_key(id.0)
+    }
+    pub fn get(&self, id: TaskId) -> Option<&T> {
+        self.tasks.get(id.0)
+    }
+    pub fn get_mut(&mut self, id: TaskId) -> Option<&mut T> {
+        self.tasks.get_mut(id.0)
+    }
+    pub fn priority(&self, id: TaskId) -> Option<&P> {
+        self.tasks.get(id.0)?;
+        self.queue
+            .priority(id.0.index())
+            .map(|(priority, ..)| priority)
+    }
+    pub fn schedule(&mut self, task: T, priority: P) -> TaskId {
+        let id = TaskId(self.tasks.insert(task));
+        let sequence = self.next_sequence();
+        self.queue.insert(id.0.index(), (priority, sequence, id));
+        id
+    }
+    pub fn cancel(&mut self, id: TaskId) -> Option<T> {
+        let task = self.tasks.remove(id.0)?;
+        self.queue.remove(id.0.index());
+        Some(task)
+    }
+    // moves a waiting task to a new priority in O(log n), returning the old one; the task queues
+    // behind anything already waiting at the new priority
+    pub fn reprioritize(&mut self, id: TaskId, priority: P) -> Option<P> {
+        self.tasks.get(id.0)?;
+        let sequence = self.next_sequence();
+        self.queue
+            .change_priority(id.0.index(), (priority, sequence, id))
+            .map(|(old, ..)| old)
+    }
+    pub fn peek_next(&self) -> Option<(TaskId, &P, &T)> {
+        let (_, (priority, _, id)) = self.queue.peek()?;
+        self.tasks.get(id.0).map(|task| (*id, priority, task))
+    }
+    // hands back the most urgent task with its id and priority, for the caller to run
+    pub fn run_next(&mut self) -> Option<(TaskId, P, T)> {
+        let (_, (priority, _, id)) = self.queue.poll()?;
+        let task = self
+            .tasks
+            .remove(id.0)
+            .expect("every queued id holds a task");
+        Some((id, priority, task))
+    }
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+        self.queue.clear();
+    }
+    fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+}
+
+impl<P, T> Default for Scheduler<P, T>
+where
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, T> Debug for Scheduler<P, T>
+where
+    P: Debug + Ord,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.queue
+                    .iter_unsorted()
+                    .map(|(_, (priority, _, id))| (priority, &self.tasks[id.0])),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,186 @@
+/*
+
+    ARENA
+
+    values stored contiguously in a Vec
+    and addressed by u32 index handles,
+    with freed slots reused through a free list
+
+*/
+
+use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    num::NonZeroU32,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
+
+// the slot index plus one, so an Option<Handle> is still four bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Handle(NonZeroU32);
+
+impl Handle {
+    fn new(index: usize) -> Self {
+        let index = u32::try_from(index + 1).expect("arena is limited to u32::MAX - 1 slots");
+        Self(NonZeroU32::new(index).unwrap())
+    }
+    pub fn index(self) -> usize {
+        self.0.get() as usize - 1
+    }
+    // a possible handle as a plain number, zero for none, for structures that pack handles
+    // together; any number turns back into a handle, though it may name a vacant slot
+    pub(crate) fn to_bits(handle: Option<Self>) -> u32 {
+        handle.map_or(0, |handle| handle.0.get())
+    }
+    pub(crate) fn from_bits(bits: u32) -> Option<Self> {
+        NonZeroU32::new(bits).map(Self)
+    }
+}
+
+#[derive(Clone)]
+enum Slot<T> {
+    Occupied(T),
+    // links to the next vacant slot
+    Vacant(Option<Handle>),
+}
+
+// handles are not checked for staleness: freeing a value and allocating another may hand the same
+// handle out again, so owners must drop a handle when they free it
+#[derive(Clone)]
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Option<Handle>,
+    len: usize,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: None,
+            len: 0,
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: None,
+            len: 0,
+        }
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+    pub fn alloc(&mut self, value: T) -> Handle {
+        self.len += 1;
+        match self.free {
+            Some(handle) => {
+                let slot = &mut self.slots[handle.index()];
+                let Slot::Vacant(next) = *slot else {
+                    unreachable!("free list points at an occupied slot");
+                };
+                self.free = next;
+                *slot = Slot::Occupied(value);
+                handle
+            }
+            None => {
+                if self.slots.len() == self.slots.capacity() {
+                    crate::instrument::allocation();
+                }
+                let handle = Handle::new(self.slots.len());
+                self.slots.push(Slot::Occupied(value));
+                handle
+            }
+        }
+    }
+    pub fn free(&mut self, handle: Handle) -> Option<T> {
+        let slot = self.slots.get_mut(handle.index())?;
+        if let Slot::Vacant(_) = slot {
+            return None;
+        }
+        let Slot::Occupied(value) = core::mem::replace(slot, Slot::Vacant(self.free)) else {
+            unreachable!();
+        };
+        self.free = Some(handle);
+        self.len -= 1;
+        Some(value)
+    }
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        match self.slots.get(handle.index()) {
+            Some(Slot::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        match self.slots.get_mut(handle.index()) {
+            Some(Slot::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+    // the value reached through the buffer pointer rather than a borrow of every slot, so
+    // pointers to other values handed out earlier stay usable alongside it
+    pub(crate) fn get_ptr(&mut self, handle: Handle) -> Option<NonNull<T>> {
+        if handle.index() >= self.slots.len() {
+            return None;
+        }
+        match unsafe { &mut *self.slots.as_mut_ptr().add(handle.index()) } {
+            Slot::Occupied(value) => Some(NonNull::from(value)),
+            Slot::Vacant(_) => None,
+        }
+    }
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.get(handle).is_some()
+    }
+    // drops every value in one pass over the slots and keeps the allocation for reuse
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.free = None;
+        self.len = 0;
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match slot {
+                Slot::Occupied(value) => Some((Handle::new(index), value)),
+                Slot::Vacant(_) => None,
+            })
+    }
+}
+
+impl<T> Index<Handle> for Arena<T> {
+    type Output = T;
+    fn index(&self, handle: Handle) -> &Self::Output {
+        self.get(handle).expect("handle to a freed slot")
+    }
+}
+
+impl<T> IndexMut<Handle> for Arena<T> {
+    fn index_mut(&mut self, handle: Handle) -> &mut Self::Output {
+        self.get_mut(handle).expect("handle to a freed slot")
+    }
+}
+
+impl<T: Debug> Debug for Arena<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(handle, value)| (handle.index(), value)))
+            .finish()
+    }
+}
@@ -0,0 +1,289 @@
+/*
+
+    BINARY DECISION DIAGRAMS
+
+    boolean functions as graphs of if-then-else
+    nodes over a fixed variable order, reduced
+    and shared so that each function has
+    exactly one graph
+
+*/
+
+use alloc::{vec, vec::Vec};
+
+use crate::hash::map::HashMap;
+
+// a function held by a particular Bdd. the diagram is canonical, so two refs from the same Bdd
+// are equal exactly when their functions are, whatever formulas built them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ref(pub(crate) u32);
+
+impl Ref {
+    pub const FALSE: Ref = Ref(0);
+    pub const TRUE: Ref = Ref(1);
+    pub fn is_constant(self) -> bool {
+        self.0 < 2
+    }
+}
+
+// the variable at level tests low when false and high when true; the two terminals sit at the
+// level below every variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Node {
+    pub(crate) level: u32,
+    pub(crate) low: Ref,
+    pub(crate) high: Ref,
+}
+
+// the node table every function of a Bdd lives in. nodes are hash-consed through unique, so a
+// node with the same test and the same two children is never made twice, and none is made
+// whose children agree; this keeps the graph reduced, and lets functions share whatever parts
+// they have in common. ite remembers each answer in computed, which makes and, or and friends
+// polynomial in the sizes of their operands. nodes are never freed
+pub struct Bdd {
+    pub(crate) nodes: Vec<Node>,
+    unique: HashMap<Node, Ref>,
+    computed: HashMap<(Ref, Ref, Ref), Ref>,
+    // order[level] is the variable tested at that level, and levels[variable] the way back
+    pub(crate) order: Vec<usize>,
+    levels: Vec<u32>,
+}
+
+impl Bdd {
+    // variables 0 to vars - 1, tested in that order
+    pub fn new(vars: usize) -> Self {
+        Self::with_order((0..vars).collect())
+    }
+    // order lists every variable once, the one tested first at the front. the size of a
+    // diagram can depend heavily on it: (x0 & x1) | (x2 & x3) | ... is linear in the order
+    // 0, 1, 2, 3, ... and exponential in 0, 2, 4, ..., 1, 3, 5, ...
+    pub fn with_order(order: Vec<usize>) -> Self {
+        let vars = order.len();
+        assert!(
+            vars < 128,
+            "at most 127 variables are supported, {} asked for",
+            vars
+        );
+        let mut levels = vec![u32::MAX; vars];
+        for (level, &var) in order.iter().enumerate() {
+            assert!(
+                var < vars && levels[var] == u32::MAX,
+                "the order {:?} does not list each of the {} variables once",
+                order,
+                vars
+            );
+            levels[var] = level as u32;
+        }
+        let terminal = |value| Node {
+            level: vars as u32,
+            low: value,
+            high: value,
+        };
+        Self {
+            nodes: vec![terminal(Ref::FALSE), terminal(Ref::TRUE)],
+            unique: HashMap::new(),
+            computed: HashMap::new(),
+            order,
+            levels,
+        }
+    }
+}
+
+impl Bdd {
+    pub fn vars(&self) -> usize {
+        self.order.len()
+    }
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+    // nodes in the table, the two terminals included, whether or not any function still uses
+    // them
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn constant(&self, value: bool) -> Ref {
+        match value {
+            true => Ref::TRUE,
+            false => Ref::FALSE,
+        }
+    }
+    // the function that is just the variable
+    pub fn var(&mut self, var: usize) -> Ref {
+        assert!(
+            var < self.vars(),
+            "variable {} out of range for {} variables",
+            var,
+            self.vars()
+        );
+        self.make(self.levels[var], Ref::FALSE, Ref::TRUE)
+    }
+    // if f then g else h; every other operation is this with constants in some places
+    pub fn ite(&mut self, f: Ref, g: Ref, h: Ref) -> Ref {
+        for r in [f, g, h] {
+            self.check(r);
+        }
+        self.apply(f, g, h)
+    }
+    pub fn not(&mut self, f: Ref) -> Ref {
+        self.ite(f, Ref::FALSE, Ref::TRUE)
+    }
+    pub fn and(&mut self, f: Ref, g: Ref) -> Ref {
+        self.ite(f, g, Ref::FALSE)
+    }
+    pub fn or(&mut self, f: Ref, g: Ref) -> Ref {
+        self.ite(f, Ref::TRUE, g)
+    }
+    pub fn xor(&mut self, f: Ref, g: Ref) -> Ref {
+        let not_g = self.not(g);
+        self.ite(f, not_g, g)
+    }
+    pub fn implies(&mut self, f: Ref, g: Ref) -> Ref {
+        self.ite(f, g, Ref::TRUE)
+    }
+    // the variable f tests first with the functions for it being false and true, or None for a
+    // constant
+    pub fn decision(&self, f: Ref) -> Option<(usize, Ref, Ref)> {
+        self.check(f);
+        let node = self.nodes[f.0 as usize];
+        (!f.is_constant()).then(|| (self.order[node.level as usize], node.low, node.high))
+    }
+    // f under a value for each variable, indexed by variable
+    pub fn eval(&self, f: Ref, assignment: &[bool]) -> bool {
+        self.check(f);
+        assert_eq!(
+            assignment.len(),
+            self.vars(),
+            "{} values given for {} variables",
+            assignment.len(),
+            self.vars()
+        );
+        let mut at = f;
+        while !at.is_constant() {
+            let node = self.nodes[at.0 as usize];
+            at = match assignment[self.order[node.level as usize]] {
+                true => node.high,
+                false => node.low,
+            };
+        }
+        at == Ref::TRUE
+    }
+    // how many of the 2^vars assignments make f true. a level skipped on the way down is a
+    // variable f ignores there, which doubles the count below it
+    pub fn sat_count(&self, f: Ref) -> u128 {
+        self.check(f);
+        let mut counts = vec![None; self.nodes.len()];
+        counts[0] = Some(0);
+        counts[1] = Some(1);
+        self.count(f, &mut counts) << self.nodes[f.0 as usize].level
+    }
+    // one assignment that makes f true, taking false wherever either will do, or None if f is
+    // unsatisfiable. since the graph is reduced, every node but FALSE reaches TRUE somehow
+    pub fn any_sat(&self, f: Ref) -> Option<Vec<bool>> {
+        self.check(f);
+        if f == Ref::FALSE {
+            return None;
+        }
+        let mut assignment = vec![false; self.vars()];
+        let mut at = f;
+        while !at.is_constant() {
+            let node = self.nodes[at.0 as usize];
+            at = match node.low {
+                Ref::FALSE => {
+                    assignment[self.order[node.level as usize]] = true;
+                    node.high
+                }
+                low => low,
+            };
+        }
+        Some(assignment)
+    }
+    // the nodes f reaches, terminals included: the size of its graph
+    pub fn node_count(&self, f: Ref) -> usize {
+        self.reachable(&[f]).len()
+    }
+    // every node below the roots once each, in table order, so children before parents
+    pub(crate) fn reachable(&self, roots: &[Ref]) -> Vec<Ref> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack = Vec::new();
+        for &root in roots {
+            self.check(root);
+            stack.push(root);
+        }
+        while let Some(at) = stack.pop() {
+            if !core::mem::replace(&mut seen[at.0 as usize], true) && !at.is_constant() {
+                let node = self.nodes[at.0 as usize];
+                stack.extend([node.low, node.high]);
+            }
+        }
+        (0..self.nodes.len() as u32)
+            .map(Ref)
+            .filter(|r| seen[r.0 as usize])
+            .collect()
+    }
+    fn apply(&mut self, f: Ref, g: Ref, h: Ref) -> Ref {
+        match (f, g, h) {
+            (Ref::TRUE, _, _) => return g,
+            (Ref::FALSE, _, _) => return h,
+            _ if g == h => return g,
+            (_, Ref::TRUE, Ref::FALSE) => return f,
+            _ => {}
+        }
+        if let Some(&done) = self.computed.get(&(f, g, h)) {
+            return done;
+        }
+        // split on whichever variable comes first among the three
+        let level = [f, g, h]
+            .iter()
+            .map(|r| self.nodes[r.0 as usize].level)
+            .min()
+            .unwrap();
+        let (f0, f1) = self.cofactors(f, level);
+        let (g0, g1) = self.cofactors(g, level);
+        let (h0, h1) = self.cofactors(h, level);
+        let low = self.apply(f0, g0, h0);
+        let high = self.apply(f1, g1, h1);
+        let result = self.make(level, low, high);
+        self.computed.insert((f, g, h), result);
+        result
+    }
+    // f with the variable at level fixed false and true; f not testing it is both
+    fn cofactors(&self, f: Ref, level: u32) -> (Ref, Ref) {
+        let node = self.nodes[f.0 as usize];
+        match node.level == level {
+            true => (node.low, node.high),
+            false => (f, f),
+        }
+    }
+    fn make(&mut self, level: u32, low: Ref, high: Ref) -> Ref {
+        if low == high {
+            return low;
+        }
+        let node = Node { level, low, high };
+        let nodes = &mut self.nodes;
+        *self.unique.get_or_insert_with(node, || {
+            nodes.push(node);
+            Ref(nodes.len() as u32 - 1)
+        })
+    }
+    // assignments to the variables from f's level down
+    fn count(&self, f: Ref, counts: &mut [Option<u128>]) -> u128 {
+        if let Some(count) = counts[f.0 as usize] {
+            return count;
+        }
+        let node = self.nodes[f.0 as usize];
+        let mut count = 0;
+        for child in [node.low, node.high] {
+            let skipped = self.nodes[child.0 as usize].level - node.level - 1;
+            count += self.count(child, counts) << skipped;
+        }
+        counts[f.0 as usize] = Some(count);
+        count
+    }
+    fn check(&self, f: Ref) {
+        assert!(
+            (f.0 as usize) < self.nodes.len(),
+            "{:?} is not in this diagram",
+            f
+        );
+    }
+}
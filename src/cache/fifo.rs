@@ -0,0 +1,134 @@
+/*
+
+    FIRST-IN-FIRST-OUT CACHE
+
+    the lru layout with recency frozen at
+    insertion: neither lookups nor updates
+    move an entry, so the oldest goes first
+
+*/
+
+use core::{borrow::Borrow, fmt::Debug, hash::Hash};
+
+use super::{CacheStats, LruCache, slots::Iter};
+
+pub struct FifoCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    inner: LruCache<K, V>,
+}
+
+impl<K, V> FifoCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: LruCache::new(capacity),
+        }
+    }
+    pub fn with_eviction_callback<F>(capacity: usize, callback: F) -> Self
+    where
+        F: FnMut(K, V) + 'static,
+    {
+        Self {
+            inner: LruCache::with_eviction_callback(capacity, callback),
+        }
+    }
+}
+
+impl<K, V> FifoCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.inner.set_capacity(capacity);
+    }
+    pub fn stats(&self) -> CacheStats {
+        self.inner.stats()
+    }
+    pub fn reset_stats(&mut self) {
+        self.inner.reset_stats();
+    }
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.lookup(key, false).map(|value| &*value)
+    }
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.lookup(key, false)
+    }
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.peek(key)
+    }
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.contains(key)
+    }
+    // overwriting a key keeps its original place in line
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.inner.store(key, value, false)
+    }
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.remove(key)
+    }
+    // the oldest entry, next in line for eviction
+    pub fn peek_oldest(&self) -> Option<(&K, &V)> {
+        self.inner.peek_lru()
+    }
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+    // from newest to oldest
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.inner.iter()
+    }
+}
+
+impl<K, V> Debug for FifoCache<K, V>
+where
+    K: Hash + Eq + Clone + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a FifoCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,238 @@
+/*
+
+    LEAST-FREQUENTLY-USED CACHE
+
+    one intrusive list per use count, each
+    ordered by recency, so the victim is the
+    least recent entry of the rarest bucket
+
+*/
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{borrow::Borrow, fmt::Debug, hash::Hash};
+
+use super::{
+    CacheStats,
+    slots::{Entry, Order},
+};
+use crate::hash::map::HashMap;
+
+pub struct LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    map: HashMap<K, usize>,
+    entries: Vec<Entry<K, V>>,
+    // only non-empty buckets are kept
+    buckets: HashMap<usize, Order>,
+    min_uses: usize,
+    capacity: usize,
+    stats: CacheStats,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K, V> LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        Self {
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            buckets: HashMap::new(),
+            min_uses: 0,
+            capacity,
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+    pub fn with_eviction_callback<F>(capacity: usize, callback: F) -> Self
+    where
+        F: FnMut(K, V) + 'static,
+    {
+        let mut cache = Self::new(capacity);
+        cache.on_evict = Some(Box::new(callback));
+        cache
+    }
+}
+
+impl<K, V> LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn unlink(&mut self, slot: usize) {
+        let uses = self.entries[slot].uses;
+        let order = self.buckets.get_mut(&uses).unwrap();
+        order.unlink(&mut self.entries, slot);
+        if order.is_empty() {
+            self.buckets.remove(&uses);
+        }
+    }
+    fn link(&mut self, slot: usize) {
+        let uses = self.entries[slot].uses;
+        let order = self.buckets.get_or_insert_with(uses, Order::default);
+        order.push_front(&mut self.entries, slot);
+    }
+    // moves an entry up into the next bucket, dragging min_uses along if it emptied the lowest
+    fn touch(&mut self, slot: usize) {
+        let uses = self.entries[slot].uses;
+        self.unlink(slot);
+        if self.min_uses == uses && !self.buckets.contains_key(&uses) {
+            self.min_uses += 1;
+        }
+        self.entries[slot].uses += 1;
+        self.link(slot);
+    }
+    fn take(&mut self, slot: usize) -> (K, V) {
+        self.unlink(slot);
+        let last = self.entries.len() - 1;
+        if slot != last {
+            let order = self.buckets.get_mut(&self.entries[last].uses).unwrap();
+            order.relocate(&mut self.entries, last, slot);
+            *self.map.get_mut(&self.entries[last].key).unwrap() = slot;
+        }
+        let entry = self.entries.swap_remove(slot);
+        self.map.remove(&entry.key);
+        (entry.key, entry.value)
+    }
+    // taking the last entry of the lowest bucket leaves min_uses pointing at nothing. put never
+    // needs the next lowest count, since its new entry brings the lowest back to one, so only
+    // remove and set_capacity pay for the search
+    fn find_min_uses(&mut self) {
+        if !self.buckets.contains_key(&self.min_uses) {
+            self.min_uses = self.buckets.keys().copied().min().unwrap_or(0);
+        }
+    }
+    fn evict(&mut self) {
+        let Some(victim) = self
+            .buckets
+            .get(&self.min_uses)
+            .and_then(|order| order.tail)
+        else {
+            return;
+        };
+        let (key, value) = self.take(victim);
+        self.stats.evictions += 1;
+        if let Some(callback) = self.on_evict.as_mut() {
+            callback(key, value);
+        }
+    }
+}
+
+impl<K, V> LfuCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn set_capacity(&mut self, capacity: usize) {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        self.capacity = capacity;
+        while self.len() > capacity {
+            self.evict();
+            self.find_min_uses();
+        }
+    }
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+    // how many times key has been stored or looked up since it was inserted
+    pub fn uses<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&slot| self.entries[slot].uses)
+    }
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(&slot) = self.map.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        self.touch(slot);
+        Some(&mut self.entries[slot].value)
+    }
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&slot| &self.entries[slot].value)
+    }
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+    // overwriting a key counts as a use; a new key starts at one use
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&slot) = self.map.get(&key) {
+            self.touch(slot);
+            return Some(core::mem::replace(&mut self.entries[slot].value, value));
+        }
+        if self.len() == self.capacity {
+            self.evict();
+        }
+        let slot = self.entries.len();
+        self.map.insert(key.clone(), slot);
+        self.entries.push(Entry::new(key, value));
+        self.link(slot);
+        self.min_uses = 1;
+        None
+    }
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &slot = self.map.get(key)?;
+        let (_, value) = self.take(slot);
+        self.find_min_uses();
+        Some(value)
+    }
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.buckets.clear();
+        self.min_uses = 0;
+    }
+    // in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|entry| (&entry.key, &entry.value))
+    }
+}
+
+impl<K, V> Debug for LfuCache<K, V>
+where
+    K: Hash + Eq + Clone + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
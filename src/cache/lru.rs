@@ -0,0 +1,229 @@
+/*
+
+    LEAST-RECENTLY-USED CACHE
+
+    a hash map from keys to slots, with the
+    slots threaded into an intrusive doubly
+    linked list from most to least recent
+
+*/
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{borrow::Borrow, fmt::Debug, hash::Hash};
+
+use super::{
+    CacheStats,
+    slots::{Entry, Iter, Order},
+};
+use crate::hash::map::HashMap;
+
+pub struct LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    map: HashMap<K, usize>,
+    // kept dense: removing an entry moves the last slot into its place
+    entries: Vec<Entry<K, V>>,
+    order: Order,
+    capacity: usize,
+    stats: CacheStats,
+    on_evict: Option<Box<dyn FnMut(K, V)>>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        Self {
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            order: Order::default(),
+            capacity,
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+    // the callback receives each entry pushed out to make room, but not ones removed explicitly
+    pub fn with_eviction_callback<F>(capacity: usize, callback: F) -> Self
+    where
+        F: FnMut(K, V) + 'static,
+    {
+        let mut cache = Self::new(capacity);
+        cache.on_evict = Some(Box::new(callback));
+        cache
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn touch(&mut self, slot: usize) {
+        if self.order.head != Some(slot) {
+            self.order.unlink(&mut self.entries, slot);
+            self.order.push_front(&mut self.entries, slot);
+        }
+    }
+    // takes a slot out of both the list and the map, moving the last slot into the hole
+    fn take(&mut self, slot: usize) -> (K, V) {
+        self.order.unlink(&mut self.entries, slot);
+        let last = self.entries.len() - 1;
+        if slot != last {
+            self.order.relocate(&mut self.entries, last, slot);
+            *self.map.get_mut(&self.entries[last].key).unwrap() = slot;
+        }
+        let entry = self.entries.swap_remove(slot);
+        self.map.remove(&entry.key);
+        (entry.key, entry.value)
+    }
+    fn evict(&mut self) {
+        if let Some(tail) = self.order.tail {
+            let (key, value) = self.take(tail);
+            self.stats.evictions += 1;
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(key, value);
+            }
+        }
+    }
+    // shared with FifoCache, which counts lookups the same way but never reorders on them
+    pub(crate) fn lookup<Q>(&mut self, key: &Q, touch: bool) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(&slot) = self.map.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        if touch {
+            self.touch(slot);
+        }
+        Some(&mut self.entries[slot].value)
+    }
+    pub(crate) fn store(&mut self, key: K, value: V, touch: bool) -> Option<V> {
+        if let Some(&slot) = self.map.get(&key) {
+            if touch {
+                self.touch(slot);
+            }
+            return Some(core::mem::replace(&mut self.entries[slot].value, value));
+        }
+        if self.len() == self.capacity {
+            self.evict();
+        }
+        let slot = self.entries.len();
+        self.map.insert(key.clone(), slot);
+        self.entries.push(Entry::new(key, value));
+        self.order.push_front(&mut self.entries, slot);
+        None
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    // shrinking evicts from the least recent end until the cache fits
+    pub fn set_capacity(&mut self, capacity: usize) {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        self.capacity = capacity;
+        while self.len() > capacity {
+            self.evict();
+        }
+    }
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+    // a lookup counts towards the statistics and marks the entry as most recently used
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.lookup(key, true).map(|value| &*value)
+    }
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.lookup(key, true)
+    }
+    // reads without touching recency or statistics
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&slot| &self.entries[slot].value)
+    }
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+    // returns the previous value for key, if there was one
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.store(key, value, true)
+    }
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &slot = self.map.get(key)?;
+        Some(self.take(slot).1)
+    }
+    // the entry that would be evicted next
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.order
+            .tail
+            .map(|tail| (&self.entries[tail].key, &self.entries[tail].value))
+    }
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.order = Order::default();
+    }
+    // from most to least recently used
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.order.iter(&self.entries, self.len())
+    }
+}
+
+impl<K, V> Debug for LruCache<K, V>
+where
+    K: Hash + Eq + Clone + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,29 @@
+pub mod fifo;
+pub mod lfu;
+pub mod lru;
+mod slots;
+
+pub use fifo::FifoCache;
+pub use lfu::LfuCache;
+pub use lru::LruCache;
+pub use slots::Iter;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+}
+
+impl CacheStats {
+    pub fn lookups(&self) -> usize {
+        self.hits + self.misses
+    }
+    // the fraction of lookups that found their key, or zero before the first lookup
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use core::iter::FusedIterator;
+
+// cache entries live densely in a Vec and are threaded into intrusive doubly linked lists by index;
+// an Order holds the two ends of one such list, so a policy can keep as many lists as it needs
+pub(crate) struct Entry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+    pub(crate) uses: usize,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl<K, V> Entry<K, V> {
+    pub(crate) fn new(key: K, value: V) -> Self {
+        Self {
+            key,
+            value,
+            uses: 1,
+            prev: None,
+            next: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Order {
+    pub(crate) head: Option<usize>,
+    pub(crate) tail: Option<usize>,
+}
+
+impl Order {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    pub(crate) fn unlink<K, V>(&mut self, entries: &mut [Entry<K, V>], slot: usize) {
+        let (prev, next) = (entries[slot].prev, entries[slot].next);
+        match prev {
+            Some(prev) => entries[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => entries[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+    pub(crate) fn push_front<K, V>(&mut self, entries: &mut [Entry<K, V>], slot: usize) {
+        entries[slot].prev = None;
+        entries[slot].next = self.head;
+        match self.head {
+            Some(head) => entries[head].prev = Some(slot),
+            None => self.tail = Some(slot),
+        }
+        self.head = Some(slot);
+    }
+    // repoints the neighbours of the linked entry at `from` to `to`, ahead of a swap_remove
+    // moving it there
+    pub(crate) fn relocate<K, V>(&mut self, entries: &mut [Entry<K, V>], from: usize, to: usize) {
+        let (prev, next) = (entries[from].prev, entries[from].next);
+        match prev {
+            Some(prev) => entries[prev].next = Some(to),
+            None => self.head = Some(to),
+        }
+        match next {
+            Some(next) => entries[next].prev = Some(to),
+            None => self.tail = Some(to),
+        }
+    }
+    pub(crate) fn iter<'a, K, V>(&self, entries: &'a [Entry<K, V>], len: usize) -> Iter<'a, K, V> {
+        Iter {
+            entries,
+            current: self.head,
+            remaining: len,
+        }
+    }
+}
+
+// walks one list from head to tail
+pub struct Iter<'a, K, V> {
+    entries: &'a [Entry<K, V>],
+    current: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = &self.entries[self.current?];
+        self.current = entry.next;
+        self.remaining -= 1;
+        Some((&entry.key, &entry.value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
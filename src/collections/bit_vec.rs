@@ -0,0 +1,309 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+const WORD: usize = u64::BITS as usize;
+
+// bits packed 64 to a word, bit i at position i % 64 of word i / 64; bits past len in the last
+// word are always kept clear
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    pub fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(bits.div_ceil(WORD)),
+            len: 0,
+        }
+    }
+    // len copies of bit, e.g. an all-clear visited set
+    pub fn repeat(bit: bool, len: usize) -> Self {
+        let fill = if bit { u64::MAX } else { 0 };
+        let mut bits = Self {
+            words: alloc::vec![fill; len.div_ceil(WORD)],
+            len,
+        };
+        bits.clear_tail();
+        bits
+    }
+}
+
+impl BitVec {
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(WORD) {
+            self.words.push(0);
+        }
+        self.words[self.len / WORD] |= u64::from(bit) << (self.len % WORD);
+        self.len += 1;
+    }
+    pub fn pop(&mut self) -> Option<bool> {
+        self.len = self.len.checked_sub(1)?;
+        let (word, mask) = (self.len / WORD, 1 << (self.len % WORD));
+        let bit = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        if self.len.is_multiple_of(WORD) {
+            self.words.pop();
+        }
+        Some(bit)
+    }
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| self.words[index / WORD] >> (index % WORD) & 1 == 1)
+    }
+    pub fn set(&mut self, index: usize, bit: bool) {
+        assert!(
+            index < self.len,
+            "bit {} out of range for {} bits",
+            index,
+            self.len
+        );
+        let mask = 1 << (index % WORD);
+        if bit {
+            self.words[index / WORD] |= mask;
+        } else {
+            self.words[index / WORD] &= !mask;
+        }
+    }
+    pub fn toggle(&mut self, index: usize) {
+        assert!(
+            index < self.len,
+            "bit {} out of range for {} bits",
+            index,
+            self.len
+        );
+        self.words[index / WORD] ^= 1 << (index % WORD);
+    }
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&word| word != 0)
+    }
+    // the number of set bits before index, so rank(len) is count_ones
+    pub fn rank(&self, index: usize) -> usize {
+        assert!(
+            index <= self.len,
+            "rank {} out of range for {} bits",
+            index,
+            self.len
+        );
+        let (full, rest) = (index / WORD, index % WORD);
+        let below: usize = self.words[..full]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+        match rest {
+            0 => below,
+            _ => below + (self.words[full] & ((1 << rest) - 1)).count_ones() as usize,
+        }
+    }
+    // the index of the set bit with rank k, counting from 0; rank(select(k)) == k
+    pub fn select(&self, k: usize) -> Option<usize> {
+        let mut remaining = k;
+        for (i, &word) in self.words.iter().enumerate() {
+            let ones = word.count_ones() as usize;
+            if remaining < ones {
+                // clear the lowest set bits until the one wanted is lowest
+                let mut word = word;
+                (0..remaining).for_each(|_| word &= word - 1);
+                return Some(i * WORD + word.trailing_zeros() as usize);
+            }
+            remaining -= ones;
+        }
+        None
+    }
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            bits: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+    // the indices of the set bits, ascending, skipping a clear word at a time
+    pub fn iter_ones(&self) -> Ones<'_> {
+        Ones {
+            words: &self.words,
+            word: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+    fn clear_tail(&mut self) {
+        if let Some(last) = self.words.last_mut()
+            && !self.len.is_multiple_of(WORD)
+        {
+            *last &= (1 << (self.len % WORD)) - 1;
+        }
+    }
+    fn zip_words(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            self.len, other.len,
+            "bitwise ops need bit vectors of the same length"
+        );
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, &b)| *a = op(*a, b));
+    }
+}
+
+// written as 0s and 1s, first bit first
+impl Debug for BitVec {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.iter()
+            .try_for_each(|bit| f.write_str(if bit { "1" } else { "0" }))
+    }
+}
+
+macro_rules! bitwise {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $f:expr) => {
+        impl $assign<&BitVec> for BitVec {
+            fn $assign_method(&mut self, other: &BitVec) {
+                self.zip_words(other, $f);
+            }
+        }
+
+        impl $op for &BitVec {
+            type Output = BitVec;
+            fn $method(self, other: Self) -> BitVec {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+
+        impl $op for BitVec {
+            type Output = BitVec;
+            fn $method(mut self, other: Self) -> BitVec {
+                self.$assign_method(&other);
+                self
+            }
+        }
+    };
+}
+
+// both sides need the same length; and, or and xor of two clear tails leave the tail clear
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+
+impl Not for BitVec {
+    type Output = BitVec;
+    fn not(mut self) -> BitVec {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.clear_tail();
+        self
+    }
+}
+
+impl Not for &BitVec {
+    type Output = BitVec;
+    fn not(self) -> BitVec {
+        !self.clone()
+    }
+}
+
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|bit| self.push(bit));
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = Self::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+pub struct Iter<'a> {
+    bits: &'a BitVec,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.bits.get(self.front - 1)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.bits.get(self.back)
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+impl<'a> IntoIterator for &'a BitVec {
+    type Item = bool;
+    type IntoIter = Iter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Ones<'a> {
+    words: &'a [u64],
+    word: usize,
+    // what is left of words[word]
+    current: u64,
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.word += 1;
+            self.current = *self.words.get(self.word)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.word * WORD + bit)
+    }
+}
+
+impl<'a> FusedIterator for Ones<'a> {}
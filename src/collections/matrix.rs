@@ -0,0 +1,223 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::{Add, Index, IndexMut, Mul},
+    slice,
+};
+
+// a dense rows x cols grid stored row-major, so (r, c) lives at r * cols + c and every row is a
+// contiguous slice
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows,
+            cols,
+            data: vec![value; rows * cols],
+        }
+    }
+    pub fn new(rows: usize, cols: usize) -> Self
+    where
+        T: Clone + Default,
+    {
+        Self::filled(rows, cols, T::default())
+    }
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "from_vec needs rows * cols items");
+        Self { rows, cols, data }
+    }
+    // every row has to be as long as the first
+    pub fn from<R, I>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = T>,
+    {
+        let (mut count, mut cols, mut data) = (0, 0, Vec::new());
+        for row in rows {
+            data.extend(row);
+            if count == 0 {
+                cols = data.len();
+            }
+            count += 1;
+            assert_eq!(data.len(), count * cols, "every row needs the same length");
+        }
+        Self {
+            rows: count,
+            cols,
+            data,
+        }
+    }
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let data = (0..rows * cols).map(|i| f(i / cols, i % cols)).collect();
+        Self { rows, cols, data }
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.data[row * self.cols + col])
+    }
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        (row < self.rows && col < self.cols).then(|| &mut self.data[row * self.cols + col])
+    }
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(
+            row < self.rows,
+            "row {} out of range for {} rows",
+            row,
+            self.rows
+        );
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(
+            row < self.rows,
+            "row {} out of range for {} rows",
+            row,
+            self.rows
+        );
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+    // rows top to bottom; a matrix with no columns still yields its empty rows
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+    pub fn col(&self, col: usize) -> impl ExactSizeIterator<Item = &T> {
+        assert!(
+            col < self.cols,
+            "column {} out of range for {} columns",
+            col,
+            self.cols
+        );
+        (0..self.rows).map(move |row| &self.data[row * self.cols + col])
+    }
+    // every item in row-major order
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.cols, self.rows, |row, col| self[(col, row)].clone())
+    }
+    // walks i, k, j so the inner loop runs along a row of both other and the result, which keeps
+    // it on contiguous memory
+    pub fn multiply(&self, other: &Self) -> Self
+    where
+        T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+    {
+        assert_eq!(
+            self.cols, other.rows,
+            "cannot multiply {}x{} by {}x{}",
+            self.rows, self.cols, other.rows, other.cols
+        );
+        let mut product = Self::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let scale = &self.data[i * self.cols + k];
+                for (out, item) in product.row_mut(i).iter_mut().zip(other.row(k)) {
+                    *out = out.clone() + scale.clone() * item.clone();
+                }
+            }
+        }
+        product
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) out of range for a {}x{} matrix",
+                row, col, self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) out of range for a {}x{} matrix",
+                row, col, rows, cols
+            )
+        })
+    }
+}
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Clone + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn mul(self, other: Self) -> Self::Output {
+        self.multiply(other)
+    }
+}
+
+impl<T: Debug> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+// one row per line with the columns right-aligned to the widest item
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let cells: Vec<String> = self.iter().map(|item| format!("{}", item)).collect();
+        let width = cells
+            .iter()
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0);
+        for row in cells.chunks(self.cols.max(1)).take(self.rows) {
+            let line: Vec<String> = row.iter().map(|cell| format!("{:>width$}", cell)).collect();
+            writeln!(f, "{}", line.join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+/*
+
+    COLLECTIONS
+
+    general-purpose containers that do not fit
+    the list, tree or hash families
+
+*/
+
+pub mod bit_vec;
+pub mod matrix;
+pub mod ring_buffer;
+pub mod slot_map;
+pub mod union_find;
+pub mod vec_stack;
+
+pub use bit_vec::BitVec;
+pub use matrix::Matrix;
+pub use ring_buffer::RingBuffer;
+pub use slot_map::{Key, SlotMap};
+pub use union_find::{DepthStats, Strategy, UnionFind};
+pub use vec_stack::VecStack;
@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, iter::FusedIterator};
+
+// a fixed-size queue over one allocation made up front: head chases tail around the buffer, so
+// nothing ever moves and nothing grows
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a ring buffer needs room for at least one item"
+        );
+        Self {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T> RingBuffer<T> {
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len
+    }
+    // hands the item back when there is no room, so the caller decides what backpressure means
+    pub fn try_push(&mut self, data: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(data);
+        }
+        let tail = self.slot(self.len);
+        self.slots[tail] = Some(data);
+        self.len += 1;
+        Ok(())
+    }
+    // never refuses: when full, the oldest item makes way and is returned
+    pub fn push_overwrite(&mut self, data: T) -> Option<T> {
+        let evicted = if self.is_full() { self.pop() } else { None };
+        let _ = self.try_push(data);
+        evicted
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let data = self.slots[self.head].take();
+        self.head = self.slot(1);
+        self.len -= 1;
+        data
+    }
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|last| self.get(last))
+    }
+    // counted from the oldest item
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index < self.len {
+            true => self.slots[self.slot(index)].as_ref(),
+            false => None,
+        }
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buffer: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.head = 0;
+    }
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % self.capacity()
+    }
+}
+
+impl<T> Clone for RingBuffer<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            head: self.head,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Debug for RingBuffer<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter<'a, T> {
+    buffer: &'a RingBuffer<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.buffer.get(self.front - 1)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.buffer.get(self.back)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
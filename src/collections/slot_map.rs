@@ -0,0 +1,288 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    iter::{Enumerate, FusedIterator},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+// a key names a slot and the generation it was issued in; removing a value bumps the slot's
+// generation, so keys handed out before the removal no longer match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    index: u32,
+    generation: u32,
+}
+
+impl Key {
+    pub fn index(self) -> usize {
+        self.index as usize
+    }
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+#[derive(Clone)]
+enum Entry<T> {
+    Occupied(T),
+    // links to the next vacant slot
+    Vacant(Option<u32>),
+}
+
+#[derive(Clone)]
+struct Slot<T> {
+    generation: u32,
+    entry: Entry<T>,
+}
+
+#[derive(Clone)]
+pub struct SlotMap<T> {
+    slots: Vec<Slot<T>>,
+    free: Option<u32>,
+    len: usize,
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotMap<T> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: None,
+            len: 0,
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: None,
+            len: 0,
+        }
+    }
+}
+
+impl<T> SlotMap<T> {
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+    pub fn insert(&mut self, value: T) -> Key {
+        self.insert_with_key(|_| value)
+    }
+    // hands the key to the constructor first, for values that need to know their own key
+    pub fn insert_with_key<F>(&mut self, make: F) -> Key
+    where
+        F: FnOnce(Key) -> T,
+    {
+        let index = match self.free {
+            Some(index) => index,
+            None => {
+                let index =
+                    u32::try_from(self.slots.len()).expect("slot map is limited to u32::MAX slots");
+                self.slots.push(Slot {
+                    generation: 0,
+                    entry: Entry::Vacant(None),
+                });
+                index
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        let Entry::Vacant(next) = slot.entry else {
+            unreachable!("free list points at an occupied slot");
+        };
+        let key = Key {
+            index,
+            generation: slot.generation,
+        };
+        slot.entry = Entry::Occupied(make(key));
+        self.free = next;
+        self.len += 1;
+        key
+    }
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        self.get(key)?;
+        let slot = &mut self.slots[key.index()];
+        let Entry::Occupied(value) = core::mem::replace(&mut slot.entry, Entry::Vacant(self.free))
+        else {
+            unreachable!();
+        };
+        // a slot whose generation would wrap is retired instead of reused, so no stale key can
+        // ever match it again
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            self.free = Some(key.index);
+        } else {
+            slot.entry = Entry::Vacant(None);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+    pub fn get(&self, key: Key) -> Option<&T> {
+        match self.slots.get(key.index()) {
+            Some(Slot {
+                generation,
+                entry: Entry::Occupied(value),
+            }) if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.slots.get_mut(key.index()) {
+            Some(Slot {
+                generation,
+                entry: Entry::Occupied(value),
+            }) if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.get(key).is_some()
+    }
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(Key, &mut T) -> bool,
+    {
+        let keys: Vec<Key> = self
+            .iter_mut()
+            .filter_map(|(key, value)| (!predicate(key, value)).then_some(key))
+            .collect();
+        keys.into_iter().for_each(|key| {
+            self.remove(key);
+        });
+    }
+    // removes every value but keeps the slots, so the keys issued so far all go stale
+    pub fn clear(&mut self) {
+        self.retain(|_, _| false);
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.slots.iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            slots: self.slots.iter_mut().enumerate(),
+            remaining: self.len,
+        }
+    }
+    pub fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<T> Index<Key> for SlotMap<T> {
+    type Output = T;
+    fn index(&self, key: Key) -> &Self::Output {
+        self.get(key).expect("stale or foreign slot map key")
+    }
+}
+
+impl<T> IndexMut<Key> for SlotMap<T> {
+    fn index_mut(&mut self, key: Key) -> &mut Self::Output {
+        self.get_mut(key).expect("stale or foreign slot map key")
+    }
+}
+
+impl<T: Debug> Debug for SlotMap<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter<'a, T> {
+    slots: Enumerate<slice::Iter<'a, Slot<T>>>,
+    remaining: usize,
+}
+
+pub struct IterMut<'a, T> {
+    slots: Enumerate<slice::IterMut<'a, Slot<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Key, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|(index, slot)| match &slot.entry {
+            Entry::Occupied(value) => {
+                self.remaining -= 1;
+                let key = Key {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                Some((key, value))
+            }
+            Entry::Vacant(_) => None,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (Key, &'a mut T);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|(index, slot)| match &mut slot.entry {
+            Entry::Occupied(value) => {
+                self.remaining -= 1;
+                let key = Key {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                Some((key, value))
+            }
+            Entry::Vacant(_) => None,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a SlotMap<T> {
+    type Item = (Key, &'a T);
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SlotMap<T> {
+    type Item = (Key, &'a mut T);
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for SlotMap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut map = Self::new();
+        iter.into_iter().for_each(|value| {
+            map.insert(value);
+        });
+        map
+    }
+}
@@ -0,0 +1,268 @@
+use alloc::vec::Vec;
+
+// which root ends up on top when two sets merge; either keeps trees O(log n) deep even with no
+// path compression
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Strategy {
+    // the root of the larger set wins
+    #[default]
+    BySize,
+    // the root of the taller tree wins, where rank bounds the height and only counts merges
+    // of equal rank
+    ByRank,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthStats {
+    // parent links from an element up to its root, roots being at depth 0
+    pub max: usize,
+    pub total: usize,
+    // histogram[d] elements sit at depth d
+    pub histogram: Vec<usize>,
+}
+
+impl DepthStats {
+    pub fn mean(&self) -> f64 {
+        match self.histogram.iter().sum::<usize>() {
+            0 => 0.0,
+            count => self.total as f64 / count as f64,
+        }
+    }
+}
+
+// a merge as rollback needs it: the root that was hung below another, and whether that bumped
+// the other's rank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Merge {
+    child: usize,
+    rank_bumped: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    // both are only meaningful at roots
+    size: Vec<usize>,
+    rank: Vec<u32>,
+    sets: usize,
+    strategy: Strategy,
+    // None when path compression is on, since compression rewrites links nothing could undo
+    history: Option<Vec<Merge>>,
+}
+
+impl UnionFind {
+    pub fn new(elements: usize) -> Self {
+        Self::build(elements, Strategy::BySize, None)
+    }
+    pub fn by_rank(elements: usize) -> Self {
+        Self::build(elements, Strategy::ByRank, None)
+    }
+    // no path compression, so find is O(log n) instead of near constant, but every union can be
+    // undone in O(1); the usual tool for offline dynamic connectivity
+    pub fn with_rollback(elements: usize, strategy: Strategy) -> Self {
+        Self::build(elements, strategy, Some(Vec::new()))
+    }
+    fn build(elements: usize, strategy: Strategy, history: Option<Vec<Merge>>) -> Self {
+        Self {
+            parent: (0..elements).collect(),
+            size: alloc::vec![1; elements],
+            rank: alloc::vec![0; elements],
+            sets: elements,
+            strategy,
+            history,
+        }
+    }
+}
+
+impl Default for UnionFind {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl UnionFind {
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+    pub fn compresses_paths(&self) -> bool {
+        self.history.is_none()
+    }
+    // a new singleton set, returning its element
+    pub fn add(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.rank.push(0);
+        self.sets += 1;
+        element
+    }
+    // the root without touching any links, so it works through a shared reference
+    pub fn root(&self, element: usize) -> usize {
+        self.check(element);
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        root
+    }
+    pub fn find(&mut self, element: usize) -> usize {
+        let root = self.root(element);
+        if self.compresses_paths() {
+            let mut at = element;
+            while at != root {
+                at = core::mem::replace(&mut self.parent[at], root);
+            }
+        }
+        root
+    }
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+    // false when a and b were already together
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // ties go to a, so the first argument's root survives between equals
+        let a_wins = match self.strategy {
+            Strategy::BySize => self.size[a] >= self.size[b],
+            Strategy::ByRank => self.rank[a] >= self.rank[b],
+        };
+        let (root, child) = if a_wins { (a, b) } else { (b, a) };
+        let rank_bumped = self.rank[root] == self.rank[child];
+        if rank_bumped {
+            self.rank[root] += 1;
+        }
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.sets -= 1;
+        if let Some(history) = &mut self.history {
+            history.push(Merge { child, rank_bumped });
+        }
+        true
+    }
+    // how many merges have happened, to hand back to rollback_to later; failed unions and add
+    // are not merges, and rolling back leaves added elements in place
+    pub fn checkpoint(&self) -> usize {
+        self.history().len()
+    }
+    // undoes the latest merge that has not been undone, false when there is none
+    pub fn rollback(&mut self) -> bool {
+        let Some(Merge { child, rank_bumped }) = self.history_mut().pop() else {
+            return false;
+        };
+        let root = self.parent[child];
+        self.parent[child] = child;
+        self.size[root] -= self.size[child];
+        if rank_bumped {
+            self.rank[root] -= 1;
+        }
+        self.sets += 1;
+        true
+    }
+    pub fn rollback_to(&mut self, checkpoint: usize) {
+        let merges = self.checkpoint();
+        assert!(
+            checkpoint <= merges,
+            "checkpoint {} is ahead of the {} merges made",
+            checkpoint,
+            merges
+        );
+        while self.checkpoint() > checkpoint {
+            self.rollback();
+        }
+    }
+    pub fn depth(&self, element: usize) -> usize {
+        self.check(element);
+        let (mut at, mut depth) = (element, 0);
+        while self.parent[at] != at {
+            at = self.parent[at];
+            depth += 1;
+        }
+        depth
+    }
+    // every element's depth in one pass: each walk stops at the first element whose depth is
+    // already known and fills in the path behind it
+    pub fn depth_stats(&self) -> DepthStats {
+        let mut depths: Vec<Option<usize>> = alloc::vec![None; self.len()];
+        let mut path = Vec::new();
+        for element in 0..self.len() {
+            let mut at = element;
+            let mut depth = loop {
+                if let Some(depth) = depths[at] {
+                    break depth;
+                }
+                if self.parent[at] == at {
+                    depths[at] = Some(0);
+                    break 0;
+                }
+                path.push(at);
+                at = self.parent[at];
+            };
+            while let Some(at) = path.pop() {
+                depth += 1;
+                depths[at] = Some(depth);
+            }
+        }
+        let mut stats = DepthStats {
+            max: 0,
+            total: 0,
+            histogram: Vec::new(),
+        };
+        for depth in depths.into_iter().flatten() {
+            if stats.histogram.len() <= depth {
+                stats.histogram.resize(depth + 1, 0);
+            }
+            stats.histogram[depth] += 1;
+            stats.max = stats.max.max(depth);
+            stats.total += depth;
+        }
+        stats
+    }
+    // each set's elements in increasing order, sets ordered by their smallest element
+    pub fn sets(&self) -> Vec<Vec<usize>> {
+        let mut index = alloc::vec![usize::MAX; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+        for element in 0..self.len() {
+            let root = self.root(element);
+            if index[root] == usize::MAX {
+                index[root] = sets.len();
+                sets.push(Vec::new());
+            }
+            sets[index[root]].push(element);
+        }
+        sets
+    }
+    fn check(&self, element: usize) {
+        assert!(
+            element < self.len(),
+            "element {} out of range for {} elements",
+            element,
+            self.len()
+        );
+    }
+    fn history(&self) -> &Vec<Merge> {
+        self.history
+            .as_ref()
+            .expect("rollback needs a union-find built with_rollback")
+    }
+    fn history_mut(&mut self) -> &mut Vec<Merge> {
+        self.history
+            .as_mut()
+            .expect("rollback needs a union-find built with_rollback")
+    }
+}
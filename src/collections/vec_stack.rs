@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+// the top is the end of the vector, so push and pop never shift anything and a stack built
+// with_capacity does not allocate again until it outgrows that
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VecStack<T> {
+    items: Vec<T>,
+}
+
+impl<T> VecStack<T> {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T> Default for VecStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> VecStack<T> {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+    pub fn reserve(&mut self, additional: usize) {
+        self.items.reserve(additional);
+    }
+    pub fn shrink_to_fit(&mut self) {
+        self.items.shrink_to_fit();
+    }
+    pub fn push(&mut self, data: T) {
+        self.items.push(data);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.last_mut()
+    }
+    // top to bottom, the order pops would come out in
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.items.iter().rev()
+    }
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+    // bottom first
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> Debug for VecStack<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for VecStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+// the last item collected ends up on top, as if each had been pushed in turn
+impl<T> FromIterator<T> for VecStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+/*
+
+    BLOCKING QUEUE
+
+    a VecDeque behind a Mutex, with a Condvar
+    waking consumers that wait for an item
+
+*/
+
+use alloc::collections::VecDeque;
+use std::{
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+pub struct BlockingQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    available: Condvar,
+}
+
+impl<T> BlockingQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Mutex::new(VecDeque::new()),
+            available: Condvar::new(),
+        }
+    }
+    // a thread that panicked while holding the lock cannot have left the deque half-updated,
+    // so poisoning is ignored rather than passed on to every other user
+    fn lock(&self) -> MutexGuard<'_, VecDeque<T>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+    pub fn offer(&self, data: T) {
+        self.lock().push_back(data);
+        self.available.notify_one();
+    }
+    // returns immediately, with None if the queue is empty
+    pub fn poll(&self) -> Option<T> {
+        self.lock().pop_front()
+    }
+    // waits up to timeout for an item to arrive
+    pub fn poll_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut items = self.lock();
+        loop {
+            if let Some(data) = items.pop_front() {
+                return Some(data);
+            }
+            // an unrepresentable deadline waits as long as take would
+            let remaining = match deadline {
+                Some(deadline) => deadline
+                    .checked_duration_since(Instant::now())
+                    .filter(|left| !left.is_zero())?,
+                None => Duration::MAX,
+            };
+            items = self
+                .available
+                .wait_timeout(items, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+    // waits for as long as it takes
+    pub fn take(&self) -> T {
+        let mut items = self.lock();
+        loop {
+            if let Some(data) = items.pop_front() {
+                return data;
+            }
+            items = self
+                .available
+                .wait(items)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+}
+
+impl<T> Default for BlockingQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for BlockingQueue<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.lock().iter()).finish()
+    }
+}
@@ -0,0 +1,18 @@
+/*
+
+    CONCURRENT COLLECTIONS
+
+    structures meant to be shared between threads
+    behind an Arc, so every operation takes &self
+
+*/
+
+#[cfg(feature = "std")]
+pub mod blocking;
+pub mod mpsc;
+pub mod treiber;
+
+#[cfg(feature = "std")]
+pub use blocking::BlockingQueue;
+pub use mpsc::MpscQueue;
+pub use treiber::TreiberStack;
@@ -0,0 +1,126 @@
+/*
+
+    LOCK-FREE MPSC QUEUE
+
+    a michael-scott style linked queue with a stub node,
+    narrowed to a single consumer at a time: producers
+    append with one atomic swap and never wait, and since
+    only the consumer frees nodes, no producer can be
+    left holding a pointer to freed memory
+
+*/
+
+use alloc::boxed::Box;
+use core::{
+    cell::UnsafeCell,
+    hint, ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+struct Node<T> {
+    data: Option<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(data: Option<T>) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            data,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+pub struct MpscQueue<T> {
+    // the most recently offered node, where producers append
+    head: AtomicPtr<Node<T>>,
+    // the stub whose successor is the next item out; only touched while holding `consuming`
+    tail: UnsafeCell<*mut Node<T>>,
+    consuming: AtomicBool,
+}
+
+// SAFETY: items move between threads, hence T: Send; producers only swap head and publish
+// next, and tail is read or written only by the thread that won the consuming flag
+unsafe impl<T: Send> Send for MpscQueue<T> {}
+unsafe impl<T: Send> Sync for MpscQueue<T> {}
+
+impl<T> MpscQueue<T> {
+    pub fn new() -> Self {
+        let stub = Node::new(None);
+        Self {
+            head: AtomicPtr::new(stub),
+            tail: UnsafeCell::new(stub),
+            consuming: AtomicBool::new(false),
+        }
+    }
+    pub fn offer(&self, data: T) {
+        let node = Node::new(Some(data));
+        let prev = self.head.swap(node, Ordering::AcqRel);
+        // SAFETY: prev is at or after the consumer's stub, and the consumer never frees a node
+        // before its next has been published, which only this producer does
+        unsafe { (*prev).next.store(node, Ordering::Release) };
+    }
+    // polls may come from several threads, but they take turns: a second one spins until the
+    // first is done, which keeps the consumer side single-threaded as the design requires
+    fn consume<R>(&self, f: impl FnOnce(&mut *mut Node<T>) -> R) -> R {
+        while self
+            .consuming
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        // SAFETY: winning the flag grants exclusive access to tail until it is released
+        let result = f(unsafe { &mut *self.tail.get() });
+        self.consuming.store(false, Ordering::Release);
+        result
+    }
+    pub fn poll(&self) -> Option<T> {
+        self.consume(|tail| {
+            let stub = *tail;
+            // SAFETY: the stub stays allocated until the consumer moves past it
+            let mut next = unsafe { (*stub).next.load(Ordering::Acquire) };
+            if next.is_null() {
+                if self.head.load(Ordering::Acquire) == stub {
+                    return None;
+                }
+                // a producer has swapped itself in but not yet linked its node; that is a
+                // window of two instructions, so wait it out rather than report empty
+                while next.is_null() {
+                    hint::spin_loop();
+                    next = unsafe { (*stub).next.load(Ordering::Acquire) };
+                }
+            }
+            *tail = next;
+            // SAFETY: next now becomes the stub, so its data is ours to take; the old stub is
+            // unreachable for producers, all of whom appended after it
+            unsafe {
+                let data = (*next).data.take();
+                drop(Box::from_raw(stub));
+                data
+            }
+        })
+    }
+    // a snapshot that may be stale by the time it is read
+    pub fn is_empty(&self) -> bool {
+        self.consume(|tail| self.head.load(Ordering::Acquire) == *tail)
+    }
+}
+
+impl<T> Default for MpscQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for MpscQueue<T> {
+    fn drop(&mut self) {
+        // with &mut self no other thread can be mid-offer, so the chain is fully linked
+        let mut current = *self.tail.get_mut();
+        while !current.is_null() {
+            // SAFETY: every node in the chain came from Box::into_raw and is visited once
+            let node = unsafe { Box::from_raw(current) };
+            current = node.next.load(Ordering::Relaxed);
+        }
+    }
+}
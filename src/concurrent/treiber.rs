@@ -0,0 +1,205 @@
+/*
+
+    TREIBER STACK
+
+    a lock-free stack of heap nodes swung in and out with
+    compare-and-swap on the head; unlinked nodes are set
+    aside and freed once no thread inside pop or peek
+    could still be holding them, which stands in for
+    hazard pointers or epochs at the cost of deferring
+    reclamation while those calls keep overlapping
+
+*/
+
+use alloc::boxed::Box;
+use core::{
+    hint,
+    mem::ManuallyDrop,
+    ptr,
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering::SeqCst},
+};
+
+struct Node<T> {
+    data: ManuallyDrop<T>,
+    next: AtomicPtr<Node<T>>,
+    // peeks currently cloning data, which a pop of this node has to wait out
+    readers: AtomicUsize,
+}
+
+pub struct TreiberStack<T> {
+    head: AtomicPtr<Node<T>>,
+    // never below the true length: pushes count before linking and pops after unlinking
+    len: AtomicUsize,
+    // threads inside pop or peek, any of which may hold a pointer to an unlinked node
+    active: AtomicUsize,
+    // unlinked nodes, already emptied of their data, waiting for active to reach zero
+    retired: AtomicPtr<Node<T>>,
+}
+
+// SAFETY: values are moved between threads, so T: Send; peek clones through a shared
+// reference from any thread, which is why it additionally asks for T: Sync
+unsafe impl<T: Send> Send for TreiberStack<T> {}
+unsafe impl<T: Send> Sync for TreiberStack<T> {}
+
+impl<T> TreiberStack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            active: AtomicUsize::new(0),
+            retired: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.head.load(SeqCst).is_null()
+    }
+    // a snapshot that concurrent pushes and pops may already have changed
+    pub fn len(&self) -> usize {
+        self.len.load(SeqCst)
+    }
+    pub fn push(&self, data: T) {
+        self.len.fetch_add(1, SeqCst);
+        let node = Box::into_raw(Box::new(Node {
+            data: ManuallyDrop::new(data),
+            next: AtomicPtr::new(self.head.load(SeqCst)),
+            readers: AtomicUsize::new(0),
+        }));
+        // SAFETY: node is not shared until the exchange succeeds
+        let next = unsafe { &(*node).next };
+        while let Err(head) =
+            self.head
+                .compare_exchange_weak(next.load(SeqCst), node, SeqCst, SeqCst)
+        {
+            next.store(head, SeqCst);
+        }
+    }
+    pub fn pop(&self) -> Option<T> {
+        self.active.fetch_add(1, SeqCst);
+        let mut node = self.head.load(SeqCst);
+        loop {
+            if node.is_null() {
+                self.active.fetch_sub(1, SeqCst);
+                return None;
+            }
+            // SAFETY: node was reachable after active was raised, so it cannot be freed yet
+            let next = unsafe { (*node).next.load(SeqCst) };
+            match self.head.compare_exchange_weak(node, next, SeqCst, SeqCst) {
+                Ok(_) => break,
+                Err(head) => node = head,
+            }
+        }
+        self.len.fetch_sub(1, SeqCst);
+        // SAFETY: node is unlinked and ours alone to empty; any peek that saw it as head either
+        // registered as a reader first, and is waited for here, or sees the new head and backs off
+        let data = unsafe {
+            while (*node).readers.load(SeqCst) != 0 {
+                hint::spin_loop();
+            }
+            ManuallyDrop::into_inner(ptr::read(&raw const (*node).data))
+        };
+        self.retire(node);
+        Some(data)
+    }
+    // clones the top item; a pop of that item waits for the clone to finish
+    pub fn peek(&self) -> Option<T>
+    where
+        T: Clone + Sync,
+    {
+        self.active.fetch_add(1, SeqCst);
+        let data = loop {
+            let node = self.head.load(SeqCst);
+            if node.is_null() {
+                break None;
+            }
+            // SAFETY: as in pop, active keeps node allocated; data is only read while it is
+            // still the head with this thread registered as a reader
+            unsafe {
+                (*node).readers.fetch_add(1, SeqCst);
+                if self.head.load(SeqCst) == node {
+                    let data = T::clone(&(*node).data);
+                    (*node).readers.fetch_sub(1, SeqCst);
+                    break Some(data);
+                }
+                (*node).readers.fetch_sub(1, SeqCst);
+            }
+        };
+        self.active.fetch_sub(1, SeqCst);
+        data
+    }
+    // frees node if this is the only thread that could see it, along with everything retired
+    // before it if no other thread arrives meanwhile; otherwise leaves them for whoever is last
+    fn retire(&self, node: *mut Node<T>) {
+        if self.active.load(SeqCst) == 1 {
+            let pending = self.retired.swap(ptr::null_mut(), SeqCst);
+            if self.active.fetch_sub(1, SeqCst) == 1 {
+                // SAFETY: nobody was active while the list was claimed, and nodes in it are
+                // unlinked, so no thread arriving afterwards can reach them
+                unsafe { free_chain(pending) };
+            } else if !pending.is_null() {
+                // SAFETY: the claimed chain is owned by this thread until handed back
+                unsafe { self.chain_retired(pending) };
+            }
+            // SAFETY: no other thread was active when node was unlinked and checked
+            unsafe { drop(Box::from_raw(node)) };
+        } else {
+            // SAFETY: node is unlinked and emptied, and retired() never frees while we are active
+            unsafe {
+                (*node).next.store(ptr::null_mut(), SeqCst);
+                self.chain_retired(node);
+            }
+            self.active.fetch_sub(1, SeqCst);
+        }
+    }
+    // pushes a whole chain of unlinked nodes, terminated by null, onto the retired list
+    unsafe fn chain_retired(&self, first: *mut Node<T>) {
+        let mut last = first;
+        unsafe {
+            loop {
+                let next = (*last).next.load(SeqCst);
+                if next.is_null() {
+                    break;
+                }
+                last = next;
+            }
+            let mut head = self.retired.load(SeqCst);
+            loop {
+                (*last).next.store(head, SeqCst);
+                match self
+                    .retired
+                    .compare_exchange_weak(head, first, SeqCst, SeqCst)
+                {
+                    Ok(_) => break,
+                    Err(actual) => head = actual,
+                }
+            }
+        }
+    }
+}
+
+// frees a null-terminated chain of nodes whose data has already been moved out
+unsafe fn free_chain<T>(mut node: *mut Node<T>) {
+    while !node.is_null() {
+        let boxed = unsafe { Box::from_raw(node) };
+        node = boxed.next.load(SeqCst);
+    }
+}
+
+impl<T> Default for TreiberStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for TreiberStack<T> {
+    fn drop(&mut self) {
+        let mut node = *self.head.get_mut();
+        while !node.is_null() {
+            // SAFETY: with &mut self no other thread is inside the stack, and linked nodes
+            // still own their data
+            let mut boxed = unsafe { Box::from_raw(node) };
+            node = *boxed.next.get_mut();
+            unsafe { ManuallyDrop::drop(&mut boxed.data) };
+        }
+        unsafe { free_chain(*self.retired.get_mut()) };
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use crate::{generate::Rng, graph::Graph};
+
+// G(n, p): every possible edge is present independently with the given probability; self loops
+// are never generated, and a directed graph can get both (a, b) and (b, a)
+pub fn erdos_renyi(rng: &mut Rng, vertices: usize, probability: f64, directed: bool) -> Graph {
+    let mut graph = match directed {
+        true => Graph::directed(vertices),
+        false => Graph::undirected(vertices),
+    };
+    for from in 0..vertices {
+        let start = if directed { 0 } else { from + 1 };
+        for to in start..vertices {
+            if from != to && rng.chance(probability) {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+    graph
+}
+
+// the undirected rows x cols lattice, vertex r * cols + c joined to its right and lower
+// neighbours
+pub fn grid(rows: usize, cols: usize) -> Graph {
+    let mut graph = Graph::undirected(rows * cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            let vertex = r * cols + c;
+            if c + 1 < cols {
+                graph.add_edge(vertex, vertex + 1);
+            }
+            if r + 1 < rows {
+                graph.add_edge(vertex, vertex + cols);
+            }
+        }
+    }
+    graph
+}
+
+// edges only run forward along a hidden shuffled order, so the result is acyclic without the
+// vertex numbers giving that order away
+pub fn random_dag(rng: &mut Rng, vertices: usize, probability: f64) -> Graph {
+    let mut order: Vec<usize> = (0..vertices).collect();
+    rng.shuffle(&mut order);
+    let mut graph = Graph::directed(vertices);
+    for (i, &from) in order.iter().enumerate() {
+        for &to in &order[i + 1..] {
+            if rng.chance(probability) {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+    graph
+}
+
+// an undirected tree: along a hidden shuffled order each vertex joins one vertex before it, chosen
+// uniformly, which keeps the expected depth logarithmic with no vertex number hinting at the shape
+pub fn random_tree(rng: &mut Rng, vertices: usize) -> Graph {
+    let mut order: Vec<usize> = (0..vertices).collect();
+    rng.shuffle(&mut order);
+    let mut graph = Graph::undirected(vertices);
+    for i in 1..vertices {
+        let parent = order[rng.below(i)];
+        graph.add_edge(parent, order[i]);
+    }
+    graph
+}
+
+// the same edges, in the same order, each given a weight drawn from 1..=max
+pub fn weighted<W>(rng: &mut Rng, graph: &Graph<W>, max: u64) -> Graph<u64> {
+    assert!(max > 0, "weights are drawn from 1..=max");
+    let mut out = match graph.is_directed() {
+        true => Graph::directed(graph.vertex_count()),
+        false => Graph::undirected(graph.vertex_count()),
+    };
+    for (from, to, _) in graph.edges() {
+        out.add_weighted_edge(from, to, 1 + rng.next_u64() % max);
+    }
+    out
+}
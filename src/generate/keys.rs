@@ -0,0 +1,46 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::generate::Rng;
+
+// the order keys 0..n are inserted in decides the shape of an unbalanced search tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    // each subtree's median before either half, giving the minimum height
+    Balanced,
+    // a single right spine, height n
+    Sorted,
+    // a single left spine, height n
+    Reversed,
+    // smallest, largest, next smallest, ...: still height n, but the path turns at every node
+    ZigZag,
+    // expected height around 3 log n
+    Random,
+}
+
+pub fn bst_keys(rng: &mut Rng, count: usize, shape: Shape) -> Vec<usize> {
+    match shape {
+        Shape::Balanced => {
+            let mut keys = Vec::with_capacity(count);
+            let mut ranges = VecDeque::from([(0, count)]);
+            while let Some((lo, hi)) = ranges.pop_front() {
+                if lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    keys.push(mid);
+                    ranges.push_back((lo, mid));
+                    ranges.push_back((mid + 1, hi));
+                }
+            }
+            keys
+        }
+        Shape::Sorted => (0..count).collect(),
+        Shape::Reversed => (0..count).rev().collect(),
+        Shape::ZigZag => (0..count)
+            .map(|i| if i % 2 == 0 { i / 2 } else { count - 1 - i / 2 })
+            .collect(),
+        Shape::Random => {
+            let mut keys: Vec<usize> = (0..count).collect();
+            rng.shuffle(&mut keys);
+            keys
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/*
+
+    GENERATE
+
+    seeded random workloads for tests, benchmarks and examples: the same seed always gives
+    the same graph, key order or operation sequence, so a failure can be replayed
+
+*/
+
+pub mod graphs;
+pub mod keys;
+pub mod ops;
+pub mod rng;
+
+pub use graphs::{erdos_renyi, grid, random_dag, random_tree, weighted};
+pub use keys::{Shape, bst_keys};
+pub use ops::{Mix, Op, operations};
+pub use rng::Rng;
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+
+use crate::generate::Rng;
+
+// the operations every set-like structure in the crate shares
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op<K> {
+    Insert(K),
+    Remove(K),
+    Contains(K),
+}
+
+// relative weights of each operation; all zero is rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mix {
+    pub insert: u32,
+    pub remove: u32,
+    pub contains: u32,
+}
+
+impl Default for Mix {
+    // inserts outrun removes so the structure grows over a run
+    fn default() -> Self {
+        Self {
+            insert: 4,
+            remove: 2,
+            contains: 4,
+        }
+    }
+}
+
+// count operations on keys drawn from 0..keys; a small key space keeps removes and lookups
+// hitting keys that are actually present
+pub fn operations(rng: &mut Rng, count: usize, keys: usize, mix: Mix) -> Vec<Op<usize>> {
+    let total = mix.insert as usize + mix.remove as usize + mix.contains as usize;
+    assert!(
+        total > 0,
+        "the mix needs at least one operation with a weight"
+    );
+    (0..count)
+        .map(|_| {
+            let pick = rng.below(total);
+            let key = rng.below(keys);
+            if pick < mix.insert as usize {
+                Op::Insert(key)
+            } else if pick < mix.insert as usize + mix.remove as usize {
+                Op::Remove(key)
+            } else {
+                Op::Contains(key)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+// xorshift64*: tiny, fast and plenty for spreading workloads around, but not for anything
+// that has to be unpredictable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the state must never be zero, and nearby seeds should not start out alike
+        Self {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Rng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+    // uniform in 0..bound by scaling rather than taking a remainder, which would favour the
+    // low values whenever bound does not divide 2^64
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot pick below 0");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+    pub fn range(&mut self, range: core::ops::Range<usize>) -> usize {
+        assert!(range.start < range.end, "cannot pick from an empty range");
+        range.start + self.below(range.end - range.start)
+    }
+    // uniform in [0, 1) from the top 53 bits, all an f64 mantissa holds
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.unit() < probability
+    }
+    // fisher-yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        match items.len() {
+            0 => None,
+            len => Some(&items[self.below(len)]),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::collections::Matrix;
+
+// vertices are 0..vertex_count. every edge is stored once in an edge list, and each adjacency
+// entry names its neighbour and the edge it came by, so parallel edges stay distinguishable;
+// an undirected edge shows up in both endpoints' lists, a self-loop just once
+#[derive(Clone, PartialEq, Eq)]
+pub struct Graph<W = ()> {
+    directed: bool,
+    adjacency: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize, W)>,
+}
+
+impl<W> Graph<W> {
+    pub fn undirected(vertices: usize) -> Self {
+        Self::with_vertices(vertices, false)
+    }
+    pub fn directed(vertices: usize) -> Self {
+        Self::with_vertices(vertices, true)
+    }
+    fn with_vertices(vertices: usize, directed: bool) -> Self {
+        let mut adjacency = Vec::with_capacity(vertices);
+        adjacency.resize_with(vertices, Vec::new);
+        Self {
+            directed,
+            adjacency,
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+    pub fn vertex_count(&self) -> usize {
+        self.adjacency.len()
+    }
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+    pub fn add_vertex(&mut self) -> usize {
+        self.adjacency.push(Vec::new());
+        self.adjacency.len() - 1
+    }
+    // returns the new edge's id, its index in edges()
+    pub fn add_edge(&mut self, from: usize, to: usize) -> usize
+    where
+        W: Default,
+    {
+        self.add_weighted_edge(from, to, W::default())
+    }
+    pub fn add_weighted_edge(&mut self, from: usize, to: usize, weight: W) -> usize {
+        let count = self.vertex_count();
+        assert!(
+            from < count && to < count,
+            "edge {} -> {} out of range for {} vertices",
+            from,
+            to,
+            count
+        );
+        let id = self.edges.len();
+        self.edges.push((from, to, weight));
+        self.adjacency[from].push((to, id));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, id));
+        }
+        id
+    }
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.adjacency
+            .get(from)
+            .is_some_and(|out| out.iter().any(|&(next, _)| next == to))
+    }
+    // for a directed graph this is the out-degree; a self-loop counts once
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.adjacency[vertex].len()
+    }
+    pub fn neighbors(&self, vertex: usize) -> impl ExactSizeIterator<Item = usize> + '_ {
+        self.adjacency[vertex].iter().map(|&(next, _)| next)
+    }
+    // (neighbour, edge id) pairs, in the order the edges were added
+    pub fn incident(&self, vertex: usize) -> &[(usize, usize)] {
+        &self.adjacency[vertex]
+    }
+    pub fn edge(&self, id: usize) -> Option<(usize, usize, &W)> {
+        self.edges
+            .get(id)
+            .map(|(from, to, weight)| (*from, *to, weight))
+    }
+    // every edge once, as it was added, in id order
+    pub fn edges(&self) -> impl ExactSizeIterator<Item = (usize, usize, &W)> {
+        self.edges
+            .iter()
+            .map(|(from, to, weight)| (*from, *to, weight))
+    }
+    pub fn vertices(&self) -> core::ops::Range<usize> {
+        0..self.vertex_count()
+    }
+    // weights[u][v] is the lightest edge from u to v, or None without one; an undirected edge
+    // fills both cells
+    pub fn adjacency_matrix(&self) -> Matrix<Option<W>>
+    where
+        W: Clone + PartialOrd,
+    {
+        let count = self.vertex_count();
+        let mut weights = Matrix::filled(count, count, None);
+        for (from, to, weight) in &self.edges {
+            let mut cells = alloc::vec![(*from, *to)];
+            if !self.directed {
+                cells.push((*to, *from));
+            }
+            for cell in cells {
+                match &weights[cell] {
+                    Some(lighter) if lighter <= weight => {}
+                    _ => weights[cell] = Some(weight.clone()),
+                }
+            }
+        }
+        weights
+    }
+    pub fn clear_edges(&mut self) {
+        self.edges.clear();
+        self.adjacency.iter_mut().for_each(Vec::clear);
+    }
+}
+
+impl<W> Debug for Graph<W>
+where
+    W: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Graph")
+            .field("directed", &self.directed)
+            .field("vertices", &self.vertex_count())
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
+
+impl<W> Extend<(usize, usize)> for Graph<W>
+where
+    W: Default,
+{
+    fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(from, to)| {
+            self.add_edge(from, to);
+        });
+    }
+}
+
+impl<W> Extend<(usize, usize, W)> for Graph<W> {
+    fn extend<I: IntoIterator<Item = (usize, usize, W)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(from, to, weight)| {
+            self.add_weighted_edge(from, to, weight);
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+    ops::Add,
+};
+
+use crate::{collections::Matrix, graph::Graph};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    // every vertex whose distance to itself had gone negative when the search stopped
+    pub vertices: Vec<usize>,
+}
+
+impl Display for NegativeCycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "negative cycle through vertices {:?}", self.vertices)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllPairs<W> {
+    distances: Matrix<Option<W>>,
+    // next[u][v] is the vertex after u on a shortest path to v
+    next: Matrix<Option<usize>>,
+}
+
+impl<W> AllPairs<W>
+where
+    W: Copy,
+{
+    pub fn distances(&self) -> &Matrix<Option<W>> {
+        &self.distances
+    }
+    pub fn next_hops(&self) -> &Matrix<Option<usize>> {
+        &self.next
+    }
+    pub fn distance(&self, from: usize, to: usize) -> Option<W> {
+        self.distances[(from, to)]
+    }
+    // the vertices of a shortest path, both ends included, or None if to is unreachable
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut path = alloc::vec![from];
+        let mut at = from;
+        while at != to {
+            at = self.next[(at, to)]?;
+            path.push(at);
+        }
+        Some(path)
+    }
+}
+
+// floyd-warshall: after round k, distances[i][j] is the shortest path using only 0..=k in
+// between. a negative cycle shows up as a negative diagonal cell in the round of its highest
+// vertex, and the search stops there, before walks around the cycle can grow without bound
+pub fn floyd_warshall<W>(weights: &Matrix<Option<W>>) -> Result<AllPairs<W>, NegativeCycle>
+where
+    W: Copy + Default + PartialOrd + Add<Output = W>,
+{
+    assert!(
+        weights.is_square(),
+        "floyd_warshall needs a square weight matrix"
+    );
+    let count = weights.rows();
+    let zero = W::default();
+    let mut distances = weights.clone();
+    let mut next = Matrix::from_fn(count, count, |from, to| weights[(from, to)].map(|_| to));
+    for v in 0..count {
+        // standing still costs nothing, unless a negative self-loop does better
+        if distances[(v, v)].is_none_or(|weight| weight >= zero) {
+            (distances[(v, v)], next[(v, v)]) = (Some(zero), Some(v));
+        }
+    }
+    for k in 0..=count {
+        let negative: Vec<usize> = (0..count)
+            .filter(|&v| distances[(v, v)].is_some_and(|d| d < zero))
+            .collect();
+        if !negative.is_empty() {
+            return Err(NegativeCycle { vertices: negative });
+        }
+        if k == count {
+            break;
+        }
+        for i in 0..count {
+            let Some(to_k) = distances[(i, k)] else {
+                continue;
+            };
+            for j in 0..count {
+                let Some(from_k) = distances[(k, j)] else {
+                    continue;
+                };
+                let through = to_k + from_k;
+                if distances[(i, j)].is_none_or(|direct| through < direct) {
+                    (distances[(i, j)], next[(i, j)]) = (Some(through), next[(i, k)]);
+                }
+            }
+        }
+    }
+    Ok(AllPairs { distances, next })
+}
+
+impl<W> Graph<W>
+where
+    W: Copy + Default + PartialOrd + Add<Output = W>,
+{
+    pub fn floyd_warshall(&self) -> Result<AllPairs<W>, NegativeCycle> {
+        floyd_warshall(&self.adjacency_matrix())
+    }
+}
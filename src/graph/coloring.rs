@@ -0,0 +1,146 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use crate::graph::Graph;
+
+// the order greedy coloring visits vertices in; the colors it needs depend heavily on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexOrder {
+    Natural,
+    // welsh-powell: the most constrained vertices pick first
+    DegreeDescending,
+    // each component from its lowest vertex outward, so most vertices meet few colored
+    // neighbours at once
+    BreadthFirst,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coloring {
+    // colors[v] is the color of vertex v, numbered from 0
+    pub colors: Vec<usize>,
+    pub count: usize,
+}
+
+impl<W> Graph<W> {
+    // the two sides of a 2-coloring, each ascending, or the vertices of an odd cycle in order
+    // around it when there is none. breadth-first search colors by depth parity, so an edge
+    // joining two equal colors closes an odd cycle through the tree paths to their common
+    // ancestor
+    pub fn is_bipartite(&self) -> Result<[Vec<usize>; 2], Vec<usize>> {
+        assert!(
+            !self.is_directed(),
+            "bipartiteness is checked on undirected graphs"
+        );
+        let count = self.vertex_count();
+        let (mut depth, mut parent) = (vec![usize::MAX; count], vec![usize::MAX; count]);
+        let mut queue = VecDeque::new();
+        for start in self.vertices() {
+            if depth[start] != usize::MAX {
+                continue;
+            }
+            depth[start] = 0;
+            queue.push_back(start);
+            while let Some(at) = queue.pop_front() {
+                for next in self.neighbors(at) {
+                    if depth[next] == usize::MAX {
+                        (depth[next], parent[next]) = (depth[at] + 1, at);
+                        queue.push_back(next);
+                    } else if depth[next] % 2 == depth[at] % 2 {
+                        return Err(odd_cycle(at, next, &depth, &parent));
+                    }
+                }
+            }
+        }
+        let (even, odd) = self.vertices().partition(|&v| depth[v] % 2 == 0);
+        Ok([even, odd])
+    }
+    // each vertex in turn takes the smallest color none of its neighbours has; self-loops are
+    // ignored, since no coloring could satisfy them
+    pub fn greedy_coloring(&self, order: VertexOrder) -> Coloring {
+        assert!(
+            !self.is_directed(),
+            "coloring is defined on undirected graphs"
+        );
+        let count = self.vertex_count();
+        let mut colors = vec![usize::MAX; count];
+        // taken[c] == v + 1 marks color c as used around v, so the marks never need clearing
+        let mut taken = vec![0; count + 1];
+        let mut used = 0;
+        for vertex in self.order(order) {
+            for next in self.neighbors(vertex).filter(|&next| next != vertex) {
+                if let Some(mark) = taken.get_mut(colors[next]) {
+                    *mark = vertex + 1;
+                }
+            }
+            let color = (0..)
+                .find(|&c| taken[c] != vertex + 1)
+                .expect("some color is free");
+            colors[vertex] = color;
+            used = used.max(color + 1);
+        }
+        Coloring {
+            colors,
+            count: used,
+        }
+    }
+    pub fn is_proper_coloring(&self, colors: &[usize]) -> bool {
+        colors.len() == self.vertex_count()
+            && self
+                .edges()
+                .all(|(from, to, _)| from == to || colors[from] != colors[to])
+    }
+    fn order(&self, order: VertexOrder) -> Vec<usize> {
+        let mut vertices: Vec<usize> = self.vertices().collect();
+        match order {
+            VertexOrder::Natural => vertices,
+            VertexOrder::DegreeDescending => {
+                vertices.sort_by_key(|&v| core::cmp::Reverse(self.degree(v)));
+                vertices
+            }
+            VertexOrder::BreadthFirst => {
+                let mut seen = vec![false; vertices.len()];
+                let mut visit = Vec::with_capacity(vertices.len());
+                for start in vertices {
+                    if seen[start] {
+                        continue;
+                    }
+                    seen[start] = true;
+                    let mut queue = VecDeque::from([start]);
+                    while let Some(at) = queue.pop_front() {
+                        visit.push(at);
+                        for next in self.neighbors(at) {
+                            if !seen[next] {
+                                seen[next] = true;
+                                queue.push_back(next);
+                            }
+                        }
+                    }
+                }
+                visit
+            }
+        }
+    }
+}
+
+// walks a and b up the search tree to where they meet; the two paths and the edge a-b form the
+// cycle, odd because a and b sit at depths of equal parity
+fn odd_cycle(a: usize, b: usize, depth: &[usize], parent: &[usize]) -> Vec<usize> {
+    let (mut up, mut down) = (vec![a], vec![b]);
+    let (mut a, mut b) = (a, b);
+    while depth[a] > depth[b] {
+        a = parent[a];
+        up.push(a);
+    }
+    while depth[b] > depth[a] {
+        b = parent[b];
+        down.push(b);
+    }
+    while a != b {
+        (a, b) = (parent[a], parent[b]);
+        up.push(a);
+        down.push(b);
+    }
+    // both paths end at the meeting point, which the cycle should hold once
+    down.pop();
+    up.extend(down.into_iter().rev());
+    up
+}
@@ -0,0 +1,136 @@
+use alloc::{vec, vec::Vec};
+
+use crate::graph::Graph;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BiconnectedComponent {
+    // ascending
+    pub vertices: Vec<usize>,
+    // edge ids in the order the search met them
+    pub edges: Vec<usize>,
+}
+
+// everything one low-link search finds. components partition the edges, self-loops aside, so
+// an isolated vertex belongs to none; a bridge is a component of its own single edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Biconnectivity {
+    // ascending
+    pub articulation_points: Vec<usize>,
+    // edge ids, ascending
+    pub bridges: Vec<usize>,
+    pub components: Vec<BiconnectedComponent>,
+}
+
+// one vertex on the explicit depth-first stack: the edge it was reached by and how far through
+// its adjacency list the search has got
+struct Frame {
+    vertex: usize,
+    via: Option<usize>,
+    next: usize,
+}
+
+impl<W> Graph<W> {
+    // tarjan's low-link search: low[v] is the earliest discovery time reachable from v's subtree
+    // by one back edge. a child w of u with low[w] >= disc[u] cannot get above u without it,
+    // which makes u a cut vertex and closes off a component; with low[w] > disc[u] not even
+    // u is reachable, so the tree edge is a bridge. parents are skipped by edge id rather than
+    // by vertex, so a parallel edge counts as the back edge it is
+    pub fn biconnectivity(&self) -> Biconnectivity {
+        assert!(
+            !self.is_directed(),
+            "biconnectivity is defined on undirected graphs"
+        );
+        let count = self.vertex_count();
+        let (mut disc, mut low) = (vec![usize::MAX; count], vec![0; count]);
+        let mut cut = vec![false; count];
+        let (mut bridges, mut components) = (Vec::new(), Vec::new());
+        let (mut stack, mut edges) = (Vec::new(), Vec::new());
+        let mut time = 0;
+        for root in self.vertices() {
+            if disc[root] != usize::MAX {
+                continue;
+            }
+            (disc[root], low[root]) = (time, time);
+            time += 1;
+            let mut root_children = 0;
+            stack.push(Frame {
+                vertex: root,
+                via: None,
+                next: 0,
+            });
+            while let Some(frame) = stack.last_mut() {
+                let v = frame.vertex;
+                if let Some(&(w, edge)) = self.incident(v).get(frame.next) {
+                    frame.next += 1;
+                    if Some(edge) == frame.via || w == v {
+                        continue;
+                    }
+                    if disc[w] == usize::MAX {
+                        (disc[w], low[w]) = (time, time);
+                        time += 1;
+                        edges.push(edge);
+                        root_children += usize::from(v == root);
+                        stack.push(Frame {
+                            vertex: w,
+                            via: Some(edge),
+                            next: 0,
+                        });
+                    } else if disc[w] < disc[v] {
+                        // a back edge up the tree; the same edge seen from its upper end is
+                        // skipped, since the lower end already recorded it
+                        low[v] = low[v].min(disc[w]);
+                        edges.push(edge);
+                    }
+                    continue;
+                }
+                let Some(Frame { via: Some(via), .. }) = stack.pop() else {
+                    continue;
+                };
+                let u = stack
+                    .last()
+                    .expect("a vertex reached by an edge has a parent")
+                    .vertex;
+                low[u] = low[u].min(low[v]);
+                if low[v] > disc[u] {
+                    bridges.push(via);
+                }
+                if low[v] >= disc[u] {
+                    cut[u] |= u != root;
+                    let at = edges
+                        .iter()
+                        .rposition(|&e| e == via)
+                        .expect("the tree edge is on the stack");
+                    components.push(self.component(edges.split_off(at)));
+                }
+            }
+            cut[root] = root_children >= 2;
+        }
+        bridges.sort_unstable();
+        Biconnectivity {
+            articulation_points: self.vertices().filter(|&v| cut[v]).collect(),
+            bridges,
+            components,
+        }
+    }
+    pub fn articulation_points(&self) -> Vec<usize> {
+        self.biconnectivity().articulation_points
+    }
+    pub fn bridges(&self) -> Vec<usize> {
+        self.biconnectivity().bridges
+    }
+    pub fn biconnected_components(&self) -> Vec<BiconnectedComponent> {
+        self.biconnectivity().components
+    }
+    fn component(&self, edges: Vec<usize>) -> BiconnectedComponent {
+        let mut vertices: Vec<usize> = edges
+            .iter()
+            .flat_map(|&id| {
+                let (from, to, _) = self.edge(id).expect("edge ids come from this graph");
+                [from, to]
+            })
+            .collect();
+        vertices.sort_unstable();
+        vertices.dedup();
+        BiconnectedComponent { vertices, edges }
+    }
+}
@@ -0,0 +1,20 @@
+/*
+
+    GRAPHS
+
+    vertices numbered from zero with edges kept
+    in adjacency lists, and the algorithms on them
+
+*/
+
+pub mod adjacency;
+pub mod all_pairs;
+pub mod coloring;
+pub mod connectivity;
+pub mod text;
+
+pub use adjacency::Graph;
+pub use all_pairs::{AllPairs, NegativeCycle, floyd_warshall};
+pub use coloring::{Coloring, VertexOrder};
+pub use connectivity::{BiconnectedComponent, Biconnectivity};
+pub use text::{GraphFormatError, TextWeight};
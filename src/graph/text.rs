@@ -0,0 +1,237 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::graph::Graph;
+
+// two line-based formats, each opening with "directed N" or "undirected N" for N vertices;
+// blank lines and anything after a # are ignored
+//
+//   edge list:       one edge per line, "from to" or "from to weight"
+//   adjacency list:  "vertex: next next ...", each next as "to" or "to:weight"; an undirected
+//                    edge appears under both ends, and is read back from its lower end only
+
+// how an edge weight reads and writes as text; () is the unweighted case and writes nothing
+pub trait TextWeight: Sized {
+    fn parse(text: Option<&str>) -> Option<Self>;
+    fn format(&self) -> Option<String>;
+}
+
+impl TextWeight for () {
+    fn parse(text: Option<&str>) -> Option<Self> {
+        text.is_none().then_some(())
+    }
+    fn format(&self) -> Option<String> {
+        None
+    }
+}
+
+macro_rules! text_weight {
+    ($($t:ty),*) => {
+        $(
+            impl TextWeight for $t {
+                fn parse(text: Option<&str>) -> Option<Self> {
+                    text?.parse().ok()
+                }
+                fn format(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+text_weight!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+#[derive(Debug)]
+pub enum GraphFormatError {
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    MissingHeader,
+    // lines count from 1
+    BadHeader {
+        line: usize,
+    },
+    BadEdge {
+        line: usize,
+    },
+    VertexOutOfRange {
+        line: usize,
+        vertex: usize,
+    },
+}
+
+impl Display for GraphFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(error) => write!(f, "could not read the graph: {}", error),
+            Self::MissingHeader => {
+                write!(f, "expected a \"directed N\" or \"undirected N\" header")
+            }
+            Self::BadHeader { line } => write!(f, "line {}: malformed header", line),
+            Self::BadEdge { line } => write!(f, "line {}: malformed edge", line),
+            Self::VertexOutOfRange { line, vertex } => {
+                write!(
+                    f,
+                    "line {}: vertex {} is past the vertex count",
+                    line, vertex
+                )
+            }
+        }
+    }
+}
+
+impl Error for GraphFormatError {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for GraphFormatError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// the numbered lines that hold something, with comments stripped
+fn content(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(at, line)| (at + 1, line.split('#').next().unwrap_or("").trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+fn header<'a, W, I>(lines: &mut I) -> Result<Graph<W>, GraphFormatError>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let (line, text) = lines.next().ok_or(GraphFormatError::MissingHeader)?;
+    let mut words = text.split_whitespace();
+    let (kind, count) = (
+        words.next(),
+        words.next().and_then(|count| count.parse().ok()),
+    );
+    match (kind, count, words.next()) {
+        (Some("directed"), Some(count), None) => Ok(Graph::directed(count)),
+        (Some("undirected"), Some(count), None) => Ok(Graph::undirected(count)),
+        _ => Err(GraphFormatError::BadHeader { line }),
+    }
+}
+
+fn vertex<W>(graph: &Graph<W>, text: &str, line: usize) -> Result<usize, GraphFormatError> {
+    let vertex = text
+        .parse()
+        .map_err(|_| GraphFormatError::BadEdge { line })?;
+    match vertex < graph.vertex_count() {
+        true => Ok(vertex),
+        false => Err(GraphFormatError::VertexOutOfRange { line, vertex }),
+    }
+}
+
+impl<W> Graph<W>
+where
+    W: TextWeight,
+{
+    pub fn parse_edge_list(text: &str) -> Result<Self, GraphFormatError> {
+        let mut lines = content(text);
+        let mut graph = header(&mut lines)?;
+        for (line, text) in lines {
+            let words: Vec<&str> = text.split_whitespace().collect();
+            let [from, to, ref rest @ ..] = words[..] else {
+                return Err(GraphFormatError::BadEdge { line });
+            };
+            let weight = match rest {
+                [] => W::parse(None),
+                [weight] => W::parse(Some(weight)),
+                _ => None,
+            };
+            let (from, to) = (vertex(&graph, from, line)?, vertex(&graph, to, line)?);
+            graph.add_weighted_edge(from, to, weight.ok_or(GraphFormatError::BadEdge { line })?);
+        }
+        Ok(graph)
+    }
+    pub fn parse_adjacency_list(text: &str) -> Result<Self, GraphFormatError> {
+        let mut lines = content(text);
+        let mut graph = header(&mut lines)?;
+        for (line, text) in lines {
+            let (from, rest) = text
+                .split_once(':')
+                .ok_or(GraphFormatError::BadEdge { line })?;
+            let from = vertex(&graph, from.trim(), line)?;
+            for entry in rest.split_whitespace() {
+                let (to, weight) = match entry.split_once(':') {
+                    Some((to, weight)) => (to, W::parse(Some(weight))),
+                    None => (entry, W::parse(None)),
+                };
+                let to = vertex(&graph, to, line)?;
+                let weight = weight.ok_or(GraphFormatError::BadEdge { line })?;
+                if graph.is_directed() || from <= to {
+                    graph.add_weighted_edge(from, to, weight);
+                }
+            }
+        }
+        Ok(graph)
+    }
+    pub fn edge_list(&self) -> String {
+        let mut out = self.header();
+        for (from, to, weight) in self.edges() {
+            match weight.format() {
+                Some(weight) => out.push_str(&format!("{} {} {}\n", from, to, weight)),
+                None => out.push_str(&format!("{} {}\n", from, to)),
+            }
+        }
+        out
+    }
+    pub fn adjacency_list(&self) -> String {
+        let mut out = self.header();
+        for from in self.vertices() {
+            out.push_str(&format!("{}:", from));
+            for &(to, id) in self.incident(from) {
+                let (.., weight) = self.edge(id).expect("incident edges exist");
+                match weight.format() {
+                    Some(weight) => out.push_str(&format!(" {}:{}", to, weight)),
+                    None => out.push_str(&format!(" {}", to)),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+    fn header(&self) -> String {
+        let kind = if self.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        format!("{} {}\n", kind, self.vertex_count())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> Graph<W>
+where
+    W: TextWeight,
+{
+    pub fn from_edge_list<R: std::io::Read>(mut reader: R) -> Result<Self, GraphFormatError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Self::parse_edge_list(&text)
+    }
+    pub fn to_edge_list<O: std::io::Write>(&self, mut out: O) -> std::io::Result<()> {
+        out.write_all(self.edge_list().as_bytes())
+    }
+    pub fn from_adjacency_list<R: std::io::Read>(mut reader: R) -> Result<Self, GraphFormatError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Self::parse_adjacency_list(&text)
+    }
+    pub fn to_adjacency_list<O: std::io::Write>(&self, mut out: O) -> std::io::Result<()> {
+        out.write_all(self.adjacency_list().as_bytes())
+    }
+}
@@ -0,0 +1,451 @@
+/*
+
+    HASH MAP
+
+    separate chaining over a power-of-two
+    number of buckets, doubling once the
+    average chain grows past 3/4
+
+*/
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    ops::Index,
+    slice,
+};
+
+use super::FnvBuildHasher;
+
+const INITIAL_BUCKETS: usize = 8;
+
+pub struct HashMap<K, V, S = FnvBuildHasher>
+where
+    K: Hash + Eq,
+{
+    buckets: Vec<Vec<(K, V)>>,
+    size: usize,
+    hasher: S,
+}
+
+impl<K, V> Default for HashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> HashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    pub fn new() -> Self {
+        Self::with_hasher(FnvBuildHasher::default())
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut map = Self::new();
+        map.reserve(capacity);
+        map
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            buckets: Vec::new(),
+            size: 0,
+            hasher,
+        }
+    }
+    fn bucket<Q>(&self, key: &Q) -> usize
+    where
+        Q: Hash + ?Sized,
+    {
+        let hash = self.hasher.hash_one(key);
+        // fold the high half in, since only the low bits pick the bucket
+        (hash ^ (hash >> 32)) as usize & (self.buckets.len() - 1)
+    }
+    fn find<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let b = self.bucket(key);
+        let i = self.buckets[b]
+            .iter()
+            .position(|(k, _)| k.borrow() == key)?;
+        Some((b, i))
+    }
+    fn resize(&mut self, buckets: usize) {
+        let old = core::mem::replace(
+            &mut self.buckets,
+            (0..buckets).map(|_| Vec::new()).collect(),
+        );
+        for (key, value) in old.into_iter().flatten() {
+            let b = self.bucket(&key);
+            self.buckets[b].push((key, value));
+        }
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn len(&self) -> usize {
+        self.size
+    }
+    // how many entries fit before the next resize
+    pub fn capacity(&self) -> usize {
+        self.buckets.len() / 4 * 3
+    }
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.size + additional;
+        if needed > self.capacity() {
+            let buckets = (needed * 4)
+                .div_ceil(3)
+                .next_power_of_two()
+                .max(INITIAL_BUCKETS);
+            self.resize(buckets);
+        }
+    }
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some((b, i)) = self.find(&key) {
+            return Some(core::mem::replace(&mut self.buckets[b][i].1, value));
+        }
+        self.reserve(1);
+        let b = self.bucket(&key);
+        self.buckets[b].push((key, value));
+        self.size += 1;
+        None
+    }
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(b, i)| &self.buckets[b][i].1)
+    }
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(b, i)| &mut self.buckets[b][i].1)
+    }
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(b, i)| {
+            let (k, v) = &self.buckets[b][i];
+            (k, v)
+        })
+    }
+    pub fn get_or_insert_with<F>(&mut self, key: K, default: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        let (b, i) = match self.find(&key) {
+            Some(found) => found,
+            None => {
+                self.reserve(1);
+                let b = self.bucket(&key);
+                self.buckets[b].push((key, default()));
+                self.size += 1;
+                (b, self.buckets[b].len() - 1)
+            }
+        };
+        &mut self.buckets[b][i].1
+    }
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (b, i) = self.find(key)?;
+        self.size -= 1;
+        Some(self.buckets[b].swap_remove(i))
+    }
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        for bucket in self.buckets.iter_mut() {
+            bucket.retain_mut(|(key, value)| predicate(key, value));
+        }
+        self.size = self.buckets.iter().map(Vec::len).sum();
+    }
+    pub fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.size = 0;
+    }
+    // the longest chain, for checking how well the hasher spreads a set of keys
+    pub fn max_chain(&self) -> usize {
+        self.buckets.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+impl<K, Q, V, S> Index<&Q> for HashMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K, V, S> PartialEq for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K, V, S> Eq for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> Debug for HashMap<K, V, S>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> Clone for HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            buckets: self.buckets.clone(),
+            size: self.size,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    buckets: slice::Iter<'a, Vec<(K, V)>>,
+    chain: slice::Iter<'a, (K, V)>,
+    remaining: usize,
+}
+
+pub struct IterMut<'a, K, V> {
+    buckets: slice::IterMut<'a, Vec<(K, V)>>,
+    chain: slice::IterMut<'a, (K, V)>,
+    remaining: usize,
+}
+
+pub struct IntoIter<K, V> {
+    buckets: alloc::vec::IntoIter<Vec<(K, V)>>,
+    chain: alloc::vec::IntoIter<(K, V)>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, value)) = self.chain.next() {
+                self.remaining -= 1;
+                return Some((key, value));
+            }
+            self.chain = self.buckets.next()?.iter();
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, value)) = self.chain.next() {
+                self.remaining -= 1;
+                return Some((key, value));
+            }
+            self.chain = self.buckets.next()?.iter_mut();
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.chain.next() {
+                self.remaining -= 1;
+                return Some(entry);
+            }
+            self.chain = self.buckets.next()?.into_iter();
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Hash + Eq,
+{
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            buckets: self.buckets.iter(),
+            chain: [].iter(),
+            remaining: self.size,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            buckets: self.buckets.iter_mut(),
+            chain: [].iter_mut(),
+            remaining: self.size,
+        }
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.iter_mut().map(|(_, value)| value)
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            buckets: self.buckets.into_iter(),
+            chain: Vec::new().into_iter(),
+            remaining: self.size,
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S>
+where
+    K: Hash + Eq,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap<K, V, S>
+where
+    K: Hash + Eq,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for HashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
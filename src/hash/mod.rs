@@ -0,0 +1,31 @@
+pub mod map;
+
+use core::hash::{BuildHasherDefault, Hasher};
+
+// 64-bit FNV-1a: fast and deterministic, which suits the crate's structures, but with no
+// resistance to keys chosen to collide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FnvHasher(u64);
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(PRIME);
+        }
+    }
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{Display, Formatter},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    hash::map::HashMap,
+    instrument::{Instrumented, OpCounts},
+    linked_list::{doubly, singly},
+    tree::{
+        arena_avl::ArenaAVLTree,
+        avl::AVLTree,
+        bst::BST,
+        heap::{BinaryHeap, PriorityQueue},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BigO {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl BigO {
+    // the growth function at n, rounded down, to set beside the measured counts
+    pub fn at(self, n: usize) -> u64 {
+        let n = n as u64;
+        let log = n.max(2).ilog2() as u64;
+        match self {
+            Self::Constant => 1,
+            Self::Logarithmic => log,
+            Self::Linear => n,
+            Self::Linearithmic => n * log,
+            Self::Quadratic => n * n,
+        }
+    }
+}
+
+impl Display for BigO {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let bound = match self {
+            Self::Constant => "O(1)",
+            Self::Logarithmic => "O(log n)",
+            Self::Linear => "O(n)",
+            Self::Linearithmic => "O(n log n)",
+            Self::Quadratic => "O(n^2)",
+        };
+        // pad here so tables can align the bound like any other column
+        f.pad(bound)
+    }
+}
+
+// the documented cost of each operation, keyed by method name; bounds are worst case unless
+// the impl says otherwise
+pub trait Complexity {
+    const NAME: &'static str;
+    const OPERATIONS: &'static [(&'static str, BigO)];
+    // the n that the bounds are stated in
+    fn problem_size(&self) -> usize;
+    fn bound(operation: &str) -> Option<BigO> {
+        Self::OPERATIONS
+            .iter()
+            .find(|(name, _)| *name == operation)
+            .map(|(_, bound)| *bound)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub operation: &'static str,
+    // None for operations that were measured but have no documented bound
+    pub bound: Option<BigO>,
+    pub calls: u64,
+    pub counts: OpCounts,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub name: &'static str,
+    pub size: usize,
+    pub rows: Vec<Row>,
+}
+
+impl<C> Instrumented<C>
+where
+    C: Complexity,
+{
+    // every documented operation in order, then anything else run_op recorded
+    pub fn report(&self) -> Report {
+        let measured = |operation: &str| {
+            self.operations
+                .iter()
+                .find(|(name, ..)| *name == operation)
+                .map_or((0, OpCounts::default()), |(_, calls, counts)| {
+                    (*calls, *counts)
+                })
+        };
+        let mut rows: Vec<Row> = C::OPERATIONS
+            .iter()
+            .map(|&(operation, bound)| {
+                let (calls, counts) = measured(operation);
+                Row {
+                    operation,
+                    bound: Some(bound),
+                    calls,
+                    counts,
+                }
+            })
+            .collect();
+        rows.extend(
+            self.operations
+                .iter()
+                .filter(|(name, ..)| C::bound(name).is_none())
+                .map(|&(operation, calls, counts)| Row {
+                    operation,
+                    bound: None,
+                    calls,
+                    counts,
+                }),
+        );
+        Report {
+            name: C::NAME,
+            size: self.inner.problem_size(),
+            rows,
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let per_call = |count: u64, calls: u64| match calls {
+            0 => 0.0,
+            calls => count as f64 / calls as f64,
+        };
+        writeln!(f, "{} (n = {})", self.name, self.size)?;
+        writeln!(
+            f,
+            "{:<14}{:<12}{:>8}{:>8}{:>12}{:>12}{:>12}{:>12}",
+            "operation",
+            "bound",
+            "at n",
+            "calls",
+            "cmp/call",
+            "swap/call",
+            "rot/call",
+            "alloc/call"
+        )?;
+        for row in &self.rows {
+            match row.bound {
+                Some(bound) => write!(
+                    f,
+                    "{:<14}{:<12}{:>8}",
+                    row.operation,
+                    bound,
+                    bound.at(self.size)
+                )?,
+                None => write!(f, "{:<14}{:<12}{:>8}", row.operation, "-", "-")?,
+            }
+            writeln!(
+                f,
+                "{:>8}{:>12.2}{:>12.2}{:>12.2}{:>12.2}",
+                row.calls,
+                per_call(row.counts.comparisons, row.calls),
+                per_call(row.counts.swaps, row.calls),
+                per_call(row.counts.rotations, row.calls),
+                per_call(row.counts.allocations, row.calls)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Complexity for AVLTree<T>
+where
+    T: Ord,
+{
+    const NAME: &'static str = "AVLTree";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("insert", BigO::Logarithmic),
+        ("remove", BigO::Logarithmic),
+        ("contains", BigO::Logarithmic),
+        ("first", BigO::Logarithmic),
+        ("kth_smallest", BigO::Logarithmic),
+        ("rank", BigO::Logarithmic),
+        ("iter", BigO::Linear),
+        ("clear", BigO::Linear),
+    ];
+    fn problem_size(&self) -> usize {
+        self.size()
+    }
+}
+
+impl<T> Complexity for ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    const NAME: &'static str = "ArenaAVLTree";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("insert", BigO::Logarithmic),
+        ("remove", BigO::Logarithmic),
+        ("contains", BigO::Logarithmic),
+        ("first", BigO::Logarithmic),
+        ("kth_smallest", BigO::Logarithmic),
+        ("rank", BigO::Logarithmic),
+        ("iter", BigO::Linear),
+        // a single buffer clear, but that still drops every element
+        ("clear", BigO::Linear),
+    ];
+    fn problem_size(&self) -> usize {
+        self.size()
+    }
+}
+
+// worst case, which is what sorted input produces; random input keeps the shape near log n
+impl<T> Complexity for BST<T>
+where
+    T: Ord,
+{
+    const NAME: &'static str = "BST";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("insert", BigO::Linear),
+        ("remove", BigO::Linear),
+        ("contains", BigO::Linear),
+        ("first", BigO::Linear),
+        ("kth_smallest", BigO::Linear),
+        ("rank", BigO::Linear),
+        ("iter", BigO::Linear),
+        ("clear", BigO::Linear),
+    ];
+    fn problem_size(&self) -> usize {
+        self.size()
+    }
+}
+
+impl<T> Complexity for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    const NAME: &'static str = "BinaryHeap";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("offer", BigO::Logarithmic),
+        ("poll", BigO::Logarithmic),
+        ("peek", BigO::Constant),
+        ("push_pop", BigO::Logarithmic),
+        ("replace_top", BigO::Logarithmic),
+        ("remove", BigO::Linear),
+    ];
+    fn problem_size(&self) -> usize {
+        self.size()
+    }
+}
+
+impl<P, V> Complexity for PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    const NAME: &'static str = "PriorityQueue";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("offer", BigO::Logarithmic),
+        ("poll", BigO::Logarithmic),
+        ("peek", BigO::Constant),
+    ];
+    fn problem_size(&self) -> usize {
+        self.size()
+    }
+}
+
+impl<T: Clone> Complexity for singly::LinkedList<T> {
+    const NAME: &'static str = "singly::LinkedList";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("push_head", BigO::Constant),
+        ("push_tail", BigO::Constant),
+        ("pop", BigO::Constant),
+        ("pop_tail", BigO::Linear),
+        ("get", BigO::Linear),
+        ("remove", BigO::Linear),
+        ("contains", BigO::Linear),
+        ("reverse", BigO::Linear),
+        ("sort", BigO::Linearithmic),
+    ];
+    fn problem_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: Clone> Complexity for doubly::LinkedList<T> {
+    const NAME: &'static str = "doubly::LinkedList";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("push_head", BigO::Constant),
+        ("push_tail", BigO::Constant),
+        ("pop_head", BigO::Constant),
+        ("pop_tail", BigO::Constant),
+        ("get", BigO::Linear),
+        ("remove", BigO::Linear),
+        ("contains", BigO::Linear),
+        ("reverse", BigO::Linear),
+        ("sort", BigO::Linearithmic),
+    ];
+    fn problem_size(&self) -> usize {
+        self.len()
+    }
+}
+
+// expected bounds under a hash that spreads keys well; a single bucket can make them linear
+impl<K, V, S> Complexity for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    const NAME: &'static str = "HashMap";
+    const OPERATIONS: &'static [(&'static str, BigO)] = &[
+        ("insert", BigO::Constant),
+        ("get", BigO::Constant),
+        ("remove", BigO::Constant),
+        ("contains_key", BigO::Constant),
+        ("clear", BigO::Linear),
+    ];
+    fn problem_size(&self) -> usize {
+        self.len()
+    }
+}
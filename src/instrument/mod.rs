@@ -0,0 +1,188 @@
+/*
+
+    INSTRUMENTATION
+
+    per-thread operation counters that the
+    collections bump as they work, compiled
+    to nothing unless the instrument feature is on
+
+*/
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Sub},
+};
+
+pub mod complexity;
+
+pub use complexity::{BigO, Complexity, Report};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    pub comparisons: u64,
+    pub swaps: u64,
+    pub rotations: u64,
+    // calls into the allocator: one per boxed node, or one per growth of a contiguous buffer
+    pub allocations: u64,
+}
+
+impl Add for OpCounts {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            comparisons: self.comparisons + other.comparisons,
+            swaps: self.swaps + other.swaps,
+            rotations: self.rotations + other.rotations,
+            allocations: self.allocations + other.allocations,
+        }
+    }
+}
+
+impl AddAssign for OpCounts {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for OpCounts {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            comparisons: self.comparisons - other.comparisons,
+            swaps: self.swaps - other.swaps,
+            rotations: self.rotations - other.rotations,
+            allocations: self.allocations - other.allocations,
+        }
+    }
+}
+
+#[cfg(feature = "instrument")]
+std::thread_local! {
+    static COUNTS: core::cell::Cell<OpCounts> = const { core::cell::Cell::new(OpCounts {
+        comparisons: 0,
+        swaps: 0,
+        rotations: 0,
+        allocations: 0,
+    }) };
+}
+
+#[inline(always)]
+#[allow(unused_variables)]
+fn bump(update: fn(&mut OpCounts)) {
+    #[cfg(feature = "instrument")]
+    COUNTS.with(|counts| {
+        let mut current = counts.get();
+        update(&mut current);
+        counts.set(current);
+    });
+}
+
+// passes an ordering through, tallying the comparison that produced it
+#[inline(always)]
+pub(crate) fn counted(order: Ordering) -> Ordering {
+    bump(|counts| counts.comparisons += 1);
+    order
+}
+
+#[inline(always)]
+pub(crate) fn swap() {
+    bump(|counts| counts.swaps += 1);
+}
+
+#[inline(always)]
+pub(crate) fn rotation() {
+    bump(|counts| counts.rotations += 1);
+}
+
+#[inline(always)]
+pub(crate) fn allocation() {
+    bump(|counts| counts.allocations += 1);
+}
+
+pub fn enabled() -> bool {
+    cfg!(feature = "instrument")
+}
+
+// running totals for the calling thread; always zero without the instrument feature
+pub fn counts() -> OpCounts {
+    #[cfg(feature = "instrument")]
+    return COUNTS.with(|counts| counts.get());
+    #[cfg(not(feature = "instrument"))]
+    OpCounts::default()
+}
+
+pub fn reset() {
+    #[cfg(feature = "instrument")]
+    COUNTS.with(|counts| counts.set(OpCounts::default()));
+}
+
+// the counts accumulated by this thread while work ran
+pub fn measure<R, F>(work: F) -> (R, OpCounts)
+where
+    F: FnOnce() -> R,
+{
+    let before = counts();
+    let result = work();
+    (result, counts() - before)
+}
+
+// owns a collection and keeps a tally of everything done to it through run
+#[derive(Debug, Clone, Default)]
+pub struct Instrumented<C> {
+    inner: C,
+    stats: OpCounts,
+    // calls and counts filed under each operation name passed to run_op
+    operations: Vec<(&'static str, u64, OpCounts)>,
+}
+
+impl<C> Instrumented<C> {
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            stats: OpCounts::default(),
+            operations: Vec::new(),
+        }
+    }
+    pub fn run<R, F>(&mut self, work: F) -> R
+    where
+        F: FnOnce(&mut C) -> R,
+    {
+        let (result, spent) = measure(|| work(&mut self.inner));
+        self.stats += spent;
+        result
+    }
+    // like run, but also files the counts under operation so report can break them down
+    pub fn run_op<R, F>(&mut self, operation: &'static str, work: F) -> R
+    where
+        F: FnOnce(&mut C) -> R,
+    {
+        let (result, spent) = measure(|| work(&mut self.inner));
+        self.stats += spent;
+        match self
+            .operations
+            .iter_mut()
+            .find(|(name, ..)| *name == operation)
+        {
+            Some((_, calls, counts)) => {
+                *calls += 1;
+                *counts += spent;
+            }
+            None => self.operations.push((operation, 1, spent)),
+        }
+        result
+    }
+    pub fn stats(&self) -> OpCounts {
+        self.stats
+    }
+    pub fn reset_stats(&mut self) {
+        self.stats = OpCounts::default();
+        self.operations.clear();
+    }
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod adt;
+pub mod algebra;
+pub mod algorithms;
+pub mod applications;
+pub mod arena;
+pub mod bdd;
+pub mod cache;
+pub mod collections;
+pub mod concurrent;
+pub mod generate;
+pub mod graph;
+pub mod hash;
+pub mod instrument;
+pub mod linked_list;
+pub mod persistent;
+pub mod prelude;
+pub mod probabilistic;
+pub mod ranges;
+pub mod search;
+pub mod sort;
+pub mod streaming;
+pub mod string;
+pub mod tree;
+pub mod visualize;
+
+#[cfg(feature = "serde")]
+mod serialize;
@@ -0,0 +1,422 @@
+/*
+
+    CIRCULAR LINKED LIST
+
+    implemented via NonNull pointers
+    with the tail linking back to the head
+
+*/
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
+
+pub type NodePtr<T> = Option<NonNull<Node<T>>>;
+
+pub struct Node<T: Clone> {
+    pub data: T,
+    next: NonNull<Node<T>>,
+}
+
+impl<T: Clone> Node<T> {
+    // a fresh node forms a ring of one
+    fn ring(data: T) -> NonNull<Node<T>> {
+        let mut node = NonNull::from(Box::leak(Box::new(Node {
+            data,
+            next: NonNull::dangling(),
+        })));
+        unsafe { node.as_mut().next = node };
+        node
+    }
+}
+
+// only the tail is stored: the head is always tail.next
+pub struct CircularList<T: Clone> {
+    tail: NodePtr<T>,
+    size: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// the ring has a single owner even though its links form a cycle, so it is as Send and Sync as
+// a Vec<T> would be
+unsafe impl<T: Clone + Send> Send for CircularList<T> {}
+unsafe impl<T: Clone + Sync> Sync for CircularList<T> {}
+
+impl<T: Clone> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> CircularList<T> {
+    pub fn new() -> Self {
+        Self {
+            tail: None,
+            size: 0,
+            marker: PhantomData,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend_tail(iter);
+        list
+    }
+}
+
+impl<T: Clone> CircularList<T> {
+    fn head(&self) -> NodePtr<T> {
+        self.tail.map(|tail| unsafe { tail.as_ref().next })
+    }
+    // splices a new node in between the tail and the head, which is both ends at once
+    fn link_after_tail(&mut self, data: T) -> NonNull<Node<T>> {
+        let mut node = Node::ring(data);
+        if let Some(mut tail) = self.tail {
+            unsafe {
+                node.as_mut().next = tail.as_ref().next;
+                tail.as_mut().next = node;
+            }
+        } else {
+            self.tail = Some(node);
+        }
+        self.size += 1;
+        node
+    }
+}
+
+impl<T: Clone> CircularList<T> {
+    pub fn is_empty(&self) -> bool {
+        self.tail.is_none()
+    }
+    pub fn len(&self) -> usize {
+        self.size
+    }
+    pub fn push_head(&mut self, data: T) {
+        self.link_after_tail(data);
+    }
+    pub fn push_tail(&mut self, data: T) {
+        let node = self.link_after_tail(data);
+        self.tail = Some(node);
+    }
+    pub fn extend_head<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_head(i);
+        }
+    }
+    pub fn extend_tail<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_tail(i);
+        }
+    }
+    pub fn pop_head(&mut self) -> Option<T> {
+        let mut tail = self.tail?;
+        let head = unsafe { Box::from_raw(tail.as_ref().next.as_ptr()) };
+        if self.size == 1 {
+            self.tail = None;
+        } else {
+            unsafe { tail.as_mut().next = head.next };
+        }
+        self.size -= 1;
+        Some(head.data)
+    }
+    pub fn peek_head(&self) -> Option<&T> {
+        self.head().map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+    pub fn peek_tail(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+    // moves the head to the back in O(1) by advancing the tail one step around the ring
+    pub fn rotate(&mut self) {
+        self.tail = self.head();
+    }
+    // josephus problem: walking the circle, every kth element is removed until one is left
+    pub fn survivor(mut self, k: usize) -> Option<T> {
+        assert!(k > 0, "josephus step must be at least 1");
+        while self.size > 1 {
+            for _ in 1..k {
+                self.rotate();
+            }
+            self.pop_head();
+        }
+        self.pop_head()
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.peek_head()
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.peek_tail()
+    }
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+    pub fn clear(&mut self) {
+        while self.pop_head().is_some() {}
+    }
+}
+
+impl<T: Clone> Index<usize> for CircularList<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for CircularList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> Drop for CircularList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for CircularList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for CircularList<T> {}
+
+impl<T: Clone + PartialOrd> PartialOrd for CircularList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Ord> Ord for CircularList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Hash> Hash for CircularList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T: Clone + Debug> Debug for CircularList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for CircularList<T> {
+    fn clone(&self) -> Self {
+        Self::from(self.iter().cloned())
+    }
+}
+
+pub struct Iter<'a, T: Clone> {
+    current: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+pub struct IterMut<'a, T: Clone> {
+    current: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+// keeps going around the ring forever, ending only when the list is empty
+pub struct Cycle<'a, T: Clone> {
+    current: NodePtr<T>,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+pub struct IntoIter<T: Clone> {
+    collection: CircularList<T>,
+}
+
+// Iter and Cycle only read through the ring like a shared borrow; IterMut hands out unique ones
+unsafe impl<T: Clone + Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Clone + Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for IterMut<'_, T> {}
+unsafe impl<T: Clone + Sync> Send for Cycle<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for Cycle<'_, T> {}
+
+impl<'a, T: Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = unsafe { &*self.current?.as_ptr() };
+        self.current = Some(node.next);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = unsafe { &mut *self.current?.as_ptr() };
+        self.current = Some(node.next);
+        self.remaining -= 1;
+        Some(&mut node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { &*self.current?.as_ptr() };
+        self.current = Some(node.next);
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.current {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a, T: Clone> FusedIterator for Cycle<'a, T> {}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.collection.pop_head()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+impl<T: Clone> CircularList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head(),
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            current: self.head(),
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+    pub fn iter_cycle(&self) -> Cycle<'_, T> {
+        Cycle {
+            current: self.head(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for CircularList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { collection: self }
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a CircularList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut CircularList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for CircularList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T: Clone> Extend<T> for CircularList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_tail(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for CircularList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_tail(iter.into_iter().copied());
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for CircularList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<CircularList<T>> for Vec<T> {
+    fn from(list: CircularList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
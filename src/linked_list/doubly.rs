@@ -2,13 +2,25 @@
 
     DOUBLY-LINKED LIST
 
-    Implemented via rc-refcell pointers
+    Implemented via NonNull pointers
+    with the list owning every node
 
 */
 
-use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
 
-pub type NodePtr<T> = Option<Rc<RefCell<Node<T>>>>;
+use crate::instrument::{self, counted};
+
+pub type NodePtr<T> = Option<NonNull<Node<T>>>;
 
 pub struct Node<T: Clone> {
     pub data: T,
@@ -24,20 +36,75 @@ impl<T: Clone> Node<T> {
             prev: None,
         }
     }
+    fn into_ptr(self) -> NonNull<Node<T>> {
+        instrument::allocation();
+        NonNull::from(Box::leak(Box::new(self)))
+    }
+}
+
+// splices two sorted chains together along their next links only, taking from a on ties
+fn merge_runs<T, F>(mut a: NodePtr<T>, mut b: NodePtr<T>, cmp: &mut F) -> NodePtr<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut head = None;
+    let mut last: NodePtr<T> = None;
+    while let (Some(x), Some(y)) = (a, b) {
+        let node = unsafe {
+            if counted(cmp(&y.as_ref().data, &x.as_ref().data)).is_lt() {
+                b = y.as_ref().next;
+                y
+            } else {
+                a = x.as_ref().next;
+                x
+            }
+        };
+        match last {
+            Some(mut last) => unsafe { last.as_mut().next = Some(node) },
+            None => head = Some(node),
+        }
+        last = Some(node);
+    }
+    let rest = a.or(b);
+    match last {
+        Some(mut last) => unsafe { last.as_mut().next = rest },
+        None => head = rest,
+    }
+    head
 }
 
-impl<T: Clone> Into<NodePtr<T>> for Node<T> {
-    fn into(self) -> NodePtr<T> {
-        Some(Rc::new(RefCell::new(self)))
+fn sort_runs<T, F>(head: NodePtr<T>, len: usize, cmp: &mut F) -> NodePtr<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if len < 2 {
+        return head;
+    }
+    let mid = len / 2;
+    let mut last = head.unwrap();
+    for _ in 1..mid {
+        last = unsafe { last.as_ref().next.unwrap() };
     }
+    let back = unsafe { last.as_mut().next.take() };
+    let front = sort_runs(head, mid, cmp);
+    let back = sort_runs(back, len - mid, cmp);
+    merge_runs(front, back, cmp)
 }
 
 pub struct LinkedList<T: Clone> {
-    pub head: NodePtr<T>,
-    pub tail: NodePtr<T>,
-    pub size: usize,
+    head: NodePtr<T>,
+    tail: NodePtr<T>,
+    size: usize,
+    marker: PhantomData<Box<Node<T>>>,
 }
 
+// every node is owned by exactly one list and reached only through it, so the NonNull links
+// carry no shared state of their own and thread safety follows from T
+unsafe impl<T: Clone + Send> Send for LinkedList<T> {}
+unsafe impl<T: Clone + Sync> Sync for LinkedList<T> {}
+
 #[macro_export]
 macro_rules! doubly_linked {
     [ $val:expr ; $count:expr ] => {{
@@ -52,12 +119,19 @@ macro_rules! doubly_linked {
     };
 }
 
+impl<T: Clone> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Clone> LinkedList<T> {
     pub fn new() -> Self {
         Self {
             head: None,
             tail: None,
             size: 0,
+            marker: PhantomData,
         }
     }
     pub fn from<I>(iter: I) -> Self
@@ -70,6 +144,79 @@ impl<T: Clone> LinkedList<T> {
     }
 }
 
+impl<T: Clone> LinkedList<T> {
+    fn node_at(&self, index: usize) -> NodePtr<T> {
+        if index >= self.size {
+            return None;
+        }
+        // walk from whichever end is closer
+        if index < self.size / 2 {
+            let mut ptr = self.head;
+            for _ in 0..index {
+                ptr = unsafe { ptr?.as_ref().next };
+            }
+            ptr
+        } else {
+            let mut ptr = self.tail;
+            for _ in index + 1..self.size {
+                ptr = unsafe { ptr?.as_ref().prev };
+            }
+            ptr
+        }
+    }
+    // links a detached node between prev and next, either of which may be the list boundary
+    fn link(&mut self, mut node: NonNull<Node<T>>, prev: NodePtr<T>, next: NodePtr<T>) {
+        unsafe {
+            node.as_mut().prev = prev;
+            node.as_mut().next = next;
+            match prev {
+                Some(mut prev) => prev.as_mut().next = Some(node),
+                None => self.head = Some(node),
+            }
+            match next {
+                Some(mut next) => next.as_mut().prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+        self.size += 1;
+    }
+    // cuts the list after node, which sits at position kept - 1, and returns the detached rest
+    fn detach_after(&mut self, mut node: NonNull<Node<T>>, kept: usize) -> Self {
+        let Some(mut next) = (unsafe { node.as_mut().next.take() }) else {
+            return Self::new();
+        };
+        unsafe {
+            next.as_mut().prev = None;
+        }
+        let split = Self {
+            head: Some(next),
+            tail: self.tail.replace(node),
+            size: self.size - kept,
+            marker: PhantomData,
+        };
+        self.size = kept;
+        split
+    }
+    // detaches a node owned by this list and hands back ownership of its allocation
+    fn unlink(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let mut node = unsafe { Box::from_raw(node.as_ptr()) };
+        unsafe {
+            match node.prev {
+                Some(mut prev) => prev.as_mut().next = node.next,
+                None => self.head = node.next,
+            }
+            match node.next {
+                Some(mut next) => next.as_mut().prev = node.prev,
+                None => self.tail = node.prev,
+            }
+        }
+        node.prev = None;
+        node.next = None;
+        self.size -= 1;
+        node
+    }
+}
+
 impl<T: Clone> LinkedList<T> {
     pub fn is_empty(&self) -> bool {
         self.head.is_none()
@@ -78,28 +225,12 @@ impl<T: Clone> LinkedList<T> {
         self.size
     }
     pub fn push_head(&mut self, data: T) {
-        let mut node = Node::new(data);
-        node.next = self.head.clone();
-        let ptr: NodePtr<T> = node.into();
-        if let Some(head) = self.head.clone() {
-            head.borrow_mut().prev = ptr.clone();
-        } else {
-            self.tail = ptr.clone();
-        }
-        self.head = ptr;
-        self.size += 1;
+        let node = Node::new(data).into_ptr();
+        self.link(node, None, self.head);
     }
     pub fn push_tail(&mut self, data: T) {
-        let mut node = Node::new(data);
-        node.prev = self.tail.clone();
-        let ptr: NodePtr<T> = node.into();
-        if let Some(tail) = self.tail.clone() {
-            tail.borrow_mut().next = ptr.clone();
-        } else {
-            self.head = ptr.clone();
-        }
-        self.tail = ptr;
-        self.size += 1;
+        let node = Node::new(data).into_ptr();
+        self.link(node, self.tail, None);
     }
     pub fn extend_head<I>(&mut self, iter: I)
     where
@@ -118,107 +249,289 @@ impl<T: Clone> LinkedList<T> {
         }
     }
     pub fn pop_head(&mut self) -> Option<T> {
-        if let Some(head) = self.head.take() {
-            if let Some(next) = head.borrow().next.clone() {
-                next.borrow_mut().prev = None;
-            }
-            self.head = head.borrow().next.clone();
-            Rc::try_unwrap(head).ok().map(|node| {
-                self.size -= 1;
-                node.into_inner().data
-            })
-        } else {
-            None
-        }
+        let head = self.head?;
+        Some(self.unlink(head).data)
     }
     pub fn pop_tail(&mut self) -> Option<T> {
-        if let Some(tail) = self.tail.take() {
-            if let Some(prev) = tail.borrow().prev.clone() {
-                prev.borrow_mut().next = None;
+        let tail = self.tail?;
+        Some(self.unlink(tail).data)
+    }
+    pub fn peek_head(&self) -> Option<&T> {
+        self.head.map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+    pub fn peek_tail(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
+    }
+    pub fn peek_head_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).data })
+    }
+    pub fn peek_tail_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).data })
+    }
+    pub fn insert(&mut self, index: usize, data: T) -> Option<()> {
+        let node = self.node_at(index)?;
+        let next = unsafe { node.as_ref().next };
+        self.link(Node::new(data).into_ptr(), Some(node), next);
+        Some(())
+    }
+    pub fn set(&mut self, index: usize, data: T) -> Option<()> {
+        *self.get_mut(index)? = data;
+        Some(())
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.node_at(index)
+            .map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_at(index)
+            .map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let node = self.node_at(index)?;
+        Some(self.unlink(node).data)
+    }
+    pub fn remove_first(&mut self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            ptr = unsafe { node.as_ref().next };
+            if unsafe { &node.as_ref().data } == data {
+                self.unlink(node);
+                return true;
             }
-            self.tail = tail.borrow().prev.clone();
-            Rc::try_unwrap(tail).ok().map(|node| {
-                self.size -= 1;
-                node.into_inner().data
-            })
-        } else {
-            None
         }
+        false
     }
-    pub fn insert(&mut self, index: usize, data: T) -> Option<()> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = self.head.clone();
-            while let Some(node) = ptr.clone() {
-                if i == index {
-                    break;
-                }
-                i += 1;
-                ptr = node.borrow().next.clone();
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            ptr = unsafe { node.as_ref().next };
+            if !predicate(unsafe { &node.as_ref().data }) {
+                self.unlink(node);
             }
-            if let Some(node) = ptr.clone() {
-                let mut new = Node::new(data);
-                new.next = node.borrow().next.clone();
-                new.prev = ptr;
-                let node_ptr: NodePtr<T> = new.into();
-                if let Some(next) = node.borrow().next.clone() {
-                    next.borrow_mut().prev = node_ptr.clone();
-                }
-                node.borrow_mut().next = node_ptr;
+        }
+    }
+    pub fn drain(&mut self) -> IntoIter<T> {
+        core::mem::take(self).into_iter()
+    }
+    // unlinks every element the predicate accepts, yielding them front to back
+    pub fn drain_filter<F>(&mut self, mut predicate: F) -> IntoIter<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut drained = Self::new();
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            ptr = unsafe { node.as_ref().next };
+            if predicate(unsafe { &node.as_ref().data }) {
+                drained.push_tail(self.unlink(node).data);
             }
-            Some(())
-        } else {
-            None
         }
+        drained.into_iter()
     }
-    pub fn set(&mut self, index: usize, data: T) -> Option<()> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = self.head.clone();
-            while let Some(node) = ptr {
-                if i == index {
-                    node.borrow_mut().data = data;
-                    break;
-                }
-                i += 1;
-                ptr = node.borrow().next.clone();
+    pub fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.size, "split index out of bounds");
+        if index == 0 {
+            return core::mem::take(self);
+        }
+        match self.node_at(index - 1) {
+            Some(node) => self.detach_after(node, index),
+            None => Self::new(),
+        }
+    }
+    pub fn split_at(mut self, index: usize) -> (Self, Self) {
+        let back = self.split_off(index);
+        (self, back)
+    }
+    pub fn append(&mut self, mut other: Self) {
+        let (Some(mut tail), Some(mut head)) = (self.tail, other.head) else {
+            if self.is_empty() {
+                core::mem::swap(self, &mut other);
             }
-            Some(())
-        } else {
-            None
-        }
-    }
-    pub fn get(&self, index: usize) -> Option<T> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = self.head.clone();
-            while let Some(node) = ptr {
-                if i == index {
-                    return Some(node.borrow().data.clone());
-                }
-                i += 1;
-                ptr = node.borrow().next.clone();
+            return;
+        };
+        // relink the two ends and hand other's nodes over without touching them
+        unsafe {
+            tail.as_mut().next = Some(head);
+            head.as_mut().prev = Some(tail);
+        }
+        self.tail = other.tail.take();
+        self.size += other.size;
+        other.head = None;
+        other.size = 0;
+    }
+    pub fn reverse(&mut self) {
+        let mut ptr = self.head;
+        while let Some(mut node) = ptr {
+            let node = unsafe { node.as_mut() };
+            core::mem::swap(&mut node.next, &mut node.prev);
+            ptr = node.prev;
+        }
+        core::mem::swap(&mut self.head, &mut self.tail);
+    }
+    pub fn rotate_left(&mut self, k: usize) {
+        if self.size == 0 || k.is_multiple_of(self.size) {
+            return;
+        }
+        let (Some(mut head), Some(mut tail)) = (self.head, self.tail) else {
+            return;
+        };
+        let Some(mut last) = self.node_at(k % self.size - 1) else {
+            return;
+        };
+        // close the ring, then cut it open again after the kth node
+        unsafe {
+            tail.as_mut().next = Some(head);
+            head.as_mut().prev = Some(tail);
+            let mut first = last.as_mut().next.take().unwrap();
+            first.as_mut().prev = None;
+            self.head = Some(first);
+        }
+        self.tail = Some(last);
+    }
+    pub fn rotate_right(&mut self, k: usize) {
+        if self.size > 0 {
+            self.rotate_left(self.size - k % self.size);
+        }
+    }
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // the list is emptied while links are in flux, so a panicking comparator only leaks
+        let len = core::mem::take(&mut self.size);
+        self.tail = None;
+        let head = sort_runs(self.head.take(), len, &mut cmp);
+        self.relink(head, len);
+    }
+    pub fn merge<F>(mut self, mut other: Self, mut cmp: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = core::mem::take(&mut self.size) + core::mem::take(&mut other.size);
+        self.tail = None;
+        other.tail = None;
+        let head = merge_runs(self.head.take(), other.head.take(), &mut cmp);
+        let mut list = Self::new();
+        list.relink(head, len);
+        list
+    }
+    // adopts a chain linked through next only, restoring prev links and the tail
+    fn relink(&mut self, head: NodePtr<T>, len: usize) {
+        let mut prev = None;
+        let mut ptr = head;
+        while let Some(mut node) = ptr {
+            unsafe {
+                node.as_mut().prev = prev;
+                ptr = node.as_ref().next;
             }
+            prev = Some(node);
         }
-        None
+        self.head = head;
+        self.tail = prev;
+        self.size = len;
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.peek_head()
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.peek_tail()
+    }
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
     }
     pub fn clear(&mut self) {
-        self.head = None;
-        self.tail = None;
-        self.size = 0;
+        while self.pop_head().is_some() {}
+    }
+}
+
+impl<T: Clone> Index<usize> for LinkedList<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // if an element's destructor panics, the guard keeps freeing the remaining nodes
+        struct DropGuard<'a, T: Clone>(&'a mut LinkedList<T>);
+        impl<T: Clone> Drop for DropGuard<'_, T> {
+            fn drop(&mut self) {
+                self.0.clear();
+            }
+        }
+        while let Some(head) = self.head {
+            let node = self.unlink(head);
+            let guard = DropGuard(self);
+            drop(node);
+            core::mem::forget(guard);
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for LinkedList<T> {}
+
+impl<T: Clone + PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
     }
 }
 
 impl<T: Clone + Debug> Debug for LinkedList<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[")?;
-        let mut ptr = self.head.clone();
-        while let Some(node) = ptr {
-            write!(f, "{:?}", node.borrow().data)?;
-            if node.borrow().next.is_some() {
+        let mut iter = self.iter().peekable();
+        while let Some(item) = iter.next() {
+            write!(f, "{:?}", item)?;
+            if iter.peek().is_some() {
                 write!(f, ", ")?;
             }
-            ptr = node.borrow().next.clone();
         }
         write!(f, "]")
     }
@@ -227,26 +540,104 @@ impl<T: Clone + Debug> Debug for LinkedList<T> {
 impl<T: Clone> Clone for LinkedList<T> {
     fn clone(&self) -> Self {
         let mut list = Self::new();
-        self.iter()
-            .for_each(|item| list.push_tail(item.borrow().data.clone()));
+        self.iter().for_each(|item| list.push_tail(item.clone()));
         list
     }
 }
 
-pub struct Iter<T: Clone> {
-    current: NodePtr<T>,
+pub struct Iter<'a, T: Clone> {
+    head: NodePtr<T>,
+    tail: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+pub struct IterMut<'a, T: Clone> {
+    head: NodePtr<T>,
+    tail: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>,
 }
 
 pub struct IntoIter<T: Clone> {
     collection: LinkedList<T>,
 }
 
-impl<T: Clone> Iterator for Iter<T> {
-    type Item = Rc<RefCell<Node<T>>>;
+// the borrowing iterators act as &LinkedList and &mut LinkedList respectively
+unsafe impl<T: Clone + Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Clone + Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T: Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.head.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.remaining -= 1;
+            self.head = node.next;
+            &node.data
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.tail.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.remaining -= 1;
+            self.tail = node.prev;
+            &node.data
+        })
+    }
+}
+
+impl<'a, T: Clone> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
-        let ptr = self.current.clone();
-        self.current = ptr.as_ref()?.borrow().next.clone();
-        Some(ptr?)
+        if self.remaining == 0 {
+            return None;
+        }
+        self.head.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.remaining -= 1;
+            self.head = node.next;
+            &mut node.data
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.tail.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.remaining -= 1;
+            self.tail = node.prev;
+            &mut node.data
+        })
     }
 }
 
@@ -255,14 +646,124 @@ impl<T: Clone> Iterator for IntoIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.collection.pop_head()
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+impl<T: Clone> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.collection.pop_tail()
+    }
 }
 
 impl<T: Clone> LinkedList<T> {
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            current: self.head.clone(),
+            head: self.head,
+            tail: self.tail,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct CursorMut<'a, T: Clone> {
+    list: &'a mut LinkedList<T>,
+    current: NodePtr<T>,
+    index: usize,
+}
+
+// current points into the list the cursor already borrows mutably, so it adds nothing to it
+unsafe impl<T: Clone + Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for CursorMut<'_, T> {}
+
+impl<T: Clone> LinkedList<T> {
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            index: self.size.saturating_sub(1),
+            list: self,
+        }
+    }
+}
+
+impl<T: Clone> CursorMut<'_, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            self.current = unsafe { node.as_ref().next };
+            self.index += 1;
+        } else {
+            self.current = self.list.head;
+            self.index = 0;
+        }
+    }
+    pub fn move_prev(&mut self) {
+        if let Some(node) = self.current {
+            self.current = unsafe { node.as_ref().prev };
+            self.index = self.index.checked_sub(1).unwrap_or(self.list.size);
+        } else {
+            self.current = self.list.tail;
+            self.index = self.list.size.saturating_sub(1);
         }
     }
+    pub fn insert_before(&mut self, data: T) {
+        let Some(node) = self.current else {
+            self.list.push_tail(data);
+            self.index = self.list.size;
+            return;
+        };
+        let prev = unsafe { node.as_ref().prev };
+        self.list.link(Node::new(data).into_ptr(), prev, Some(node));
+        self.index += 1;
+    }
+    pub fn insert_after(&mut self, data: T) {
+        let Some(node) = self.current else {
+            self.list.push_head(data);
+            self.index = self.list.size;
+            return;
+        };
+        let next = unsafe { node.as_ref().next };
+        self.list.link(Node::new(data).into_ptr(), Some(node), next);
+    }
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        self.current = unsafe { node.as_ref().next };
+        Some(self.list.unlink(node).data)
+    }
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let Some(node) = self.current else {
+            return core::mem::take(self.list);
+        };
+        self.list.detach_after(node, self.index + 1)
+    }
 }
 
 impl<T: Clone> IntoIterator for LinkedList<T> {
@@ -273,8 +774,48 @@ impl<T: Clone> IntoIterator for LinkedList<T> {
     }
 }
 
+impl<'a, T: Clone> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T: Clone> FromIterator<T> for LinkedList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::from(iter)
     }
 }
+
+impl<T: Clone> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_tail(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_tail(iter.into_iter().copied());
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
@@ -1,2 +1,5 @@
 pub mod singly;
-pub mod doubly;
\ No newline at end of file
+pub mod doubly;
+pub mod raw;
+pub mod unrolled;
+pub mod circular;
@@ -0,0 +1,438 @@
+/*
+
+    XOR-LINKED LIST
+
+    nodes kept in an arena, each storing
+    the xor of its neighbours' slot numbers
+
+*/
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+};
+
+use crate::arena::{Arena, Handle};
+
+pub struct Node<T: Clone> {
+    pub data: T,
+    // slot numbers rather than addresses: a pointer rebuilt from the xor of two addresses could
+    // only carry the provenance of one of them, while a slot is found from the arena itself
+    link: u32,
+}
+
+// the neighbour of node on the side opposite to from
+fn step<T: Clone>(node: &Node<T>, from: Option<Handle>) -> Option<Handle> {
+    Handle::from_bits(node.link ^ Handle::to_bits(from))
+}
+
+pub struct LinkedList<T: Clone> {
+    nodes: Arena<Node<T>>,
+    head: Option<Handle>,
+    tail: Option<Handle>,
+    size: usize,
+}
+
+impl<T: Clone> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            head: None,
+            tail: None,
+            size: 0,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend_tail(iter);
+        list
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    pub fn len(&self) -> usize {
+        self.size
+    }
+    pub fn push_head(&mut self, data: T) {
+        let node = Some(self.nodes.alloc(Node {
+            data,
+            link: Handle::to_bits(self.head),
+        }));
+        match self.head {
+            Some(head) => self.nodes[head].link ^= Handle::to_bits(node),
+            None => self.tail = node,
+        }
+        self.head = node;
+        self.size += 1;
+    }
+    pub fn push_tail(&mut self, data: T) {
+        let node = Some(self.nodes.alloc(Node {
+            data,
+            link: Handle::to_bits(self.tail),
+        }));
+        match self.tail {
+            Some(tail) => self.nodes[tail].link ^= Handle::to_bits(node),
+            None => self.head = node,
+        }
+        self.tail = node;
+        self.size += 1;
+    }
+    pub fn extend_head<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_head(i);
+        }
+    }
+    pub fn extend_tail<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_tail(i);
+        }
+    }
+    pub fn pop_head(&mut self) -> Option<T> {
+        let head = self.head?;
+        let node = self.nodes.free(head).expect("the head is in the arena");
+        let next = Handle::from_bits(node.link);
+        match next {
+            Some(next) => self.nodes[next].link ^= Handle::to_bits(Some(head)),
+            None => self.tail = None,
+        }
+        self.head = next;
+        self.size -= 1;
+        Some(node.data)
+    }
+    pub fn pop_tail(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let node = self.nodes.free(tail).expect("the tail is in the arena");
+        let prev = Handle::from_bits(node.link);
+        match prev {
+            Some(prev) => self.nodes[prev].link ^= Handle::to_bits(Some(tail)),
+            None => self.head = None,
+        }
+        self.tail = prev;
+        self.size -= 1;
+        Some(node.data)
+    }
+    pub fn peek_head(&self) -> Option<&T> {
+        self.head.map(|head| &self.nodes[head].data)
+    }
+    pub fn peek_tail(&self) -> Option<&T> {
+        self.tail.map(|tail| &self.nodes[tail].data)
+    }
+    pub fn peek_head_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|head| &mut self.nodes[head].data)
+    }
+    pub fn peek_tail_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|tail| &mut self.nodes[tail].data)
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.size / 2 {
+            self.iter().nth(index)
+        } else {
+            self.iter().rev().nth(self.size.checked_sub(index + 1)?)
+        }
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let size = self.size;
+        if index < size / 2 {
+            self.iter_mut().nth(index)
+        } else {
+            self.iter_mut().rev().nth(size.checked_sub(index + 1)?)
+        }
+    }
+    // every link is symmetric in prev and next, so swapping the ends reverses the list
+    pub fn reverse(&mut self) {
+        core::mem::swap(&mut self.head, &mut self.tail);
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.peek_head()
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.peek_tail()
+    }
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = None;
+        self.tail = None;
+        self.size = 0;
+    }
+}
+
+impl<T: Clone> Index<usize> for LinkedList<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for LinkedList<T> {}
+
+impl<T: Clone + PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T: Clone + Debug> Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        Self::from(self.iter().cloned())
+    }
+}
+
+pub struct Iter<'a, T: Clone> {
+    nodes: &'a Arena<Node<T>>,
+    head: Option<Handle>,
+    before_head: Option<Handle>,
+    tail: Option<Handle>,
+    after_tail: Option<Handle>,
+    remaining: usize,
+}
+
+pub struct IterMut<'a, T: Clone> {
+    nodes: &'a mut Arena<Node<T>>,
+    head: Option<Handle>,
+    before_head: Option<Handle>,
+    tail: Option<Handle>,
+    after_tail: Option<Handle>,
+    remaining: usize,
+}
+
+pub struct IntoIter<T: Clone> {
+    collection: LinkedList<T>,
+}
+
+impl<'a, T: Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let at = self.head?;
+        let node = &self.nodes[at];
+        self.head = step(node, self.before_head);
+        self.before_head = Some(at);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let at = self.tail?;
+        let node = &self.nodes[at];
+        self.tail = step(node, self.after_tail);
+        self.after_tail = Some(at);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<'a, T: Clone> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let at = self.head?;
+        // one slot at a time through a pointer, never the whole arena, so the references already
+        // handed out stay valid; each node is yielded once, so they never overlap
+        let node = unsafe { &mut *self.nodes.get_ptr(at)?.as_ptr() };
+        self.head = step(node, self.before_head);
+        self.before_head = Some(at);
+        self.remaining -= 1;
+        Some(&mut node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let at = self.tail?;
+        // as in next
+        let node = unsafe { &mut *self.nodes.get_ptr(at)?.as_ptr() };
+        self.tail = step(node, self.after_tail);
+        self.after_tail = Some(at);
+        self.remaining -= 1;
+        Some(&mut node.data)
+    }
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.collection.pop_head()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+impl<T: Clone> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.collection.pop_tail()
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: &self.nodes,
+            head: self.head,
+            before_head: None,
+            tail: self.tail,
+            after_tail: None,
+            remaining: self.size,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            nodes: &mut self.nodes,
+            head: self.head,
+            before_head: None,
+            tail: self.tail,
+            after_tail: None,
+            remaining: self.size,
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { collection: self }
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T: Clone> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_tail(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_tail(iter.into_iter().copied());
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
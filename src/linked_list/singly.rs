@@ -2,38 +2,108 @@
 
     SINGLY-LINKED LIST
 
-    implemented via Box pointer
-    with raw mut ptr as tail
+    implemented via NonNull pointers
+    with the tail kept for O(1) appends
 
 */
 
-use std::{fmt::Debug, ptr::null_mut};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
 
-pub type NodePtr<T> = Option<Box<Node<T>>>;
+use crate::instrument::{self, counted};
+
+pub type NodePtr<T> = Option<NonNull<Node<T>>>;
 
 pub struct Node<T: Clone> {
     pub data: T,
-    pub next: NodePtr<T>,
+    next: NodePtr<T>,
 }
 
 impl<T: Clone> Node<T> {
     pub fn new(data: T) -> Self {
         Self { data, next: None }
     }
+    fn into_ptr(self) -> NonNull<Node<T>> {
+        instrument::allocation();
+        NonNull::from(Box::leak(Box::new(self)))
+    }
+}
+
+fn advance<T: Clone>(ptr: NodePtr<T>) -> NodePtr<T> {
+    ptr.and_then(|node| unsafe { node.as_ref().next })
+}
+
+// splices two sorted chains together, taking from a on ties to keep the sort stable
+fn merge_runs<T, F>(mut a: NodePtr<T>, mut b: NodePtr<T>, cmp: &mut F) -> NodePtr<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut head = None;
+    let mut last: NodePtr<T> = None;
+    while let (Some(x), Some(y)) = (a, b) {
+        let node = unsafe {
+            if counted(cmp(&y.as_ref().data, &x.as_ref().data)).is_lt() {
+                b = y.as_ref().next;
+                y
+            } else {
+                a = x.as_ref().next;
+                x
+            }
+        };
+        match last {
+            Some(mut last) => unsafe { last.as_mut().next = Some(node) },
+            None => head = Some(node),
+        }
+        last = Some(node);
+    }
+    let rest = a.or(b);
+    match last {
+        Some(mut last) => unsafe { last.as_mut().next = rest },
+        None => head = rest,
+    }
+    head
 }
 
-impl<T: Clone> Into<NodePtr<T>> for Node<T> {
-    fn into(self) -> NodePtr<T> {
-        Some(Box::new(self))
+fn sort_runs<T, F>(head: NodePtr<T>, len: usize, cmp: &mut F) -> NodePtr<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if len < 2 {
+        return head;
+    }
+    let mid = len / 2;
+    let mut last = head.unwrap();
+    for _ in 1..mid {
+        last = unsafe { last.as_ref().next.unwrap() };
     }
+    let back = unsafe { last.as_mut().next.take() };
+    let front = sort_runs(head, mid, cmp);
+    let back = sort_runs(back, len - mid, cmp);
+    merge_runs(front, back, cmp)
 }
 
 pub struct LinkedList<T: Clone> {
-    pub head: NodePtr<T>,
-    pub tail: *mut Node<T>,
-    pub size: usize,
+    head: NodePtr<T>,
+    tail: NodePtr<T>,
+    size: usize,
+    marker: PhantomData<Box<Node<T>>>,
 }
 
+// head and tail are both plain pointers into a chain the list alone owns, so neither is a
+// second owner and the list can cross threads exactly when its elements can
+unsafe impl<T: Clone + Send> Send for LinkedList<T> {}
+unsafe impl<T: Clone + Sync> Sync for LinkedList<T> {}
+
 #[macro_export]
 macro_rules! singly_linked {
     [ $val:expr ; $count:expr ] => {{
@@ -48,12 +118,19 @@ macro_rules! singly_linked {
     };
 }
 
+impl<T: Clone> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Clone> LinkedList<T> {
     pub fn new() -> Self {
         Self {
             head: None,
-            tail: null_mut(),
+            tail: None,
             size: 0,
+            marker: PhantomData,
         }
     }
     pub fn from<I>(iter: I) -> Self
@@ -66,6 +143,34 @@ impl<T: Clone> LinkedList<T> {
     }
 }
 
+impl<T: Clone> LinkedList<T> {
+    fn node_at(&self, index: usize) -> NodePtr<T> {
+        if index >= self.size {
+            return None;
+        }
+        let mut ptr = self.head;
+        for _ in 0..index {
+            ptr = advance(ptr);
+        }
+        ptr
+    }
+    // detaches node, whose predecessor is prev or which is the head when prev is None, and hands
+    // back ownership of its allocation
+    fn unlink_after(&mut self, prev: NodePtr<T>, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let mut node = unsafe { Box::from_raw(node.as_ptr()) };
+        match prev {
+            Some(mut prev) => unsafe { prev.as_mut().next = node.next },
+            None => self.head = node.next,
+        }
+        if node.next.is_none() {
+            self.tail = prev;
+        }
+        node.next = None;
+        self.size -= 1;
+        node
+    }
+}
+
 impl<T: Clone> LinkedList<T> {
     pub fn is_empty(&self) -> bool {
         self.head.is_none()
@@ -75,28 +180,21 @@ impl<T: Clone> LinkedList<T> {
     }
     pub fn push_head(&mut self, data: T) {
         let mut node = Node::new(data);
-        node.next = self.head.take();
-        self.head = node.into();
-        if self.tail.is_null() {
-            self.tail = self
-                .head
-                .as_mut()
-                .map(|ptr| ptr.as_mut() as *mut Node<T>)
-                .unwrap();
+        node.next = self.head;
+        let node = node.into_ptr();
+        if self.tail.is_none() {
+            self.tail = Some(node);
         }
+        self.head = Some(node);
         self.size += 1;
     }
     pub fn push_tail(&mut self, data: T) {
-        let mut new_node = Box::new(Node::new(data));
-        let raw_node: *mut Node<T> = &mut *new_node;
-        if let Some(tail_ptr) = (!self.tail.is_null()).then_some(self.tail) {
-            unsafe {
-                (*tail_ptr).next = Some(new_node);
-            }
-        } else {
-            self.head = Some(new_node);
+        let node = Node::new(data).into_ptr();
+        match self.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => self.head = Some(node),
         }
-        self.tail = raw_node;
+        self.tail = Some(node);
         self.size += 1;
     }
     pub fn extend_head<I>(&mut self, iter: I)
@@ -116,34 +214,28 @@ impl<T: Clone> LinkedList<T> {
         }
     }
     pub fn pop(&mut self) -> Option<T> {
-        if let Some(head) = self.head.take() {
-            self.head = head.next;
-            self.size -= 1;
-            if self.is_empty() {
-                self.tail = null_mut();
-            }
-            Some(head.data)
-        } else {
-            None
-        }
+        let head = self.head?;
+        Some(self.unlink_after(None, head).data)
+    }
+    // nodes only link forward, so finding the new tail walks the whole list: O(n)
+    pub fn pop_tail(&mut self) -> Option<T> {
+        self.remove(self.size.checked_sub(1)?)
     }
     pub fn insert(&mut self, index: usize, data: T) -> Option<()> {
         if index < self.size {
             if index == 0 {
                 self.push_head(data);
             } else {
-                let mut i = 0;
-                let mut ptr = &mut self.head;
-                while let Some(node) = ptr {
-                    if i == index {
-                        let mut new = Node::new(data);
-                        new.next = node.next.take();
-                        node.next = new.into();
-                        break;
-                    }
-                    i += 1;
-                    ptr = &mut node.next;
+                let mut node = self.node_at(index)?;
+                let mut new = Node::new(data);
+                new.next = unsafe { node.as_ref().next };
+                let last = new.next.is_none();
+                let new = new.into_ptr();
+                unsafe { node.as_mut().next = Some(new) };
+                if last {
+                    self.tail = Some(new);
                 }
+                self.size += 1;
             }
             Some(())
         } else {
@@ -151,69 +243,288 @@ impl<T: Clone> LinkedList<T> {
         }
     }
     pub fn set(&mut self, index: usize, data: T) -> Option<()> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = &mut self.head;
-            while let Some(node) = ptr {
-                if i == index {
-                    node.data = data;
-                    break;
-                }
-                i += 1;
-                ptr = &mut node.next;
+        *self.get_mut(index)? = data;
+        Some(())
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.node_at(index).map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_at(index).map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+    pub fn has_cycle(&self) -> bool {
+        // floyd's tortoise and hare: a cycle eventually lets the hare lap the tortoise
+        let mut slow = self.head;
+        let mut fast = self.head;
+        while let Some(next) = advance(fast) {
+            fast = advance(Some(next));
+            slow = advance(slow);
+            if slow.is_some() && slow == fast {
+                return true;
             }
-            Some(())
+        }
+        false
+    }
+    // test hooks for has_cycle: links the tail back to the node at index, which no list can
+    // otherwise reach. safety: until break_cycle undoes it, nothing but has_cycle may touch the
+    // list, dropping it included
+    #[doc(hidden)]
+    #[cfg(feature = "verify")]
+    pub unsafe fn make_cycle(&mut self, index: usize) {
+        let target = self.node_at(index).expect("cycle target in range");
+        if let Some(mut tail) = self.tail {
+            unsafe { tail.as_mut().next = Some(target) };
+        }
+    }
+    #[doc(hidden)]
+    #[cfg(feature = "verify")]
+    pub fn break_cycle(&mut self) {
+        if let Some(mut tail) = self.tail {
+            unsafe { tail.as_mut().next = None };
+        }
+    }
+    pub fn middle(&self) -> Option<&T> {
+        // the hare moves two nodes per step, so the tortoise stops halfway
+        let mut slow = self.head?;
+        let mut fast = self.head;
+        while let Some(next) = advance(fast) {
+            fast = advance(Some(next));
+            slow = advance(Some(slow))?;
+        }
+        Some(unsafe { &(*slow.as_ptr()).data })
+    }
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        // keep the lead n nodes ahead so the trail lands on the answer when the lead runs out
+        let mut lead = self.head?;
+        for _ in 0..n {
+            lead = advance(Some(lead))?;
+        }
+        let mut trail = self.head?;
+        while let Some(next) = advance(Some(lead)) {
+            lead = next;
+            trail = advance(Some(trail))?;
+        }
+        Some(unsafe { &(*trail.as_ptr()).data })
+    }
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.size {
+            return None;
+        }
+        let prev = index.checked_sub(1).and_then(|prev| self.node_at(prev));
+        let node = match prev {
+            Some(prev) => advance(Some(prev))?,
+            None => self.head?,
+        };
+        Some(self.unlink_after(prev, node).data)
+    }
+    pub fn remove_first(&mut self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        if let Some(index) = self.iter().position(|item| item == data) {
+            self.remove(index);
+            true
         } else {
-            None
+            false
         }
     }
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = &self.head;
-            while let Some(node) = ptr {
-                if i == index {
-                    return Some(&node.data);
-                }
-                i += 1;
-                ptr = &node.next;
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept: NodePtr<T> = None;
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            ptr = advance(ptr);
+            if predicate(unsafe { &node.as_ref().data }) {
+                kept = Some(node);
+            } else {
+                drop(self.unlink_after(kept, node));
             }
         }
-        None
     }
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index < self.size {
-            let mut i = 0;
-            let mut ptr = &mut self.head;
-            while let Some(node) = ptr {
-                if i == index {
-                    return Some(&mut node.data);
-                }
-                i += 1;
-                ptr = &mut node.next;
+    pub fn drain(&mut self) -> IntoIter<T> {
+        core::mem::take(self).into_iter()
+    }
+    // the inverse of retain: matching elements are moved out, in order, instead of dropped
+    pub fn drain_filter<F>(&mut self, mut predicate: F) -> IntoIter<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut drained = Self::new();
+        let mut kept: NodePtr<T> = None;
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            ptr = advance(ptr);
+            if predicate(unsafe { &node.as_ref().data }) {
+                drained.push_tail(self.unlink_after(kept, node).data);
+            } else {
+                kept = Some(node);
             }
         }
-        None
+        drained.into_iter()
+    }
+    pub fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.size, "split index out of bounds");
+        if index == 0 {
+            return core::mem::take(self);
+        }
+        let mut last = self.node_at(index - 1).unwrap();
+        let mut split = Self::new();
+        if let Some(head) = unsafe { last.as_mut().next.take() } {
+            split.head = Some(head);
+            split.tail = self.tail.replace(last);
+            split.size = self.size - index;
+            self.size = index;
+        }
+        split
+    }
+    pub fn split_at(mut self, index: usize) -> (Self, Self) {
+        let back = self.split_off(index);
+        (self, back)
+    }
+    pub fn append(&mut self, mut other: Self) {
+        let Some(head) = other.head.take() else {
+            return;
+        };
+        match self.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(head) },
+            None => self.head = Some(head),
+        }
+        self.tail = other.tail.take();
+        self.size += core::mem::take(&mut other.size);
+    }
+    pub fn reverse(&mut self) {
+        let mut prev: NodePtr<T> = None;
+        let mut ptr = self.head;
+        // the old head ends up last
+        self.tail = self.head;
+        while let Some(mut node) = ptr {
+            ptr = advance(ptr);
+            unsafe { node.as_mut().next = prev };
+            prev = Some(node);
+        }
+        self.head = prev;
+    }
+    pub fn rotate_left(&mut self, k: usize) {
+        if self.size == 0 || k.is_multiple_of(self.size) {
+            return;
+        }
+        let mut rotated = self.split_off(k % self.size);
+        rotated.append(core::mem::take(self));
+        *self = rotated;
+    }
+    pub fn rotate_right(&mut self, k: usize) {
+        if self.size > 0 {
+            self.rotate_left(self.size - k % self.size);
+        }
+    }
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = core::mem::take(&mut self.size);
+        self.tail = None;
+        self.head = sort_runs(self.head.take(), len, &mut cmp);
+        self.size = len;
+        self.relink_tail();
+    }
+    pub fn merge<F>(mut self, mut other: Self, mut cmp: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut list = Self::new();
+        list.size = core::mem::take(&mut self.size) + core::mem::take(&mut other.size);
+        self.tail = None;
+        other.tail = None;
+        list.head = merge_runs(self.head.take(), other.head.take(), &mut cmp);
+        list.relink_tail();
+        list
+    }
+    fn relink_tail(&mut self) {
+        self.tail = self.head;
+        while let Some(next) = advance(self.tail) {
+            self.tail = Some(next);
+        }
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
     }
     pub fn clear(&mut self) {
-        self.head = None;
-        self.tail = null_mut();
-        self.size = 0;
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T: Clone> Index<usize> for LinkedList<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for LinkedList<T> {}
+
+impl<T: Clone + PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
     }
 }
 
 impl<T: Clone + Debug> Debug for LinkedList<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[")?;
-        let mut ptr = &self.head;
-        while let Some(node) = ptr {
-            write!(f, "{:?}", node.data)?;
-            if node.next.is_some() {
-                write!(f, ", ")?;
-            }
-            ptr = &node.next;
-        }
-        write!(f, "]")
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
@@ -226,56 +537,93 @@ impl<T: Clone> Clone for LinkedList<T> {
 }
 
 pub struct Iter<'a, T: Clone> {
-    current: Option<&'a Node<T>>,
+    current: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
 }
 
 pub struct IterMut<'a, T: Clone> {
-    current: Option<&'a mut Node<T>>,
+    current: NodePtr<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut Node<T>>,
 }
 
 pub struct IntoIter<T: Clone> {
     collection: LinkedList<T>,
 }
 
+// the iterators hold node pointers only for the lifetime of a & or &mut borrow of the list
+unsafe impl<T: Clone + Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Clone + Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Clone + Sync> Sync for IterMut<'_, T> {}
+
 impl<'a, T: Clone> Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
-        self.current.map(|node| {
-            self.current = node.next.as_deref();
+        self.current.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.current = node.next;
+            self.remaining -= 1;
             &node.data
         })
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for Iter<'a, T> {}
+
 impl<'a, T: Clone> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
-        let current_node = self.current.take()?;
-        let (data, next) = {
-            let Node { data, next } = current_node;
-            (data, next.as_deref_mut())
-        };
-        self.current = next;
-        Some(data)
+        self.current.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.current = node.next;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IterMut<'a, T> {}
+
 impl<T: Clone> Iterator for IntoIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         self.collection.pop()
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len();
+        (len, Some(len))
+    }
 }
 
+impl<T: Clone> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
 impl<T: Clone> LinkedList<T> {
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter {
-            current: self.head.as_deref(),
+            current: self.head,
+            remaining: self.size,
+            marker: PhantomData,
         }
     }
     pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, T> {
         IterMut {
-            current: self.head.as_deref_mut(),
+            current: self.head,
+            remaining: self.size,
+            marker: PhantomData,
         }
     }
 }
@@ -292,4 +640,28 @@ impl<T: Clone> FromIterator<T> for LinkedList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::from(iter)
     }
-}
\ No newline at end of file
+}
+
+impl<T: Clone> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_tail(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_tail(iter.into_iter().copied());
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
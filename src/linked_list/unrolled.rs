@@ -0,0 +1,593 @@
+/*
+
+    UNROLLED LINKED LIST
+
+    implemented via NonNull pointers
+    with up to N elements stored inline per node
+
+*/
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+    ptr::{self, NonNull},
+};
+
+pub type NodePtr<T, const N: usize> = Option<NonNull<Node<T, N>>>;
+
+// the live elements of a node are always items[start..start + len]
+pub struct Node<T: Clone, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    start: usize,
+    len: usize,
+    next: NodePtr<T, N>,
+    prev: NodePtr<T, N>,
+}
+
+impl<T: Clone, const N: usize> Node<T, N> {
+    fn new(start: usize) -> NonNull<Self> {
+        let node = Self {
+            items: [const { MaybeUninit::uninit() }; N],
+            start,
+            len: 0,
+            next: None,
+            prev: None,
+        };
+        NonNull::from(Box::leak(Box::new(node)))
+    }
+    // slides the live window so it begins at start, opening room on the other side
+    fn shift_to(&mut self, start: usize) {
+        let base = self.items.as_mut_ptr();
+        unsafe {
+            ptr::copy(base.add(self.start), base.add(start), self.len);
+        }
+        self.start = start;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillStats {
+    pub nodes: usize,
+    pub elements: usize,
+    pub capacity: usize,
+    pub min_fill: usize,
+    pub max_fill: usize,
+}
+
+impl FillStats {
+    pub fn load_factor(&self) -> f64 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            self.elements as f64 / self.capacity as f64
+        }
+    }
+}
+
+pub struct UnrolledList<T: Clone, const N: usize = 16> {
+    head: NodePtr<T, N>,
+    tail: NodePtr<T, N>,
+    size: usize,
+    nodes: usize,
+    marker: PhantomData<Box<Node<T, N>>>,
+}
+
+// nodes and the initialised slots inside them belong to this list alone
+unsafe impl<T: Clone + Send, const N: usize> Send for UnrolledList<T, N> {}
+unsafe impl<T: Clone + Sync, const N: usize> Sync for UnrolledList<T, N> {}
+
+impl<T: Clone, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> UnrolledList<T, N> {
+    pub fn new() -> Self {
+        const { assert!(N > 0, "unrolled nodes must hold at least one element") };
+        Self {
+            head: None,
+            tail: None,
+            size: 0,
+            nodes: 0,
+            marker: PhantomData,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new();
+        list.extend_tail(iter);
+        list
+    }
+}
+
+impl<T: Clone, const N: usize> UnrolledList<T, N> {
+    fn node_of(&self, index: usize) -> Option<(NonNull<Node<T, N>>, usize)> {
+        if index >= self.size {
+            return None;
+        }
+        // walk from whichever end is closer, counting whole nodes at a time
+        if index < self.size / 2 {
+            let mut ptr = self.head;
+            let mut offset = index;
+            while let Some(node) = ptr {
+                let len = unsafe { (*node.as_ptr()).len };
+                if offset < len {
+                    return Some((node, offset));
+                }
+                offset -= len;
+                ptr = unsafe { (*node.as_ptr()).next };
+            }
+        } else {
+            let mut ptr = self.tail;
+            let mut offset = self.size - index;
+            while let Some(node) = ptr {
+                let len = unsafe { (*node.as_ptr()).len };
+                if offset <= len {
+                    return Some((node, len - offset));
+                }
+                offset -= len;
+                ptr = unsafe { (*node.as_ptr()).prev };
+            }
+        }
+        None
+    }
+    fn free(&mut self, node: NonNull<Node<T, N>>) {
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        match node.prev {
+            Some(prev) => unsafe { (*prev.as_ptr()).next = node.next },
+            None => self.head = node.next,
+        }
+        match node.next {
+            Some(next) => unsafe { (*next.as_ptr()).prev = node.prev },
+            None => self.tail = node.prev,
+        }
+        self.nodes -= 1;
+    }
+}
+
+impl<T: Clone, const N: usize> UnrolledList<T, N> {
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn len(&self) -> usize {
+        self.size
+    }
+    pub fn node_capacity(&self) -> usize {
+        N
+    }
+    pub fn node_count(&self) -> usize {
+        self.nodes
+    }
+    pub fn push_head(&mut self, data: T) {
+        let node = match self.head {
+            Some(head) if unsafe { (*head.as_ptr()).len } < N => head,
+            _ => {
+                // new head nodes fill from the back so repeated pushes stay O(1)
+                let node = Node::new(N);
+                unsafe { (*node.as_ptr()).next = self.head };
+                match self.head {
+                    Some(head) => unsafe { (*head.as_ptr()).prev = Some(node) },
+                    None => self.tail = Some(node),
+                }
+                self.head = Some(node);
+                self.nodes += 1;
+                node
+            }
+        };
+        let node = unsafe { &mut *node.as_ptr() };
+        if node.start == 0 {
+            node.shift_to(N - node.len);
+        }
+        node.start -= 1;
+        node.len += 1;
+        node.items[node.start].write(data);
+        self.size += 1;
+    }
+    pub fn push_tail(&mut self, data: T) {
+        let node = match self.tail {
+            Some(tail) if unsafe { (*tail.as_ptr()).len } < N => tail,
+            _ => {
+                let node = Node::new(0);
+                unsafe { (*node.as_ptr()).prev = self.tail };
+                match self.tail {
+                    Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+                    None => self.head = Some(node),
+                }
+                self.tail = Some(node);
+                self.nodes += 1;
+                node
+            }
+        };
+        let node = unsafe { &mut *node.as_ptr() };
+        if node.start + node.len == N {
+            node.shift_to(0);
+        }
+        node.items[node.start + node.len].write(data);
+        node.len += 1;
+        self.size += 1;
+    }
+    pub fn extend_head<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_head(i);
+        }
+    }
+    pub fn extend_tail<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for i in iter {
+            self.push_tail(i);
+        }
+    }
+    pub fn pop_head(&mut self) -> Option<T> {
+        let head = self.head?;
+        let node = unsafe { &mut *head.as_ptr() };
+        let data = unsafe { node.items[node.start].assume_init_read() };
+        node.start += 1;
+        node.len -= 1;
+        if node.len == 0 {
+            self.free(head);
+        }
+        self.size -= 1;
+        Some(data)
+    }
+    pub fn pop_tail(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let node = unsafe { &mut *tail.as_ptr() };
+        node.len -= 1;
+        let data = unsafe { node.items[node.start + node.len].assume_init_read() };
+        if node.len == 0 {
+            self.free(tail);
+        }
+        self.size -= 1;
+        Some(data)
+    }
+    pub fn peek_head(&self) -> Option<&T> {
+        self.head.map(|head| unsafe {
+            let node = &*head.as_ptr();
+            node.items[node.start].assume_init_ref()
+        })
+    }
+    pub fn peek_tail(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe {
+            let node = &*tail.as_ptr();
+            node.items[node.start + node.len - 1].assume_init_ref()
+        })
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (node, offset) = self.node_of(index)?;
+        let node = unsafe { &*node.as_ptr() };
+        Some(unsafe { node.items[node.start + offset].assume_init_ref() })
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (node, offset) = self.node_of(index)?;
+        let node = unsafe { &mut *node.as_ptr() };
+        Some(unsafe { node.items[node.start + offset].assume_init_mut() })
+    }
+    pub fn set(&mut self, index: usize, data: T) -> Option<()> {
+        *self.get_mut(index)? = data;
+        Some(())
+    }
+    pub fn fill_stats(&self) -> FillStats {
+        let mut stats = FillStats {
+            nodes: self.nodes,
+            elements: self.size,
+            capacity: self.nodes * N,
+            min_fill: if self.nodes == 0 { 0 } else { N },
+            max_fill: 0,
+        };
+        let mut ptr = self.head;
+        while let Some(node) = ptr {
+            let node = unsafe { &*node.as_ptr() };
+            stats.min_fill = stats.min_fill.min(node.len);
+            stats.max_fill = stats.max_fill.max(node.len);
+            ptr = node.next;
+        }
+        stats
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.peek_head()
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.peek_tail()
+    }
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+    pub fn clear(&mut self) {
+        while self.pop_head().is_some() {}
+    }
+}
+
+impl<T: Clone, const N: usize> Index<usize> for UnrolledList<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone, const N: usize> IndexMut<usize> for UnrolledList<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Clone, const N: usize> Drop for UnrolledList<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> PartialEq for UnrolledList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq, const N: usize> Eq for UnrolledList<T, N> {}
+
+impl<T: Clone + PartialOrd, const N: usize> PartialOrd for UnrolledList<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Ord, const N: usize> Ord for UnrolledList<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Clone + Hash, const N: usize> Hash for UnrolledList<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T: Clone + Debug, const N: usize> Debug for UnrolledList<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledList<T, N> {
+    fn clone(&self) -> Self {
+        Self::from(self.iter().cloned())
+    }
+}
+
+// a double-ended walk over element slots, read through raw places so that no
+// reference ever covers a whole node while one of its elements is lent out
+struct Slots<T: Clone, const N: usize> {
+    front: NodePtr<T, N>,
+    front_slot: usize,
+    back: NodePtr<T, N>,
+    back_slot: usize,
+    remaining: usize,
+}
+
+fn first_slot<T: Clone, const N: usize>(node: NodePtr<T, N>) -> usize {
+    node.map_or(0, |node| unsafe { (*node.as_ptr()).start })
+}
+
+fn end_slot<T: Clone, const N: usize>(node: NodePtr<T, N>) -> usize {
+    node.map_or(0, |node| unsafe {
+        (*node.as_ptr()).start + (*node.as_ptr()).len
+    })
+}
+
+impl<T: Clone, const N: usize> Slots<T, N> {
+    fn new(list: &UnrolledList<T, N>) -> Self {
+        Self {
+            front: list.head,
+            front_slot: first_slot(list.head),
+            back: list.tail,
+            back_slot: end_slot(list.tail),
+            remaining: list.size,
+        }
+    }
+    fn step_front(&mut self) -> Option<(*mut Node<T, N>, usize)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.front?.as_ptr();
+        let slot = self.front_slot;
+        self.front_slot += 1;
+        if self.front_slot == end_slot(self.front) {
+            self.front = unsafe { (*node).next };
+            self.front_slot = first_slot(self.front);
+        }
+        self.remaining -= 1;
+        Some((node, slot))
+    }
+    fn step_back(&mut self) -> Option<(*mut Node<T, N>, usize)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.back?.as_ptr();
+        self.back_slot -= 1;
+        let slot = self.back_slot;
+        if self.back_slot == first_slot(self.back) {
+            self.back = unsafe { (*node).prev };
+            self.back_slot = end_slot(self.back);
+        }
+        self.remaining -= 1;
+        Some((node, slot))
+    }
+}
+
+pub struct Iter<'a, T: Clone, const N: usize> {
+    slots: Slots<T, N>,
+    marker: PhantomData<&'a Node<T, N>>,
+}
+
+pub struct IterMut<'a, T: Clone, const N: usize> {
+    slots: Slots<T, N>,
+    marker: PhantomData<&'a mut Node<T, N>>,
+}
+
+pub struct IntoIter<T: Clone, const N: usize> {
+    collection: UnrolledList<T, N>,
+}
+
+// the slot walkers borrow the list; IterMut yields disjoint &mut items, one slot at a time
+unsafe impl<T: Clone + Sync, const N: usize> Send for Iter<'_, T, N> {}
+unsafe impl<T: Clone + Sync, const N: usize> Sync for Iter<'_, T, N> {}
+unsafe impl<T: Clone + Send, const N: usize> Send for IterMut<'_, T, N> {}
+unsafe impl<T: Clone + Sync, const N: usize> Sync for IterMut<'_, T, N> {}
+
+impl<'a, T: Clone, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, slot) = self.slots.step_front()?;
+        Some(unsafe { (*node).items[slot].assume_init_ref() })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.remaining, Some(self.slots.remaining))
+    }
+}
+
+impl<'a, T: Clone, const N: usize> ExactSizeIterator for Iter<'a, T, N> {}
+
+impl<'a, T: Clone, const N: usize> FusedIterator for Iter<'a, T, N> {}
+
+impl<'a, T: Clone, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (node, slot) = self.slots.step_back()?;
+        Some(unsafe { (*node).items[slot].assume_init_ref() })
+    }
+}
+
+impl<'a, T: Clone, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, slot) = self.slots.step_front()?;
+        Some(unsafe { (*node).items[slot].assume_init_mut() })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.remaining, Some(self.slots.remaining))
+    }
+}
+
+impl<'a, T: Clone, const N: usize> ExactSizeIterator for IterMut<'a, T, N> {}
+
+impl<'a, T: Clone, const N: usize> FusedIterator for IterMut<'a, T, N> {}
+
+impl<'a, T: Clone, const N: usize> DoubleEndedIterator for IterMut<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (node, slot) = self.slots.step_back()?;
+        Some(unsafe { (*node).items[slot].assume_init_mut() })
+    }
+}
+
+impl<T: Clone, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.collection.pop_head()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T: Clone, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T: Clone, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.collection.pop_tail()
+    }
+}
+
+impl<T: Clone, const N: usize> UnrolledList<T, N> {
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            slots: Slots::new(self),
+            marker: PhantomData,
+        }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut {
+            slots: Slots::new(self),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> IntoIterator for UnrolledList<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { collection: self }
+    }
+}
+
+impl<'a, T: Clone, const N: usize> IntoIterator for &'a UnrolledList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone, const N: usize> IntoIterator for &'a mut UnrolledList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T: Clone, const N: usize> Extend<T> for UnrolledList<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_tail(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for UnrolledList<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_tail(iter.into_iter().copied());
+    }
+}
+
+impl<T: Clone, const N: usize> From<Vec<T>> for UnrolledList<T, N> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Clone, const N: usize> From<UnrolledList<T, N>> for Vec<T> {
+    fn from(list: UnrolledList<T, N>) -> Self {
+        list.into_iter().collect()
+    }
+}
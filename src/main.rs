@@ -1,8 +1,4 @@
-use crate::{linked_list::doubly::LinkedList, tree::heap::BinaryHeap};
-
-pub mod adt;
-pub mod linked_list;
-pub mod tree;
+use dsa_rust::{linked_list::doubly::LinkedList, tree::heap::BinaryHeap};
 
 fn main() {
     let mut heap = BinaryHeap::from(|a, b| a.cmp(b), (0..10).rev());
@@ -19,12 +15,12 @@ fn main() {
         println!("{}", num);
     }
 
-    let list = LinkedList::from(0..10);
+    let mut list = LinkedList::from(0..10);
 
     println!("{:?}", list);
 
-    for i in list.iter() {
-        i.borrow_mut().data *= 7;
+    for i in list.iter_mut() {
+        *i *= 7;
     }
 
     println!("{:?}", list);
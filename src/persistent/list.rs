@@ -0,0 +1,201 @@
+/*
+
+    PERSISTENT CONS LIST
+
+    push_front, pop_front, first and clone are O(1)
+    and share the whole existing list as the new tail;
+    get and len-sized walks are O(n), like any cons list
+
+*/
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+
+struct Node<T> {
+    data: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+pub struct List<T> {
+    head: Option<Rc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+    // the first item yielded ends up at the front
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let items: Vec<T> = iter.into_iter().collect();
+        items
+            .into_iter()
+            .rev()
+            .fold(Self::new(), |list, data| list.push_front(data))
+    }
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn push_front(&self, data: T) -> Self {
+        Self {
+            head: Some(Rc::new(Node {
+                data,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+    // the list without its first item, sharing every remaining node
+    pub fn pop_front(&self) -> Option<Self> {
+        self.head.as_ref().map(|head| Self {
+            head: head.next.clone(),
+            len: self.len - 1,
+        })
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.head.as_ref().map(|head| &head.data)
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+    // true when both lists are the very same version, not merely equal
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone,
+{
+    // copies every node, since no suffix of the reversal matches a suffix of the original
+    pub fn reverse(&self) -> Self {
+        self.iter()
+            .fold(Self::new(), |list, data| list.push_front(data.clone()))
+    }
+    // shares other entirely and copies the nodes of self in front of it
+    pub fn append(&self, other: &Self) -> Self {
+        let items: Vec<&T> = self.iter().collect();
+        items
+            .into_iter()
+            .rev()
+            .fold(other.clone(), |list, data| list.push_front(data.clone()))
+    }
+}
+
+impl<T> Drop for List<T> {
+    // unwinds the chain iteratively for as long as this version is its only owner, so long
+    // lists do not overflow the stack with recursive drops
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => current = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PartialEq for List<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T> Eq for List<T> where T: Eq {}
+
+impl<T> Hash for List<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> Debug for List<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List::from(iter)
+    }
+}
+
+pub struct Iter<'a, T> {
+    current: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
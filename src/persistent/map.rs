@@ -0,0 +1,477 @@
+/*
+
+    PERSISTENT ORDERED MAP
+
+    an avl tree of Rc-shared nodes rebuilt by path copying:
+    insert and remove allocate only the O(log n) nodes on
+    the search path and share every other subtree, so a
+    snapshot is an O(1) clone that later updates never touch
+
+*/
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::Index,
+};
+
+use crate::tree::avl::TreeInvariantError;
+
+type Link<K, V> = Option<Rc<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    left: Link<K, V>,
+    right: Link<K, V>,
+    height: i8,
+    size: usize,
+}
+
+fn height<K, V>(link: &Link<K, V>) -> i8 {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn join<K, V>(key: K, value: V, left: Link<K, V>, right: Link<K, V>) -> Rc<Node<K, V>> {
+    Rc::new(Node {
+        height: 1 + height(&left).max(height(&right)),
+        size: 1 + size(&left) + size(&right),
+        key,
+        value,
+        left,
+        right,
+    })
+}
+
+// join for subtrees whose heights differ by at most two, rotating where the mutable tree would;
+// a rotation copies the one or two nodes it moves and reuses their subtrees
+fn balance<K, V>(key: K, value: V, left: Link<K, V>, right: Link<K, V>) -> Rc<Node<K, V>>
+where
+    K: Clone,
+    V: Clone,
+{
+    let (lh, rh) = (height(&left), height(&right));
+    if lh > rh + 1 {
+        let l = left.unwrap();
+        if height(&l.left) >= height(&l.right) {
+            let right = join(key, value, l.right.clone(), right);
+            return join(l.key.clone(), l.value.clone(), l.left.clone(), Some(right));
+        }
+        let lr = l.right.as_ref().unwrap();
+        let left = join(
+            l.key.clone(),
+            l.value.clone(),
+            l.left.clone(),
+            lr.left.clone(),
+        );
+        let right = join(key, value, lr.right.clone(), right);
+        return join(lr.key.clone(), lr.value.clone(), Some(left), Some(right));
+    }
+    if rh > lh + 1 {
+        let r = right.unwrap();
+        if height(&r.right) >= height(&r.left) {
+            let left = join(key, value, left, r.left.clone());
+            return join(r.key.clone(), r.value.clone(), Some(left), r.right.clone());
+        }
+        let rl = r.left.as_ref().unwrap();
+        let left = join(key, value, left, rl.left.clone());
+        let right = join(
+            r.key.clone(),
+            r.value.clone(),
+            rl.right.clone(),
+            r.right.clone(),
+        );
+        return join(rl.key.clone(), rl.value.clone(), Some(left), Some(right));
+    }
+    join(key, value, left, right)
+}
+
+// returns the new subtree and whether the key was new rather than replaced
+fn insert_node<K, V>(link: &Link<K, V>, key: K, value: V) -> (Rc<Node<K, V>>, bool)
+where
+    K: Ord + Clone,
+    V: Clone,
+{
+    let Some(node) = link else {
+        return (join(key, value, None, None), true);
+    };
+    match key.cmp(&node.key) {
+        Ordering::Equal => (
+            join(key, value, node.left.clone(), node.right.clone()),
+            false,
+        ),
+        Ordering::Less => {
+            let (left, added) = insert_node(&node.left, key, value);
+            let node = balance(
+                node.key.clone(),
+                node.value.clone(),
+                Some(left),
+                node.right.clone(),
+            );
+            (node, added)
+        }
+        Ordering::Greater => {
+            let (right, added) = insert_node(&node.right, key, value);
+            let node = balance(
+                node.key.clone(),
+                node.value.clone(),
+                node.left.clone(),
+                Some(right),
+            );
+            (node, added)
+        }
+    }
+}
+
+fn remove_min<K, V>(node: &Rc<Node<K, V>>) -> (Link<K, V>, K, V)
+where
+    K: Clone,
+    V: Clone,
+{
+    match &node.left {
+        None => (node.right.clone(), node.key.clone(), node.value.clone()),
+        Some(left) => {
+            let (left, key, value) = remove_min(left);
+            let node = balance(
+                node.key.clone(),
+                node.value.clone(),
+                left,
+                node.right.clone(),
+            );
+            (Some(node), key, value)
+        }
+    }
+}
+
+// None when the key is absent, so the caller can keep sharing the untouched tree
+fn remove_node<K, V, Q>(link: &Link<K, V>, key: &Q) -> Option<Link<K, V>>
+where
+    K: Ord + Clone + Borrow<Q>,
+    V: Clone,
+    Q: Ord + ?Sized,
+{
+    let node = link.as_ref()?;
+    Some(match key.cmp(node.key.borrow()) {
+        Ordering::Less => {
+            let left = remove_node(&node.left, key)?;
+            Some(balance(
+                node.key.clone(),
+                node.value.clone(),
+                left,
+                node.right.clone(),
+            ))
+        }
+        Ordering::Greater => {
+            let right = remove_node(&node.right, key)?;
+            Some(balance(
+                node.key.clone(),
+                node.value.clone(),
+                node.left.clone(),
+                right,
+            ))
+        }
+        Ordering::Equal => match (&node.left, &node.right) {
+            (None, child) | (child, None) => child.clone(),
+            (Some(_), Some(right)) => {
+                let (right, key, value) = remove_min(right);
+                Some(balance(key, value, node.left.clone(), right))
+            }
+        },
+    })
+}
+
+fn validate_node<'a, K, V>(
+    link: &'a Link<K, V>,
+    lower: Option<&'a K>,
+    upper: Option<&'a K>,
+    index: &mut usize,
+) -> Result<(i8, usize), TreeInvariantError>
+where
+    K: Ord,
+{
+    let Some(node) = link else {
+        return Ok((0, 0));
+    };
+    let (left_height, left_size) = validate_node(&node.left, lower, Some(&node.key), index)?;
+    let id = *index;
+    *index += 1;
+    if lower.is_some_and(|lower| &node.key <= lower)
+        || upper.is_some_and(|upper| &node.key >= upper)
+    {
+        return Err(TreeInvariantError::Unordered { node: id });
+    }
+    let (right_height, right_size) = validate_node(&node.right, Some(&node.key), upper, index)?;
+    let actual = 1 + left_height.max(right_height);
+    if node.height != actual {
+        return Err(TreeInvariantError::Height {
+            node: id,
+            recorded: node.height,
+            actual,
+        });
+    }
+    let factor = left_height - right_height;
+    if factor.abs() > 1 {
+        return Err(TreeInvariantError::Balance { node: id, factor });
+    }
+    let actual = 1 + left_size + right_size;
+    if node.size != actual {
+        return Err(TreeInvariantError::SubtreeSize {
+            node: id,
+            recorded: node.size,
+            actual,
+        });
+    }
+    Ok((node.height, node.size))
+}
+
+pub struct OrderedMap<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+    // a version to keep: O(1), and unaffected by anything done to self afterwards
+    pub fn snapshot(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+    // true when both maps are the very same version, not merely equal
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.root, &other.root) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some((&node.key, &node.value))
+    }
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some((&node.key, &node.value))
+    }
+    // in ascending key order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::with_capacity(height(&self.root) as usize),
+            remaining: self.len(),
+        };
+        iter.descend(self.root.as_deref());
+        iter
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: Ord,
+{
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match key.cmp(node.key.borrow()) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return Some(&node.value),
+            }
+        }
+        None
+    }
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+    pub fn validate(&self) -> Result<(), TreeInvariantError> {
+        validate_node(&self.root, None, None, &mut 0).map(|_| ())
+    }
+    fn audit(&self) {
+        #[cfg(feature = "verify")]
+        if cfg!(debug_assertions)
+            && let Err(err) = self.validate()
+        {
+            panic!("OrderedMap invariant violated: {}", err);
+        }
+    }
+}
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: Ord + Clone,
+    V: Clone,
+{
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        iter.into_iter()
+            .fold(Self::new(), |map, (key, value)| map.insert(key, value))
+    }
+    // a key already present has its value replaced in the new version only
+    pub fn insert(&self, key: K, value: V) -> Self {
+        let (root, _) = insert_node(&self.root, key, value);
+        let map = Self { root: Some(root) };
+        map.audit();
+        map
+    }
+    // removing an absent key hands back a version that shares the whole tree
+    pub fn remove<Q>(&self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let map = match remove_node(&self.root, key) {
+            Some(root) => Self { root },
+            None => self.snapshot(),
+        };
+        map.audit();
+        map
+    }
+}
+
+impl<K, V> Clone for OrderedMap<K, V> {
+    fn clone(&self) -> Self {
+        self.snapshot()
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, Q> Index<&Q> for OrderedMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K, V> PartialEq for OrderedMap<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<K, V> Eq for OrderedMap<K, V>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V> Hash for OrderedMap<K, V>
+where
+    K: Hash,
+    V: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for entry in self.iter() {
+            entry.hash(state);
+        }
+    }
+}
+
+impl<K, V> Debug for OrderedMap<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedMap<K, V>
+where
+    K: Ord + Clone,
+    V: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        OrderedMap::from(iter)
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn descend(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.descend(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<'a, K, V> IntoIterator for &'a OrderedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
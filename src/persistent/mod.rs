@@ -0,0 +1,17 @@
+/*
+
+    PERSISTENT COLLECTIONS
+
+    every update returns a new version and leaves the old
+    one intact; versions share whatever they have in
+    common through Rc, so keeping history is cheap
+
+*/
+
+pub mod list;
+pub mod map;
+pub mod vector;
+
+pub use list::List;
+pub use map::OrderedMap;
+pub use vector::Vector;
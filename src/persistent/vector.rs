@@ -0,0 +1,337 @@
+/*
+
+    PERSISTENT VECTOR
+
+    a bit-partitioned trie with 32-way branching plus a
+    tail buffer for the last partial leaf: push_back,
+    pop_back, get and update are O(log32 n), which is at
+    most 7 levels for any addressable length, and clone
+    is O(1); an update copies only its root-to-leaf path
+
+*/
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::Index,
+};
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+#[derive(Clone)]
+enum Node<T> {
+    Branch(Vec<Rc<Node<T>>>),
+    Leaf(Vec<T>),
+}
+
+pub struct Vector<T> {
+    // leaves hang below the root after shift / BITS levels; every leaf in the trie is full
+    root: Rc<Node<T>>,
+    tail: Rc<Vec<T>>,
+    shift: usize,
+    len: usize,
+}
+
+impl<T> Vector<T> {
+    pub fn new() -> Self {
+        Self {
+            root: Rc::new(Node::Branch(Vec::new())),
+            tail: Rc::new(Vec::new()),
+            shift: BITS,
+            len: 0,
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    fn tail_offset(&self) -> usize {
+        self.len - self.tail.len()
+    }
+    // the leaf holding index, which must be in bounds
+    fn leaf(&self, index: usize) -> &[T] {
+        if index >= self.tail_offset() {
+            return &self.tail;
+        }
+        let (mut node, mut level) = (&*self.root, self.shift);
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = &children[(index >> level) & MASK];
+                    level -= BITS;
+                }
+                Node::Leaf(items) => return items,
+            }
+        }
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len).then(|| &self.leaf(index)[index & MASK])
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.tail.last()
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            vector: self,
+            leaf: &[],
+            index: 0,
+        }
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Clone,
+{
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // nothing is shared yet, so every push below happens in place
+        let mut vector = Self::new();
+        for item in iter {
+            vector.push_mut(item);
+        }
+        vector
+    }
+    pub fn push_back(&self, value: T) -> Self {
+        let mut vector = self.clone();
+        vector.push_mut(value);
+        vector
+    }
+    pub fn pop_back(&self) -> Option<Self> {
+        let mut vector = self.clone();
+        vector.pop_mut().map(|_| vector)
+    }
+    pub fn update(&self, index: usize, value: T) -> Option<Self> {
+        if index >= self.len {
+            return None;
+        }
+        let mut vector = self.clone();
+        vector.update_mut(index, value);
+        Some(vector)
+    }
+
+    // the in-place versions copy a node only when Rc::make_mut finds it shared with another
+    // version, so a version nobody else holds is updated without any copying
+
+    fn push_mut(&mut self, value: T) {
+        if self.tail.len() < WIDTH {
+            Rc::make_mut(&mut self.tail).push(value);
+            self.len += 1;
+            return;
+        }
+        let full = core::mem::replace(&mut self.tail, Rc::new(Vec::with_capacity(WIDTH)));
+        let leaf = Rc::new(Node::Leaf(Rc::unwrap_or_clone(full)));
+        // the trie holds len - WIDTH items and is full once that reaches WIDTH^(levels)
+        if (self.len >> BITS) > (1 << self.shift) {
+            let path = new_path(self.shift, leaf);
+            let old = core::mem::replace(&mut self.root, Rc::new(Node::Branch(Vec::new())));
+            self.root = Rc::new(Node::Branch(alloc::vec![old, path]));
+            self.shift += BITS;
+        } else {
+            push_leaf(Rc::make_mut(&mut self.root), self.shift, self.len - 1, leaf);
+        }
+        Rc::make_mut(&mut self.tail).push(value);
+        self.len += 1;
+    }
+    fn pop_mut(&mut self) -> Option<T> {
+        let value = Rc::make_mut(&mut self.tail).pop()?;
+        self.len -= 1;
+        // an emptied tail is refilled from the last leaf, so last() stays a tail lookup
+        if self.tail.is_empty() && self.len > 0 {
+            self.tail = Rc::new(pop_leaf(Rc::make_mut(&mut self.root), self.shift));
+            if let Node::Branch(children) = &*self.root
+                && self.shift > BITS
+                && children.len() == 1
+            {
+                self.root = children[0].clone();
+                self.shift -= BITS;
+            }
+        }
+        Some(value)
+    }
+    fn update_mut(&mut self, index: usize, value: T) {
+        if index >= self.tail_offset() {
+            Rc::make_mut(&mut self.tail)[index & MASK] = value;
+            return;
+        }
+        let (mut node, mut level) = (Rc::make_mut(&mut self.root), self.shift);
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = Rc::make_mut(&mut children[(index >> level) & MASK]);
+                    level -= BITS;
+                }
+                Node::Leaf(items) => {
+                    items[index & MASK] = value;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn new_path<T>(level: usize, leaf: Rc<Node<T>>) -> Rc<Node<T>> {
+    match level {
+        0 => leaf,
+        _ => Rc::new(Node::Branch(alloc::vec![new_path(level - BITS, leaf)])),
+    }
+}
+
+// hangs a full leaf at position index, where index is the last index that leaf will hold
+fn push_leaf<T>(node: &mut Node<T>, level: usize, index: usize, leaf: Rc<Node<T>>)
+where
+    T: Clone,
+{
+    let Node::Branch(children) = node else {
+        unreachable!("leaves sit only at level zero")
+    };
+    let slot = (index >> level) & MASK;
+    if level == BITS {
+        children.push(leaf);
+    } else if slot < children.len() {
+        push_leaf(Rc::make_mut(&mut children[slot]), level - BITS, index, leaf);
+    } else {
+        children.push(new_path(level - BITS, leaf));
+    }
+}
+
+// detaches the rightmost leaf, pruning any branch left empty behind it
+fn pop_leaf<T>(node: &mut Node<T>, level: usize) -> Vec<T>
+where
+    T: Clone,
+{
+    let Node::Branch(children) = node else {
+        unreachable!("leaves sit only at level zero")
+    };
+    let last = children
+        .last_mut()
+        .expect("branches below the root are never empty");
+    if level == BITS {
+        let leaf = children.pop().unwrap();
+        return match Rc::unwrap_or_clone(leaf) {
+            Node::Leaf(items) => items,
+            Node::Branch(_) => unreachable!("level one holds only leaves"),
+        };
+    }
+    let items = pop_leaf(Rc::make_mut(last), level - BITS);
+    if let Node::Branch(grandchildren) = &**last
+        && grandchildren.is_empty()
+    {
+        children.pop();
+    }
+    items
+}
+
+impl<T> Clone for Vector<T> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            tail: self.tail.clone(),
+            shift: self.shift,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> PartialEq for Vector<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for Vector<T> where T: Eq {}
+
+impl<T> Hash for Vector<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> Debug for Vector<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T>
+where
+    T: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector::from(iter)
+    }
+}
+
+// walks a leaf at a time, so a full pass is O(n) rather than n lookups
+pub struct Iter<'a, T> {
+    vector: &'a Vector<T>,
+    leaf: &'a [T],
+    index: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.vector.len {
+            return None;
+        }
+        if self.index & MASK == 0 {
+            self.leaf = self.vector.leaf(self.index);
+        }
+        let item = &self.leaf[self.index & MASK];
+        self.index += 1;
+        Some(item)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vector.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,28 @@
+pub use crate::{
+    adt::{Cache, ConcurrentQueue, Queue, Stack},
+    cache::{FifoCache, LfuCache, LruCache},
+    collections::{BitVec, Matrix, RingBuffer, SlotMap, UnionFind, VecStack},
+    graph::Graph,
+    hash::map::HashMap,
+    linked_list::{
+        circular::CircularList, doubly::LinkedList as DoublyLinkedList,
+        raw::LinkedList as XorLinkedList, singly::LinkedList as SinglyLinkedList,
+        unrolled::UnrolledList,
+    },
+    persistent::{List as PersistentList, OrderedMap, Vector as PersistentVector},
+    ranges::{FenwickTree, LazySegmentTree, SegmentTree, SparseTable},
+    streaming::{MedianTracker, MonotonicDeque, WindowAggregator},
+    tree::{
+        arena_avl::ArenaAVLTree,
+        avl::AVLTree,
+        bst::BST,
+        heap::{BinaryHeap, BoundedHeap, IndexedHeap, PriorityQueue, top_k},
+        huffman::HuffmanTree,
+        kd::KdTree,
+        multiset::AVLMultiSet,
+        quadtree::{QuadTree, Rect},
+        trie::Trie,
+    },
+    visualize::{DotStyle, ToDot},
+};
+pub use crate::{doubly_linked, singly_linked};
@@ -0,0 +1,123 @@
+/*
+
+    COUNT-MIN SKETCH
+
+    a depth x width grid of counters, one row per
+    hash function; an estimate is the smallest
+    counter an item maps to, so it never undercounts
+
+*/
+
+use alloc::{vec, vec::Vec};
+use core::hash::{BuildHasher, Hash};
+
+use super::{ln, mixed_hash};
+use crate::hash::FnvBuildHasher;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountMinSketch<S = FnvBuildHasher> {
+    width: usize,
+    depth: usize,
+    // row-major, depth rows of width counters
+    counters: Vec<u64>,
+    total: u64,
+    hasher: S,
+}
+
+impl CountMinSketch {
+    pub fn new(width: usize, depth: usize) -> Self {
+        Self::with_hasher(width, depth, FnvBuildHasher::default())
+    }
+    // sized so that an estimate exceeds the true count by more than epsilon * total with
+    // probability at most delta
+    pub fn with_error(epsilon: f64, delta: f64) -> Self {
+        assert!(epsilon > 0.0 && epsilon < 1.0, "epsilon must lie in (0, 1)");
+        assert!(delta > 0.0 && delta < 1.0, "delta must lie in (0, 1)");
+        Self::new(ceil(core::f64::consts::E / epsilon), ceil(ln(1.0 / delta)))
+    }
+}
+
+impl<S> CountMinSketch<S>
+where
+    S: BuildHasher,
+{
+    pub fn with_hasher(width: usize, depth: usize, hasher: S) -> Self {
+        assert!(
+            width > 0 && depth > 0,
+            "sketch dimensions must be at least 1"
+        );
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth],
+            total: 0,
+            hasher,
+        }
+    }
+    // one column per row, derived from a single hash by double hashing
+    fn columns<T>(&self, item: &T) -> impl Iterator<Item = usize> + use<T, S>
+    where
+        T: Hash + ?Sized,
+    {
+        let hash = mixed_hash(&self.hasher, item);
+        let (low, high) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+        let width = self.width as u64;
+        (0..self.depth as u64).map(move |row| {
+            (row * width + low.wrapping_add(row.wrapping_mul(high)) % width) as usize
+        })
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    // the sum of every count added so far
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+    pub fn add<T>(&mut self, item: &T)
+    where
+        T: Hash + ?Sized,
+    {
+        self.add_count(item, 1);
+    }
+    pub fn add_count<T>(&mut self, item: &T, count: u64)
+    where
+        T: Hash + ?Sized,
+    {
+        for column in self.columns(item) {
+            self.counters[column] = self.counters[column].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+    pub fn estimate<T>(&self, item: &T) -> u64
+    where
+        T: Hash + ?Sized,
+    {
+        self.columns(item)
+            .map(|column| self.counters[column])
+            .min()
+            .unwrap_or(0)
+    }
+    // afterwards self estimates the combined stream, as if it had seen both
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.width == other.width && self.depth == other.depth,
+            "only sketches of the same dimensions can be merged"
+        );
+        for (mine, theirs) in self.counters.iter_mut().zip(&other.counters) {
+            *mine = mine.saturating_add(*theirs);
+        }
+        self.total = self.total.saturating_add(other.total);
+    }
+    pub fn clear(&mut self) {
+        self.counters.fill(0);
+        self.total = 0;
+    }
+}
+
+fn ceil(x: f64) -> usize {
+    let floor = x as usize;
+    if (floor as f64) < x { floor + 1 } else { floor }
+}
@@ -0,0 +1,103 @@
+/*
+
+    HYPERLOGLOG
+
+    2^precision registers, each remembering the
+    longest run of leading zeros among the hashes
+    routed to it; their harmonic mean estimates
+    how many distinct items have been seen
+
+*/
+
+use alloc::{vec, vec::Vec};
+use core::hash::{BuildHasher, Hash};
+
+use super::{ln, mixed_hash};
+use crate::hash::FnvBuildHasher;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog<S = FnvBuildHasher> {
+    precision: u32,
+    registers: Vec<u8>,
+    hasher: S,
+}
+
+impl HyperLogLog {
+    pub fn new(precision: u32) -> Self {
+        Self::with_hasher(precision, FnvBuildHasher::default())
+    }
+}
+
+impl<S> HyperLogLog<S>
+where
+    S: BuildHasher,
+{
+    // the standard error is about 1.04 / sqrt(2^precision), so 4 gives 26% and 16 gives 0.4%
+    pub fn with_hasher(precision: u32, hasher: S) -> Self {
+        assert!(
+            (4..=16).contains(&precision),
+            "precision must lie in 4..=16"
+        );
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+            hasher,
+        }
+    }
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|&register| register == 0)
+    }
+    pub fn add<T>(&mut self, item: &T)
+    where
+        T: Hash + ?Sized,
+    {
+        let hash = mixed_hash(&self.hasher, item);
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the top bits pick the register; the guard bit caps the rank once they are shifted out
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&register| 1.0 / (1u64 << register) as f64)
+            .sum();
+        let raw = alpha * m * m / sum;
+        // small cardinalities leave registers untouched, and counting those is more accurate
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|&&register| register == 0)
+            .count();
+        if raw <= 2.5 * m && zeros > 0 {
+            m * ln(m / zeros as f64)
+        } else {
+            raw
+        }
+    }
+    // afterwards self estimates the union of both streams
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.precision == other.precision,
+            "only estimators of the same precision can be merged"
+        );
+        for (mine, &theirs) in self.registers.iter_mut().zip(&other.registers) {
+            *mine = (*mine).max(theirs);
+        }
+    }
+    pub fn clear(&mut self) {
+        self.registers.fill(0);
+    }
+}
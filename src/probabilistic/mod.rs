@@ -0,0 +1,35 @@
+pub mod count_min;
+pub mod hyperloglog;
+
+pub use count_min::CountMinSketch;
+pub use hyperloglog::HyperLogLog;
+
+use core::hash::{BuildHasher, Hash};
+
+// fnv leaves its high bits poorly mixed, and the sketches slice hashes into indices and bit
+// patterns, so every hash goes through the splitmix64 finaliser first
+pub(crate) fn mixed_hash<T, S>(hasher: &S, item: &T) -> u64
+where
+    T: Hash + ?Sized,
+    S: BuildHasher,
+{
+    let mut hash = hasher.hash_one(item);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+// natural logarithm for positive finite x, since core has no float math; splits off the binary
+// exponent and sums the atanh series on the mantissa, which converges fast on [1, 2)
+pub(crate) fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let (mut term, mut sum) = (z, 0.0);
+    for k in (1..40).step_by(2) {
+        sum += term / k as f64;
+        term *= z * z;
+    }
+    exponent as f64 * core::f64::consts::LN_2 + 2.0 * sum
+}
@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::RangeBounds};
+
+use crate::algebra::{Group, Monoid};
+
+// tree[i - 1] folds the items in (i - lowbit(i), i], so any prefix is folded from at most log n
+// entries and an item sits in at most log n of them. entries fold out of order, so the monoid
+// has to be commutative as well; ranges that do not start at 0 also need a group, to take the
+// shorter prefix back off the longer one
+pub struct FenwickTree<M>
+where
+    M: Monoid + Clone,
+{
+    tree: Vec<M>,
+}
+
+impl<M> FenwickTree<M>
+where
+    M: Monoid + Clone,
+{
+    // len items, each the identity
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: alloc::vec![M::identity(); len],
+        }
+    }
+    // O(n): each entry passes its fold on to the one entry that covers it next
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+    {
+        let mut tree: Vec<M> = iter.into_iter().collect();
+        for i in 1..=tree.len() {
+            let parent = i + lowbit(i);
+            if parent <= tree.len() {
+                tree[parent - 1] = tree[parent - 1].combine(&tree[i - 1]);
+            }
+        }
+        Self { tree }
+    }
+}
+
+impl<M> FenwickTree<M>
+where
+    M: Monoid + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+    // combines delta into the item at index, e.g. Sum(5) adds five to it
+    pub fn add(&mut self, index: usize, delta: &M) {
+        assert!(
+            index < self.len(),
+            "index {} out of range for {} items",
+            index,
+            self.len()
+        );
+        let mut i = index + 1;
+        while i <= self.len() {
+            self.tree[i - 1] = self.tree[i - 1].combine(delta);
+            i += lowbit(i);
+        }
+    }
+    // the fold of the first end items; panics past the end
+    pub fn prefix(&self, end: usize) -> M {
+        let (_, end) = super::bounds(..end, self.len());
+        let mut fold = M::identity();
+        let mut i = end;
+        while i > 0 {
+            fold = fold.combine(&self.tree[i - 1]);
+            i -= lowbit(i);
+        }
+        fold
+    }
+}
+
+impl<M> FenwickTree<M>
+where
+    M: Group + Clone,
+{
+    // the fold over the range, the identity for an empty one; panics past the end
+    pub fn query<R>(&self, range: R) -> M
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = super::bounds(range, self.len());
+        if start >= end {
+            return M::identity();
+        }
+        self.prefix(end).combine(&self.prefix(start).inverse())
+    }
+    pub fn get(&self, index: usize) -> M {
+        self.query(index..=index)
+    }
+    pub fn set(&mut self, index: usize, value: M) {
+        let delta = value.combine(&self.get(index).inverse());
+        self.add(index, &delta);
+    }
+}
+
+impl<M> Clone for FenwickTree<M>
+where
+    M: Monoid + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<M> Debug for FenwickTree<M>
+where
+    M: Group + Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|index| self.get(index)))
+            .finish()
+    }
+}
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
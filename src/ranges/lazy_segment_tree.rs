@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::RangeBounds};
+
+use crate::algebra::{Action, Monoid};
+
+// a top-down tree where node 1 covers every item and node i splits its range between 2i and
+// 2i + 1. a range update stops at the O(log n) nodes that cover the range exactly, folding the
+// action into each and leaving it pending there; whatever later walks below such a node pushes
+// the pending action down to its children first
+pub struct LazySegmentTree<M, A = ()>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    folds: Vec<M>,
+    pending: Vec<A>,
+    len: usize,
+}
+
+impl<M, A> LazySegmentTree<M, A>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    // len items, each the identity
+    pub fn new(len: usize) -> Self {
+        Self::from((0..len).map(|_| M::identity()))
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+    {
+        let items: Vec<M> = iter.into_iter().collect();
+        let len = items.len();
+        let nodes = 4 * len.max(1);
+        let mut tree = Self {
+            folds: alloc::vec![M::identity(); nodes],
+            pending: alloc::vec![A::identity(); nodes],
+            len,
+        };
+        if len > 0 {
+            tree.build(1, 0, len, &items);
+        }
+        tree
+    }
+    fn build(&mut self, node: usize, lo: usize, hi: usize, items: &[M]) {
+        if hi - lo == 1 {
+            self.folds[node] = items[lo].clone();
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        self.build(2 * node, lo, mid, items);
+        self.build(2 * node + 1, mid, hi, items);
+        self.pull(node);
+    }
+}
+
+impl<M, A> LazySegmentTree<M, A>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    // the fold over the range, the identity for an empty one; panics past the end. takes &mut
+    // since pending actions get pushed down on the way
+    pub fn query<R>(&mut self, range: R) -> M
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = super::bounds(range, self.len);
+        if start >= end {
+            return M::identity();
+        }
+        self.fold(1, 0, self.len, start, end)
+    }
+    // applies the action to every item in the range; panics past the end
+    pub fn update<R>(&mut self, range: R, action: &A)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = super::bounds(range, self.len);
+        if start < end {
+            self.apply(1, 0, self.len, start, end, action);
+        }
+    }
+    pub fn get(&mut self, index: usize) -> M {
+        self.check(index);
+        self.query(index..=index)
+    }
+    pub fn set(&mut self, index: usize, value: M) {
+        self.check(index);
+        self.assign(1, 0, self.len, index, value);
+    }
+    // every item in order, with each pending action applied
+    pub fn to_vec(&mut self) -> Vec<M> {
+        (0..self.len).map(|index| self.get(index)).collect()
+    }
+    fn fold(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize) -> M {
+        if start <= lo && hi <= end {
+            return self.folds[node].clone();
+        }
+        self.push(node, lo, hi);
+        let mid = lo + (hi - lo) / 2;
+        match (start < mid, mid < end) {
+            (true, true) => {
+                let left = self.fold(2 * node, lo, mid, start, end);
+                left.combine(&self.fold(2 * node + 1, mid, hi, start, end))
+            }
+            (true, false) => self.fold(2 * node, lo, mid, start, end),
+            _ => self.fold(2 * node + 1, mid, hi, start, end),
+        }
+    }
+    fn apply(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize, action: &A) {
+        if start <= lo && hi <= end {
+            self.settle(node, hi - lo, action);
+            return;
+        }
+        self.push(node, lo, hi);
+        let mid = lo + (hi - lo) / 2;
+        if start < mid {
+            self.apply(2 * node, lo, mid, start, end, action);
+        }
+        if mid < end {
+            self.apply(2 * node + 1, mid, hi, start, end, action);
+        }
+        self.pull(node);
+    }
+    fn assign(&mut self, node: usize, lo: usize, hi: usize, index: usize, value: M) {
+        if hi - lo == 1 {
+            self.folds[node] = value;
+            return;
+        }
+        self.push(node, lo, hi);
+        let mid = lo + (hi - lo) / 2;
+        match index < mid {
+            true => self.assign(2 * node, lo, mid, index, value),
+            false => self.assign(2 * node + 1, mid, hi, index, value),
+        }
+        self.pull(node);
+    }
+    // the action reaches this node's whole range now, and its children whenever they are next
+    // visited
+    fn settle(&mut self, node: usize, len: usize, action: &A) {
+        self.folds[node] = action.act(&self.folds[node], len);
+        self.pending[node] = self.pending[node].combine(action);
+    }
+    fn push(&mut self, node: usize, lo: usize, hi: usize) {
+        let action = core::mem::replace(&mut self.pending[node], A::identity());
+        let mid = lo + (hi - lo) / 2;
+        self.settle(2 * node, mid - lo, &action);
+        self.settle(2 * node + 1, hi - mid, &action);
+    }
+    fn pull(&mut self, node: usize) {
+        self.folds[node] = self.folds[2 * node].combine(&self.folds[2 * node + 1]);
+    }
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "index {} out of range for {} items",
+            index,
+            self.len
+        );
+    }
+}
+
+impl<M, A> Clone for LazySegmentTree<M, A>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            folds: self.folds.clone(),
+            pending: self.pending.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<M, A> Debug for LazySegmentTree<M, A>
+where
+    M: Monoid + Clone + Debug,
+    A: Action<M> + Clone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone().to_vec()).finish()
+    }
+}
@@ -0,0 +1,45 @@
+/*
+
+    RANGES
+
+    structures built over a sequence to answer
+    queries about any contiguous range of it
+
+*/
+
+pub mod fenwick;
+pub mod lazy_segment_tree;
+pub mod segment_tree;
+pub mod sparse_table;
+
+pub use fenwick::FenwickTree;
+pub use lazy_segment_tree::LazySegmentTree;
+pub use segment_tree::SegmentTree;
+pub use sparse_table::SparseTable;
+
+use core::ops::{Bound, RangeBounds};
+
+// a range over len items as start..end; panics if it ends past len, like slicing does, while a
+// start at or past the end is just an empty range
+fn bounds<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        end <= len,
+        "range end {} out of range for {} items",
+        end,
+        len
+    );
+    (start, end)
+}
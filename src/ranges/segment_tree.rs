@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::RangeBounds};
+
+use crate::algebra::Monoid;
+
+// a bottom-up tree in one vector: the leaves sit at len..2 * len and node i folds its children
+// 2i and 2i + 1, so a point update walks one path to the root and a query folds at most two
+// nodes per level. queries fold in order from the left, so the monoid need not be commutative
+pub struct SegmentTree<M>
+where
+    M: Monoid + Clone,
+{
+    nodes: Vec<M>,
+    len: usize,
+}
+
+impl<M> SegmentTree<M>
+where
+    M: Monoid + Clone,
+{
+    // len items, each the identity
+    pub fn new(len: usize) -> Self {
+        Self {
+            nodes: alloc::vec![M::identity(); 2 * len],
+            len,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+    {
+        let leaves: Vec<M> = iter.into_iter().collect();
+        let len = leaves.len();
+        let mut nodes = alloc::vec![M::identity(); len];
+        nodes.extend(leaves);
+        for i in (1..len).rev() {
+            nodes[i] = nodes[2 * i].combine(&nodes[2 * i + 1]);
+        }
+        Self { nodes, len }
+    }
+}
+
+impl<M> SegmentTree<M>
+where
+    M: Monoid + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn get(&self, index: usize) -> Option<&M> {
+        match index < self.len {
+            true => Some(&self.nodes[self.len + index]),
+            false => None,
+        }
+    }
+    pub fn set(&mut self, index: usize, value: M) {
+        self.check(index);
+        let mut i = self.len + index;
+        self.nodes[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.nodes[i] = self.nodes[2 * i].combine(&self.nodes[2 * i + 1]);
+        }
+    }
+    // replaces the item with f of it, e.g. adding to a sum rather than overwriting it
+    pub fn update<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&M) -> M,
+    {
+        self.check(index);
+        let value = f(&self.nodes[self.len + index]);
+        self.set(index, value);
+    }
+    // the fold over the range, the identity for an empty one; panics past the end
+    pub fn query<R>(&self, range: R) -> M
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = super::bounds(range, self.len);
+        let (mut left, mut right) = (M::identity(), M::identity());
+        if start >= end {
+            return left;
+        }
+        let (mut lo, mut hi) = (start + self.len, end + self.len);
+        while lo < hi {
+            if lo % 2 == 1 {
+                left = left.combine(&self.nodes[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                right = self.nodes[hi].combine(&right);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+        left.combine(&right)
+    }
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &M> + ExactSizeIterator {
+        self.nodes[self.len..].iter()
+    }
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "index {} out of range for {} items",
+            index,
+            self.len
+        );
+    }
+}
+
+impl<M> Clone for SegmentTree<M>
+where
+    M: Monoid + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<M> Debug for SegmentTree<M>
+where
+    M: Monoid + Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::RangeBounds};
+
+use crate::algebra::{Gcd, Idempotent, Semigroup};
+
+// levels[j][i] folds the 2^j items starting at i. any range is covered by two of those blocks,
+// overlapping in the middle, so queries are O(1) whenever op is associative and idempotent
+// (op(x, x) == x), as min, max, gcd, and, or are; sums count the overlap twice and need
+// another structure
+pub struct SparseTable<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    levels: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T, F> SparseTable<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    pub fn new(items: &[T], op: F) -> Self {
+        let mut levels = alloc::vec![items.to_vec()];
+        let mut width = 1;
+        while 2 * width <= items.len() {
+            let below = &levels[levels.len() - 1];
+            let level = (0..=items.len() - 2 * width)
+                .map(|i| op(&below[i], &below[i + width]))
+                .collect();
+            levels.push(level);
+            width *= 2;
+        }
+        Self { levels, op }
+    }
+}
+
+impl<T> SparseTable<T, fn(&T, &T) -> T>
+where
+    T: Clone + Ord,
+{
+    pub fn min(items: &[T]) -> Self {
+        Self::new(items, |a, b| a.min(b).clone())
+    }
+    pub fn max(items: &[T]) -> Self {
+        Self::new(items, |a, b| a.max(b).clone())
+    }
+}
+
+// any idempotent semigroup brings its own op, e.g. SparseTable::over(&[Gcd(12u64), Gcd(18)])
+impl<S> SparseTable<S, fn(&S, &S) -> S>
+where
+    S: Idempotent + Clone,
+{
+    pub fn over(items: &[S]) -> Self {
+        Self::new(items, S::combine)
+    }
+}
+
+impl<T, F> SparseTable<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.levels[0].get(index)
+    }
+    // the fold of op over the range, or None for an empty one; panics past the end, like
+    // slicing does
+    pub fn query<R>(&self, range: R) -> Option<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = super::bounds(range, self.len());
+        if start >= end {
+            return None;
+        }
+        let level = (end - start).ilog2() as usize;
+        let blocks = &self.levels[level];
+        Some((self.op)(&blocks[start], &blocks[end - (1 << level)]))
+    }
+}
+
+impl<T, F> Debug for SparseTable<T, F>
+where
+    T: Clone + Debug,
+    F: Fn(&T, &T) -> T,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.levels[0]).finish()
+    }
+}
+
+// the Gcd semigroup on bare u64s, shaped to be handed straight to SparseTable::new
+pub fn gcd(a: &u64, b: &u64) -> u64 {
+    Gcd(*a).combine(&Gcd(*b)).0
+}
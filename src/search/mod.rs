@@ -0,0 +1,154 @@
+use core::{cmp::Ordering, ops::Range};
+
+// the first index whose element fails the predicate, for a slice where every passing element
+// comes before every failing one; this is the primitive all the binary searches below share
+pub fn partition_point<T, P>(slice: &[T], mut predicate: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let (mut lo, mut hi) = (0, slice.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if predicate(&slice[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+// the first index whose element is not less than target, or len if there is none
+pub fn lower_bound<T>(slice: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    lower_bound_by(slice, |item| item.cmp(target))
+}
+
+// cmp reports how each element orders against the target, as with slice::binary_search_by
+pub fn lower_bound_by<T, F>(slice: &[T], mut cmp: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(slice, |item| cmp(item).is_lt())
+}
+
+// the first index whose element is greater than target, or len if there is none
+pub fn upper_bound<T>(slice: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    upper_bound_by(slice, |item| item.cmp(target))
+}
+
+pub fn upper_bound_by<T, F>(slice: &[T], mut cmp: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(slice, |item| cmp(item).is_le())
+}
+
+// the indices of every element equal to target; empty, but positioned where target would go, if absent
+pub fn equal_range<T>(slice: &[T], target: &T) -> Range<usize>
+where
+    T: Ord,
+{
+    lower_bound(slice, target)..upper_bound(slice, target)
+}
+
+// the index of the first element equal to target
+pub fn binary_search<T>(slice: &[T], target: &T) -> Option<usize>
+where
+    T: Ord,
+{
+    let i = lower_bound(slice, target);
+    (slice.get(i) == Some(target)).then_some(i)
+}
+
+// gallops out in powers of two before binary searching, so the cost depends on where target
+// sits rather than on the length of the slice; finds the first match like binary_search
+pub fn exponential_search<T>(slice: &[T], target: &T) -> Option<usize>
+where
+    T: Ord,
+{
+    let mut bound = 1;
+    while bound < slice.len() && slice[bound] < *target {
+        bound *= 2;
+    }
+    let lo = bound / 2;
+    let hi = slice.len().min(bound + 1);
+    let i = lo + lower_bound(slice.get(lo..hi)?, target);
+    (slice.get(i) == Some(target)).then_some(i)
+}
+
+// probes where target should fall assuming evenly spread values, which averages O(log log n)
+// on uniform data; returns the index of some element equal to target, not necessarily the first
+pub fn interpolation_search<T>(slice: &[T], target: T) -> Option<usize>
+where
+    T: Copy + Ord + Into<i128>,
+{
+    let (mut lo, mut hi) = (0, slice.len().checked_sub(1)?);
+    while lo <= hi && slice[lo] <= target && target <= slice[hi] {
+        let (low, high) = (slice[lo].into(), slice[hi].into());
+        if low == high {
+            return Some(lo);
+        }
+        let offset = (target.into() - low) * (hi - lo) as i128 / (high - low);
+        let pos = lo + offset as usize;
+        match slice[pos].cmp(&target) {
+            Ordering::Equal => return Some(pos),
+            Ordering::Less => lo = pos + 1,
+            Ordering::Greater => hi = pos - 1,
+        }
+    }
+    None
+}
+
+// the point in [lo, hi] maximizing f, to within epsilon, where f strictly increases up to its
+// peak and strictly decreases after it
+pub fn ternary_search<F>(mut lo: f64, mut hi: f64, epsilon: f64, mut f: F) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    assert!(epsilon > 0.0, "epsilon must be positive");
+    while hi - lo > epsilon {
+        let third = (hi - lo) / 3.0;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1;
+        } else {
+            hi = m2;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+// the same over the integers in [lo, hi], returning the smallest x at which f peaks
+pub fn ternary_search_int<T, F>(mut lo: i64, mut hi: i64, mut f: F) -> i64
+where
+    T: Ord,
+    F: FnMut(i64) -> T,
+{
+    assert!(lo <= hi, "empty search range");
+    while hi - lo > 2 {
+        let third = (hi - lo) / 3;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1 + 1;
+        } else {
+            hi = m2;
+        }
+    }
+    // a handful of candidates are left, too few to split into thirds
+    let mut best = lo;
+    let mut peak = f(lo);
+    for x in lo + 1..=hi {
+        let value = f(x);
+        if value > peak {
+            best = x;
+            peak = value;
+        }
+    }
+    best
+}
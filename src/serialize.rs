@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    linked_list::{doubly, singly},
+    tree::{avl::AVLTree, heap::BinaryHeap, multiset::AVLMultiSet},
+};
+
+impl<T: Clone + Serialize> Serialize for singly::LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Clone + Deserialize<'de>> Deserialize<'de> for singly::LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: Clone + Serialize> Serialize for doubly::LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Clone + Deserialize<'de>> Deserialize<'de> for doubly::LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T> Serialize for AVLTree<T>
+where
+    T: Ord + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AVLTree<T>
+where
+    T: Ord + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T> Serialize for AVLMultiSet<T>
+where
+    T: Ord + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AVLMultiSet<T>
+where
+    T: Ord + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+// the comparator can't be serialized, so heaps are written as their backing storage, in heap
+// order, and read back as min-heaps. a heap kept under any other order comes back through
+// from_serialized with its comparator
+impl<T> Serialize for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_unsorted())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from_iter)
+    }
+}
+
+impl<T> BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    // storage written under the same comparator is already in heap order and keeps its layout
+    pub fn from_serialized<'de, D>(
+        cmp: fn(&T, &T) -> Ordering,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(|items| Self::from(cmp, items))
+    }
+}
@@ -0,0 +1,354 @@
+use alloc::vec::Vec;
+use core::{
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+};
+
+use crate::tree::heap::{heapify, sift_down};
+
+// operation counts reported by every sort, so the algorithms can be compared side by side
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SortStats {
+    pub comparisons: usize,
+    pub swaps: usize,
+    // elements copied through a scratch buffer by the sorts that are not in-place
+    pub writes: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Pivot {
+    First,
+    Last,
+    Middle,
+    #[default]
+    MedianOfThree,
+}
+
+// wraps a comparator so that every call and every swap gets tallied
+struct Counter<F> {
+    cmp: F,
+    stats: SortStats,
+}
+
+impl<F> Counter<F> {
+    fn new(cmp: F) -> Self {
+        Self {
+            cmp,
+            stats: SortStats::default(),
+        }
+    }
+    fn less<T>(&mut self, a: &T, b: &T) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.stats.comparisons += 1;
+        (self.cmp)(a, b).is_lt()
+    }
+    fn swap<T>(&mut self, slice: &mut [T], i: usize, j: usize) {
+        self.stats.swaps += 1;
+        slice.swap(i, j);
+    }
+}
+
+pub fn insertion_sort<T>(slice: &mut [T]) -> SortStats
+where
+    T: Ord,
+{
+    insertion_sort_by(slice, T::cmp)
+}
+
+pub fn insertion_sort_by<T, F>(slice: &mut [T], cmp: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut counter = Counter::new(cmp);
+    for i in 1..slice.len() {
+        let mut j = i;
+        while j > 0 && counter.less(&slice[j], &slice[j - 1]) {
+            counter.swap(slice, j, j - 1);
+            j -= 1;
+        }
+    }
+    counter.stats
+}
+
+pub fn selection_sort<T>(slice: &mut [T]) -> SortStats
+where
+    T: Ord,
+{
+    selection_sort_by(slice, T::cmp)
+}
+
+pub fn selection_sort_by<T, F>(slice: &mut [T], cmp: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut counter = Counter::new(cmp);
+    for i in 0..slice.len() {
+        let mut min = i;
+        for j in i + 1..slice.len() {
+            if counter.less(&slice[j], &slice[min]) {
+                min = j;
+            }
+        }
+        if min != i {
+            counter.swap(slice, i, min);
+        }
+    }
+    counter.stats
+}
+
+pub fn bubble_sort<T>(slice: &mut [T]) -> SortStats
+where
+    T: Ord,
+{
+    bubble_sort_by(slice, T::cmp)
+}
+
+pub fn bubble_sort_by<T, F>(slice: &mut [T], cmp: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut counter = Counter::new(cmp);
+    let mut end = slice.len();
+    // each pass bubbles the largest remaining element into place; a pass without swaps means we're done
+    while end > 1 {
+        let mut swapped = false;
+        for j in 1..end {
+            if counter.less(&slice[j], &slice[j - 1]) {
+                counter.swap(slice, j, j - 1);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        end -= 1;
+    }
+    counter.stats
+}
+
+pub fn merge_sort<T>(slice: &mut [T]) -> SortStats
+where
+    T: Clone + Ord,
+{
+    merge_sort_by(slice, T::cmp)
+}
+
+pub fn merge_sort_by<T, F>(slice: &mut [T], cmp: F) -> SortStats
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut counter = Counter::new(cmp);
+    let mut buffer = Vec::with_capacity(slice.len() / 2);
+    merge_halves(slice, &mut buffer, &mut counter);
+    counter.stats
+}
+
+// sorts both halves, then merges them back using a copy of the left half
+fn merge_halves<T, F>(slice: &mut [T], buffer: &mut Vec<T>, counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    if len < 2 {
+        return;
+    }
+    let mid = len / 2;
+    merge_halves(&mut slice[..mid], buffer, counter);
+    merge_halves(&mut slice[mid..], buffer, counter);
+    buffer.clear();
+    buffer.extend_from_slice(&slice[..mid]);
+    counter.stats.writes += mid;
+    let (mut i, mut j) = (0, mid);
+    for k in 0..len {
+        // taking from the left on ties keeps the sort stable
+        let take_right = i == mid || (j < len && counter.less(&slice[j], &buffer[i]));
+        if take_right {
+            if j == k {
+                // the rest of the right half is already in place
+                break;
+            }
+            slice[k] = slice[j].clone();
+            j += 1;
+        } else {
+            slice[k] = buffer[i].clone();
+            i += 1;
+        }
+        counter.stats.writes += 1;
+    }
+}
+
+pub fn quick_sort<T>(slice: &mut [T], pivot: Pivot) -> SortStats
+where
+    T: Ord,
+{
+    quick_sort_by(slice, pivot, T::cmp)
+}
+
+pub fn quick_sort_by<T, F>(slice: &mut [T], pivot: Pivot, cmp: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut counter = Counter::new(cmp);
+    quick_sort_range(slice, pivot, &mut counter);
+    counter.stats
+}
+
+fn quick_sort_range<T, F>(mut slice: &mut [T], pivot: Pivot, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // recursing into the smaller side only keeps the stack logarithmic even for bad pivots
+    while slice.len() > 1 {
+        let p = partition(slice, pivot, counter);
+        let (left, right) = slice.split_at_mut(p);
+        let right = &mut right[1..];
+        if left.len() < right.len() {
+            quick_sort_range(left, pivot, counter);
+            slice = right;
+        } else {
+            quick_sort_range(right, pivot, counter);
+            slice = left;
+        }
+    }
+}
+
+fn choose_pivot<T, F>(slice: &[T], pivot: Pivot, counter: &mut Counter<F>) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = slice.len() - 1;
+    let mid = last / 2;
+    match pivot {
+        Pivot::First => 0,
+        Pivot::Last => last,
+        Pivot::Middle => mid,
+        Pivot::MedianOfThree => {
+            let ab = counter.less(&slice[0], &slice[mid]);
+            let bc = counter.less(&slice[mid], &slice[last]);
+            if ab == bc {
+                mid
+            } else if ab == counter.less(&slice[0], &slice[last]) {
+                last
+            } else {
+                0
+            }
+        }
+    }
+}
+
+// lomuto partition: the pivot is parked at the end, then everything smaller is swept in front of it
+fn partition<T, F>(slice: &mut [T], pivot: Pivot, counter: &mut Counter<F>) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = slice.len() - 1;
+    let p = choose_pivot(slice, pivot, counter);
+    if p != last {
+        counter.swap(slice, p, last);
+    }
+    let mut store = 0;
+    for i in 0..last {
+        if counter.less(&slice[i], &slice[last]) {
+            if i != store {
+                counter.swap(slice, i, store);
+            }
+            store += 1;
+        }
+    }
+    if store != last {
+        counter.swap(slice, store, last);
+    }
+    store
+}
+
+pub fn heap_sort<T>(slice: &mut [T]) -> SortStats
+where
+    T: Ord,
+{
+    heap_sort_by(slice, T::cmp)
+}
+
+pub fn heap_sort_by<T, F>(slice: &mut [T], cmp: F) -> SortStats
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let comparisons = Cell::new(0);
+    let cmp = RefCell::new(cmp);
+    // the heap helpers put the least element on top, so reversing the order builds a max-heap
+    let reversed = |a: &T, b: &T| {
+        comparisons.set(comparisons.get() + 1);
+        (cmp.borrow_mut())(b, a)
+    };
+    let mut swaps = heapify(slice, reversed);
+    for end in (1..slice.len()).rev() {
+        slice.swap(0, end);
+        swaps += 1 + sift_down(&mut slice[..end], 0, reversed);
+    }
+    SortStats {
+        comparisons: comparisons.get(),
+        swaps,
+        writes: 0,
+    }
+}
+
+pub fn counting_sort(slice: &mut [usize]) -> SortStats {
+    counting_sort_by_key(slice, |&key| key)
+}
+
+// stable, and allocates one counter per possible key up to the largest one present
+pub fn counting_sort_by_key<T, K>(slice: &mut [T], mut key: K) -> SortStats
+where
+    T: Clone,
+    K: FnMut(&T) -> usize,
+{
+    let mut stats = SortStats::default();
+    let Some(max) = slice.iter().map(&mut key).max() else {
+        return stats;
+    };
+    let mut offsets = alloc::vec![0usize; max + 1];
+    for item in slice.iter() {
+        offsets[key(item)] += 1;
+    }
+    // prefix sums turn each key's count into the index where its first element goes
+    let mut total = 0;
+    for offset in offsets.iter_mut() {
+        let count = *offset;
+        *offset = total;
+        total += count;
+    }
+    let items = slice.to_vec();
+    for item in items {
+        let k = key(&item);
+        slice[offsets[k]] = item;
+        offsets[k] += 1;
+    }
+    stats.writes = 2 * slice.len();
+    stats
+}
+
+pub fn radix_sort(slice: &mut [u64]) -> SortStats {
+    radix_sort_by_key(slice, |&key| key)
+}
+
+// least significant digit first, running one stable counting pass per byte of the key
+pub fn radix_sort_by_key<T, K>(slice: &mut [T], mut key: K) -> SortStats
+where
+    T: Clone,
+    K: FnMut(&T) -> u64,
+{
+    let mut stats = SortStats::default();
+    let Some(max) = slice.iter().map(&mut key).max() else {
+        return stats;
+    };
+    let mut shift = 0;
+    while shift < u64::BITS && max >> shift > 0 {
+        let pass = counting_sort_by_key(slice, |item| ((key(item) >> shift) & 0xff) as usize);
+        stats.writes += pass.writes;
+        shift += 8;
+    }
+    stats
+}
@@ -0,0 +1,131 @@
+use core::fmt::Debug;
+
+use crate::tree::heap::BinaryHeap;
+
+// the lower half sits in a max-heap and the upper half in a min-heap, and the lower half may hold
+// one item more, so the median is always on top of one of them
+pub struct MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    lower: BinaryHeap<T>,
+    upper: BinaryHeap<T>,
+}
+
+impl<T> Default for MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            lower: BinaryHeap::max(),
+            upper: BinaryHeap::min(),
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tracker = Self::new();
+        tracker.extend(iter);
+        tracker
+    }
+}
+
+impl<T> MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.lower.size() + self.upper.size()
+    }
+    pub fn insert(&mut self, data: T) {
+        match self.lower.peek() {
+            Some(top) if &data > top => self.upper.offer(data),
+            _ => self.lower.offer(data),
+        }
+        // at most one item moves across per insert
+        if self.lower.size() > self.upper.size() + 1 {
+            self.upper.extend(self.lower.poll());
+        } else if self.upper.size() > self.lower.size() {
+            self.lower.extend(self.upper.poll());
+        }
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| self.insert(item));
+    }
+    // the lower median when the count is even, since there is no average of two arbitrary Ts
+    pub fn median(&self) -> Option<&T> {
+        self.lower.peek()
+    }
+    // the two middle items, or the middle one twice when the count is odd
+    pub fn median_pair(&self) -> Option<(&T, &T)> {
+        let lower = self.lower.peek()?;
+        if self.lower.size() > self.upper.size() {
+            Some((lower, lower))
+        } else {
+            self.upper.peek().map(|upper| (lower, upper))
+        }
+    }
+    pub fn clear(&mut self) {
+        self.lower.clear();
+        self.upper.clear();
+    }
+}
+
+impl<T> Debug for MedianTracker<T>
+where
+    T: Debug + Clone + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MedianTracker")
+            .field("size", &self.size())
+            .field("median", &self.median_pair())
+            .finish()
+    }
+}
+
+impl<T> Clone for MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    fn clone(&self) -> Self {
+        Self {
+            lower: self.lower.clone(),
+            upper: self.upper.clone(),
+        }
+    }
+}
+
+impl<T> Extend<T> for MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        MedianTracker::extend(self, iter);
+    }
+}
+
+impl<T> FromIterator<T> for MedianTracker<T>
+where
+    T: Clone + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
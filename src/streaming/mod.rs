@@ -0,0 +1,14 @@
+/*
+
+    STREAMING
+
+    structures that answer a running question
+    about a sequence as items keep arriving
+
+*/
+
+pub mod median;
+pub mod window;
+
+pub use median::MedianTracker;
+pub use window::{MonotonicDeque, WindowAggregator};
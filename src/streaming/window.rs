@@ -0,0 +1,225 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::fmt::Debug;
+
+use crate::instrument::counted;
+
+// push never evicts by itself, so a burst can be pushed before the window is trimmed; each
+// structure here answers over everything it holds, pop_expired brings that back to the window
+
+// the window in arrival order, plus the positions of the items that can still become the max
+// (or the min): each of those lists is monotone, so its front is the answer
+pub struct MonotonicDeque<T>
+where
+    T: Ord,
+{
+    items: VecDeque<T>,
+    maxima: VecDeque<usize>,
+    minima: VecDeque<usize>,
+    // the arrival position of items[0], so positions survive eviction
+    front: usize,
+    window: usize,
+}
+
+impl<T> MonotonicDeque<T>
+where
+    T: Ord,
+{
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window must hold at least one item");
+        Self {
+            items: VecDeque::with_capacity(window),
+            maxima: VecDeque::new(),
+            minima: VecDeque::new(),
+            front: 0,
+            window,
+        }
+    }
+}
+
+impl<T> MonotonicDeque<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn window(&self) -> usize {
+        self.window
+    }
+    // an item that is no larger than a newer one can never be the max again, so it is dropped
+    // from the maxima here; every position is pushed and popped once, hence amortized O(1)
+    pub fn push(&mut self, data: T) {
+        let position = self.front + self.items.len();
+        while let Some(&last) = self.maxima.back()
+            && counted(self.items[last - self.front].cmp(&data)).is_le()
+        {
+            self.maxima.pop_back();
+        }
+        while let Some(&last) = self.minima.back()
+            && counted(self.items[last - self.front].cmp(&data)).is_ge()
+        {
+            self.minima.pop_back();
+        }
+        self.maxima.push_back(position);
+        self.minima.push_back(position);
+        self.items.push_back(data);
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        let data = self.items.pop_front()?;
+        if self.maxima.front() == Some(&self.front) {
+            self.maxima.pop_front();
+        }
+        if self.minima.front() == Some(&self.front) {
+            self.minima.pop_front();
+        }
+        self.front += 1;
+        Some(data)
+    }
+    // drops the oldest items until only the window is left, returning how many went
+    pub fn pop_expired(&mut self) -> usize {
+        let expired = self.items.len().saturating_sub(self.window);
+        (0..expired).for_each(|_| {
+            self.pop_front();
+        });
+        expired
+    }
+    pub fn max(&self) -> Option<&T> {
+        self.maxima
+            .front()
+            .map(|&position| &self.items[position - self.front])
+    }
+    pub fn min(&self) -> Option<&T> {
+        self.minima
+            .front()
+            .map(|&position| &self.items[position - self.front])
+    }
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.items.iter()
+    }
+    pub fn clear(&mut self) {
+        self.front += self.items.len();
+        self.items.clear();
+        self.maxima.clear();
+        self.minima.clear();
+    }
+}
+
+impl<T> Debug for MonotonicDeque<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for MonotonicDeque<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+// folds a sliding window with any associative op in amortized O(1), with no inverse needed: new
+// items fold into a running total at the back, and when the oldest has to go the back is moved
+// onto a stack whose entries hold the fold of themselves and everything newer up to the split.
+// the op can be non-commutative; the fold always runs oldest to newest
+pub struct WindowAggregator<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    // oldest on top, each paired with the fold from it through the newest item in front
+    front: Vec<(T, T)>,
+    back: Vec<T>,
+    back_total: Option<T>,
+    op: F,
+    window: usize,
+}
+
+impl<T, F> WindowAggregator<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    pub fn new(window: usize, op: F) -> Self {
+        assert!(window > 0, "window must hold at least one item");
+        Self {
+            front: Vec::new(),
+            back: Vec::new(),
+            back_total: None,
+            op,
+            window,
+        }
+    }
+}
+
+impl<T, F> WindowAggregator<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+    pub fn window(&self) -> usize {
+        self.window
+    }
+    pub fn push(&mut self, data: T) {
+        self.back_total = Some(match &self.back_total {
+            Some(total) => (self.op)(total, &data),
+            None => data.clone(),
+        });
+        self.back.push(data);
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            self.back_total = None;
+            while let Some(data) = self.back.pop() {
+                let total = match self.front.last() {
+                    Some((_, newer)) => (self.op)(&data, newer),
+                    None => data.clone(),
+                };
+                self.front.push((data, total));
+            }
+        }
+        self.front.pop().map(|(data, _)| data)
+    }
+    pub fn pop_expired(&mut self) -> usize {
+        let expired = self.len().saturating_sub(self.window);
+        (0..expired).for_each(|_| {
+            self.pop_front();
+        });
+        expired
+    }
+    pub fn aggregate(&self) -> Option<T> {
+        match (self.front.last(), &self.back_total) {
+            (Some((_, older)), Some(newer)) => Some((self.op)(older, newer)),
+            (Some((_, total)), None) | (None, Some(total)) => Some(total.clone()),
+            (None, None) => None,
+        }
+    }
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+        self.back_total = None;
+    }
+}
+
+impl<T, F> Extend<T> for WindowAggregator<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
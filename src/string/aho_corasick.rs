@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::{
+    adt::Queue,
+    linked_list::singly::LinkedList,
+    tree::trie::{ROOT, Trie},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    // index of the pattern in the order it was given to new
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+// a trie of the patterns plus a failure link per node, pointing at the node for the longest
+// proper suffix of that node's path which is also in the trie
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    trie: Trie,
+    failure: Vec<usize>,
+    // every pattern that ends at a node, including those reached by following failure links
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let mut trie = Trie::new();
+        let mut ends = Vec::new();
+        let mut lengths = Vec::new();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            let (end, _) = trie.insert_key(pattern);
+            ends.push(end);
+            lengths.push(pattern.len());
+        }
+        let mut outputs = alloc::vec![Vec::new(); trie.node_count()];
+        for (pattern, &end) in ends.iter().enumerate() {
+            outputs[end].push(pattern);
+        }
+        let mut automaton = Self {
+            failure: alloc::vec![ROOT; trie.node_count()],
+            trie,
+            outputs,
+            lengths,
+        };
+        automaton.link_failures();
+        automaton
+    }
+    // breadth first, so a node's failure target is always shallower and already linked
+    fn link_failures(&mut self) {
+        let mut queue = LinkedList::new();
+        for (_, child) in self.trie.children(ROOT) {
+            // empty patterns sit on the root and match wherever the walk is
+            let inherited = self.outputs[ROOT].clone();
+            self.outputs[child].extend(inherited);
+            queue.offer(child);
+        }
+        while let Some(node) = queue.poll() {
+            for (byte, child) in self.trie.children(node).collect::<Vec<_>>() {
+                let target = self.step(self.failure[node], byte);
+                self.failure[child] = target;
+                let inherited = self.outputs[target].clone();
+                self.outputs[child].extend(inherited);
+                queue.offer(child);
+            }
+        }
+    }
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(next) = self.trie.child(state, byte) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.failure[state];
+        }
+    }
+}
+
+impl AhoCorasick {
+    pub fn pattern_count(&self) -> usize {
+        self.lengths.len()
+    }
+    pub fn is_match(&self, text: &[u8]) -> bool {
+        self.find_iter(text).next().is_some()
+    }
+    // matches come out ordered by where they end, longest first among those ending together;
+    // an empty pattern matches at every position, the start included
+    pub fn find_iter<'a>(&'a self, text: &'a [u8]) -> FindIter<'a> {
+        FindIter {
+            automaton: self,
+            text,
+            position: 0,
+            state: ROOT,
+            pending: 0,
+        }
+    }
+}
+
+pub struct FindIter<'a> {
+    automaton: &'a AhoCorasick,
+    text: &'a [u8],
+    position: usize,
+    state: usize,
+    // how many of the current state's outputs have already been yielded
+    pending: usize,
+}
+
+impl Iterator for FindIter<'_> {
+    type Item = Match;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&pattern) = self.automaton.outputs[self.state].get(self.pending) {
+                self.pending += 1;
+                return Some(Match {
+                    pattern,
+                    start: self.position - self.automaton.lengths[pattern],
+                    end: self.position,
+                });
+            }
+            let &byte = self.text.get(self.position)?;
+            self.state = self.automaton.step(self.state, byte);
+            self.position += 1;
+            self.pending = 0;
+        }
+    }
+}
+
+impl FusedIterator for FindIter<'_> {}
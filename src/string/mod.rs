@@ -0,0 +1,197 @@
+pub mod aho_corasick;
+pub mod suffix_array;
+
+use alloc::vec::Vec;
+
+// every search below reports the start index of each match, overlapping ones included;
+// an empty pattern matches at every position from 0 to text.len(), like str::match_indices
+
+// failure[i] is the length of the longest proper prefix of pattern[..=i] that is also its suffix,
+// which tells kmp how far it can fall back after a mismatch without rescanning the text
+pub fn failure_table<T>(pattern: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    let mut failure = alloc::vec![0; pattern.len()];
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = failure[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+        failure[i] = k;
+    }
+    failure
+}
+
+pub fn kmp_find_all<T>(text: &[T], pattern: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let failure = failure_table(pattern);
+    let mut matches = Vec::new();
+    let mut k = 0;
+    for (i, item) in text.iter().enumerate() {
+        while k > 0 && *item != pattern[k] {
+            k = failure[k - 1];
+        }
+        if *item == pattern[k] {
+            k += 1;
+        }
+        if k == pattern.len() {
+            matches.push(i + 1 - k);
+            k = failure[k - 1];
+        }
+    }
+    matches
+}
+
+pub fn kmp_find<T>(text: &[T], pattern: &[T]) -> Option<usize>
+where
+    T: PartialEq,
+{
+    kmp_find_all(text, pattern).first().copied()
+}
+
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 257;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % MODULUS as u128) as u64
+}
+
+// polynomial hash of a fixed-width window, updated in O(1) as the window slides one byte right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollingHash {
+    hash: u64,
+    // BASE raised to the window width, the weight of the byte about to leave
+    power: u64,
+}
+
+impl RollingHash {
+    pub fn new(window: &[u8]) -> Self {
+        let mut hash = 0;
+        let mut power = 1;
+        for &byte in window {
+            hash = (mul_mod(hash, BASE) + byte as u64) % MODULUS;
+            power = mul_mod(power, BASE);
+        }
+        Self { hash, power }
+    }
+    pub fn value(&self) -> u64 {
+        self.hash
+    }
+    pub fn roll(&mut self, outgoing: u8, incoming: u8) {
+        let shifted = (mul_mod(self.hash, BASE) + incoming as u64) % MODULUS;
+        let dropped = mul_mod(outgoing as u64, self.power);
+        self.hash = (shifted + MODULUS - dropped) % MODULUS;
+    }
+}
+
+// windows whose hash matches the pattern's are compared byte for byte, so collisions only cost time
+pub fn rabin_karp_find_all(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    if pattern.len() > text.len() {
+        return Vec::new();
+    }
+    let target = RollingHash::new(pattern).value();
+    let mut window = RollingHash::new(&text[..pattern.len()]);
+    let mut matches = Vec::new();
+    for start in 0..=text.len() - pattern.len() {
+        if start > 0 {
+            window.roll(text[start - 1], text[start + pattern.len() - 1]);
+        }
+        if window.value() == target && &text[start..start + pattern.len()] == pattern {
+            matches.push(start);
+        }
+    }
+    matches
+}
+
+pub fn rabin_karp_find(text: &[u8], pattern: &[u8]) -> Option<usize> {
+    rabin_karp_find_all(text, pattern).first().copied()
+}
+
+// z over the sequence at(0), at(1), .., at(len - 1), so a search can run over pattern ++ text
+// without building the concatenation
+fn z_function<'a, T, F>(len: usize, at: F) -> Vec<usize>
+where
+    T: PartialEq + 'a,
+    F: Fn(usize) -> &'a T,
+{
+    let mut z = alloc::vec![0; len];
+    if len == 0 {
+        return z;
+    }
+    z[0] = len;
+    // [left, right) is the rightmost window found so far that matches a prefix
+    let (mut left, mut right) = (0, 0);
+    for i in 1..len {
+        let mut k = if i < right {
+            z[i - left].min(right - i)
+        } else {
+            0
+        };
+        while i + k < len && at(k) == at(i + k) {
+            k += 1;
+        }
+        z[i] = k;
+        if i + k > right {
+            left = i;
+            right = i + k;
+        }
+    }
+    z
+}
+
+// z[i] is the length of the longest common prefix of sequence and sequence[i..], so z[0] is the
+// whole length
+pub fn z_array<T>(sequence: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    z_function(sequence.len(), |i| &sequence[i])
+}
+
+pub fn z_find_all<T>(text: &[T], pattern: &[T]) -> Vec<usize>
+where
+    T: PartialEq,
+{
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let m = pattern.len();
+    let z = z_function(m + text.len(), |i| {
+        if i < m { &pattern[i] } else { &text[i - m] }
+    });
+    (0..text.len()).filter(|&start| z[m + start] >= m).collect()
+}
+
+pub fn common_prefix_len<T>(a: &[T], b: &[T]) -> usize
+where
+    T: PartialEq,
+{
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+// the longest prefix shared by every string, cut back to a char boundary
+pub fn longest_common_prefix<'a>(strings: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = strings.split_first() else {
+        return "";
+    };
+    let mut len = rest
+        .iter()
+        .map(|s| common_prefix_len(first.as_bytes(), s.as_bytes()))
+        .fold(first.len(), usize::min);
+    while !first.is_char_boundary(len) {
+        len -= 1;
+    }
+    &first[..len]
+}
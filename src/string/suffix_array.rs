@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use crate::{search::partition_point, sort::counting_sort_by_key};
+
+// the starting index of every suffix of the text, in lexicographic order, alongside
+// lcp[i], the length of the prefix that suffix i shares with suffix i - 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    pub fn new(text: &[u8]) -> Self {
+        let suffixes = build_suffixes(text);
+        let lcp = build_lcp(text, &suffixes);
+        Self {
+            text: text.to_vec(),
+            suffixes,
+            lcp,
+        }
+    }
+}
+
+impl SuffixArray {
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+    pub fn text(&self) -> &[u8] {
+        &self.text
+    }
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+    // the suffixes starting with pattern form one contiguous block, found with two binary searches
+    fn matching(&self, pattern: &[u8]) -> &[usize] {
+        let head = |start: usize| {
+            let suffix = &self.text[start..];
+            &suffix[..suffix.len().min(pattern.len())]
+        };
+        let lower = partition_point(&self.suffixes, |&start| head(start) < pattern);
+        let upper = partition_point(&self.suffixes, |&start| head(start) <= pattern);
+        &self.suffixes[lower..upper]
+    }
+    // as with the other string searches, an empty pattern also matches at the very end
+    pub fn count_occurrences(&self, pattern: &[u8]) -> usize {
+        if pattern.is_empty() {
+            return self.len() + 1;
+        }
+        self.matching(pattern).len()
+    }
+    // every position where pattern occurs, overlapping matches included, in ascending order
+    pub fn find_all(&self, pattern: &[u8]) -> Vec<usize> {
+        if pattern.is_empty() {
+            return (0..=self.len()).collect();
+        }
+        let mut positions = self.matching(pattern).to_vec();
+        positions.sort_unstable();
+        positions
+    }
+}
+
+// prefix doubling: once suffixes are ranked by their first k bytes, sorting by the pair of ranks
+// at i and i + k ranks them by their first 2k, with two stable counting passes per round
+fn build_suffixes(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let mut suffixes: Vec<usize> = (0..n).collect();
+    if n == 0 {
+        return suffixes;
+    }
+    // rank 0 is kept for positions past the end, which sort before everything
+    let mut rank: Vec<usize> = text.iter().map(|&byte| byte as usize + 1).collect();
+    let mut next = alloc::vec![0; n];
+    let mut k = 1;
+    loop {
+        let pair = |i: usize| (rank[i], rank.get(i + k).copied().unwrap_or(0));
+        counting_sort_by_key(&mut suffixes, |&i| pair(i).1);
+        counting_sort_by_key(&mut suffixes, |&i| pair(i).0);
+        next[suffixes[0]] = 1;
+        for j in 1..n {
+            let distinct = pair(suffixes[j]) != pair(suffixes[j - 1]);
+            next[suffixes[j]] = next[suffixes[j - 1]] + distinct as usize;
+        }
+        core::mem::swap(&mut rank, &mut next);
+        if rank[suffixes[n - 1]] == n || k >= n {
+            return suffixes;
+        }
+        k *= 2;
+    }
+}
+
+// kasai: walking suffixes in text order, the shared prefix shrinks by at most one per step
+fn build_lcp(text: &[u8], suffixes: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut inverse = alloc::vec![0; n];
+    for (i, &start) in suffixes.iter().enumerate() {
+        inverse[start] = i;
+    }
+    let mut lcp = alloc::vec![0; n];
+    let mut h: usize = 0;
+    for start in 0..n {
+        if inverse[start] == 0 {
+            h = 0;
+            continue;
+        }
+        let previous = suffixes[inverse[start] - 1];
+        while start + h < n && previous + h < n && text[start + h] == text[previous + h] {
+            h += 1;
+        }
+        lcp[inverse[start]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
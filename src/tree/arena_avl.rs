@@ -0,0 +1,527 @@
+/*
+
+    ARENA AVL TREE
+
+    the same balancing as AVLTree, but nodes
+    live in one Arena and link by u32 handles,
+    so clearing is a single Vec clear
+
+*/
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+
+use crate::{
+    arena::{Arena, Handle},
+    instrument::{self, counted},
+    tree::avl::TreeInvariantError,
+};
+
+type Link = Option<Handle>;
+
+#[derive(Clone)]
+struct Node<T> {
+    data: T,
+    left: Link,
+    right: Link,
+    height: i8,
+    size: usize,
+}
+
+type Nodes<T> = Arena<Node<T>>;
+
+fn height<T>(nodes: &Nodes<T>, link: Link) -> i8 {
+    link.map_or(0, |handle| nodes[handle].height)
+}
+
+fn size<T>(nodes: &Nodes<T>, link: Link) -> usize {
+    link.map_or(0, |handle| nodes[handle].size)
+}
+
+fn balance_factor<T>(nodes: &Nodes<T>, handle: Handle) -> i8 {
+    height(nodes, nodes[handle].left) - height(nodes, nodes[handle].right)
+}
+
+fn update<T>(nodes: &mut Nodes<T>, handle: Handle) {
+    let (left, right) = (nodes[handle].left, nodes[handle].right);
+    let node_height = 1 + height(nodes, left).max(height(nodes, right));
+    let node_size = 1 + size(nodes, left) + size(nodes, right);
+    let node = &mut nodes[handle];
+    node.height = node_height;
+    node.size = node_size;
+}
+
+fn left_rotate<T>(nodes: &mut Nodes<T>, x: Handle) -> Handle {
+    instrument::rotation();
+    let y = nodes[x].right.unwrap();
+    nodes[x].right = nodes[y].left;
+    nodes[y].left = Some(x);
+    update(nodes, x);
+    update(nodes, y);
+    y
+}
+
+fn right_rotate<T>(nodes: &mut Nodes<T>, y: Handle) -> Handle {
+    instrument::rotation();
+    let x = nodes[y].left.unwrap();
+    nodes[y].left = nodes[x].right;
+    nodes[x].right = Some(y);
+    update(nodes, y);
+    update(nodes, x);
+    x
+}
+
+fn rebalance<T>(nodes: &mut Nodes<T>, handle: Handle) -> Handle {
+    update(nodes, handle);
+    let balance = balance_factor(nodes, handle);
+    if balance > 1 {
+        let left = nodes[handle].left.unwrap();
+        if balance_factor(nodes, left) < 0 {
+            nodes[handle].left = Some(left_rotate(nodes, left));
+        }
+        return right_rotate(nodes, handle);
+    }
+    if balance < -1 {
+        let right = nodes[handle].right.unwrap();
+        if balance_factor(nodes, right) > 0 {
+            nodes[handle].right = Some(right_rotate(nodes, right));
+        }
+        return left_rotate(nodes, handle);
+    }
+    handle
+}
+
+fn insert_node<T>(nodes: &mut Nodes<T>, link: Link, data: T) -> (Handle, bool)
+where
+    T: Ord,
+{
+    let Some(handle) = link else {
+        let node = Node {
+            data,
+            left: None,
+            right: None,
+            height: 1,
+            size: 1,
+        };
+        return (nodes.alloc(node), true);
+    };
+    let inserted = match counted(data.cmp(&nodes[handle].data)) {
+        Ordering::Equal => return (handle, false),
+        Ordering::Less => {
+            let (left, inserted) = insert_node(nodes, nodes[handle].left, data);
+            nodes[handle].left = Some(left);
+            inserted
+        }
+        Ordering::Greater => {
+            let (right, inserted) = insert_node(nodes, nodes[handle].right, data);
+            nodes[handle].right = Some(right);
+            inserted
+        }
+    };
+    (rebalance(nodes, handle), inserted)
+}
+
+// detaches the leftmost node below handle, returning what is left of the subtree and the node
+fn remove_min<T>(nodes: &mut Nodes<T>, handle: Handle) -> (Link, Handle) {
+    match nodes[handle].left {
+        None => (nodes[handle].right.take(), handle),
+        Some(left) => {
+            let (left, min) = remove_min(nodes, left);
+            nodes[handle].left = left;
+            (Some(rebalance(nodes, handle)), min)
+        }
+    }
+}
+
+fn remove_node<T, Q>(nodes: &mut Nodes<T>, link: Link, key: &Q) -> (Link, Option<T>)
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let Some(handle) = link else {
+        return (None, None);
+    };
+    let removed = match counted(key.cmp(nodes[handle].data.borrow())) {
+        Ordering::Less => {
+            let (left, removed) = remove_node(nodes, nodes[handle].left, key);
+            nodes[handle].left = left;
+            removed
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove_node(nodes, nodes[handle].right, key);
+            nodes[handle].right = right;
+            removed
+        }
+        Ordering::Equal => {
+            let node = nodes.free(handle).unwrap();
+            // the successor node takes the removed node's place, so no data has to move
+            let replacement = match (node.left, node.right) {
+                (None, child) | (child, None) => child,
+                (Some(left), Some(right)) => {
+                    let (right, successor) = remove_min(nodes, right);
+                    nodes[successor].left = Some(left);
+                    nodes[successor].right = right;
+                    Some(rebalance(nodes, successor))
+                }
+            };
+            return (replacement, Some(node.data));
+        }
+    };
+    (Some(rebalance(nodes, handle)), removed)
+}
+
+fn validate_node<'a, T>(
+    nodes: &'a Nodes<T>,
+    link: Link,
+    lower: Option<&'a T>,
+    upper: Option<&'a T>,
+    index: &mut usize,
+) -> Result<(i8, usize), TreeInvariantError>
+where
+    T: Ord,
+{
+    let Some(handle) = link else {
+        return Ok((0, 0));
+    };
+    let node = &nodes[handle];
+    let (left_height, left_size) = validate_node(nodes, node.left, lower, Some(&node.data), index)?;
+    let id = *index;
+    *index += 1;
+    if lower.is_some_and(|lower| &node.data <= lower)
+        || upper.is_some_and(|upper| &node.data >= upper)
+    {
+        return Err(TreeInvariantError::Unordered { node: id });
+    }
+    let (right_height, right_size) =
+        validate_node(nodes, node.right, Some(&node.data), upper, index)?;
+    let actual = 1 + left_height.max(right_height);
+    if node.height != actual {
+        return Err(TreeInvariantError::Height {
+            node: id,
+            recorded: node.height,
+            actual,
+        });
+    }
+    let factor = left_height - right_height;
+    if factor.abs() > 1 {
+        return Err(TreeInvariantError::Balance { node: id, factor });
+    }
+    let actual = 1 + left_size + right_size;
+    if node.size != actual {
+        return Err(TreeInvariantError::SubtreeSize {
+            node: id,
+            recorded: node.size,
+            actual,
+        });
+    }
+    Ok((node.height, node.size))
+}
+
+#[derive(Clone)]
+pub struct ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    nodes: Nodes<T>,
+    root: Link,
+}
+
+impl<T> Default for ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            root: None,
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Arena::with_capacity(capacity),
+            root: None,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T> ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+    // the number of nodes the arena can hold before it has to grow
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+    pub fn insert(&mut self, data: T) -> bool {
+        let (root, inserted) = insert_node(&mut self.nodes, self.root, data);
+        self.root = Some(root);
+        self.audit();
+        inserted
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| {
+            self.insert(item);
+        });
+    }
+    pub fn contains<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(data).is_some()
+    }
+    pub fn get<Q>(&self, key: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = self.root;
+        while let Some(handle) = link {
+            let node = &self.nodes[handle];
+            match counted(key.cmp(node.data.borrow())) {
+                Ordering::Less => link = node.left,
+                Ordering::Greater => link = node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+    pub fn remove<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.take(data).is_some()
+    }
+    pub fn take<Q>(&mut self, data: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (root, removed) = remove_node(&mut self.nodes, self.root, data);
+        self.root = root;
+        self.audit();
+        removed
+    }
+    pub fn first(&self) -> Option<&T> {
+        let mut handle = self.root?;
+        while let Some(left) = self.nodes[handle].left {
+            handle = left;
+        }
+        Some(&self.nodes[handle].data)
+    }
+    pub fn last(&self) -> Option<&T> {
+        let mut handle = self.root?;
+        while let Some(right) = self.nodes[handle].right {
+            handle = right;
+        }
+        Some(&self.nodes[handle].data)
+    }
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        let mut link = self.root;
+        let mut k = k;
+        while let Some(handle) = link {
+            let node = &self.nodes[handle];
+            let left = size(&self.nodes, node.left);
+            match k.cmp(&left) {
+                Ordering::Less => link = node.left,
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Greater => {
+                    k -= left + 1;
+                    link = node.right;
+                }
+            }
+        }
+        None
+    }
+    pub fn rank<Q>(&self, data: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = self.root;
+        let mut count = 0;
+        while let Some(handle) = link {
+            let node = &self.nodes[handle];
+            if data <= node.data.borrow() {
+                link = node.left;
+            } else {
+                count += size(&self.nodes, node.left) + 1;
+                link = node.right;
+            }
+        }
+        count
+    }
+    pub fn validate(&self) -> Result<(), TreeInvariantError> {
+        let (_, actual) = validate_node(&self.nodes, self.root, None, None, &mut 0)?;
+        if actual != self.nodes.len() {
+            return Err(TreeInvariantError::Size {
+                recorded: self.nodes.len(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+    fn audit(&self) {
+        #[cfg(feature = "verify")]
+        if cfg!(debug_assertions)
+            && let Err(err) = self.validate()
+        {
+            panic!("ArenaAVLTree invariant violated: {}", err);
+        }
+    }
+    // every node sits in the one arena, so nothing has to walk the tree to free it
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = None;
+    }
+}
+
+impl<T> PartialEq for ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for ArenaAVLTree<T> where T: Ord {}
+
+impl<T> Hash for ArenaAVLTree<T>
+where
+    T: Hash + Ord,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T> Debug for ArenaAVLTree<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// in-order walk keeping the unvisited left spine on a stack of handles
+pub struct Iter<'a, T>
+where
+    T: Ord,
+{
+    nodes: &'a Nodes<T>,
+    stack: Vec<Handle>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: Ord,
+{
+    fn push_spine(&mut self, mut link: Link) {
+        while let Some(handle) = link {
+            self.stack.push(handle);
+            link = self.nodes[handle].left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let handle = self.stack.pop()?;
+        let node = &self.nodes[handle];
+        self.push_spine(node.right);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: Ord {}
+
+impl<T> ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            nodes: &self.nodes,
+            stack: Vec::with_capacity(height(&self.nodes, self.root) as usize),
+            remaining: self.size(),
+        };
+        iter.push_spine(self.root);
+        iter
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T> Extend<T> for ArenaAVLTree<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        ArenaAVLTree::extend(self, iter);
+    }
+}
@@ -1,183 +1,288 @@
-use std::fmt::Debug;
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    error::Error,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+};
 
-use crate::linked_list::singly::LinkedList;
+use crate::{
+    adt::Queue,
+    instrument::{self, counted},
+    linked_list::{doubly, singly::LinkedList},
+};
 
 pub type NodePtr<T> = Option<Box<Node<T>>>;
 
 pub struct Node<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     pub data: T,
     pub left: NodePtr<T>,
     pub right: NodePtr<T>,
     pub height: i8,
+    pub size: usize,
 }
 
-impl<T> Into<NodePtr<T>> for Node<T>
+impl<T> From<Node<T>> for NodePtr<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
-    fn into(self) -> NodePtr<T> {
-        Some(Box::new(self))
+    fn from(node: Node<T>) -> Self {
+        instrument::allocation();
+        Some(Box::new(node))
     }
 }
 
 fn height<T>(ptr: &NodePtr<T>) -> i8
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     if let Some(node) = ptr { node.height } else { 0 }
 }
 
-fn insert_rec<T>(ptr: NodePtr<T>, data: &T) -> (NodePtr<T>, bool)
+fn size<T>(ptr: &NodePtr<T>) -> usize
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
-    if let Some(mut node) = ptr {
-        let inserted: bool;
-        if data < &node.data {
-            let (new_left, ok) = insert_rec(node.left, data);
-            node.left = new_left;
-            inserted = ok;
-        } else if data > &node.data {
-            let (new_right, ok) = insert_rec(node.right, data);
-            node.right = new_right;
-            inserted = ok;
-        } else {
-            return (Some(node), false);
-        }
-        node.update_height();
-        let balance = node.balance_factor();
-        if balance > 1 {
-            if let Some(left) = &node.left {
-                if data < &left.data {
-                    return (Some(node).right_rotate(), true);
-                }
-                if data > &left.data {
-                    node.left = node.left.left_rotate();
-                    return (Some(node).right_rotate(), true);
-                }
+    if let Some(node) = ptr { node.size } else { 0 }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeInvariantError {
+    Unordered { node: usize },
+    Height { node: usize, recorded: i8, actual: i8 },
+    Balance { node: usize, factor: i8 },
+    SubtreeSize { node: usize, recorded: usize, actual: usize },
+    Size { recorded: usize, actual: usize },
+}
+
+impl Display for TreeInvariantError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unordered { node } => {
+                write!(f, "node {} is out of order", node)
             }
-        }
-        if balance < -1 {
-            if let Some(right) = &node.right {
-                if data > &right.data {
-                    return (Some(node).left_rotate(), true);
-                }
-                if data < &right.data {
-                    node.right = node.right.right_rotate();
-                    return (Some(node).left_rotate(), true);
-                }
+            Self::Height { node, recorded, actual } => {
+                write!(f, "node {} records height {} but has height {}", node, recorded, actual)
+            }
+            Self::Balance { node, factor } => {
+                write!(f, "node {} has balance factor {}", node, factor)
+            }
+            Self::SubtreeSize { node, recorded, actual } => {
+                write!(f, "node {} records subtree size {} but has {}", node, recorded, actual)
+            }
+            Self::Size { recorded, actual } => {
+                write!(f, "tree records size {} but has {} nodes", recorded, actual)
             }
         }
-        (Some(node), inserted)
-    } else {
-        (Node::new(data.clone()).into(), true)
     }
 }
 
-fn delete_rec<T>(ptr: NodePtr<T>, data: &T) -> (NodePtr<T>, bool)
-where 
-    T: Clone + Ord + PartialOrd + Eq,
-{
-    if let Some(mut node) = ptr {
-        let deleted: bool;
-        if data < &node.data {
-            let (new_left, ok) = delete_rec(node.left, data);
-            node.left = new_left;
-            deleted = ok;
-        } else if data > &node.data {
-            let (new_right, ok) = delete_rec(node.right, data);
-            node.right = new_right;
-            deleted = ok;
-        } else {
-            deleted = true;
-            if node.left.is_none() || node.right.is_none() {
-                return (node.left.or(node.right), true);
-            } else {
-                let tmp = node.right.min_value();
-                node.data = tmp.unwrap().data.clone();
-                let (new_right, _) = delete_rec(node.right, &node.data);
-                node.right = new_right;
-            }
+impl Error for TreeInvariantError {}
+
+fn validate_node<'a, T>(
+    ptr: &'a NodePtr<T>,
+    lower: Option<&'a T>,
+    upper: Option<&'a T>,
+    index: &mut usize,
+) -> Result<(i8, usize), TreeInvariantError>
+where
+    T: Ord,
+{
+    let Some(node) = ptr else {
+        return Ok((0, 0));
+    };
+    let (left_height, left_size) = validate_node(&node.left, lower, Some(&node.data), index)?;
+    let id = *index;
+    *index += 1;
+    if lower.is_some_and(|lower| &node.data <= lower) || upper.is_some_and(|upper| &node.data >= upper) {
+        return Err(TreeInvariantError::Unordered { node: id });
+    }
+    let (right_height, right_size) = validate_node(&node.right, Some(&node.data), upper, index)?;
+    let actual = 1 + left_height.max(right_height);
+    if node.height != actual {
+        return Err(TreeInvariantError::Height { node: id, recorded: node.height, actual });
+    }
+    let factor = left_height - right_height;
+    if factor.abs() > 1 {
+        return Err(TreeInvariantError::Balance { node: id, factor });
+    }
+    let actual = 1 + left_size + right_size;
+    if node.size != actual {
+        return Err(TreeInvariantError::SubtreeSize { node: id, recorded: node.size, actual });
+    }
+    Ok((node.height, node.size))
+}
+
+fn rebalance<T>(mut node: Box<Node<T>>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    node.update();
+    let balance = node.balance_factor();
+    if balance > 1 {
+        if node.left.as_ref().is_some_and(|left| left.balance_factor() < 0) {
+            node.left = node.left.left_rotate();
         }
-        node.update_height();
-        let balance = node.balance_factor();
-        if let Some(ref left) = node.left {
-            if balance > 1 && left.balance_factor() >= 0 {
-                return (Some(node).right_rotate(), deleted);
-            }
-            if balance > 1 && left.balance_factor() < 0 {
-                node.left = node.left.left_rotate();
-                return (Some(node).right_rotate(), deleted);
-            }
+        return Some(node).right_rotate();
+    }
+    if balance < -1 {
+        if node.right.as_ref().is_some_and(|right| right.balance_factor() > 0) {
+            node.right = node.right.right_rotate();
         }
-        if let Some(ref right) = node.right {
-            if balance < -1 && right.balance_factor() <= 0 {
-                return (Some(node).left_rotate(), deleted);
-            }
-            if balance < -1 && right.balance_factor() > 0 {
-                node.right = node.right.right_rotate();
-                return (Some(node).left_rotate(), deleted);
+        return Some(node).left_rotate();
+    }
+    Some(node)
+}
+
+type Path<T> = Vec<(Box<Node<T>>, Ordering)>;
+
+fn unwind<T>(mut path: Path<T>, mut ptr: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    while let Some((mut parent, dir)) = path.pop() {
+        if dir == Ordering::Less {
+            parent.left = ptr;
+        } else {
+            parent.right = ptr;
+        }
+        ptr = rebalance(parent);
+    }
+    ptr
+}
+
+fn insert_node<T>(root: NodePtr<T>, data: T) -> (NodePtr<T>, bool)
+where
+    T: Ord,
+{
+    let mut path = Path::with_capacity(height(&root) as usize);
+    let mut ptr = root;
+    let inserted = loop {
+        match ptr {
+            None => {
+                ptr = Node::new(data).into();
+                break true;
             }
+            Some(mut node) => match counted(data.cmp(&node.data)) {
+                Ordering::Equal => {
+                    ptr = Some(node);
+                    break false;
+                }
+                dir => {
+                    ptr = if dir == Ordering::Less {
+                        node.left.take()
+                    } else {
+                        node.right.take()
+                    };
+                    path.push((node, dir));
+                }
+            },
         }
-        (Some(node), deleted)
-    } else {
-        (None, false)
+    };
+    (unwind(path, ptr), inserted)
+}
+
+fn remove_min<T>(mut node: Box<Node<T>>) -> (NodePtr<T>, T)
+where
+    T: Ord,
+{
+    let mut path = Path::with_capacity(node.height as usize);
+    while let Some(left) = node.left.take() {
+        path.push((node, Ordering::Less));
+        node = left;
     }
+    let right = node.right.take();
+    (unwind(path, right), node.data)
+}
+
+fn delete_node<T, F>(root: NodePtr<T>, probe: F) -> (NodePtr<T>, Option<T>)
+where
+    T: Ord,
+    F: Fn(&T) -> Ordering,
+{
+    let mut path = Path::with_capacity(height(&root) as usize);
+    let mut ptr = root;
+    let deleted = loop {
+        match ptr {
+            None => break None,
+            Some(mut node) => match counted(probe(&node.data)) {
+                Ordering::Equal => {
+                    match (node.left.take(), node.right.take()) {
+                        (None, child) | (child, None) => {
+                            ptr = child;
+                            break Some(node.data);
+                        }
+                        (Some(left), Some(right)) => {
+                            let (new_right, successor) = remove_min(right);
+                            node.left = Some(left);
+                            node.right = new_right;
+                            let val = core::mem::replace(&mut node.data, successor);
+                            ptr = rebalance(node);
+                            break Some(val);
+                        }
+                    }
+                }
+                dir => {
+                    ptr = if dir == Ordering::Less {
+                        node.left.take()
+                    } else {
+                        node.right.take()
+                    };
+                    path.push((node, dir));
+                }
+            },
+        }
+    };
+    (unwind(path, ptr), deleted)
 }
 
 trait Rotate<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     fn left_rotate(self) -> Self;
     fn right_rotate(self) -> Self;
-    fn min_value(&mut self) -> Option<&mut Node<T>>;
 }
 
 impl<T> Rotate<T> for NodePtr<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     fn left_rotate(mut self) -> Self {
+        instrument::rotation();
         let mut x = self.take().unwrap();
         let mut y = x.right.take().unwrap();
         x.right = y.left.take();
         y.left = Some(x);
         if let Some(left) = &mut y.left {
-            left.update_height();
+            left.update();
         }
-        y.update_height();
+        y.update();
         Some(y)
     }
     fn right_rotate(mut self) -> Self {
+        instrument::rotation();
         let mut y = self.take().unwrap();
         let mut x = y.left.take().unwrap();
         y.left = x.right.take();
         x.right = Some(y);
         if let Some(right) = &mut x.right {
-            right.update_height();
+            right.update();
         }
-        x.update_height();
+        x.update();
         Some(x)
     }
-    fn min_value(&mut self) -> Option<&mut Node<T>> {
-        let mut ptr = self.as_mut()?;
-        loop {
-            if ptr.left.is_none() {
-                return Some(ptr);
-            }
-            let next = ptr.left.as_mut().unwrap();
-            ptr = next;
-        }
-    }
 }
 
 impl<T> Node<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     pub fn new(data: T) -> Self {
         Self {
@@ -185,27 +290,38 @@ where
             left: None,
             right: None,
             height: 1,
+            size: 1,
         }
     }
-    fn balance_factor(&self) -> i8 {
+    pub(crate) fn balance_factor(&self) -> i8 {
         height(&self.left) - height(&self.right)
     }
-    fn update_height(&mut self) {
+    fn update(&mut self) {
         self.height = 1 + height(&self.left).max(height(&self.right));
+        self.size = 1 + size(&self.left) + size(&self.right);
     }
 }
 
 pub struct AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
-    root: NodePtr<T>,
+    pub(crate) root: NodePtr<T>,
     size: usize,
 }
 
+impl<T> Default for AVLTree<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     pub fn new() -> Self {
         Self {
@@ -225,7 +341,211 @@ where
 
 impl<T> AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
+{
+    // builds a perfectly balanced tree in O(n) by splitting at the midpoint instead of
+    // inserting one by one; duplicates keep their first copy. panics unless the input ascends
+    pub fn from_sorted_vec(mut vec: Vec<T>) -> Self {
+        assert!(vec.is_sorted(), "from_sorted_vec needs ascending input");
+        vec.dedup();
+        let size = vec.len();
+        let tree = Self {
+            root: build_sorted(&mut vec.into_iter(), size),
+            size,
+        };
+        tree.audit();
+        tree
+    }
+    pub fn from_sorted_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_sorted_vec(iter.into_iter().collect())
+    }
+}
+
+// joins two trees around mid, which has to sit between them; the taller side is walked down
+// until the heights meet, so the cost is the height difference rather than the sizes
+fn join_node<T>(left: NodePtr<T>, mut mid: Box<Node<T>>, right: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    let (left_height, right_height) = (height(&left), height(&right));
+    if left_height > right_height + 1 {
+        let mut node = left.unwrap();
+        node.right = join_node(node.right.take(), mid, right);
+        rebalance(node)
+    } else if right_height > left_height + 1 {
+        let mut node = right.unwrap();
+        node.left = join_node(left, mid, node.left.take());
+        rebalance(node)
+    } else {
+        mid.left = left;
+        mid.right = right;
+        mid.update();
+        Some(mid)
+    }
+}
+
+fn take_max<T>(mut node: Box<Node<T>>) -> (NodePtr<T>, Box<Node<T>>)
+where
+    T: Ord,
+{
+    match node.right.take() {
+        None => (node.left.take(), node),
+        Some(right) => {
+            let (rest, max) = take_max(right);
+            node.right = rest;
+            (rebalance(node), max)
+        }
+    }
+}
+
+fn join_pair<T>(left: NodePtr<T>, right: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    match left {
+        None => right,
+        Some(left) => {
+            let (rest, max) = take_max(left);
+            join_node(rest, max, right)
+        }
+    }
+}
+
+fn split_node<T, Q>(ptr: NodePtr<T>, key: &Q) -> (NodePtr<T>, Option<T>, NodePtr<T>)
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let Some(mut node) = ptr else {
+        return (None, None, None);
+    };
+    let (left, right) = (node.left.take(), node.right.take());
+    match counted(key.cmp(node.data.borrow())) {
+        Ordering::Equal => (left, Some(node.data), right),
+        Ordering::Less => {
+            let (below, found, above) = split_node(left, key);
+            (below, found, join_node(above, node, right))
+        }
+        Ordering::Greater => {
+            let (below, found, above) = split_node(right, key);
+            (join_node(left, node, below), found, above)
+        }
+    }
+}
+
+// the set operations split the second tree around the first one's root and recurse on the
+// halves, which costs O(m log(n / m + 1)) for sizes m <= n instead of a pass over both
+fn union_node<T>(left: NodePtr<T>, right: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    let Some(mut root) = left else {
+        return right;
+    };
+    if right.is_none() {
+        return Some(root);
+    }
+    let (below, _, above) = split_node(right, &root.data);
+    let smaller = union_node(root.left.take(), below);
+    let larger = union_node(root.right.take(), above);
+    join_node(smaller, root, larger)
+}
+
+fn intersection_node<T>(left: NodePtr<T>, right: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    let (Some(mut root), Some(_)) = (left, &right) else {
+        return None;
+    };
+    let (below, found, above) = split_node(right, &root.data);
+    let smaller = intersection_node(root.left.take(), below);
+    let larger = intersection_node(root.right.take(), above);
+    if found.is_some() {
+        join_node(smaller, root, larger)
+    } else {
+        join_pair(smaller, larger)
+    }
+}
+
+fn difference_node<T>(left: NodePtr<T>, right: NodePtr<T>) -> NodePtr<T>
+where
+    T: Ord,
+{
+    let (Some(_), Some(mut root)) = (&left, right) else {
+        return left;
+    };
+    let (below, _, above) = split_node(left, &root.data);
+    join_pair(
+        difference_node(below, root.left.take()),
+        difference_node(above, root.right.take()),
+    )
+}
+
+impl<T> AVLTree<T>
+where
+    T: Ord,
+{
+    fn from_root(root: NodePtr<T>) -> Self {
+        let tree = Self {
+            size: size(&root),
+            root,
+        };
+        tree.audit();
+        tree
+    }
+    // everything below key, the item equal to it if any, and everything above
+    pub fn split<Q>(mut self, key: &Q) -> (Self, Option<T>, Self)
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (below, found, above) = split_node(self.root.take(), key);
+        (Self::from_root(below), found, Self::from_root(above))
+    }
+    // the inverse of split; every item of left has to be smaller than every item of right
+    pub fn join(mut left: Self, mut right: Self) -> Self {
+        if let (Some(last), Some(first)) = (left.last(), right.first()) {
+            assert!(last < first, "join needs every item of left below every item of right");
+        }
+        Self::from_root(join_pair(left.root.take(), right.root.take()))
+    }
+    pub fn union(mut self, mut other: Self) -> Self {
+        Self::from_root(union_node(self.root.take(), other.root.take()))
+    }
+    pub fn intersection(mut self, mut other: Self) -> Self {
+        Self::from_root(intersection_node(self.root.take(), other.root.take()))
+    }
+    // what self has and other does not
+    pub fn difference(mut self, mut other: Self) -> Self {
+        Self::from_root(difference_node(self.root.take(), other.root.take()))
+    }
+}
+
+// takes the next len items in order: the first half becomes the left subtree, then the root,
+// then the rest, so the recursion only goes log2(len) deep
+fn build_sorted<T, I>(items: &mut I, len: usize) -> NodePtr<T>
+where
+    T: Ord,
+    I: Iterator<Item = T>,
+{
+    if len == 0 {
+        return None;
+    }
+    let left = build_sorted(items, len / 2);
+    let mut node = Node::new(items.next()?);
+    node.left = left;
+    node.right = build_sorted(items, len - len / 2 - 1);
+    node.update();
+    node.into()
+}
+
+impl<T> AVLTree<T>
+where
+    T: Ord,
 {
     pub fn is_empty(&self) -> bool {
         self.root.is_none()
@@ -234,11 +554,12 @@ where
         self.size
     }
     pub fn insert(&mut self, data: T) -> bool {
-        let (new_root, inserted) = insert_rec(self.root.take(), &data);
+        let (new_root, inserted) = insert_node(self.root.take(), data);
         self.root = new_root;
         if inserted {
             self.size += 1;
         }
+        self.audit();
         inserted
     }
     pub fn extend<I>(&mut self, iter: I)
@@ -249,27 +570,89 @@ where
             self.insert(item);
         } );
     }
-    pub fn contains(&self, data: &T) -> bool {
+    pub fn contains<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let mut ptr = &self.root;
         while let Some(node) = ptr {
-            if data < &node.data {
-                ptr = &node.left;
-            } else if data > &node.data {
-                ptr = &node.right;
-            } else {
-                return true;
+            match counted(data.cmp(node.data.borrow())) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Greater => ptr = &node.right,
+                Ordering::Equal => return true,
             }
         }
         false
     }
-    pub fn remove(&mut self, data: &T) -> bool {
-        let (new_root, deleted) = delete_rec(self.root.take(), data);
+    pub fn remove<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_by(|probe| data.cmp(probe.borrow())).is_some()
+    }
+    pub fn get<Q>(&self, key: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find_by(|probe| key.cmp(probe.borrow()))
+    }
+    pub fn get_mut<'a, Q>(&'a mut self, key: &'a Q) -> Option<ValueMut<'a, T, Q>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find_by_mut(|probe| key.cmp(probe.borrow()))
+            .map(|value| ValueMut { value, key })
+    }
+    pub(crate) fn find_by<F>(&self, probe: F) -> Option<&T>
+    where
+        F: Fn(&T) -> Ordering,
+    {
+        let mut ptr = &self.root;
+        while let Some(node) = ptr {
+            match counted(probe(&node.data)) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Greater => ptr = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+    pub(crate) fn find_by_mut<F>(&mut self, probe: F) -> Option<&mut T>
+    where
+        F: Fn(&T) -> Ordering,
+    {
+        let mut ptr = &mut self.root;
+        while let Some(node) = ptr {
+            match counted(probe(&node.data)) {
+                Ordering::Less => ptr = &mut node.left,
+                Ordering::Greater => ptr = &mut node.right,
+                Ordering::Equal => return Some(&mut node.data),
+            }
+        }
+        None
+    }
+    pub(crate) fn remove_by<F>(&mut self, probe: F) -> Option<T>
+    where
+        F: Fn(&T) -> Ordering,
+    {
+        let (new_root, deleted) = delete_node(self.root.take(), probe);
         self.root = new_root;
-        if deleted {
+        if deleted.is_some() {
             self.size -= 1;
         }
+        self.audit();
         deleted
     }
+    pub fn first(&self) -> Option<&T> {
+        self.get_min()
+    }
+    pub fn last(&self) -> Option<&T> {
+        self.get_max()
+    }
     pub fn get_min(&self) -> Option<&T> {
         let mut ptr = &self.root;
         while let Some(node) = ptr {
@@ -292,13 +675,17 @@ where
         }
         None
     }
-    pub fn get_floor(&self, floor: &T) -> Option<&T> {
+    pub fn get_floor<Q>(&self, floor: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let mut ptr = &self.root;
         let mut candidate = None;
         while let Some(node) = ptr {
-            if &node.data == floor {
+            if node.data.borrow() == floor {
                 return Some(&node.data);
-            } else if &node.data > floor {
+            } else if node.data.borrow() > floor {
                 ptr = &node.left;
             } else {
                 candidate = Some(&node.data);
@@ -307,13 +694,17 @@ where
         }
         candidate
     }
-    pub fn get_ceil(&self, ceil: &T) -> Option<&T> {
+    pub fn get_ceil<Q>(&self, ceil: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let mut ptr = &self.root;
         let mut candidate = None;
         while let Some(node) = ptr {
-            if &node.data == ceil {
+            if node.data.borrow() == ceil {
                 return Some(&node.data);
-            } else if &node.data < ceil {
+            } else if node.data.borrow() < ceil {
                 ptr = &node.right;
             } else {
                 candidate = Some(&node.data);
@@ -322,17 +713,298 @@ where
         }
         candidate
     }
+    // strictly greater than value, which does not have to be in the tree itself
+    pub fn successor<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            if node.data.borrow() > value {
+                candidate = Some(&node.data);
+                ptr = &node.left;
+            } else {
+                ptr = &node.right;
+            }
+        }
+        candidate
+    }
+    // strictly less than value, the mirror of successor
+    pub fn predecessor<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            if node.data.borrow() < value {
+                candidate = Some(&node.data);
+                ptr = &node.right;
+            } else {
+                ptr = &node.left;
+            }
+        }
+        candidate
+    }
+    fn count_before<Q>(&self, data: &Q, inclusive: bool) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut count = 0;
+        while let Some(node) = ptr {
+            if data < node.data.borrow() || (!inclusive && data == node.data.borrow()) {
+                ptr = &node.left;
+            } else {
+                count += size(&node.left) + 1;
+                ptr = &node.right;
+            }
+        }
+        count
+    }
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        let mut ptr = &self.root;
+        let mut k = k;
+        while let Some(node) = ptr {
+            let left = size(&node.left);
+            match k.cmp(&left) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Greater => {
+                    k -= left + 1;
+                    ptr = &node.right;
+                }
+            }
+        }
+        None
+    }
+    pub fn rank<Q>(&self, data: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.count_before(data, false)
+    }
+    pub fn index_of<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.contains(data).then(|| self.rank(data))
+    }
+    // levels on the longest root-to-leaf path, 0 when empty
+    pub fn height(&self) -> usize {
+        height(&self.root) as usize
+    }
+    pub fn leaf_count(&self) -> usize {
+        self.nodes()
+            .filter(|(node, _)| node.left.is_none() && node.right.is_none())
+            .count()
+    }
+    // the sum of every node's depth, counting the root as depth 0
+    pub fn internal_path_length(&self) -> usize {
+        self.nodes().map(|(_, depth)| depth).sum()
+    }
+    // reads the recorded heights, which validate checks against the real ones
+    pub fn is_balanced(&self) -> bool {
+        self.nodes().all(|(node, _)| node.balance_factor().abs() <= 1)
+    }
+    fn nodes(&self) -> impl Iterator<Item = (&Node<T>, usize)> {
+        let mut stack: Vec<(&Node<T>, usize)> =
+            self.root.as_deref().map(|root| (root, 0)).into_iter().collect();
+        core::iter::from_fn(move || {
+            let (node, depth) = stack.pop()?;
+            stack.extend(node.left.as_deref().map(|left| (left, depth + 1)));
+            stack.extend(node.right.as_deref().map(|right| (right, depth + 1)));
+            Some((node, depth))
+        })
+    }
+    pub fn validate(&self) -> Result<(), TreeInvariantError> {
+        let (_, actual) = validate_node(&self.root, None, None, &mut 0)?;
+        if actual != self.size {
+            return Err(TreeInvariantError::Size { recorded: self.size, actual });
+        }
+        Ok(())
+    }
+    fn audit(&self) {
+        #[cfg(feature = "verify")]
+        if cfg!(debug_assertions) && let Err(err) = self.validate() {
+            panic!("AVLTree invariant violated: {}", err);
+        }
+    }
     pub fn clear(&mut self) {
-        self.root = None;
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
         self.size = 0;
     }
+    pub fn drain(&mut self) -> IntoIter<T> {
+        self.size = 0;
+        IntoIter::new(self.root.take())
+    }
+    // drains in sorted order, rebuilding the tree from whatever the predicate rejects
+    pub fn drain_filter<F>(&mut self, mut predicate: F) -> IntoIter<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut drained = Self::new();
+        let mut kept = Self::new();
+        for item in self.drain() {
+            if predicate(&item) {
+                drained.insert(item);
+            } else {
+                kept.insert(item);
+            }
+        }
+        *self = kept;
+        drained.into_iter()
+    }
+}
+
+impl<T> Drop for AVLTree<T>
+where
+    T: Ord,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> AVLTree<T>
+where
+    T: Debug + Ord,
+{
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        let mut stack: Vec<(&Node<T>, String, &str)> = Vec::new();
+        if let Some(root) = &self.root {
+            stack.push((root, String::new(), ""));
+        }
+        while let Some((node, prefix, branch)) = stack.pop() {
+            out.push_str(&format!(
+                "{}{}{:?} (h={}, bf={})\n",
+                prefix,
+                branch,
+                node.data,
+                node.height,
+                node.balance_factor()
+            ));
+            let child_prefix = match branch {
+                "" => prefix,
+                b if b.starts_with('`') => format!("{}    ", prefix),
+                _ => format!("{}|   ", prefix),
+            };
+            match (&node.left, &node.right) {
+                (Some(left), Some(right)) => {
+                    stack.push((right, child_prefix.clone(), "`-- R: "));
+                    stack.push((left, child_prefix, "+-- L: "));
+                }
+                (Some(left), None) => stack.push((left, child_prefix, "`-- L: ")),
+                (None, Some(right)) => stack.push((right, child_prefix, "`-- R: ")),
+                (None, None) => {}
+            }
+        }
+        out
+    }
+    #[cfg(feature = "std")]
+    pub fn display_tree(&self) {
+        std::print!("{}", self.to_ascii());
+    }
+}
+
+pub struct ValueMut<'a, T, Q>
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    pub(crate) value: &'a mut T,
+    pub(crate) key: &'a Q,
+}
+
+impl<T, Q> Deref for ValueMut<'_, T, Q>
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T, Q> DerefMut for ValueMut<'_, T, Q>
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+    }
+}
+
+impl<T, Q> Drop for ValueMut<'_, T, Q>
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    fn drop(&mut self) {
+        assert!(
+            (*self.value).borrow() == self.key,
+            "value mutated through get_mut no longer matches its key"
+        );
+    }
+}
+
+impl<T> PartialEq for AVLTree<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for AVLTree<T> where T: Ord {}
+
+impl<T> PartialOrd for AVLTree<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for AVLTree<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Hash for AVLTree<T>
+where
+    T: Hash + Ord,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size());
+        self.iter().for_each(|item| item.hash(state));
+    }
 }
 
 impl<T> Debug for AVLTree<T>
 where
-    T: Debug + Clone + Ord + PartialOrd + Eq,
+    T: Debug + Ord,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[")?;
         let mut iter = Iter::new(&self.root);
         while let Some(item) = iter.next() {
@@ -347,16 +1019,17 @@ where
 
 impl<T> Clone for AVLTree<T>
 where 
-    T: Clone + Ord + PartialOrd + Eq
+    T: Clone + Ord,
 {
     fn clone(&self) -> Self {
         fn preorder_copy<T>(src: &NodePtr<T>) -> NodePtr<T>
         where
-            T: Clone + Ord + PartialOrd + Eq
+            T: Clone + Ord,
         {
             if let Some(node) = src {
                 let mut cpy = Node::new(node.data.clone());
                 cpy.height = node.height;
+                cpy.size = node.size;
                 cpy.left = preorder_copy(&node.left);
                 cpy.right = preorder_copy(&node.right);
                 cpy.into()
@@ -370,113 +1043,433 @@ where
 
 pub struct Iter<'a, T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
+{
+    front: LinkedList<&'a Node<T>>,
+    back: LinkedList<&'a Node<T>>,
+    remaining: usize,
+}
+
+pub struct Range<'a, T>
+where
+    T: Ord,
+{
+    iter: Iter<'a, T>,
+}
+
+pub struct Preorder<'a, T>
+where
+    T: Ord,
 {
     stack: LinkedList<&'a Node<T>>,
+    remaining: usize,
+}
+
+pub struct Postorder<'a, T>
+where
+    T: Ord,
+{
+    stack: LinkedList<(&'a Node<T>, bool)>,
+    remaining: usize,
+}
+
+pub struct LevelOrder<'a, T>
+where
+    T: Ord,
+{
+    queue: LinkedList<&'a Node<T>>,
+    remaining: usize,
 }
 
 pub struct IntoIter<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     stack: Vec<Box<Node<T>>>,
+    remaining: usize,
 }
 
 impl<'a, T> Iter<'a, T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     fn new(root: &'a NodePtr<T>) -> Self {
+        Self::seek(root, Bound::Unbounded, Bound::Unbounded, size(root))
+    }
+    fn seek<Q>(root: &'a NodePtr<T>, start: Bound<&Q>, end: Bound<&Q>, remaining: usize) -> Self
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let mut iter = Iter {
-            stack: LinkedList::new(),
+            front: LinkedList::new(),
+            back: LinkedList::new(),
+            remaining,
         };
-        iter.push_left_branch(root);
+        let mut ptr = root;
+        while let Some(node) = ptr {
+            let in_range = match start {
+                Bound::Included(start) => node.data.borrow() >= start,
+                Bound::Excluded(start) => node.data.borrow() > start,
+                Bound::Unbounded => true,
+            };
+            if in_range {
+                iter.front.push_head(node);
+                ptr = &node.left;
+            } else {
+                ptr = &node.right;
+            }
+        }
+        let mut ptr = root;
+        while let Some(node) = ptr {
+            let in_range = match end {
+                Bound::Included(end) => node.data.borrow() <= end,
+                Bound::Excluded(end) => node.data.borrow() < end,
+                Bound::Unbounded => true,
+            };
+            if in_range {
+                iter.back.push_head(node);
+                ptr = &node.right;
+            } else {
+                ptr = &node.left;
+            }
+        }
         iter
     }
     fn push_left_branch(&mut self, mut ptr: &'a NodePtr<T>) {
         while let Some(node) = ptr.as_ref() {
-            self.stack.push_head(node);
+            self.front.push_head(node);
             ptr = &node.left;
         }
     }
+    fn push_right_branch(&mut self, mut ptr: &'a NodePtr<T>) {
+        while let Some(node) = ptr.as_ref() {
+            self.back.push_head(node);
+            ptr = &node.right;
+        }
+    }
     fn has_next(&self) -> bool {
-        !self.stack.is_empty()
+        self.remaining > 0
     }
 }
 
 impl<T> IntoIter<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     fn new(root: NodePtr<T>) -> Self {
-        let mut iter = IntoIter { stack: Vec::new() };
+        let mut iter = IntoIter {
+            stack: Vec::new(),
+            remaining: size(&root),
+        };
         iter.push_left(root);
         iter
     }
     fn push_left(&mut self, mut node: Option<Box<Node<T>>>) {
         while let Some(mut n) = node {
-            let right = n.right.take();
+            let left = n.left.take();
             self.stack.push(n);
-            node = right;
+            node = left;
         }
     }
 }
 
 impl<'a, T> Iterator for Iter<'a, T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.stack.pop() {
-            self.push_left_branch(&node.right);
-            Some(&node.data)
-        } else {
-            None
+        if !self.has_next() {
+            return None;
+        }
+        let node = self.front.pop()?;
+        self.push_left_branch(&node.right);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.has_next() {
+            return None;
+        }
+        let node = self.back.pop()?;
+        self.push_right_branch(&node.left);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<'a, T> Iterator for Range<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.remaining, Some(self.iter.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Range<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Range<'a, T> where T: Ord {}
+
+impl<'a, T> DoubleEndedIterator for Range<'a, T>
+where
+    T: Ord,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> Iterator for Preorder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        if let Some(right) = &node.right {
+            self.stack.push_head(right);
+        }
+        if let Some(left) = &node.left {
+            self.stack.push_head(left);
         }
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<'a, T> ExactSizeIterator for Preorder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Preorder<'a, T> where T: Ord {}
+
+impl<'a, T> Iterator for Postorder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                self.remaining -= 1;
+                return Some(&node.data);
+            }
+            self.stack.push_head((node, true));
+            if let Some(right) = &node.right {
+                self.stack.push_head((right, false));
+            }
+            if let Some(left) = &node.left {
+                self.stack.push_head((left, false));
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Postorder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Postorder<'a, T> where T: Ord {}
+
+impl<'a, T> Iterator for LevelOrder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.poll()?;
+        if let Some(left) = &node.left {
+            self.queue.offer(left);
+        }
+        if let Some(right) = &node.right {
+            self.queue.offer(right);
+        }
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for LevelOrder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for LevelOrder<'a, T> where T: Ord {}
+
 impl<T> Iterator for IntoIter<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         let mut node = self.stack.pop()?;
-        let val = node.data;
-        if let Some(left) = node.left.take() {
-            self.stack.push(left);
-        }
-        Some(val)
+        self.push_left(node.right.take());
+        self.remaining -= 1;
+        Some(node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> where T: Ord {}
+
+impl<T> FusedIterator for IntoIter<T> where T: Ord {}
+
 impl<T> AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter::new(&self.root)
     }
+    pub fn iter_preorder(&self) -> Preorder<'_, T> {
+        Preorder {
+            stack: LinkedList::from(self.root.as_deref()),
+            remaining: self.size,
+        }
+    }
+    pub fn iter_postorder(&self) -> Postorder<'_, T> {
+        Postorder {
+            stack: LinkedList::from(self.root.as_deref().map(|root| (root, false))),
+            remaining: self.size,
+        }
+    }
+    pub fn iter_level_order(&self) -> LevelOrder<'_, T> {
+        LevelOrder {
+            queue: LinkedList::from(self.root.as_deref()),
+            remaining: self.size,
+        }
+    }
+    pub fn range<Q, R>(&self, bounds: R) -> Range<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let remaining = self.range_count((bounds.start_bound(), bounds.end_bound()));
+        Range {
+            iter: Iter::seek(&self.root, bounds.start_bound(), bounds.end_bound(), remaining),
+        }
+    }
+    pub fn range_count<Q, R>(&self, bounds: R) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let lower = match bounds.start_bound() {
+            Bound::Included(start) => self.count_before(start, false),
+            Bound::Excluded(start) => self.count_before(start, true),
+            Bound::Unbounded => 0,
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(end) => self.count_before(end, true),
+            Bound::Excluded(end) => self.count_before(end, false),
+            Bound::Unbounded => self.size,
+        };
+        upper.saturating_sub(lower)
+    }
 }
 
 impl<T> IntoIterator for AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq,
+    T: Ord,
 {
     type Item = T;
     type IntoIter = IntoIter<T>;
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(self.root)
+    fn into_iter(mut self) -> Self::IntoIter {
+        IntoIter::new(self.root.take())
     }
 }
 
-impl<T: Clone> FromIterator<T> for AVLTree<T>
+impl<T> FromIterator<T> for AVLTree<T>
 where
-    T: Clone + Ord + PartialOrd + Eq
+    T: Ord,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        Self::from(iter)
+        <Self as From<Vec<T>>>::from(iter.into_iter().collect())
     }
-}
\ No newline at end of file
+}
+
+impl<T> Extend<T> for AVLTree<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        AVLTree::extend(self, iter);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for AVLTree<T>
+where
+    T: Copy + Ord + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        AVLTree::extend(self, iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for AVLTree<T>
+where
+    T: Ord,
+{
+    // checking costs one pass, and sorted input then skips every comparison of the inserts
+    fn from(vec: Vec<T>) -> Self {
+        if vec.is_sorted() {
+            return Self::from_sorted_vec(vec);
+        }
+        let mut tree = Self::new();
+        tree.extend(vec);
+        tree
+    }
+}
+
+impl<T> From<AVLTree<T>> for Vec<T>
+where
+    T: Ord,
+{
+    fn from(tree: AVLTree<T>) -> Self {
+        tree.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for AVLTree<T>
+where
+    T: Clone + Ord,
+{
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<doubly::LinkedList<T>> for AVLTree<T>
+where
+    T: Clone + Ord,
+{
+    fn from(list: doubly::LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
@@ -0,0 +1,433 @@
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    ops::Add,
+    str::FromStr,
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Node<T> {
+    data: T,
+    left: BinaryTree<T>,
+    right: BinaryTree<T>,
+}
+
+// a binary tree with no ordering between parent and children: the shape is whatever it was
+// built as, child by child. every subtree is a BinaryTree itself, so anything that works on a
+// tree works on any part of one
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BinaryTree<T> {
+    root: Option<Box<Node<T>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconstructError {
+    LengthMismatch { preorder: usize, inorder: usize },
+    // the split of preorder at each root needs every value to be distinct
+    Duplicate,
+    // the two orders cannot come from one tree
+    Inconsistent,
+}
+
+impl Display for ReconstructError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LengthMismatch { preorder, inorder } => write!(
+                f,
+                "preorder has {} values but inorder has {}",
+                preorder, inorder
+            ),
+            Self::Duplicate => write!(f, "values must be distinct to rebuild the tree"),
+            Self::Inconsistent => write!(f, "the traversals describe no single tree"),
+        }
+    }
+}
+
+impl Error for ReconstructError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTreeError {
+    // tokens count from 0, missing children included
+    BadValue { at: usize },
+    // more tokens than there are children left to fill
+    Trailing { at: usize },
+}
+
+impl Display for ParseTreeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BadValue { at } => write!(f, "token {} is not a value or #", at),
+            Self::Trailing { at } => write!(f, "token {} has no parent to hang from", at),
+        }
+    }
+}
+
+impl Error for ParseTreeError {}
+
+impl<T> Default for BinaryTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BinaryTree<T> {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+    pub fn leaf(data: T) -> Self {
+        Self::node(data, Self::new(), Self::new())
+    }
+    pub fn node(data: T, left: BinaryTree<T>, right: BinaryTree<T>) -> Self {
+        Self {
+            root: Some(Box::new(Node { data, left, right })),
+        }
+    }
+}
+
+impl<T> BinaryTree<T> {
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.fold(|_, left, right| 1 + left.unwrap_or(0) + right.unwrap_or(0))
+            .unwrap_or(0)
+    }
+    // nodes on the longest path down from the root, 0 for an empty tree
+    pub fn height(&self) -> usize {
+        self.fold(|_, left, right| 1 + left.unwrap_or(0).max(right.unwrap_or(0)))
+            .unwrap_or(0)
+    }
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.data)
+    }
+    pub fn root_mut(&mut self) -> Option<&mut T> {
+        self.root.as_mut().map(|node| &mut node.data)
+    }
+    // the subtrees below the root, which may themselves be empty; None for an empty tree
+    pub fn left(&self) -> Option<&BinaryTree<T>> {
+        self.root.as_ref().map(|node| &node.left)
+    }
+    pub fn right(&self) -> Option<&BinaryTree<T>> {
+        self.root.as_ref().map(|node| &node.right)
+    }
+    pub fn left_mut(&mut self) -> Option<&mut BinaryTree<T>> {
+        self.root.as_mut().map(|node| &mut node.left)
+    }
+    pub fn right_mut(&mut self) -> Option<&mut BinaryTree<T>> {
+        self.root.as_mut().map(|node| &mut node.right)
+    }
+    // hangs subtree below the root and hands back whatever hung there before
+    pub fn set_left(&mut self, subtree: BinaryTree<T>) -> BinaryTree<T> {
+        let node = self
+            .root
+            .as_mut()
+            .expect("an empty tree has no children to set");
+        core::mem::replace(&mut node.left, subtree)
+    }
+    pub fn set_right(&mut self, subtree: BinaryTree<T>) -> BinaryTree<T> {
+        let node = self
+            .root
+            .as_mut()
+            .expect("an empty tree has no children to set");
+        core::mem::replace(&mut node.right, subtree)
+    }
+    pub fn preorder(&self) -> Vec<&T> {
+        let mut out = Vec::new();
+        let mut stack = vec![self];
+        while let Some(tree) = stack.pop() {
+            if let Some(node) = &tree.root {
+                out.push(&node.data);
+                stack.push(&node.right);
+                stack.push(&node.left);
+            }
+        }
+        out
+    }
+    pub fn inorder(&self) -> Vec<&T> {
+        let mut out = Vec::new();
+        let mut stack = Vec::new();
+        let mut at = self;
+        loop {
+            while let Some(node) = &at.root {
+                stack.push(node);
+                at = &node.left;
+            }
+            let Some(node) = stack.pop() else {
+                return out;
+            };
+            out.push(&node.data);
+            at = &node.right;
+        }
+    }
+    pub fn postorder(&self) -> Vec<&T> {
+        // root, right, left reversed is left, right, root
+        let mut out = Vec::new();
+        let mut stack = vec![self];
+        while let Some(tree) = stack.pop() {
+            if let Some(node) = &tree.root {
+                out.push(&node.data);
+                stack.push(&node.left);
+                stack.push(&node.right);
+            }
+        }
+        out.reverse();
+        out
+    }
+    pub fn level_order(&self) -> Vec<&T> {
+        let mut out = Vec::new();
+        let mut queue = VecDeque::from([self]);
+        while let Some(tree) = queue.pop_front() {
+            if let Some(node) = &tree.root {
+                out.push(&node.data);
+                queue.push_back(&node.left);
+                queue.push_back(&node.right);
+            }
+        }
+        out
+    }
+    // bottom up: f gets each node's value with what it made of the left and right subtrees, None
+    // standing for an empty one; None overall for an empty tree
+    pub fn fold<A, F>(&self, mut f: F) -> Option<A>
+    where
+        F: FnMut(&T, Option<A>, Option<A>) -> A,
+    {
+        enum Step<'a, T> {
+            Visit(&'a BinaryTree<T>),
+            Combine(&'a Node<T>),
+        }
+        let mut results = Vec::new();
+        let mut stack = vec![Step::Visit(self)];
+        while let Some(step) = stack.pop() {
+            match step {
+                Step::Visit(tree) => match &tree.root {
+                    Some(node) => {
+                        stack.push(Step::Combine(node));
+                        stack.push(Step::Visit(&node.right));
+                        stack.push(Step::Visit(&node.left));
+                    }
+                    None => results.push(None),
+                },
+                Step::Combine(node) => {
+                    let right = results.pop().unwrap();
+                    let left = results.pop().unwrap();
+                    results.push(Some(f(&node.data, left, right)));
+                }
+            }
+        }
+        results.pop().unwrap()
+    }
+    // mirrors the tree, swapping the children of every node
+    pub fn invert(&mut self) {
+        let mut stack = vec![self];
+        while let Some(tree) = stack.pop() {
+            if let Some(node) = tree.root.as_deref_mut() {
+                core::mem::swap(&mut node.left, &mut node.right);
+                stack.push(&mut node.left);
+                stack.push(&mut node.right);
+            }
+        }
+    }
+    pub fn clear(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.root.take());
+            stack.extend(node.right.root.take());
+        }
+    }
+}
+
+impl<T> Drop for BinaryTree<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> BinaryTree<T>
+where
+    T: PartialEq,
+{
+    // whether the tree is its own mirror image, shape and values both
+    pub fn is_symmetric(&self) -> bool {
+        let Some(node) = &self.root else {
+            return true;
+        };
+        let mut pairs = vec![(&node.left, &node.right)];
+        while let Some((a, b)) = pairs.pop() {
+            match (&a.root, &b.root) {
+                (None, None) => {}
+                (Some(a), Some(b)) if a.data == b.data => {
+                    pairs.push((&a.left, &b.right));
+                    pairs.push((&a.right, &b.left));
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+impl<T> BinaryTree<T>
+where
+    T: Copy + Ord + Add<Output = T> + Default,
+{
+    // the largest sum along any path of at least one node, where a path runs up from one node
+    // and back down to another without reusing an edge. each subtree reports the best sum
+    // reaching down from its root, dropping branches that would only subtract; T::default() is
+    // taken as zero
+    pub fn max_path_sum(&self) -> Option<T> {
+        let zero = T::default();
+        let mut best = None;
+        self.fold(|&data, left: Option<T>, right: Option<T>| {
+            let left = left.unwrap_or(zero).max(zero);
+            let right = right.unwrap_or(zero).max(zero);
+            let through = data + left + right;
+            best = Some(best.map_or(through, |best: T| best.max(through)));
+            data + left.max(right)
+        });
+        best
+    }
+}
+
+impl<T> BinaryTree<T>
+where
+    T: Ord + Clone,
+{
+    // the one tree with these traversals; each root is the next value in preorder, and where it
+    // sits in inorder splits the rest into the left and right subtrees
+    pub fn from_preorder_inorder(preorder: &[T], inorder: &[T]) -> Result<Self, ReconstructError> {
+        if preorder.len() != inorder.len() {
+            return Err(ReconstructError::LengthMismatch {
+                preorder: preorder.len(),
+                inorder: inorder.len(),
+            });
+        }
+        let mut positions = BTreeMap::new();
+        for (at, value) in inorder.iter().enumerate() {
+            if positions.insert(value, at).is_some() {
+                return Err(ReconstructError::Duplicate);
+            }
+        }
+        let mut next = 0;
+        build(preorder, &positions, &mut next, 0, inorder.len())
+    }
+}
+
+// the subtree over inorder[lo..hi], rooted at preorder[*next]
+fn build<T>(
+    preorder: &[T],
+    positions: &BTreeMap<&T, usize>,
+    next: &mut usize,
+    lo: usize,
+    hi: usize,
+) -> Result<BinaryTree<T>, ReconstructError>
+where
+    T: Ord + Clone,
+{
+    if lo == hi {
+        return Ok(BinaryTree::new());
+    }
+    let data = &preorder[*next];
+    let at = match positions.get(data) {
+        Some(&at) if (lo..hi).contains(&at) => at,
+        _ => return Err(ReconstructError::Inconsistent),
+    };
+    *next += 1;
+    let left = build(preorder, positions, next, lo, at)?;
+    let right = build(preorder, positions, next, at + 1, hi)?;
+    Ok(BinaryTree::node(data.clone(), left, right))
+}
+
+impl<T> BinaryTree<T>
+where
+    T: Display,
+{
+    // level order with # for each missing child of a present node and the trailing #s dropped,
+    // so 1,2,3,#,4 is a root 1 whose left child 2 has a right child 4
+    pub fn level_order_string(&self) -> String {
+        let mut tokens = Vec::new();
+        let mut queue = VecDeque::from([self]);
+        while let Some(tree) = queue.pop_front() {
+            match &tree.root {
+                Some(node) => {
+                    tokens.push(node.data.to_string());
+                    queue.push_back(&node.left);
+                    queue.push_back(&node.right);
+                }
+                None => tokens.push(String::from("#")),
+            }
+        }
+        while tokens.last().is_some_and(|token| token == "#") {
+            tokens.pop();
+        }
+        tokens.join(",")
+    }
+}
+
+impl<T> BinaryTree<T>
+where
+    T: FromStr,
+{
+    // reads what level_order_string writes; whitespace around tokens is ignored, and an empty
+    // string or a lone # is the empty tree
+    pub fn parse_level_order(text: &str) -> Result<Self, ParseTreeError> {
+        let mut tree = Self::new();
+        if text.trim().is_empty() {
+            return Ok(tree);
+        }
+        let mut slots = VecDeque::from([&mut tree]);
+        for (at, token) in text.split(',').map(str::trim).enumerate() {
+            let slot = slots.pop_front().ok_or(ParseTreeError::Trailing { at })?;
+            if token == "#" {
+                continue;
+            }
+            let data = token.parse().map_err(|_| ParseTreeError::BadValue { at })?;
+            *slot = Self::leaf(data);
+            let node = slot.root.as_deref_mut().unwrap();
+            slots.push_back(&mut node.left);
+            slots.push_back(&mut node.right);
+        }
+        Ok(tree)
+    }
+}
+
+impl<T> Debug for BinaryTree<T>
+where
+    T: Debug,
+{
+    // the level order form, with values in their Debug form
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut tokens = Vec::new();
+        let mut queue = VecDeque::from([self]);
+        while let Some(tree) = queue.pop_front() {
+            match &tree.root {
+                Some(node) => {
+                    tokens.push(Some(&node.data));
+                    queue.push_back(&node.left);
+                    queue.push_back(&node.right);
+                }
+                None => tokens.push(None),
+            }
+        }
+        while tokens.last().is_some_and(Option::is_none) {
+            tokens.pop();
+        }
+        write!(f, "BinaryTree [")?;
+        for (i, token) in tokens.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match token {
+                Some(data) => write!(f, "{:?}", data)?,
+                None => write!(f, "#")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
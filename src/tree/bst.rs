@@ -0,0 +1,1138 @@
+/*
+
+    BINARY SEARCH TREE
+
+    the AVLTree interface without any rebalancing,
+    kept as a baseline: sorted input degrades it
+    into a list, which height() makes visible
+
+*/
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    instrument::{self, counted},
+    tree::avl::{TreeInvariantError, ValueMut},
+};
+
+type NodePtr<T> = Option<Box<Node<T>>>;
+
+struct Node<T>
+where
+    T: Ord,
+{
+    data: T,
+    left: NodePtr<T>,
+    right: NodePtr<T>,
+    size: usize,
+}
+
+impl<T> From<Node<T>> for NodePtr<T>
+where
+    T: Ord,
+{
+    fn from(node: Node<T>) -> Self {
+        instrument::allocation();
+        Some(Box::new(node))
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Ord,
+{
+    fn new(data: T) -> Self {
+        Self {
+            data,
+            left: None,
+            right: None,
+            size: 1,
+        }
+    }
+}
+
+fn size<T>(ptr: &NodePtr<T>) -> usize
+where
+    T: Ord,
+{
+    if let Some(node) = ptr { node.size } else { 0 }
+}
+
+// a degenerate tree is as deep as it is large, so nothing here may recurse on the shape. the
+// walks go down through a cursor and fix subtree sizes on the way, betting that the change will
+// happen; when it does not, resize_path walks the same path again to undo them
+fn resize_path<T, Q>(root: &mut NodePtr<T>, key: &Q, grow: bool)
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut ptr = root;
+    while let Some(node) = ptr {
+        let dir = key.cmp(node.data.borrow());
+        if dir == Ordering::Equal {
+            return;
+        }
+        if grow {
+            node.size += 1;
+        } else {
+            node.size -= 1;
+        }
+        ptr = if dir == Ordering::Less {
+            &mut node.left
+        } else {
+            &mut node.right
+        };
+    }
+}
+
+fn insert_node<T>(root: &mut NodePtr<T>, data: T) -> bool
+where
+    T: Ord,
+{
+    let mut ptr = &mut *root;
+    while let Some(node) = ptr {
+        match counted(data.cmp(&node.data)) {
+            Ordering::Less => {
+                node.size += 1;
+                ptr = &mut node.left;
+            }
+            Ordering::Greater => {
+                node.size += 1;
+                ptr = &mut node.right;
+            }
+            Ordering::Equal => {
+                resize_path(root, &data, false);
+                return false;
+            }
+        }
+    }
+    *ptr = Node::new(data).into();
+    true
+}
+
+fn remove_min<T>(mut ptr: &mut NodePtr<T>) -> Option<T>
+where
+    T: Ord,
+{
+    while ptr.as_ref()?.left.is_some() {
+        let node = ptr.as_mut()?;
+        node.size -= 1;
+        ptr = &mut node.left;
+    }
+    let mut min = ptr.take()?;
+    *ptr = min.right.take();
+    Some(min.data)
+}
+
+fn delete_node<T, Q>(root: &mut NodePtr<T>, key: &Q) -> Option<T>
+where
+    T: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut ptr = &mut *root;
+    while let Some(dir) = ptr
+        .as_deref()
+        .map(|node| counted(key.cmp(node.data.borrow())))
+    {
+        if dir == Ordering::Equal {
+            break;
+        }
+        let Some(node) = ptr else {
+            break;
+        };
+        node.size -= 1;
+        ptr = if dir == Ordering::Less {
+            &mut node.left
+        } else {
+            &mut node.right
+        };
+    }
+    let Some(mut node) = ptr.take() else {
+        resize_path(root, key, true);
+        return None;
+    };
+    // with two children the successor moves up into this node instead
+    if node.left.is_some()
+        && let Some(successor) = remove_min(&mut node.right)
+    {
+        node.size -= 1;
+        let data = core::mem::replace(&mut node.data, successor);
+        *ptr = Some(node);
+        return Some(data);
+    }
+    *ptr = node.left.take().or_else(|| node.right.take());
+    Some(node.data)
+}
+
+pub struct BST<T>
+where
+    T: Ord,
+{
+    root: NodePtr<T>,
+    size: usize,
+}
+
+impl<T> Default for BST<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BST<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            size: 0,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T> BST<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn insert(&mut self, data: T) -> bool {
+        let inserted = insert_node(&mut self.root, data);
+        if inserted {
+            self.size += 1;
+        }
+        self.audit();
+        inserted
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| {
+            self.insert(item);
+        });
+    }
+    pub fn contains<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(data).is_some()
+    }
+    pub fn remove<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.take(data).is_some()
+    }
+    pub fn take<Q>(&mut self, data: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let deleted = delete_node(&mut self.root, data);
+        if deleted.is_some() {
+            self.size -= 1;
+        }
+        self.audit();
+        deleted
+    }
+    pub fn get<Q>(&self, key: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        while let Some(node) = ptr {
+            match counted(key.cmp(node.data.borrow())) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Greater => ptr = &node.right,
+                Ordering::Equal => return Some(&node.data),
+            }
+        }
+        None
+    }
+    pub fn get_mut<'a, Q>(&'a mut self, key: &'a Q) -> Option<ValueMut<'a, T, Q>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &mut self.root;
+        while let Some(node) = ptr {
+            match counted(key.cmp(node.data.borrow())) {
+                Ordering::Less => ptr = &mut node.left,
+                Ordering::Greater => ptr = &mut node.right,
+                Ordering::Equal => {
+                    return Some(ValueMut {
+                        value: &mut node.data,
+                        key,
+                    });
+                }
+            }
+        }
+        None
+    }
+    pub fn first(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.data)
+    }
+    pub fn last(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.data)
+    }
+    pub fn get_floor<Q>(&self, floor: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            match node.data.borrow().cmp(floor) {
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Greater => ptr = &node.left,
+                Ordering::Less => {
+                    candidate = Some(&node.data);
+                    ptr = &node.right;
+                }
+            }
+        }
+        candidate
+    }
+    pub fn get_ceil<Q>(&self, ceil: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            match node.data.borrow().cmp(ceil) {
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Less => ptr = &node.right,
+                Ordering::Greater => {
+                    candidate = Some(&node.data);
+                    ptr = &node.left;
+                }
+            }
+        }
+        candidate
+    }
+    // strictly greater than value, which does not have to be in the tree itself
+    pub fn successor<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            if node.data.borrow() > value {
+                candidate = Some(&node.data);
+                ptr = &node.left;
+            } else {
+                ptr = &node.right;
+            }
+        }
+        candidate
+    }
+    // strictly less than value, the mirror of successor
+    pub fn predecessor<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut candidate = None;
+        while let Some(node) = ptr {
+            if node.data.borrow() < value {
+                candidate = Some(&node.data);
+                ptr = &node.right;
+            } else {
+                ptr = &node.left;
+            }
+        }
+        candidate
+    }
+    fn count_before<Q>(&self, data: &Q, inclusive: bool) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut count = 0;
+        while let Some(node) = ptr {
+            if data < node.data.borrow() || (!inclusive && data == node.data.borrow()) {
+                ptr = &node.left;
+            } else {
+                count += size(&node.left) + 1;
+                ptr = &node.right;
+            }
+        }
+        count
+    }
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        let mut ptr = &self.root;
+        let mut k = k;
+        while let Some(node) = ptr {
+            let left = size(&node.left);
+            match k.cmp(&left) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Greater => {
+                    k -= left + 1;
+                    ptr = &node.right;
+                }
+            }
+        }
+        None
+    }
+    pub fn rank<Q>(&self, data: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.count_before(data, false)
+    }
+    pub fn index_of<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.contains(data).then(|| self.rank(data))
+    }
+    // levels on the longest root-to-leaf path, so 0 when empty and size() once degenerate
+    pub fn height(&self) -> usize {
+        self.nodes().map(|(_, depth)| depth + 1).max().unwrap_or(0)
+    }
+    pub fn leaf_count(&self) -> usize {
+        self.nodes()
+            .filter(|(node, _)| node.left.is_none() && node.right.is_none())
+            .count()
+    }
+    // the sum of every node's depth, counting the root as depth 0; n^2 / 2 for a list against
+    // n log n for a balanced shape
+    pub fn internal_path_length(&self) -> usize {
+        self.nodes().map(|(_, depth)| depth).sum()
+    }
+    // whether the shape happens to meet the AVL condition; nodes keep no heights here, so they
+    // are worked out bottom up with the children's finished before their parent
+    pub fn is_balanced(&self) -> bool {
+        let mut heights: Vec<usize> = Vec::new();
+        let mut stack: Vec<(&Node<T>, bool)> = self
+            .root
+            .as_deref()
+            .map(|root| (root, false))
+            .into_iter()
+            .collect();
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                let right = node.right.as_ref().and_then(|_| heights.pop()).unwrap_or(0);
+                let left = node.left.as_ref().and_then(|_| heights.pop()).unwrap_or(0);
+                if left.abs_diff(right) > 1 {
+                    return false;
+                }
+                heights.push(1 + left.max(right));
+                continue;
+            }
+            stack.push((node, true));
+            stack.extend(node.right.as_deref().map(|right| (right, false)));
+            stack.extend(node.left.as_deref().map(|left| (left, false)));
+        }
+        true
+    }
+    fn nodes(&self) -> impl Iterator<Item = (&Node<T>, usize)> {
+        let mut stack: Vec<(&Node<T>, usize)> = self
+            .root
+            .as_deref()
+            .map(|root| (root, 0))
+            .into_iter()
+            .collect();
+        core::iter::from_fn(move || {
+            let (node, depth) = stack.pop()?;
+            stack.extend(node.left.as_deref().map(|left| (left, depth + 1)));
+            stack.extend(node.right.as_deref().map(|right| (right, depth + 1)));
+            Some((node, depth))
+        })
+    }
+    // edges between the root and the node holding data
+    pub fn depth<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut ptr = &self.root;
+        let mut depth = 0;
+        while let Some(node) = ptr {
+            match data.cmp(node.data.borrow()) {
+                Ordering::Less => ptr = &node.left,
+                Ordering::Greater => ptr = &node.right,
+                Ordering::Equal => return Some(depth),
+            }
+            depth += 1;
+        }
+        None
+    }
+    // no heights to check, and sorted in-order output is exactly the ordering invariant;
+    // each size only needs to agree with its children's, which makes all of them correct
+    pub fn validate(&self) -> Result<(), TreeInvariantError> {
+        let mut stack = Vec::new();
+        let mut ptr = &self.root;
+        let mut previous: Option<&T> = None;
+        let mut index = 0;
+        loop {
+            while let Some(node) = ptr {
+                stack.push(node);
+                ptr = &node.left;
+            }
+            let Some(node) = stack.pop() else {
+                break;
+            };
+            if previous.is_some_and(|previous| previous >= &node.data) {
+                return Err(TreeInvariantError::Unordered { node: index });
+            }
+            let actual = 1 + size(&node.left) + size(&node.right);
+            if node.size != actual {
+                return Err(TreeInvariantError::SubtreeSize {
+                    node: index,
+                    recorded: node.size,
+                    actual,
+                });
+            }
+            previous = Some(&node.data);
+            index += 1;
+            ptr = &node.right;
+        }
+        if index != self.size {
+            return Err(TreeInvariantError::Size {
+                recorded: self.size,
+                actual: index,
+            });
+        }
+        Ok(())
+    }
+    fn audit(&self) {
+        #[cfg(feature = "verify")]
+        if cfg!(debug_assertions)
+            && let Err(err) = self.validate()
+        {
+            panic!("BST invariant violated: {}", err);
+        }
+    }
+    pub fn clear(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+        self.size = 0;
+    }
+    pub fn drain(&mut self) -> IntoIter<T> {
+        self.size = 0;
+        IntoIter::new(self.root.take())
+    }
+    pub fn drain_filter<F>(&mut self, mut predicate: F) -> IntoIter<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // refilling in sorted order would leave a list behind, so both halves go back in
+        // preorder, which keeps the relative shape of what was there
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        let (mut drained, mut kept) = (Self::new(), Self::new());
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.right.take());
+            stack.extend(node.left.take());
+            if predicate(&node.data) {
+                drained.insert(node.data);
+            } else {
+                kept.insert(node.data);
+            }
+        }
+        *self = kept;
+        drained.into_iter()
+    }
+}
+
+impl<T> Drop for BST<T>
+where
+    T: Ord,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> BST<T>
+where
+    T: Debug + Ord,
+{
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+        let mut stack: Vec<(&Node<T>, String, &str)> = Vec::new();
+        if let Some(root) = &self.root {
+            stack.push((root, String::new(), ""));
+        }
+        while let Some((node, prefix, branch)) = stack.pop() {
+            out.push_str(&format!("{}{}{:?}\n", prefix, branch, node.data));
+            let child_prefix = match branch {
+                "" => prefix,
+                b if b.starts_with('`') => format!("{}    ", prefix),
+                _ => format!("{}|   ", prefix),
+            };
+            match (&node.left, &node.right) {
+                (Some(left), Some(right)) => {
+                    stack.push((right, child_prefix.clone(), "`-- R: "));
+                    stack.push((left, child_prefix, "+-- L: "));
+                }
+                (Some(left), None) => stack.push((left, child_prefix, "`-- L: ")),
+                (None, Some(right)) => stack.push((right, child_prefix, "`-- R: ")),
+                (None, None) => {}
+            }
+        }
+        out
+    }
+    #[cfg(feature = "std")]
+    pub fn display_tree(&self) {
+        std::print!("{}", self.to_ascii());
+    }
+}
+
+impl<T> PartialEq for BST<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for BST<T> where T: Ord {}
+
+impl<T> PartialOrd for BST<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for BST<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Hash for BST<T>
+where
+    T: Hash + Ord,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T> Debug for BST<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Clone for BST<T>
+where
+    T: Clone + Ord,
+{
+    // copies in postorder so both children are finished before their parent, keeping the
+    // shape without recursing down it
+    fn clone(&self) -> Self {
+        let mut built: Vec<Box<Node<T>>> = Vec::new();
+        let mut stack: Vec<(&Node<T>, bool)> = self
+            .root
+            .as_deref()
+            .map(|root| (root, false))
+            .into_iter()
+            .collect();
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                let mut copy = Node::new(node.data.clone());
+                copy.size = node.size;
+                copy.right = node.right.as_ref().and_then(|_| built.pop());
+                copy.left = node.left.as_ref().and_then(|_| built.pop());
+                built.extend(NodePtr::from(copy));
+                continue;
+            }
+            stack.push((node, true));
+            stack.extend(node.right.as_deref().map(|right| (right, false)));
+            stack.extend(node.left.as_deref().map(|left| (left, false)));
+        }
+        Self {
+            root: built.pop(),
+            size: self.size,
+        }
+    }
+}
+
+pub struct Iter<'a, T>
+where
+    T: Ord,
+{
+    front: Vec<&'a Node<T>>,
+    back: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+pub struct Range<'a, T>
+where
+    T: Ord,
+{
+    iter: Iter<'a, T>,
+}
+
+pub struct Preorder<'a, T>
+where
+    T: Ord,
+{
+    stack: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+pub struct Postorder<'a, T>
+where
+    T: Ord,
+{
+    stack: Vec<(&'a Node<T>, bool)>,
+    remaining: usize,
+}
+
+pub struct LevelOrder<'a, T>
+where
+    T: Ord,
+{
+    queue: alloc::collections::VecDeque<&'a Node<T>>,
+    remaining: usize,
+}
+
+pub struct IntoIter<T>
+where
+    T: Ord,
+{
+    stack: Vec<Box<Node<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: Ord,
+{
+    fn seek<Q>(root: &'a NodePtr<T>, start: Bound<&Q>, end: Bound<&Q>, remaining: usize) -> Self
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut iter = Iter {
+            front: Vec::new(),
+            back: Vec::new(),
+            remaining,
+        };
+        let mut ptr = root;
+        while let Some(node) = ptr {
+            let in_range = match start {
+                Bound::Included(start) => node.data.borrow() >= start,
+                Bound::Excluded(start) => node.data.borrow() > start,
+                Bound::Unbounded => true,
+            };
+            if in_range {
+                iter.front.push(node);
+                ptr = &node.left;
+            } else {
+                ptr = &node.right;
+            }
+        }
+        let mut ptr = root;
+        while let Some(node) = ptr {
+            let in_range = match end {
+                Bound::Included(end) => node.data.borrow() <= end,
+                Bound::Excluded(end) => node.data.borrow() < end,
+                Bound::Unbounded => true,
+            };
+            if in_range {
+                iter.back.push(node);
+                ptr = &node.right;
+            } else {
+                ptr = &node.left;
+            }
+        }
+        iter
+    }
+}
+
+impl<T> IntoIter<T>
+where
+    T: Ord,
+{
+    fn new(root: NodePtr<T>) -> Self {
+        let mut iter = IntoIter {
+            stack: Vec::new(),
+            remaining: size(&root),
+        };
+        iter.push_left(root);
+        iter
+    }
+    fn push_left(&mut self, mut node: NodePtr<T>) {
+        while let Some(mut n) = node {
+            let left = n.left.take();
+            self.stack.push(n);
+            node = left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.front.pop()?;
+        let mut ptr = &node.right;
+        while let Some(next) = ptr {
+            self.front.push(next);
+            ptr = &next.left;
+        }
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.back.pop()?;
+        let mut ptr = &node.left;
+        while let Some(next) = ptr {
+            self.back.push(next);
+            ptr = &next.right;
+        }
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<'a, T> Iterator for Range<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Range<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Range<'a, T> where T: Ord {}
+
+impl<'a, T> DoubleEndedIterator for Range<'a, T>
+where
+    T: Ord,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> Iterator for Preorder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.right.as_deref());
+        self.stack.extend(node.left.as_deref());
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Preorder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Preorder<'a, T> where T: Ord {}
+
+impl<'a, T> Iterator for Postorder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                self.remaining -= 1;
+                return Some(&node.data);
+            }
+            self.stack.push((node, true));
+            self.stack
+                .extend(node.right.as_deref().map(|right| (right, false)));
+            self.stack
+                .extend(node.left.as_deref().map(|left| (left, false)));
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Postorder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Postorder<'a, T> where T: Ord {}
+
+impl<'a, T> Iterator for LevelOrder<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.left.as_deref());
+        self.queue.extend(node.right.as_deref());
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for LevelOrder<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for LevelOrder<'a, T> where T: Ord {}
+
+impl<T> Iterator for IntoIter<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.stack.pop()?;
+        self.push_left(node.right.take());
+        self.remaining -= 1;
+        Some(node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> where T: Ord {}
+
+impl<T> FusedIterator for IntoIter<T> where T: Ord {}
+
+impl<T> Drop for IntoIter<T>
+where
+    T: Ord,
+{
+    // the boxes left on the stack still own whole right spines
+    fn drop(&mut self) {
+        while let Some(mut node) = self.stack.pop() {
+            self.stack.extend(node.left.take());
+            self.stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<T> BST<T>
+where
+    T: Ord,
+{
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::seek(
+            &self.root,
+            Bound::<&T>::Unbounded,
+            Bound::Unbounded,
+            self.size,
+        )
+    }
+    pub fn iter_preorder(&self) -> Preorder<'_, T> {
+        Preorder {
+            stack: self.root.as_deref().into_iter().collect(),
+            remaining: self.size,
+        }
+    }
+    pub fn iter_postorder(&self) -> Postorder<'_, T> {
+        Postorder {
+            stack: self
+                .root
+                .as_deref()
+                .map(|root| (root, false))
+                .into_iter()
+                .collect(),
+            remaining: self.size,
+        }
+    }
+    pub fn iter_level_order(&self) -> LevelOrder<'_, T> {
+        LevelOrder {
+            queue: self.root.as_deref().into_iter().collect(),
+            remaining: self.size,
+        }
+    }
+    pub fn range<Q, R>(&self, bounds: R) -> Range<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let remaining = self.range_count((bounds.start_bound(), bounds.end_bound()));
+        Range {
+            iter: Iter::seek(
+                &self.root,
+                bounds.start_bound(),
+                bounds.end_bound(),
+                remaining,
+            ),
+        }
+    }
+    pub fn range_count<Q, R>(&self, bounds: R) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let lower = match bounds.start_bound() {
+            Bound::Included(start) => self.count_before(start, false),
+            Bound::Excluded(start) => self.count_before(start, true),
+            Bound::Unbounded => 0,
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(end) => self.count_before(end, true),
+            Bound::Excluded(end) => self.count_before(end, false),
+            Bound::Unbounded => self.size,
+        };
+        upper.saturating_sub(lower)
+    }
+}
+
+impl<T> IntoIterator for BST<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.drain()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BST<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for BST<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T> Extend<T> for BST<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        BST::extend(self, iter);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for BST<T>
+where
+    T: Copy + Ord + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        BST::extend(self, iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for BST<T>
+where
+    T: Ord,
+{
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<BST<T>> for Vec<T>
+where
+    T: Ord,
+{
+    fn from(tree: BST<T>) -> Self {
+        tree.into_iter().collect()
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::{fmt::Debug, iter::FusedIterator};
+
+use crate::collections::slot_map::{Key, SlotMap};
+
+// a node id is its slot map key, so an id kept after its subtree was removed reads as gone
+// rather than reaching whichever node takes the slot next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(Key);
+
+#[derive(Clone)]
+struct Node<T> {
+    data: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+// any number of children per node, kept in the order they were added; nodes live in one slot
+// map and link by id, so there is no ordering between siblings beyond insertion and no
+// balancing. navigation from an id that is not in the tree panics, while get and contains
+// just answer None and false
+#[derive(Clone)]
+pub struct Tree<T> {
+    nodes: SlotMap<Node<T>>,
+    root: Option<NodeId>,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tree<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: SlotMap::new(),
+            root: None,
+        }
+    }
+    pub fn with_root(data: T) -> Self {
+        let mut tree = Self::new();
+        tree.add_root(data);
+        tree
+    }
+}
+
+impl<T> Tree<T> {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn root(&self) -> Option<NodeId> {
+        self.root
+    }
+    pub fn add_root(&mut self, data: T) -> NodeId {
+        assert!(self.root.is_none(), "the tree already has a root");
+        let id = self.insert(data, None);
+        self.root = Some(id);
+        id
+    }
+    // the new child goes after any the parent already has
+    pub fn add_child(&mut self, parent: NodeId, data: T) -> NodeId {
+        self.node(parent);
+        let id = self.insert(data, Some(parent));
+        self.nodes[parent.0].children.push(id);
+        id
+    }
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.nodes.contains_key(id.0)
+    }
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.nodes.get(id.0).map(|node| &node.data)
+    }
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        self.nodes.get_mut(id.0).map(|node| &mut node.data)
+    }
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+    pub fn children(
+        &self,
+        id: NodeId,
+    ) -> impl DoubleEndedIterator<Item = NodeId> + ExactSizeIterator + '_ {
+        self.node(id).children.iter().copied()
+    }
+    // edges up to the root, which sits at depth 0
+    pub fn depth(&self, id: NodeId) -> usize {
+        let mut depth = 0;
+        let mut at = self.node(id);
+        while let Some(parent) = at.parent {
+            depth += 1;
+            at = &self.nodes[parent.0];
+        }
+        depth
+    }
+    // detaches the subtree below id, id included, and hands it back as a tree of its own; the
+    // nodes get new ids there, and every old id in it stops being in this tree
+    pub fn remove_subtree(&mut self, id: NodeId) -> Option<Tree<T>> {
+        let parent = self.nodes.get(id.0)?.parent;
+        match parent {
+            Some(parent) => self.nodes[parent.0].children.retain(|&child| child != id),
+            None => self.root = None,
+        }
+        let mut out = Tree::new();
+        let mut stack = vec![(id, None)];
+        while let Some((old, parent)) = stack.pop() {
+            let node = self.nodes.remove(old.0).unwrap();
+            let new = match parent {
+                Some(parent) => out.add_child(parent, node.data),
+                None => out.add_root(node.data),
+            };
+            stack.extend(
+                node.children
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, Some(new))),
+            );
+        }
+        Some(out)
+    }
+    // the subtree below from in preorder: each node before its children, siblings in order
+    pub fn depth_first(&self, from: NodeId) -> DepthFirst<'_, T> {
+        self.node(from);
+        DepthFirst {
+            tree: self,
+            stack: vec![from],
+        }
+    }
+    // the subtree below from level by level, each level in sibling order
+    pub fn breadth_first(&self, from: NodeId) -> BreadthFirst<'_, T> {
+        self.node(from);
+        BreadthFirst {
+            tree: self,
+            queue: VecDeque::from([from]),
+        }
+    }
+    // the subtree below from, same shape, each value passed through f in preorder
+    pub fn map<U, F>(&self, from: NodeId, mut f: F) -> Tree<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.node(from);
+        let mut out = Tree::new();
+        let mut stack = vec![(from, None)];
+        while let Some((old, parent)) = stack.pop() {
+            let node = &self.nodes[old.0];
+            let data = f(&node.data);
+            let new = match parent {
+                Some(parent) => out.add_child(parent, data),
+                None => out.add_root(data),
+            };
+            stack.extend(node.children.iter().rev().map(|&child| (child, Some(new))));
+        }
+        out
+    }
+    // bottom up over the subtree below from: f gets each node's value with its children's
+    // results in sibling order, a leaf getting none, and whatever f makes of from is the answer
+    pub fn fold<A, F>(&self, from: NodeId, mut f: F) -> A
+    where
+        F: FnMut(&T, Vec<A>) -> A,
+    {
+        self.node(from);
+        let mut results = Vec::new();
+        // each frame is a node and how many of its children are folded already
+        let mut stack = vec![(from, 0)];
+        while let Some(frame) = stack.last_mut() {
+            let node = &self.nodes[frame.0.0];
+            match node.children.get(frame.1) {
+                Some(&child) => {
+                    frame.1 += 1;
+                    stack.push((child, 0));
+                }
+                None => {
+                    let folded = results.split_off(results.len() - node.children.len());
+                    results.push(f(&node.data, folded));
+                    stack.pop();
+                }
+            }
+        }
+        results.pop().unwrap()
+    }
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = None;
+    }
+    fn insert(&mut self, data: T, parent: Option<NodeId>) -> NodeId {
+        NodeId(self.nodes.insert(Node {
+            data,
+            parent,
+            children: Vec::new(),
+        }))
+    }
+    fn node(&self, id: NodeId) -> &Node<T> {
+        match self.nodes.get(id.0) {
+            Some(node) => node,
+            None => panic!("{:?} is not in the tree", id),
+        }
+    }
+}
+
+// the whole tree in preorder, children indented below their parent
+impl<T> Debug for Tree<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some(root) = self.root else {
+            return write!(f, "Tree []");
+        };
+        write!(f, "Tree [")?;
+        for id in self.depth_first(root) {
+            write!(
+                f,
+                "\n{:indent$}{:?}",
+                "",
+                self.nodes[id.0].data,
+                indent = 2 * (self.depth(id) + 1)
+            )?;
+        }
+        write!(f, "\n]")
+    }
+}
+
+pub struct DepthFirst<'a, T> {
+    tree: &'a Tree<T>,
+    stack: Vec<NodeId>,
+}
+
+impl<T> Iterator for DepthFirst<'_, T> {
+    type Item = NodeId;
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.stack.pop()?;
+        self.stack
+            .extend(self.tree.nodes[id.0].children.iter().rev());
+        Some(id)
+    }
+}
+
+impl<T> FusedIterator for DepthFirst<'_, T> {}
+
+pub struct BreadthFirst<'a, T> {
+    tree: &'a Tree<T>,
+    queue: VecDeque<NodeId>,
+}
+
+impl<T> Iterator for BreadthFirst<'_, T> {
+    type Item = NodeId;
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.queue.pop_front()?;
+        self.queue.extend(self.tree.nodes[id.0].children.iter());
+        Some(id)
+    }
+}
+
+impl<T> FusedIterator for BreadthFirst<'_, T> {}
@@ -1,15 +1,153 @@
-use std::{cmp::Ordering, fmt::Debug};
+use alloc::{format, string::String, vec::Vec};
+use core::{cmp::Ordering, fmt::Debug, iter::FusedIterator};
+
+use crate::{
+    collections::slot_map::{Key, SlotMap},
+    instrument::{self, counted},
+};
 
 fn left(i: usize) -> usize { 2 * i + 1 }
 fn right(i: usize) -> usize { 2 * i + 2 }
 fn parent(i: usize) -> usize { (i - 1) / 2 }
 
+fn sift_up<T, F>(heap: &mut [T], i: usize, cmp: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    sift_up_with(heap, i, cmp, &mut |_, _| {});
+}
+
+// the _with variants report every swap to moved, which is how handle positions keep up
+fn sift_up_with<T, F, M>(heap: &mut [T], mut i: usize, cmp: F, moved: &mut M)
+where
+    F: Fn(&T, &T) -> Ordering,
+    M: FnMut(usize, usize),
+{
+    while i > 0 {
+        let p = parent(i);
+        if counted(cmp(&heap[i], &heap[p])).is_lt() {
+            instrument::swap();
+            heap.swap(i, p);
+            moved(i, p);
+            i = p;
+        } else {
+            break;
+        }
+    }
+}
+
+// returns the number of swaps made, which the sorting module reports
+pub(crate) fn sift_down<T, F>(heap: &mut [T], i: usize, cmp: F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    sift_down_with(heap, i, cmp, &mut |_, _| {})
+}
+
+fn sift_down_with<T, F, M>(heap: &mut [T], mut i: usize, cmp: F, moved: &mut M) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+    M: FnMut(usize, usize),
+{
+    let mut swaps = 0;
+    loop {
+        let l = left(i);
+        if l >= heap.len() {
+            break;
+        }
+        let r = right(i);
+        let best = if r < heap.len() && counted(cmp(&heap[r], &heap[l])).is_lt() {
+            r
+        } else {
+            l
+        };
+        if counted(cmp(&heap[best], &heap[i])).is_lt() {
+            instrument::swap();
+            heap.swap(i, best);
+            moved(i, best);
+            swaps += 1;
+            i = best;
+        } else {
+            break;
+        }
+    }
+    swaps
+}
+
+pub(crate) fn heapify<T, F>(heap: &mut [T], cmp: F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering + Copy,
+{
+    heapify_with(heap, cmp, &mut |_, _| {})
+}
+
+fn heapify_with<T, F, M>(heap: &mut [T], cmp: F, moved: &mut M) -> usize
+where
+    F: Fn(&T, &T) -> Ordering + Copy,
+    M: FnMut(usize, usize),
+{
+    (0..heap.len() / 2).rev().map(|i| sift_down_with(heap, i, cmp, moved)).sum()
+}
+
+// names one element offered with offer_with_handle for as long as it stays in the heap; once it
+// leaves, by any route, the handle goes stale and every lookup with it returns None
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeapHandle(Key);
+
+// which handle sits at each heap index, kept in step with every swap; at stays empty until the
+// first handle is issued, so heaps that never use one pay nothing beyond a length check
+#[derive(Clone, Default)]
+struct Handles {
+    at: Vec<Option<Key>>,
+    positions: SlotMap<usize>,
+}
+
+impl Handles {
+    fn is_on(&self) -> bool {
+        !self.at.is_empty()
+    }
+    fn push(&mut self, key: Option<Key>) {
+        if self.is_on() || key.is_some() {
+            self.at.push(key);
+        }
+    }
+    fn swap(&mut self, i: usize, j: usize) {
+        if self.is_on() {
+            self.at.swap(i, j);
+            self.place(i);
+            self.place(j);
+        }
+    }
+    fn place(&mut self, i: usize) {
+        if let Some(key) = self.at[i] {
+            self.positions[key] = i;
+        }
+    }
+    // forgets whatever handle is at i, leaving the slot untracked
+    fn release(&mut self, i: usize) {
+        if let Some(key) = self.at.get_mut(i).and_then(Option::take) {
+            self.positions.remove(key);
+        }
+    }
+    fn truncate(&mut self, len: usize) {
+        if self.is_on() {
+            (len..self.at.len()).for_each(|i| self.release(i));
+            self.at.truncate(len);
+        }
+    }
+    fn clear(&mut self) {
+        self.at.clear();
+        self.positions.clear();
+    }
+}
+
 pub struct BinaryHeap<T>
 where
     T: Clone + Ord + PartialOrd,
 {
     heap: Vec<T>,
     cmp: fn(&T, &T) -> Ordering,
+    handles: Handles,
 }
 
 impl<T> BinaryHeap<T>
@@ -17,13 +155,13 @@ where
     T: Clone + Ord + PartialOrd,
 {
     pub fn new(cmp: fn(&T, &T) -> Ordering) -> Self {
-        Self { heap: Vec::new(), cmp }
+        Self { heap: Vec::new(), cmp, handles: Handles::default() }
     }
     pub fn min() -> Self {
-        Self { heap: Vec::new(), cmp: |a, b| a.cmp(b) }
+        Self::new(|a, b| a.cmp(b))
     }
     pub fn max() -> Self {
-        Self { heap: Vec::new(), cmp: |a, b| b.cmp(a) }
+        Self::new(|a, b| b.cmp(a))
     }
     pub fn from<I>(cmp: fn(&T, &T) -> Ordering, iter: I) -> Self
     where
@@ -46,17 +184,14 @@ where
         self.heap.len()
     }
     pub fn offer(&mut self, data: T) {
-        self.heap.push(data);
-        let mut i = self.heap.len() - 1;
-        while i > 0 {
-            let p = parent(i);
-            if (self.cmp)(&self.heap[i], &self.heap[p]).is_lt() {
-                self.heap.swap(i, p);
-                i = p;
-            } else {
-                break;
-            }
+        if self.heap.len() == self.heap.capacity() {
+            instrument::allocation();
         }
+        self.heap.push(data);
+        self.handles.push(None);
+        let last = self.heap.len() - 1;
+        sift_up_with(&mut self.heap, last, self.cmp, &mut |i, j| self.handles.swap(i, j));
+        self.audit();
     }
     pub fn extend<I>(&mut self, iter: I)
     where
@@ -70,48 +205,197 @@ where
         if self.heap.is_empty() {
             return None;
         }
-        let val = self.heap.swap_remove(0);
-        if !self.heap.is_empty() {
-            let mut i = 0;
-            loop {
-                let l = left(i);
-                if l >= self.heap.len() {
-                    break;
-                }
-                let r = right(i);
-                let best = if r < self.heap.len() &&
-                    (self.cmp)(&self.heap[r], &self.heap[l]).is_lt()
-                {
-                    r
-                } else {
-                    l
-                };
-                if (self.cmp)(&self.heap[best], &self.heap[i]).is_lt() {
-                    self.heap.swap(i, best);
-                    i = best;
-                } else {
-                    break;
-                }
+        Some(self.remove_at(0))
+    }
+    // swaps the last element into i and sifts it whichever way it has to go
+    fn remove_at(&mut self, i: usize) -> T {
+        self.handles.release(i);
+        let last = self.heap.len() - 1;
+        self.heap.swap(i, last);
+        self.handles.swap(i, last);
+        let val = self.heap.pop().expect("i is in range");
+        self.handles.truncate(last);
+        if i < self.heap.len() {
+            let handles = &mut self.handles;
+            sift_down_with(&mut self.heap, i, self.cmp, &mut |a, b| handles.swap(a, b));
+            sift_up_with(&mut self.heap, i, self.cmp, &mut |a, b| handles.swap(a, b));
+        }
+        self.audit();
+        val
+    }
+    pub fn replace_top(&mut self, data: T) -> Option<T> {
+        if let Some(top) = self.heap.first_mut() {
+            let val = core::mem::replace(top, data);
+            self.handles.release(0);
+            sift_down_with(&mut self.heap, 0, self.cmp, &mut |i, j| self.handles.swap(i, j));
+            self.audit();
+            Some(val)
+        } else {
+            self.heap.push(data);
+            None
+        }
+    }
+    pub fn push_pop(&mut self, data: T) -> T {
+        match self.heap.first() {
+            Some(top) if (self.cmp)(top, &data).is_lt() => {
+                let val = core::mem::replace(&mut self.heap[0], data);
+                self.handles.release(0);
+                sift_down_with(&mut self.heap, 0, self.cmp, &mut |i, j| self.handles.swap(i, j));
+                self.audit();
+                val
+            }
+            _ => data,
+        }
+    }
+    pub fn remove(&mut self, data: &T) -> bool {
+        if let Some(i) = self.heap.iter().position(|item| item == data) {
+            self.remove_at(i);
+            true
+        } else {
+            false
+        }
+    }
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.split_off_rejected(predicate);
+    }
+    // both drains yield in priority order, like into_iter
+    pub fn drain(&mut self) -> IntoIter<T> {
+        let heap = core::mem::take(&mut self.heap);
+        self.handles.clear();
+        Self { heap, cmp: self.cmp, handles: Handles::default() }.into_iter()
+    }
+    pub fn drain_filter<F>(&mut self, mut predicate: F) -> IntoIter<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut drained = self.split_off_rejected(|item| !predicate(item));
+        heapify(&mut drained, self.cmp);
+        Self { heap: drained, cmp: self.cmp, handles: Handles::default() }.into_iter()
+    }
+    // moves what keep accepts to the front in its original order, cuts off the rest and
+    // rebuilds the heap from what is left
+    fn split_off_rejected<F>(&mut self, mut keep: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept = 0;
+        for i in 0..self.heap.len() {
+            if keep(&self.heap[i]) {
+                self.heap.swap(kept, i);
+                self.handles.swap(kept, i);
+                kept += 1;
             }
         }
-        Some(val)
+        let rejected = self.heap.split_off(kept);
+        self.handles.truncate(kept);
+        heapify_with(&mut self.heap, self.cmp, &mut |i, j| self.handles.swap(i, j));
+        self.audit();
+        rejected
     }
     pub fn peek(&self) -> Option<&T> {
-        self.heap.get(0)
+        self.heap.first()
+    }
+    pub fn merge(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+    // the result is ordered by self's comparator whatever other's was, since the combined
+    // buffer gets heapified again either way
+    pub fn append(&mut self, other: &mut Self) {
+        // the handles of other belong to its own slot map, so they cannot come along
+        other.handles.clear();
+        if other.heap.len() > self.heap.len() && !self.handles.is_on() {
+            core::mem::swap(&mut self.heap, &mut other.heap);
+        }
+        other.heap.iter().for_each(|_| self.handles.push(None));
+        self.heap.append(&mut other.heap);
+        heapify_with(&mut self.heap, self.cmp, &mut |i, j| self.handles.swap(i, j));
+        self.audit();
     }
     pub fn clear(&mut self) {
         self.heap.clear();
+        self.handles.clear();
+    }
+    // both rebuild bottom-up in O(n), cheaper than offering everything again
+    pub fn set_comparator(&mut self, cmp: fn(&T, &T) -> Ordering) {
+        self.cmp = cmp;
+        self.reheapify();
+    }
+    pub fn into_with_cmp(mut self, cmp: fn(&T, &T) -> Ordering) -> Self {
+        self.set_comparator(cmp);
+        self
+    }
+    // for when the ordering of elements already inside changed behind the heap's back, through
+    // interior mutability on what iter_unsorted hands out
+    pub fn reheapify(&mut self) {
+        heapify_with(&mut self.heap, self.cmp, &mut |i, j| self.handles.swap(i, j));
+        self.audit();
+    }
+    // no comparisons are counted here, so checking does not skew instrumented runs
+    pub fn is_valid_heap(&self) -> bool {
+        self.violation().is_none()
+    }
+    // the first index that orders strictly before its parent
+    fn violation(&self) -> Option<usize> {
+        (1..self.heap.len()).find(|&i| (self.cmp)(&self.heap[i], &self.heap[parent(i)]).is_lt())
+    }
+    fn audit(&self) {
+        #[cfg(feature = "verify")]
+        if cfg!(debug_assertions)
+            && let Some(i) = self.violation()
+        {
+            panic!("BinaryHeap invariant violated: index {} orders before its parent {}", i, parent(i));
+        }
     }
     pub fn shrink_to_fit(&mut self) {
         self.heap.shrink_to_fit();
     }
+    // the backing buffer in heap order, without any sorting
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap
+    }
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.heap.sort_by(self.cmp);
+        self.heap
+    }
 }
 
+impl<T> Default for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn default() -> Self {
+        Self::min()
+    }
+}
+
+impl<T> PartialEq for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // equal heaps may lay out their elements differently, so compare them sorted
+        if self.heap.len() != other.heap.len() {
+            return false;
+        }
+        let mut lhs = self.heap.clone();
+        let mut rhs = other.heap.clone();
+        lhs.sort_unstable();
+        rhs.sort_unstable();
+        lhs == rhs
+    }
+}
+
+impl<T> Eq for BinaryHeap<T> where T: Clone + Ord + PartialOrd {}
+
 impl<T> Debug for BinaryHeap<T>
 where
     T: Debug + Clone + Ord + PartialOrd,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(&self.heap).finish()
     }
 }
@@ -121,7 +405,55 @@ where
     T: Clone + Ord + PartialOrd,
 {
     fn clone(&self) -> Self {
-        Self { heap: self.heap.clone(), cmp: self.cmp }
+        Self { heap: self.heap.clone(), cmp: self.cmp, handles: self.handles.clone() }
+    }
+}
+
+impl<T> Extend<T> for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        BinaryHeap::extend(self, iter);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for BinaryHeap<T>
+where
+    T: Copy + Ord + PartialOrd + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        BinaryHeap::extend(self, iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<BinaryHeap<T>> for Vec<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn from(heap: BinaryHeap<T>) -> Self {
+        heap.into_vec()
+    }
+}
+
+impl<T> FromIterator<T> for BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::min();
+        heap.heap = iter.into_iter().collect();
+        heapify(&mut heap.heap, heap.cmp);
+        heap
     }
 }
 
@@ -153,8 +485,16 @@ where
             None
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.collection.len() - self.index;
+        (len, Some(len))
+    }
 }
 
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: Clone + Ord + PartialOrd {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: Clone + Ord + PartialOrd {}
+
 impl<'a, T> BinaryHeap<T>
 where
     T: Clone + Ord + PartialOrd
@@ -164,6 +504,131 @@ where
     }
 }
 
+// the implicit tree: index 0 is the root and i has its children at 2i + 1 and 2i + 2, so the
+// buffer read level by level is exactly heap order
+impl<T> BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.heap.get(index)
+    }
+    pub fn parent_of(&self, index: usize) -> Option<usize> {
+        self.check(index);
+        (index > 0).then(|| parent(index))
+    }
+    // (left, right), each None past the end of the heap
+    pub fn children_of(&self, index: usize) -> (Option<usize>, Option<usize>) {
+        self.check(index);
+        let len = self.heap.len();
+        (Some(left(index)).filter(|&l| l < len), Some(right(index)).filter(|&r| r < len))
+    }
+    // level d holds indices 2^d - 1 up to 2^(d+1) - 2; only the last one can be short
+    pub fn level_iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        let mut start = 0;
+        let mut width = 1;
+        core::iter::from_fn(move || {
+            if start >= self.heap.len() {
+                return None;
+            }
+            let end = (start + width).min(self.heap.len());
+            let level = &self.heap[start..end];
+            start = end;
+            width *= 2;
+            Some(level)
+        })
+    }
+    pub fn depth(&self) -> usize {
+        match self.heap.len() {
+            0 => 0,
+            len => len.ilog2() as usize + 1,
+        }
+    }
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.heap.len(),
+            "index {} out of range for a heap of {}",
+            index,
+            self.heap.len()
+        );
+    }
+}
+
+impl<T> BinaryHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    pub fn offer_with_handle(&mut self, data: T) -> HeapHandle {
+        if !self.handles.is_on() {
+            self.handles.at.resize(self.heap.len(), None);
+        }
+        let key = self.handles.positions.insert(self.heap.len());
+        self.heap.push(data);
+        self.handles.push(Some(key));
+        let last = self.heap.len() - 1;
+        sift_up_with(&mut self.heap, last, self.cmp, &mut |i, j| self.handles.swap(i, j));
+        self.audit();
+        HeapHandle(key)
+    }
+    pub fn contains_handle(&self, handle: HeapHandle) -> bool {
+        self.handles.positions.contains_key(handle.0)
+    }
+    // where the element sits in the buffer right now, for use with the implicit tree view
+    pub fn position_of(&self, handle: HeapHandle) -> Option<usize> {
+        self.handles.positions.get(handle.0).copied()
+    }
+    pub fn get_by_handle(&self, handle: HeapHandle) -> Option<&T> {
+        self.position_of(handle).map(|i| &self.heap[i])
+    }
+    // replaces the element and sifts it whichever way the new value needs, returning the old
+    // one; the handle keeps naming the element
+    pub fn update(&mut self, handle: HeapHandle, data: T) -> Option<T> {
+        let i = self.position_of(handle)?;
+        let old = core::mem::replace(&mut self.heap[i], data);
+        let handles = &mut self.handles;
+        sift_up_with(&mut self.heap, i, self.cmp, &mut |a, b| handles.swap(a, b));
+        let i = handles.positions[handle.0];
+        sift_down_with(&mut self.heap, i, self.cmp, &mut |a, b| handles.swap(a, b));
+        self.audit();
+        Some(old)
+    }
+    pub fn remove_handle(&mut self, handle: HeapHandle) -> Option<T> {
+        let i = self.position_of(handle)?;
+        Some(self.remove_at(i))
+    }
+}
+
+impl<T> BinaryHeap<T>
+where
+    T: Debug + Clone + Ord + PartialOrd,
+{
+    // drawn like BST::to_ascii, with each node's buffer index in brackets
+    pub fn to_tree_string(&self) -> String {
+        let mut out = String::new();
+        let mut stack: Vec<(usize, String, &str)> = Vec::new();
+        if !self.heap.is_empty() {
+            stack.push((0, String::new(), ""));
+        }
+        while let Some((i, prefix, branch)) = stack.pop() {
+            out.push_str(&format!("{}{}[{}] {:?}\n", prefix, branch, i, self.heap[i]));
+            let child_prefix = match branch {
+                "" => prefix,
+                b if b.starts_with('`') => format!("{}    ", prefix),
+                _ => format!("{}|   ", prefix),
+            };
+            match self.children_of(i) {
+                (Some(l), Some(r)) => {
+                    stack.push((r, child_prefix.clone(), "`-- R: "));
+                    stack.push((l, child_prefix, "+-- L: "));
+                }
+                (Some(l), None) => stack.push((l, child_prefix, "`-- L: ")),
+                _ => {}
+            }
+        }
+        out
+    }
+}
+
 impl<T> Iterator for IntoIter<T>
 where
     T: Clone + Ord + PartialOrd,
@@ -172,8 +637,16 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.consumer.poll()
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.consumer.size();
+        (len, Some(len))
+    }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> where T: Clone + Ord + PartialOrd {}
+
+impl<T> FusedIterator for IntoIter<T> where T: Clone + Ord + PartialOrd {}
+
 impl<T> IntoIterator for BinaryHeap<T>
 where
     T: Clone + Ord + PartialOrd,
@@ -184,3 +657,507 @@ where
         IntoIter { consumer: self }
     }
 }
+
+
+pub struct BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    heap: Vec<T>,
+    cmp: fn(&T, &T) -> Ordering,
+    limit: usize,
+}
+
+impl<T> BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    pub fn with_capacity_limit(limit: usize, cmp: fn(&T, &T) -> Ordering) -> Self {
+        Self { heap: Vec::with_capacity(limit), cmp, limit }
+    }
+}
+
+impl<T> BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+    pub fn is_full(&self) -> bool {
+        self.heap.len() >= self.limit
+    }
+    pub fn offer(&mut self, data: T) -> Option<T> {
+        let cmp = self.cmp;
+        let worst_first = move |a: &T, b: &T| cmp(b, a);
+        if !self.is_full() {
+            self.heap.push(data);
+            let last = self.heap.len() - 1;
+            sift_up(&mut self.heap, last, worst_first);
+            return None;
+        }
+        match self.heap.first_mut() {
+            Some(worst) if cmp(&data, worst).is_lt() => {
+                let evicted = core::mem::replace(worst, data);
+                sift_down(&mut self.heap, 0, worst_first);
+                Some(evicted)
+            }
+            _ => Some(data),
+        }
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for item in iter {
+            self.offer(item);
+        }
+    }
+    pub fn peek_worst(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+    pub fn iter_unsorted(&self) -> Iter<'_, T> {
+        Iter { collection: &self.heap, index: 0 }
+    }
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.heap.sort_by(self.cmp);
+        self.heap
+    }
+}
+
+impl<T> PartialEq for BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // equal heaps may lay out their elements differently, so compare them sorted
+        if self.heap.len() != other.heap.len() {
+            return false;
+        }
+        let mut lhs = self.heap.clone();
+        let mut rhs = other.heap.clone();
+        lhs.sort_unstable();
+        rhs.sort_unstable();
+        lhs == rhs
+    }
+}
+
+impl<T> Eq for BoundedHeap<T> where T: Clone + Ord + PartialOrd {}
+
+impl<T> Debug for BoundedHeap<T>
+where
+    T: Debug + Clone + Ord + PartialOrd,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.heap).finish()
+    }
+}
+
+impl<T> Clone for BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn clone(&self) -> Self {
+        Self { heap: self.heap.clone(), cmp: self.cmp, limit: self.limit }
+    }
+}
+
+impl<T> Extend<T> for BoundedHeap<T>
+where
+    T: Clone + Ord + PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        BoundedHeap::extend(self, iter);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for BoundedHeap<T>
+where
+    T: Copy + Ord + PartialOrd + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        BoundedHeap::extend(self, iter.into_iter().copied());
+    }
+}
+
+// a heap of (priority, value) pairs that only ever compares the priorities, so the values need
+// no Ord of their own; equal priorities come out in no particular order
+pub struct PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    heap: Vec<(P, V)>,
+    cmp: fn(&P, &P) -> Ordering,
+}
+
+fn by_priority<P, V>(cmp: fn(&P, &P) -> Ordering) -> impl Fn(&(P, V), &(P, V)) -> Ordering + Copy {
+    move |a, b| cmp(&a.0, &b.0)
+}
+
+impl<P, V> PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    pub fn new(cmp: fn(&P, &P) -> Ordering) -> Self {
+        Self { heap: Vec::new(), cmp }
+    }
+    pub fn min() -> Self {
+        Self { heap: Vec::new(), cmp: |a, b| a.cmp(b) }
+    }
+    pub fn max() -> Self {
+        Self { heap: Vec::new(), cmp: |a, b| b.cmp(a) }
+    }
+    pub fn from<I>(cmp: fn(&P, &P) -> Ordering, iter: I) -> Self
+    where
+        I: IntoIterator<Item = (P, V)>,
+    {
+        let mut queue = Self::new(cmp);
+        queue.heap = iter.into_iter().collect();
+        heapify(&mut queue.heap, by_priority(queue.cmp));
+        queue
+    }
+}
+
+impl<P, V> PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn offer(&mut self, priority: P, value: V) {
+        if self.heap.len() == self.heap.capacity() {
+            instrument::allocation();
+        }
+        self.heap.push((priority, value));
+        let last = self.heap.len() - 1;
+        sift_up(&mut self.heap, last, by_priority(self.cmp));
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (P, V)>,
+    {
+        for (priority, value) in iter {
+            self.offer(priority, value);
+        }
+    }
+    pub fn poll(&mut self) -> Option<V> {
+        self.poll_with_priority().map(|(_, value)| value)
+    }
+    pub fn poll_with_priority(&mut self) -> Option<(P, V)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let top = self.heap.swap_remove(0);
+        if !self.heap.is_empty() {
+            sift_down(&mut self.heap, 0, by_priority(self.cmp));
+        }
+        Some(top)
+    }
+    pub fn peek(&self) -> Option<&V> {
+        self.heap.first().map(|(_, value)| value)
+    }
+    pub fn peek_with_priority(&self) -> Option<(&P, &V)> {
+        self.heap.first().map(|(priority, value)| (priority, value))
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+    // pairs in heap order, without any sorting
+    pub fn iter_unsorted(&self) -> impl ExactSizeIterator<Item = (&P, &V)> {
+        self.heap.iter().map(|(priority, value)| (priority, value))
+    }
+    pub fn into_sorted_vec(mut self) -> Vec<(P, V)> {
+        self.heap.sort_by(by_priority(self.cmp));
+        self.heap
+    }
+}
+
+impl<P, V> Default for PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::min()
+    }
+}
+
+impl<P, V> Debug for PriorityQueue<P, V>
+where
+    P: Debug + Ord,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_unsorted()).finish()
+    }
+}
+
+impl<P, V> Clone for PriorityQueue<P, V>
+where
+    P: Clone + Ord,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { heap: self.heap.clone(), cmp: self.cmp }
+    }
+}
+
+impl<P, V> Extend<(P, V)> for PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    fn extend<I: IntoIterator<Item = (P, V)>>(&mut self, iter: I) {
+        PriorityQueue::extend(self, iter);
+    }
+}
+
+impl<P, V> FromIterator<(P, V)> for PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = (P, V)>>(iter: I) -> Self {
+        Self::from(|a, b| a.cmp(b), iter)
+    }
+}
+
+pub struct PriorityIntoIter<P, V>
+where
+    P: Ord,
+{
+    consumer: PriorityQueue<P, V>,
+}
+
+impl<P, V> Iterator for PriorityIntoIter<P, V>
+where
+    P: Ord,
+{
+    type Item = (P, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.consumer.poll_with_priority()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.consumer.size();
+        (len, Some(len))
+    }
+}
+
+impl<P, V> ExactSizeIterator for PriorityIntoIter<P, V> where P: Ord {}
+
+impl<P, V> FusedIterator for PriorityIntoIter<P, V> where P: Ord {}
+
+// yields the pairs in priority order, like BinaryHeap's into_iter
+impl<P, V> IntoIterator for PriorityQueue<P, V>
+where
+    P: Ord,
+{
+    type Item = (P, V);
+    type IntoIter = PriorityIntoIter<P, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        PriorityIntoIter { consumer: self }
+    }
+}
+
+// a heap over small integer ids whose priorities can be changed or dropped in place, which is
+// what decrease-key needs: positions maps every id to its slot, so a sift can start from
+// wherever that id sits instead of searching for it. ids index straight into the tables, so
+// storage grows with the largest id offered, not with the number held
+pub struct IndexedHeap<P>
+where
+    P: Ord,
+{
+    // ids in heap order
+    heap: Vec<usize>,
+    positions: Vec<Option<usize>>,
+    priorities: Vec<Option<P>>,
+    cmp: fn(&P, &P) -> Ordering,
+}
+
+impl<P> IndexedHeap<P>
+where
+    P: Ord,
+{
+    pub fn new(cmp: fn(&P, &P) -> Ordering) -> Self {
+        Self { heap: Vec::new(), positions: Vec::new(), priorities: Vec::new(), cmp }
+    }
+    pub fn min() -> Self {
+        Self::new(|a, b| a.cmp(b))
+    }
+    pub fn max() -> Self {
+        Self::new(|a, b| b.cmp(a))
+    }
+}
+
+impl<P> IndexedHeap<P>
+where
+    P: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn contains(&self, id: usize) -> bool {
+        self.position(id).is_some()
+    }
+    pub fn priority(&self, id: usize) -> Option<&P> {
+        self.priorities.get(id)?.as_ref()
+    }
+    // offers id at priority, or moves it there if it is already held, returning the old one
+    pub fn insert(&mut self, id: usize, priority: P) -> Option<P> {
+        if self.contains(id) {
+            return self.change_priority(id, priority);
+        }
+        if id >= self.positions.len() {
+            instrument::allocation();
+            self.positions.resize(id + 1, None);
+            self.priorities.resize_with(id + 1, || None);
+        }
+        self.positions[id] = Some(self.heap.len());
+        self.priorities[id] = Some(priority);
+        self.heap.push(id);
+        self.sift_up(self.heap.len() - 1);
+        None
+    }
+    // either direction works, the id sifts whichever way its new priority sends it; an id that
+    // is not held is left alone
+    pub fn change_priority(&mut self, id: usize, priority: P) -> Option<P> {
+        let at = self.position(id)?;
+        let old = self.priorities[id].replace(priority);
+        let at = self.sift_up(at);
+        self.sift_down(at);
+        old
+    }
+    pub fn remove(&mut self, id: usize) -> Option<P> {
+        let at = self.position(id)?;
+        let last = self.heap.len() - 1;
+        self.swap(at, last);
+        self.heap.pop();
+        self.positions[id] = None;
+        if at < self.heap.len() {
+            let at = self.sift_up(at);
+            self.sift_down(at);
+        }
+        self.priorities[id].take()
+    }
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        let &id = self.heap.first()?;
+        self.priority(id).map(|priority| (id, priority))
+    }
+    pub fn poll(&mut self) -> Option<(usize, P)> {
+        let &id = self.heap.first()?;
+        self.remove(id).map(|priority| (id, priority))
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.positions.clear();
+        self.priorities.clear();
+    }
+    // (id, priority) pairs in heap order, without any sorting
+    pub fn iter_unsorted(&self) -> impl ExactSizeIterator<Item = (usize, &P)> {
+        self.heap.iter().map(|&id| (id, self.priorities[id].as_ref().expect("held ids have a priority")))
+    }
+    fn position(&self, id: usize) -> Option<usize> {
+        *self.positions.get(id)?
+    }
+    fn less(&self, a: usize, b: usize) -> bool {
+        let priority = |at: usize| self.priorities[self.heap[at]].as_ref().expect("held ids have a priority");
+        counted((self.cmp)(priority(a), priority(b))).is_lt()
+    }
+    fn swap(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        instrument::swap();
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+    // the same walks as the free sift functions, but every swap also moves the two positions
+    fn sift_up(&mut self, mut i: usize) -> usize {
+        while i > 0 && self.less(i, parent(i)) {
+            self.swap(i, parent(i));
+            i = parent(i);
+        }
+        i
+    }
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (l, r) = (left(i), right(i));
+            if l >= self.heap.len() {
+                break;
+            }
+            let best = if r < self.heap.len() && self.less(r, l) { r } else { l };
+            if !self.less(best, i) {
+                break;
+            }
+            self.swap(i, best);
+            i = best;
+        }
+    }
+}
+
+impl<P> Default for IndexedHeap<P>
+where
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::min()
+    }
+}
+
+impl<P> Debug for IndexedHeap<P>
+where
+    P: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_unsorted()).finish()
+    }
+}
+
+impl<P> Clone for IndexedHeap<P>
+where
+    P: Clone + Ord,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+            positions: self.positions.clone(),
+            priorities: self.priorities.clone(),
+            cmp: self.cmp,
+        }
+    }
+}
+
+impl<P> Extend<(usize, P)> for IndexedHeap<P>
+where
+    P: Ord,
+{
+    fn extend<I: IntoIterator<Item = (usize, P)>>(&mut self, iter: I) {
+        for (id, priority) in iter {
+            self.insert(id, priority);
+        }
+    }
+}
+
+pub fn top_k<T, I>(iter: I, k: usize, cmp: fn(&T, &T) -> Ordering) -> Vec<T>
+where
+    T: Clone + Ord + PartialOrd,
+    I: IntoIterator<Item = T>,
+{
+    let mut heap = BoundedHeap::with_capacity_limit(k, cmp);
+    heap.extend(iter);
+    heap.into_sorted_vec()
+}
@@ -0,0 +1,200 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    algebra::{Action, Monoid},
+    graph::Graph,
+    ranges::LazySegmentTree,
+    tree::lca::{Forest, parents_from},
+};
+
+// heavy-light decomposition: every vertex's heavy child is the one with the largest subtree, and
+// following heavy children splits the forest into chains. numbering vertices in a dfs that takes
+// the heavy child first lays every chain, and every subtree, out contiguously, so one segment
+// tree over that order covers them all. a path leaves a chain only by a light edge, which at
+// least halves the subtree size, so any path crosses O(log n) chains and each path query or
+// update costs O(log^2 n). segments fold in whatever order the chains come, so the monoid
+// should be commutative, as sums, minima and maxima are
+pub struct HeavyLight<M, A = ()>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    forest: Forest,
+    // the top vertex of the chain each vertex is on, and its place in the decomposition order
+    head: Vec<usize>,
+    position: Vec<usize>,
+    size: Vec<usize>,
+    values: LazySegmentTree<M, A>,
+}
+
+impl<M, A> HeavyLight<M, A>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    // values[v] is the value on vertex v; panics unless there is one per vertex
+    pub fn new<I>(parents: &[Option<usize>], values: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+    {
+        let forest = Forest::new(parents);
+        let count = parents.len();
+        let values: Vec<M> = values.into_iter().collect();
+        assert_eq!(
+            values.len(),
+            count,
+            "{} values given for {} vertices",
+            values.len(),
+            count
+        );
+        let mut size = vec![1; count];
+        for &vertex in forest.preorder.iter().rev() {
+            if let Some(parent) = forest.parent[vertex] {
+                size[parent] += size[vertex];
+            }
+        }
+        let heavy: Vec<Option<usize>> = (0..count)
+            .map(|vertex| {
+                forest.children[vertex]
+                    .iter()
+                    .copied()
+                    .max_by_key(|&child| size[child])
+            })
+            .collect();
+        let (mut head, mut position) = (vec![0; count], vec![0; count]);
+        let mut order = Vec::with_capacity(count);
+        for start in (0..count).filter(|&vertex| parents[vertex].is_none()) {
+            head[start] = start;
+            let mut stack = vec![start];
+            while let Some(vertex) = stack.pop() {
+                position[vertex] = order.len();
+                order.push(vertex);
+                // the heavy child goes on last so that it comes off next
+                for &child in &forest.children[vertex] {
+                    if Some(child) != heavy[vertex] {
+                        head[child] = child;
+                        stack.push(child);
+                    }
+                }
+                if let Some(child) = heavy[vertex] {
+                    head[child] = head[vertex];
+                    stack.push(child);
+                }
+            }
+        }
+        let values = LazySegmentTree::from(order.iter().map(|&vertex| values[vertex].clone()));
+        Self {
+            forest,
+            head,
+            position,
+            size,
+            values,
+        }
+    }
+    pub fn from_graph<W, I>(graph: &Graph<W>, root: usize, values: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+    {
+        Self::new(&parents_from(graph, root), values)
+    }
+}
+
+impl<M, A> HeavyLight<M, A>
+where
+    M: Monoid + Clone,
+    A: Action<M> + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.head.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.head.len()
+    }
+    pub fn get(&mut self, vertex: usize) -> M {
+        self.forest.check(vertex);
+        self.values.get(self.position[vertex])
+    }
+    pub fn set(&mut self, vertex: usize, value: M) {
+        self.forest.check(vertex);
+        self.values.set(self.position[vertex], value);
+    }
+    pub fn lca(&self, u: usize, v: usize) -> Option<usize> {
+        let mut ancestor = None;
+        self.chains(u, v, |_, _| {}, |lower| ancestor = Some(lower));
+        ancestor
+    }
+    // the fold of the values on every vertex of the path from u to v, both ends included, or
+    // None when they are in different trees
+    pub fn path_query(&mut self, u: usize, v: usize) -> Option<M> {
+        let mut segments = Vec::new();
+        let mut reached = false;
+        self.chains(
+            u,
+            v,
+            |start, end| segments.push((start, end)),
+            |_| reached = true,
+        );
+        reached.then(|| {
+            segments.iter().fold(M::identity(), |fold, &(start, end)| {
+                fold.combine(&self.values.query(start..=end))
+            })
+        })
+    }
+    // applies the action to every vertex on the path; false, changing nothing, when u and v are
+    // in different trees
+    pub fn path_update(&mut self, u: usize, v: usize, action: &A) -> bool {
+        let mut segments = Vec::new();
+        let mut reached = false;
+        self.chains(
+            u,
+            v,
+            |start, end| segments.push((start, end)),
+            |_| reached = true,
+        );
+        if reached {
+            for (start, end) in segments {
+                self.values.update(start..=end, action);
+            }
+        }
+        reached
+    }
+    // the subtree below a vertex is one contiguous run from its own position
+    pub fn subtree_query(&mut self, vertex: usize) -> M {
+        self.forest.check(vertex);
+        let start = self.position[vertex];
+        self.values.query(start..start + self.size[vertex])
+    }
+    pub fn subtree_update(&mut self, vertex: usize, action: &A) {
+        self.forest.check(vertex);
+        let start = self.position[vertex];
+        self.values.update(start..start + self.size[vertex], action);
+    }
+    // walks u and v up chain by chain, always moving the one whose chain head is deeper, handing
+    // each stretch of positions passed over to segment; once both share a chain, the last
+    // stretch between them goes too and the shallower of the two, their lca, goes to meet
+    fn chains<S, L>(&self, mut u: usize, mut v: usize, mut segment: S, meet: L)
+    where
+        S: FnMut(usize, usize),
+        L: FnOnce(usize),
+    {
+        self.forest.check(u);
+        self.forest.check(v);
+        if self.forest.root[u] != self.forest.root[v] {
+            return;
+        }
+        let depth = &self.forest.depth;
+        while self.head[u] != self.head[v] {
+            if depth[self.head[u]] < depth[self.head[v]] {
+                (u, v) = (v, u);
+            }
+            segment(self.position[self.head[u]], self.position[u]);
+            u = self.forest.parent[self.head[u]]
+                .expect("a chain head below another chain has a parent");
+        }
+        if depth[u] > depth[v] {
+            (u, v) = (v, u);
+        }
+        segment(self.position[u], self.position[v]);
+        meet(u);
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{collections::BitVec, tree::heap::BinaryHeap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Leaf(u8),
+    // the children sit at these indices of the node list; a 0 bit goes left
+    Internal(usize, usize),
+}
+
+// the tree lives in a flat node list with the root last, since every merge appends the node it
+// builds; codes are worked out once up front so encoding is a table lookup per byte
+#[derive(Clone, PartialEq, Eq)]
+pub struct HuffmanTree {
+    nodes: Vec<Node>,
+    codes: Vec<Option<BitVec>>,
+    weight: u64,
+}
+
+impl HuffmanTree {
+    // symbols with a frequency of zero get no code; None when nothing is left to code
+    pub fn from_frequencies<I>(frequencies: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (u8, u64)>,
+    {
+        let mut counts = [0u64; 256];
+        for (symbol, count) in frequencies {
+            counts[symbol as usize] += count;
+        }
+        // (weight, node) pairs in a min-heap; ties go to the lower index, which keeps the shape
+        // the same from one build to the next
+        let mut heap = BinaryHeap::min();
+        let mut nodes = Vec::new();
+        for (symbol, &count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            heap.offer((count, nodes.len()));
+            nodes.push(Node::Leaf(symbol as u8));
+        }
+        // the two lightest trees merge until one is left, which is what makes the code optimal
+        while heap.size() > 1 {
+            let (Some((a, left)), Some((b, right))) = (heap.poll(), heap.poll()) else {
+                unreachable!("the heap held at least two trees");
+            };
+            heap.offer((a + b, nodes.len()));
+            nodes.push(Node::Internal(left, right));
+        }
+        let (weight, _) = heap.poll()?;
+        let codes = Self::code_table(&nodes);
+        Some(Self {
+            nodes,
+            codes,
+            weight,
+        })
+    }
+    // counts the bytes first, for a tree fitted to exactly this data
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        let mut counts = [0u64; 256];
+        data.iter().for_each(|&byte| counts[byte as usize] += 1);
+        Self::from_frequencies((0..=255).zip(counts))
+    }
+    // a lone symbol still needs a bit per occurrence, so it is given the code 0
+    fn code_table(nodes: &[Node]) -> Vec<Option<BitVec>> {
+        let mut codes = alloc::vec![None; 256];
+        let mut pending = alloc::vec![(nodes.len() - 1, BitVec::new())];
+        while let Some((at, mut path)) = pending.pop() {
+            match nodes[at] {
+                Node::Leaf(symbol) => {
+                    if path.is_empty() {
+                        path.push(false);
+                    }
+                    codes[symbol as usize] = Some(path);
+                }
+                Node::Internal(left, right) => {
+                    let mut right_path = path.clone();
+                    right_path.push(true);
+                    path.push(false);
+                    pending.push((right, right_path));
+                    pending.push((left, path));
+                }
+            }
+        }
+        codes
+    }
+}
+
+impl HuffmanTree {
+    pub fn symbols(&self) -> usize {
+        self.codes.iter().flatten().count()
+    }
+    // the total frequency the tree was built from
+    pub fn weight(&self) -> u64 {
+        self.weight
+    }
+    pub fn code(&self, symbol: u8) -> Option<&BitVec> {
+        self.codes[symbol as usize].as_ref()
+    }
+    // (symbol, code) for every symbol with a code, in symbol order
+    pub fn codes(&self) -> impl Iterator<Item = (u8, &BitVec)> {
+        (0..=255)
+            .zip(&self.codes)
+            .filter_map(|(symbol, code)| Some((symbol, code.as_ref()?)))
+    }
+    // panics on a byte the tree has no code for, because there is no way to write it down
+    pub fn encode(&self, data: &[u8]) -> BitVec {
+        let mut bits = BitVec::new();
+        for &byte in data {
+            let code = self
+                .code(byte)
+                .unwrap_or_else(|| panic!("byte {} has no code in this tree", byte));
+            bits.extend(code);
+        }
+        bits
+    }
+    // walks from the root and starts again at every leaf; None if the bits stop partway through
+    // a code
+    pub fn decode(&self, bits: &BitVec) -> Option<Vec<u8>> {
+        let root = self.nodes.len() - 1;
+        if let Node::Leaf(symbol) = self.nodes[root] {
+            return bits
+                .iter()
+                .all(|bit| !bit)
+                .then(|| alloc::vec![symbol; bits.len()]);
+        }
+        let mut data = Vec::new();
+        let mut at = root;
+        for bit in bits {
+            let Node::Internal(left, right) = self.nodes[at] else {
+                unreachable!("the walk restarts at every leaf");
+            };
+            at = if bit { right } else { left };
+            if let Node::Leaf(symbol) = self.nodes[at] {
+                data.push(symbol);
+                at = root;
+            }
+        }
+        (at == root).then_some(data)
+    }
+}
+
+impl Debug for HuffmanTree {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.codes()).finish()
+    }
+}
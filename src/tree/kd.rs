@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::tree::heap::BinaryHeap;
+
+#[derive(Clone)]
+struct Node<const K: usize, T> {
+    point: [T; K],
+    // indices into the node list; a node at depth d splits on axis d % K, with smaller
+    // coordinates to the left and equal or greater to the right
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+// points live in one flat list with the links as indices, so the tree never recurses and drops
+// in one pass. inserts do not rebalance; from builds a balanced tree by splitting at medians
+#[derive(Clone)]
+pub struct KdTree<const K: usize, T>
+where
+    T: Copy + PartialOrd,
+{
+    nodes: Vec<Node<K, T>>,
+}
+
+// squared euclidean distance, measured with the larger coordinate first so unsigned types
+// never underflow
+fn distance<const K: usize, T>(a: &[T; K], b: &[T; K]) -> T
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    a.iter()
+        .zip(b)
+        .fold(T::default(), |sum, (&x, &y)| sum + gap(x, y) * gap(x, y))
+}
+
+fn gap<T>(x: T, y: T) -> T
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    if x > y { x - y } else { y - x }
+}
+
+// a candidate in the k-nearest heap, ordered by distance then by node so the heap has a total
+// order even for floats; an incomparable distance (a NaN) sorts as a tie
+#[derive(Clone, Copy, PartialEq)]
+struct Candidate<T>(T, usize);
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .unwrap_or(Ordering::Equal)
+            .then(self.1.cmp(&other.1))
+    }
+}
+
+impl<const K: usize, T> KdTree<K, T>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    pub fn new() -> Self {
+        assert!(K > 0, "a k-d tree needs at least one axis");
+        Self { nodes: Vec::new() }
+    }
+    // each subtree is sorted along its axis and split at the median, with the split moved left
+    // past any equal coordinates so that equal still means right
+    pub fn from<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = [T; K]>,
+    {
+        let mut tree = Self::new();
+        let mut points: Vec<[T; K]> = points.into_iter().collect();
+        tree.nodes.reserve(points.len());
+        let mut pending = alloc::vec![(0, points.len(), 0, None::<(usize, bool)>)];
+        while let Some((lo, hi, depth, parent)) = pending.pop() {
+            if lo == hi {
+                continue;
+            }
+            let axis = depth % K;
+            let block = &mut points[lo..hi];
+            block.sort_by(|a, b| a[axis].partial_cmp(&b[axis]).unwrap_or(Ordering::Equal));
+            let mut mid = block.len() / 2;
+            while mid > 0 && block[mid - 1][axis] == block[mid][axis] {
+                mid -= 1;
+            }
+            let at = tree.nodes.len();
+            tree.nodes.push(Node {
+                point: block[mid],
+                left: None,
+                right: None,
+            });
+            tree.link(parent, at);
+            pending.push((lo, lo + mid, depth + 1, Some((at, false))));
+            pending.push((lo + mid + 1, hi, depth + 1, Some((at, true))));
+        }
+        tree
+    }
+}
+
+impl<const K: usize, T> KdTree<K, T>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+    // duplicates are kept, each as its own point
+    pub fn insert(&mut self, point: [T; K]) {
+        let at = self.nodes.len();
+        let parent = self.descend(&point, |_| false).map(|(parent, depth)| {
+            let axis = depth % K;
+            (parent, point[axis] >= self.nodes[parent].point[axis])
+        });
+        self.nodes.push(Node {
+            point,
+            left: None,
+            right: None,
+        });
+        self.link(parent, at);
+    }
+    pub fn contains(&self, point: &[T; K]) -> bool {
+        let mut found = false;
+        self.descend(point, |node| {
+            found = node == point;
+            found
+        });
+        found
+    }
+    pub fn nearest(&self, target: &[T; K]) -> Option<&[T; K]> {
+        self.k_nearest(target, 1).pop()
+    }
+    // the k points closest to target, nearest first. a max-heap keeps the best k so far with
+    // the worst on top, and a subtree is skipped once its splitting plane is no closer than that
+    pub fn k_nearest(&self, target: &[T; K], k: usize) -> Vec<&[T; K]> {
+        let mut best: BinaryHeap<Candidate<T>> = BinaryHeap::max();
+        if k == 0 || self.nodes.is_empty() {
+            return Vec::new();
+        }
+        // (node, depth, a lower bound on the distance to anything under it)
+        let mut pending = alloc::vec![(0, 0, T::default())];
+        while let Some((at, depth, bound)) = pending.pop() {
+            if best.size() == k && best.peek().is_some_and(|worst| bound >= worst.0) {
+                continue;
+            }
+            let node = &self.nodes[at];
+            let candidate = Candidate(distance(target, &node.point), at);
+            if best.size() < k {
+                best.offer(candidate);
+            } else if best.peek().is_some_and(|worst| candidate < *worst) {
+                best.replace_top(candidate);
+            }
+            let axis = depth % K;
+            let (near, far) = if target[axis] < node.point[axis] {
+                (node.left, node.right)
+            } else {
+                (node.right, node.left)
+            };
+            let across = gap(target[axis], node.point[axis]);
+            // far first, so the near side comes off the stack first and tightens the bound
+            if let Some(far) = far {
+                pending.push((far, depth + 1, across * across));
+            }
+            if let Some(near) = near {
+                pending.push((near, depth + 1, bound));
+            }
+        }
+        let mut nearest: Vec<&[T; K]> = best
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Candidate(_, at)| &self.nodes[at].point)
+            .collect();
+        nearest.reverse();
+        nearest
+    }
+    // every point inside the box from min to max, both corners included, in no particular order
+    pub fn range_query(&self, min: &[T; K], max: &[T; K]) -> Vec<&[T; K]> {
+        let mut found = Vec::new();
+        let mut pending: Vec<(usize, usize)> =
+            self.nodes.first().map(|_| (0, 0)).into_iter().collect();
+        while let Some((at, depth)) = pending.pop() {
+            let node = &self.nodes[at];
+            if (0..K).all(|axis| min[axis] <= node.point[axis] && node.point[axis] <= max[axis]) {
+                found.push(&node.point);
+            }
+            let axis = depth % K;
+            if let Some(left) = node.left
+                && min[axis] < node.point[axis]
+            {
+                pending.push((left, depth + 1));
+            }
+            if let Some(right) = node.right
+                && max[axis] >= node.point[axis]
+            {
+                pending.push((right, depth + 1));
+            }
+        }
+        found
+    }
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut pending: Vec<(usize, usize)> =
+            self.nodes.first().map(|_| (0, 1)).into_iter().collect();
+        while let Some((at, depth)) = pending.pop() {
+            height = height.max(depth);
+            let node = &self.nodes[at];
+            pending.extend(
+                [node.left, node.right]
+                    .into_iter()
+                    .flatten()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+        height
+    }
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+    // the points in insertion order for inserted ones, build order for the rest
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &[T; K]> {
+        self.nodes.iter().map(|node| &node.point)
+    }
+    // follows point down from the root until stop accepts a node or the walk falls off, and
+    // returns the last node visited with its depth
+    fn descend<F>(&self, point: &[T; K], mut stop: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&[T; K]) -> bool,
+    {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let (mut at, mut depth) = (0, 0);
+        loop {
+            let node = &self.nodes[at];
+            if stop(&node.point) {
+                return Some((at, depth));
+            }
+            let axis = depth % K;
+            let next = if point[axis] < node.point[axis] {
+                node.left
+            } else {
+                node.right
+            };
+            match next {
+                Some(next) => (at, depth) = (next, depth + 1),
+                None => return Some((at, depth)),
+            }
+        }
+    }
+    fn link(&mut self, parent: Option<(usize, bool)>, child: usize) {
+        match parent {
+            Some((parent, true)) => self.nodes[parent].right = Some(child),
+            Some((parent, false)) => self.nodes[parent].left = Some(child),
+            None => {}
+        }
+    }
+}
+
+impl<const K: usize, T> Default for KdTree<K, T>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const K: usize, T> Debug for KdTree<K, T>
+where
+    T: Copy + Debug + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const K: usize, T> Extend<[T; K]> for KdTree<K, T>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    fn extend<I: IntoIterator<Item = [T; K]>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|point| self.insert(point));
+    }
+}
+
+impl<const K: usize, T> FromIterator<[T; K]> for KdTree<K, T>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = [T; K]>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
@@ -0,0 +1,280 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use crate::{algebra::Min, graph::Graph, ranges::SparseTable};
+
+// how lca queries are answered; both take O(n log n) to build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Backend {
+    // ancestors 2^j levels up for every j: lca and kth_ancestor climb in O(log n)
+    #[default]
+    BinaryLifting,
+    // the shallowest vertex between two first visits of a dfs tour is their lca, found by a
+    // sparse table in O(1); kth_ancestor binary searches the vertices at the target depth
+    EulerTour,
+}
+
+// a rooted forest as parent links, with children listed and vertices numbered in preorder; the
+// shared groundwork for the structures that answer path questions on it
+#[derive(Debug, Clone)]
+pub(crate) struct Forest {
+    pub parent: Vec<Option<usize>>,
+    pub children: Vec<Vec<usize>>,
+    pub depth: Vec<usize>,
+    pub root: Vec<usize>,
+    // vertices in dfs preorder, each tree in turn, and every vertex's position in it
+    pub preorder: Vec<usize>,
+    pub position: Vec<usize>,
+}
+
+impl Forest {
+    pub fn new(parents: &[Option<usize>]) -> Self {
+        let count = parents.len();
+        let mut children = vec![Vec::new(); count];
+        for (vertex, &parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                assert!(
+                    parent < count,
+                    "parent {} out of range for {} vertices",
+                    parent,
+                    count
+                );
+                children[parent].push(vertex);
+            }
+        }
+        let (mut depth, mut root) = (vec![0; count], vec![usize::MAX; count]);
+        let (mut preorder, mut position) = (Vec::with_capacity(count), vec![0; count]);
+        for start in (0..count).filter(|&vertex| parents[vertex].is_none()) {
+            let mut stack = vec![start];
+            root[start] = start;
+            while let Some(vertex) = stack.pop() {
+                position[vertex] = preorder.len();
+                preorder.push(vertex);
+                for &child in children[vertex].iter().rev() {
+                    depth[child] = depth[vertex] + 1;
+                    root[child] = start;
+                    stack.push(child);
+                }
+            }
+        }
+        // whatever no root reached hangs off a cycle
+        if let Some(vertex) = (0..count).find(|&vertex| root[vertex] == usize::MAX) {
+            panic!("parents form a cycle through vertex {}", vertex);
+        }
+        Self {
+            parent: parents.to_vec(),
+            children,
+            depth,
+            root,
+            preorder,
+            position,
+        }
+    }
+    pub fn check(&self, vertex: usize) {
+        assert!(
+            vertex < self.parent.len(),
+            "vertex {} out of range for {} vertices",
+            vertex,
+            self.parent.len()
+        );
+    }
+}
+
+// the parent links of a breadth-first tree from root; only the vertices root reaches get one,
+// and edges that would close a cycle are left out
+pub fn parents_from<W>(graph: &Graph<W>, root: usize) -> Vec<Option<usize>> {
+    let mut parents = vec![None; graph.vertex_count()];
+    let mut seen = vec![false; graph.vertex_count()];
+    let mut queue = VecDeque::from([root]);
+    seen[root] = true;
+    while let Some(vertex) = queue.pop_front() {
+        for next in graph.neighbors(vertex) {
+            if !seen[next] {
+                seen[next] = true;
+                parents[next] = Some(vertex);
+                queue.push_back(next);
+            }
+        }
+    }
+    parents
+}
+
+type Tour = SparseTable<
+    Min<(usize, usize)>,
+    fn(&Min<(usize, usize)>, &Min<(usize, usize)>) -> Min<(usize, usize)>,
+>;
+
+enum Tables {
+    // up[j][v] is 2^j levels above v, stopping at the root
+    Lifting(Vec<Vec<usize>>),
+    Euler {
+        // (depth, vertex) along the tour, and where each vertex first shows up in it
+        tour: Tour,
+        first: Vec<usize>,
+        // levels[d] holds the vertices at depth d in preorder
+        levels: Vec<Vec<usize>>,
+    },
+}
+
+// lowest common ancestors on a rooted forest given as parent links, None for the roots; vertices
+// in different trees have no common ancestor, so queries across trees answer None
+pub struct Lca {
+    forest: Forest,
+    tables: Tables,
+}
+
+impl Lca {
+    pub fn new(parents: &[Option<usize>], backend: Backend) -> Self {
+        let forest = Forest::new(parents);
+        let tables = match backend {
+            Backend::BinaryLifting => Self::lifting(&forest),
+            Backend::EulerTour => Self::euler(&forest),
+        };
+        Self { forest, tables }
+    }
+    pub fn from_graph<W>(graph: &Graph<W>, root: usize, backend: Backend) -> Self {
+        Self::new(&parents_from(graph, root), backend)
+    }
+    fn lifting(forest: &Forest) -> Tables {
+        let count = forest.parent.len();
+        let parents: Vec<usize> = (0..count)
+            .map(|vertex| forest.parent[vertex].unwrap_or(vertex))
+            .collect();
+        let height = forest
+            .depth
+            .iter()
+            .max()
+            .map_or(0, |&deepest| deepest.max(1).ilog2() + 1);
+        let mut up = vec![parents];
+        for j in 1..height as usize {
+            let below = &up[j - 1];
+            let level = (0..count).map(|vertex| below[below[vertex]]).collect();
+            up.push(level);
+        }
+        Tables::Lifting(up)
+    }
+    fn euler(forest: &Forest) -> Tables {
+        let count = forest.parent.len();
+        let (mut tour, mut first) = (Vec::with_capacity(2 * count), vec![0; count]);
+        let mut levels: Vec<Vec<usize>> = Vec::new();
+        for &vertex in &forest.preorder {
+            let depth = forest.depth[vertex];
+            if levels.len() <= depth {
+                levels.push(Vec::new());
+            }
+            levels[depth].push(vertex);
+        }
+        // each frame is a vertex and how many of its children have been toured so far
+        for start in (0..count).filter(|&vertex| forest.parent[vertex].is_none()) {
+            let mut stack = vec![(start, 0)];
+            first[start] = tour.len();
+            while let Some(frame) = stack.last_mut() {
+                let (vertex, next) = *frame;
+                tour.push(Min((forest.depth[vertex], vertex)));
+                match forest.children[vertex].get(next) {
+                    Some(&child) => {
+                        frame.1 += 1;
+                        first[child] = tour.len();
+                        stack.push((child, 0));
+                    }
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        Tables::Euler {
+            tour: SparseTable::over(&tour),
+            first,
+            levels,
+        }
+    }
+}
+
+impl Lca {
+    pub fn is_empty(&self) -> bool {
+        self.forest.parent.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.forest.parent.len()
+    }
+    pub fn backend(&self) -> Backend {
+        match self.tables {
+            Tables::Lifting(_) => Backend::BinaryLifting,
+            Tables::Euler { .. } => Backend::EulerTour,
+        }
+    }
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.forest.check(vertex);
+        self.forest.parent[vertex]
+    }
+    // edges up to the root, which sits at depth 0
+    pub fn depth(&self, vertex: usize) -> usize {
+        self.forest.check(vertex);
+        self.forest.depth[vertex]
+    }
+    pub fn root(&self, vertex: usize) -> usize {
+        self.forest.check(vertex);
+        self.forest.root[vertex]
+    }
+    // the vertex k edges above, or None past the root; kth_ancestor(v, 0) is v itself
+    pub fn kth_ancestor(&self, vertex: usize, k: usize) -> Option<usize> {
+        let depth = self.depth(vertex);
+        if k > depth {
+            return None;
+        }
+        match &self.tables {
+            Tables::Lifting(up) => Some(Self::climb(up, vertex, k)),
+            // the ancestor is the last vertex at its depth to come before this one in preorder,
+            // since any later one would sit inside the ancestor's subtree at the same depth
+            Tables::Euler { levels, .. } => {
+                let level = &levels[depth - k];
+                let position = &self.forest.position;
+                let at = level.partition_point(|&other| position[other] <= position[vertex]);
+                Some(level[at - 1])
+            }
+        }
+    }
+    pub fn lca(&self, u: usize, v: usize) -> Option<usize> {
+        if self.root(u) != self.root(v) {
+            return None;
+        }
+        match &self.tables {
+            Tables::Lifting(up) => {
+                let (mut u, mut v) = (u, v);
+                let (du, dv) = (self.forest.depth[u], self.forest.depth[v]);
+                if du > dv {
+                    u = Self::climb(up, u, du - dv);
+                } else {
+                    v = Self::climb(up, v, dv - du);
+                }
+                if u == v {
+                    return Some(u);
+                }
+                for level in up.iter().rev() {
+                    if level[u] != level[v] {
+                        (u, v) = (level[u], level[v]);
+                    }
+                }
+                self.forest.parent[u]
+            }
+            Tables::Euler { tour, first, .. } => {
+                let (a, b) = (first[u].min(first[v]), first[u].max(first[v]));
+                tour.query(a..=b).map(|Min((_, vertex))| vertex)
+            }
+        }
+    }
+    // edges on the path between u and v, None when they are in different trees
+    pub fn distance(&self, u: usize, v: usize) -> Option<usize> {
+        let ancestor = self.lca(u, v)?;
+        let depth = &self.forest.depth;
+        Some(depth[u] + depth[v] - 2 * depth[ancestor])
+    }
+    fn climb(up: &[Vec<usize>], mut vertex: usize, k: usize) -> usize {
+        for (j, level) in up.iter().enumerate() {
+            if k >> j & 1 == 1 {
+                vertex = level[vertex];
+            }
+        }
+        vertex
+    }
+}
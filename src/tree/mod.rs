@@ -1,3 +1,16 @@
+pub mod arena_avl;
 pub mod avl;
+pub mod binary;
+pub mod bst;
+pub mod generic;
 pub mod heap;
-pub mod trie;
\ No newline at end of file
+pub mod hld;
+pub mod huffman;
+pub mod kd;
+pub mod lca;
+pub mod multiset;
+pub mod quadtree;
+pub mod threaded;
+pub mod trie;
+pub mod two_three_four;
+pub mod veb;
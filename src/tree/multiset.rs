@@ -0,0 +1,414 @@
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+
+use crate::tree::avl::{self, AVLTree};
+
+struct Entry<T>
+where
+    T: Ord,
+{
+    data: T,
+    count: usize,
+}
+
+impl<T> PartialEq for Entry<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T> Eq for Entry<T> where T: Ord {}
+
+impl<T> PartialOrd for Entry<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
+pub struct AVLMultiSet<T>
+where
+    T: Ord,
+{
+    tree: AVLTree<Entry<T>>,
+    size: usize,
+}
+
+impl<T> Default for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AVLMultiSet<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            tree: AVLTree::new(),
+            size: 0,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> AVLMultiSet<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn distinct(&self) -> usize {
+        self.tree.size()
+    }
+    pub fn insert(&mut self, data: T) {
+        if let Some(entry) = self.tree.find_by_mut(|probe| data.cmp(&probe.data)) {
+            entry.count += 1;
+        } else {
+            self.tree.insert(Entry { data, count: 1 });
+        }
+        self.size += 1;
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| self.insert(item));
+    }
+    pub fn contains<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.count(data) > 0
+    }
+    pub fn count<Q>(&self, data: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.tree
+            .find_by(|probe| data.cmp(probe.data.borrow()))
+            .map_or(0, |entry| entry.count)
+    }
+    pub fn remove_one<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self.tree.find_by_mut(|probe| data.cmp(probe.data.borrow())) {
+            Some(entry) if entry.count > 1 => entry.count -= 1,
+            Some(_) => {
+                self.tree.remove_by(|probe| data.cmp(probe.data.borrow()));
+            }
+            None => return false,
+        }
+        self.size -= 1;
+        true
+    }
+    pub fn remove_all<Q>(&mut self, data: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let removed = self
+            .tree
+            .remove_by(|probe| data.cmp(probe.data.borrow()))
+            .map_or(0, |entry| entry.count);
+        self.size -= removed;
+        removed
+    }
+    pub fn get_min(&self) -> Option<&T> {
+        self.tree.get_min().map(|entry| &entry.data)
+    }
+    pub fn get_max(&self) -> Option<&T> {
+        self.tree.get_max().map(|entry| &entry.data)
+    }
+    pub fn clear(&mut self) {
+        self.tree.clear();
+        self.size = 0;
+    }
+}
+
+impl<T> PartialEq for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for AVLMultiSet<T> where T: Ord {}
+
+impl<T> PartialOrd for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Hash for AVLMultiSet<T>
+where
+    T: Hash + Ord,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T> Debug for AVLMultiSet<T>
+where
+    T: Debug + Ord,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Clone for AVLMultiSet<T>
+where
+    T: Clone + Ord,
+{
+    fn clone(&self) -> Self {
+        let mut set = Self::new();
+        for (data, count) in self.iter_counts() {
+            set.tree.insert(Entry {
+                data: data.clone(),
+                count,
+            });
+        }
+        set.size = self.size;
+        set
+    }
+}
+
+pub struct Iter<'a, T>
+where
+    T: Ord,
+{
+    entries: avl::Iter<'a, Entry<T>>,
+    current: Option<(&'a T, usize)>,
+    remaining: usize,
+}
+
+pub struct Counts<'a, T>
+where
+    T: Ord,
+{
+    entries: avl::Iter<'a, Entry<T>>,
+}
+
+pub struct IntoIter<T>
+where
+    T: Ord,
+{
+    entries: avl::IntoIter<Entry<T>>,
+    current: Option<(T, usize)>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current.as_mut() {
+                Some((data, count)) if *count > 0 => {
+                    *count -= 1;
+                    self.remaining -= 1;
+                    return Some(*data);
+                }
+                _ => {
+                    let entry = self.entries.next()?;
+                    self.current = Some((&entry.data, entry.count));
+                }
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: Ord {}
+
+impl<'a, T> Iterator for Counts<'a, T>
+where
+    T: Ord,
+{
+    type Item = (&'a T, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| (&entry.data, entry.count))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.entries.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Counts<'a, T> where T: Ord {}
+
+impl<'a, T> FusedIterator for Counts<'a, T> where T: Ord {}
+
+impl<T> Iterator for IntoIter<T>
+where
+    T: Ord + Clone,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current.take() {
+                Some((data, 1)) => {
+                    self.remaining -= 1;
+                    return Some(data);
+                }
+                Some((data, count)) => {
+                    self.current = Some((data.clone(), count - 1));
+                    self.remaining -= 1;
+                    return Some(data);
+                }
+                None => {
+                    let entry = self.entries.next()?;
+                    self.current = Some((entry.data, entry.count));
+                }
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> where T: Ord + Clone {}
+
+impl<T> FusedIterator for IntoIter<T> where T: Ord + Clone {}
+
+impl<T> AVLMultiSet<T>
+where
+    T: Ord,
+{
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            entries: self.tree.iter(),
+            current: None,
+            remaining: self.size,
+        }
+    }
+    pub fn iter_counts(&self) -> Counts<'_, T> {
+        Counts {
+            entries: self.tree.iter(),
+        }
+    }
+}
+
+impl<T> IntoIterator for AVLMultiSet<T>
+where
+    T: Ord + Clone,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            remaining: self.size,
+            entries: self.tree.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<T> Extend<T> for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        AVLMultiSet::extend(self, iter);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for AVLMultiSet<T>
+where
+    T: Copy + Ord + 'a,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        AVLMultiSet::extend(self, iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for AVLMultiSet<T>
+where
+    T: Ord,
+{
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<AVLMultiSet<T>> for Vec<T>
+where
+    T: Clone + Ord,
+{
+    fn from(tree: AVLMultiSet<T>) -> Self {
+        tree.into_iter().collect()
+    }
+}
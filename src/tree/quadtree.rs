@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+
+// an axis-aligned box, both edges included
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub min: [f64; 2],
+    pub max: [f64; 2],
+}
+
+impl Rect {
+    pub fn new(min: [f64; 2], max: [f64; 2]) -> Self {
+        assert!(
+            min[0] <= max[0] && min[1] <= max[1],
+            "rect min must not exceed max"
+        );
+        Self { min, max }
+    }
+    pub fn contains(&self, point: &[f64; 2]) -> bool {
+        (0..2).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+    }
+    pub fn intersects(&self, other: &Rect) -> bool {
+        (0..2).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+    }
+    pub fn center(&self) -> [f64; 2] {
+        [
+            (self.min[0] + self.max[0]) / 2.0,
+            (self.min[1] + self.max[1]) / 2.0,
+        ]
+    }
+    // squared distance from point to the nearest point of the box, 0 inside it
+    fn distance_to(&self, point: &[f64; 2]) -> f64 {
+        (0..2)
+            .map(|axis| {
+                let gap = (self.min[axis] - point[axis])
+                    .max(point[axis] - self.max[axis])
+                    .max(0.0);
+                gap * gap
+            })
+            .sum()
+    }
+    // the quadrant a point falls in, as an offset from the first child: bit 0 is east, bit 1
+    // north, and points on a midline go east or north
+    fn quadrant(&self, point: &[f64; 2]) -> usize {
+        let center = self.center();
+        usize::from(point[0] >= center[0]) + 2 * usize::from(point[1] >= center[1])
+    }
+    fn split(&self) -> [Rect; 4] {
+        let [cx, cy] = self.center();
+        let ([x0, y0], [x1, y1]) = (self.min, self.max);
+        [
+            Rect {
+                min: [x0, y0],
+                max: [cx, cy],
+            },
+            Rect {
+                min: [cx, y0],
+                max: [x1, cy],
+            },
+            Rect {
+                min: [x0, cy],
+                max: [cx, y1],
+            },
+            Rect {
+                min: [cx, cy],
+                max: [x1, y1],
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Node {
+    pub(crate) bounds: Rect,
+    pub(crate) points: Vec<[f64; 2]>,
+    // the four children sit next to each other in the node list, starting here
+    pub(crate) children: Option<usize>,
+}
+
+// past this depth a leaf keeps growing instead of splitting, since no amount of splitting
+// separates a pile of identical points
+const MAX_DEPTH: usize = 32;
+
+// a region quadtree: the space is fixed up front and every node stands for a quarter of its
+// parent's square, whatever the points in it. a leaf splits once it holds more than capacity
+// points, and only leaves hold points
+#[derive(Debug, Clone)]
+pub struct QuadTree {
+    pub(crate) nodes: Vec<Node>,
+    capacity: usize,
+    size: usize,
+}
+
+impl QuadTree {
+    pub fn new(bounds: Rect, capacity: usize) -> Self {
+        assert!(capacity > 0, "a node must hold at least one point");
+        Self {
+            nodes: alloc::vec![Node {
+                bounds,
+                points: Vec::new(),
+                children: None
+            }],
+            capacity,
+            size: 0,
+        }
+    }
+}
+
+impl QuadTree {
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn bounds(&self) -> Rect {
+        self.nodes[0].bounds
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+    // false, with nothing stored, for a point outside the bounds
+    pub fn insert(&mut self, point: [f64; 2]) -> bool {
+        if !self.bounds().contains(&point) {
+            return false;
+        }
+        let (leaf, depth) = self.leaf_for(&point);
+        self.nodes[leaf].points.push(point);
+        self.size += 1;
+        if self.nodes[leaf].points.len() > self.capacity && depth < MAX_DEPTH {
+            self.subdivide(leaf, depth);
+        }
+        true
+    }
+    pub fn contains(&self, point: &[f64; 2]) -> bool {
+        self.bounds().contains(point) && self.nodes[self.leaf_for(point).0].points.contains(point)
+    }
+    // every point inside area, edges included, in no particular order
+    pub fn query_rect(&self, area: &Rect) -> Vec<&[f64; 2]> {
+        self.query(
+            |bounds| bounds.intersects(area),
+            |point| area.contains(point),
+        )
+    }
+    pub fn query_circle(&self, center: &[f64; 2], radius: f64) -> Vec<&[f64; 2]> {
+        let reach = radius * radius;
+        self.query(
+            |bounds| bounds.distance_to(center) <= reach,
+            |point| {
+                let (dx, dy) = (point[0] - center[0], point[1] - center[1]);
+                dx * dx + dy * dy <= reach
+            },
+        )
+    }
+    // the deepest leaf, with the root at depth 0
+    pub fn depth(&self) -> usize {
+        let mut deepest = 0;
+        let mut pending = alloc::vec![(0, 0)];
+        while let Some((at, depth)) = pending.pop() {
+            deepest = deepest.max(depth);
+            if let Some(first) = self.nodes[at].children {
+                pending.extend((first..first + 4).map(|child| (child, depth + 1)));
+            }
+        }
+        deepest
+    }
+    pub fn clear(&mut self) {
+        let bounds = self.bounds();
+        self.nodes.clear();
+        self.nodes.push(Node {
+            bounds,
+            points: Vec::new(),
+            children: None,
+        });
+        self.size = 0;
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &[f64; 2]> {
+        self.nodes.iter().flat_map(|node| &node.points)
+    }
+    fn leaf_for(&self, point: &[f64; 2]) -> (usize, usize) {
+        let (mut at, mut depth) = (0, 0);
+        while let Some(first) = self.nodes[at].children {
+            at = first + self.nodes[at].bounds.quadrant(point);
+            depth += 1;
+        }
+        (at, depth)
+    }
+    // hands the leaf's points down to four new children; with every point in one quadrant the
+    // child that got them is over capacity too, so it splits in turn
+    fn subdivide(&mut self, leaf: usize, depth: usize) {
+        let mut pending = alloc::vec![(leaf, depth)];
+        while let Some((at, depth)) = pending.pop() {
+            if self.nodes[at].points.len() <= self.capacity || depth >= MAX_DEPTH {
+                continue;
+            }
+            let first = self.nodes.len();
+            let bounds = self.nodes[at].bounds;
+            self.nodes.extend(bounds.split().map(|bounds| Node {
+                bounds,
+                points: Vec::new(),
+                children: None,
+            }));
+            for point in core::mem::take(&mut self.nodes[at].points) {
+                self.nodes[first + bounds.quadrant(&point)]
+                    .points
+                    .push(point);
+            }
+            self.nodes[at].children = Some(first);
+            pending.extend((first..first + 4).map(|child| (child, depth + 1)));
+        }
+    }
+    fn query<V, P>(&self, visit: V, keep: P) -> Vec<&[f64; 2]>
+    where
+        V: Fn(&Rect) -> bool,
+        P: Fn(&[f64; 2]) -> bool,
+    {
+        let mut found = Vec::new();
+        let mut pending = alloc::vec![0];
+        while let Some(at) = pending.pop() {
+            let node = &self.nodes[at];
+            if !visit(&node.bounds) {
+                continue;
+            }
+            found.extend(node.points.iter().filter(|point| keep(point)));
+            if let Some(first) = node.children {
+                pending.extend(first..first + 4);
+            }
+        }
+        found
+    }
+}
+
+impl Extend<[f64; 2]> for QuadTree {
+    fn extend<I: IntoIterator<Item = [f64; 2]>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|point| {
+            self.insert(point);
+        });
+    }
+}
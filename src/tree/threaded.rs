@@ -0,0 +1,284 @@
+use alloc::vec::Vec;
+use core::{borrow::Borrow, cmp::Ordering, fmt::Debug, iter::FusedIterator};
+
+use crate::arena::{Arena, Handle};
+
+// where a right link leads: a real child, or a thread up to the in-order successor, which the
+// largest item lacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Right {
+    Child(Handle),
+    Thread(Option<Handle>),
+}
+
+#[derive(Clone)]
+struct Node<T> {
+    data: T,
+    left: Option<Handle>,
+    right: Right,
+}
+
+// an unbalanced binary search tree whose empty right links are not empty: each points to the
+// in-order successor instead. walking in order then needs no stack and no parent links, only
+// the current node, since after a node comes either the leftmost node of its right subtree or
+// the node its thread names. nodes live in an Arena, so the threads are plain handles
+#[derive(Clone)]
+pub struct ThreadedBST<T>
+where
+    T: Ord,
+{
+    nodes: Arena<Node<T>>,
+    root: Option<Handle>,
+}
+
+impl<T> Default for ThreadedBST<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ThreadedBST<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            root: None,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T> ThreadedBST<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+    // false, leaving the tree as it was, when an equal item is already in it. a new left child
+    // threads to its parent, which comes right after it; a new right child takes over its
+    // parent's thread, since it now comes between the parent and the parent's old successor
+    pub fn insert(&mut self, data: T) -> bool {
+        let Some(mut at) = self.root else {
+            self.root = Some(self.nodes.alloc(Node {
+                data,
+                left: None,
+                right: Right::Thread(None),
+            }));
+            return true;
+        };
+        loop {
+            let node = &self.nodes[at];
+            match data.cmp(&node.data) {
+                Ordering::Equal => return false,
+                Ordering::Less => match node.left {
+                    Some(left) => at = left,
+                    None => {
+                        let child = self.nodes.alloc(Node {
+                            data,
+                            left: None,
+                            right: Right::Thread(Some(at)),
+                        });
+                        self.nodes[at].left = Some(child);
+                        return true;
+                    }
+                },
+                Ordering::Greater => match node.right {
+                    Right::Child(right) => at = right,
+                    Right::Thread(successor) => {
+                        let child = self.nodes.alloc(Node {
+                            data,
+                            left: None,
+                            right: Right::Thread(successor),
+                        });
+                        self.nodes[at].right = Right::Child(child);
+                        return true;
+                    }
+                },
+            }
+        }
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| {
+            self.insert(item);
+        });
+    }
+    pub fn contains<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(data).is_some()
+    }
+    pub fn get<Q>(&self, data: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = self.root;
+        while let Some(at) = link {
+            let node = &self.nodes[at];
+            link = match data.cmp(node.data.borrow()) {
+                Ordering::Equal => return Some(&node.data),
+                Ordering::Less => node.left,
+                Ordering::Greater => match node.right {
+                    Right::Child(right) => Some(right),
+                    Right::Thread(_) => None,
+                },
+            };
+        }
+        None
+    }
+    pub fn first(&self) -> Option<&T> {
+        self.root.map(|root| &self.nodes[self.leftmost(root)].data)
+    }
+    pub fn last(&self) -> Option<&T> {
+        let mut at = self.root?;
+        while let Right::Child(right) = self.nodes[at].right {
+            at = right;
+        }
+        Some(&self.nodes[at].data)
+    }
+    // the smallest item greater than the given one, in or out of the tree
+    pub fn successor<Q>(&self, data: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (mut link, mut best) = (self.root, None);
+        while let Some(at) = link {
+            let node = &self.nodes[at];
+            link = match node.data.borrow() > data {
+                true => {
+                    best = Some(&node.data);
+                    node.left
+                }
+                false => match node.right {
+                    Right::Child(right) => Some(right),
+                    Right::Thread(_) => None,
+                },
+            };
+        }
+        best
+    }
+    pub fn height(&self) -> usize {
+        let mut deepest = 0;
+        let mut stack: Vec<(Handle, usize)> = self.root.map(|root| (root, 1)).into_iter().collect();
+        while let Some((at, depth)) = stack.pop() {
+            deepest = deepest.max(depth);
+            let node = &self.nodes[at];
+            stack.extend(node.left.map(|left| (left, depth + 1)));
+            if let Right::Child(right) = node.right {
+                stack.push((right, depth + 1));
+            }
+        }
+        deepest
+    }
+    // in order, holding only the next node: O(1) extra space however deep the tree is
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            tree: self,
+            next: self.root.map(|root| self.leftmost(root)),
+            remaining: self.size(),
+        }
+    }
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = None;
+    }
+    fn leftmost(&self, mut at: Handle) -> Handle {
+        while let Some(left) = self.nodes[at].left {
+            at = left;
+        }
+        at
+    }
+}
+
+impl<T> Debug for ThreadedBST<T>
+where
+    T: Ord + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for ThreadedBST<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        ThreadedBST::extend(self, iter);
+    }
+}
+
+impl<T> FromIterator<T> for ThreadedBST<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+pub struct Iter<'a, T>
+where
+    T: Ord,
+{
+    tree: &'a ThreadedBST<T>,
+    next: Option<Handle>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        let at = self.next?;
+        let node = &self.tree.nodes[at];
+        self.next = match node.right {
+            Right::Child(right) => Some(self.tree.leftmost(right)),
+            Right::Thread(successor) => successor,
+        };
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: Ord {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: Ord {}
+
+impl<'a, T> IntoIterator for &'a ThreadedBST<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,161 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+// nodes live in one arena and refer to each other by index, which lets automata built on the
+// trie (see string::aho_corasick) hang extra links off each node in side tables
+pub(crate) const ROOT: usize = 0;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: BTreeMap<u8, usize>,
+    terminal: bool,
+}
+
+// node numbering depends on insertion order, so equality is left to comparing keys()
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    size: usize,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self {
+            nodes: alloc::vec![Node::default()],
+            size: 0,
+        }
+    }
+    pub fn from<I, K>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        let mut trie = Self::new();
+        for key in iter {
+            trie.insert(key);
+        }
+        trie
+    }
+}
+
+impl Trie {
+    // walks key from the root, creating missing nodes, and returns the node it ends on
+    fn insert_path(&mut self, key: &[u8]) -> usize {
+        let mut node = ROOT;
+        for &byte in key {
+            node = match self.nodes[node].children.get(&byte) {
+                Some(&child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(byte, child);
+                    child
+                }
+            };
+        }
+        node
+    }
+    // marks key as stored, returning the node it ends on and whether it is new
+    pub(crate) fn insert_key(&mut self, key: &[u8]) -> (usize, bool) {
+        let node = self.insert_path(key);
+        let inserted = !self.nodes[node].terminal;
+        self.nodes[node].terminal = true;
+        self.size += inserted as usize;
+        (node, inserted)
+    }
+    fn find(&self, key: &[u8]) -> Option<usize> {
+        key.iter().try_fold(ROOT, |node, byte| self.child(node, *byte))
+    }
+    pub(crate) fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        self.nodes[node].children.get(&byte).copied()
+    }
+    pub(crate) fn children(&self, node: usize) -> impl Iterator<Item = (u8, usize)> + '_ {
+        self.nodes[node].children.iter().map(|(&byte, &child)| (byte, child))
+    }
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl Trie {
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn insert<K>(&mut self, key: K) -> bool
+    where
+        K: AsRef<[u8]>,
+    {
+        self.insert_key(key.as_ref()).1
+    }
+    pub fn contains<K>(&self, key: K) -> bool
+    where
+        K: AsRef<[u8]>,
+    {
+        self.find(key.as_ref()).is_some_and(|node| self.nodes[node].terminal)
+    }
+    pub fn starts_with<K>(&self, prefix: K) -> bool
+    where
+        K: AsRef<[u8]>,
+    {
+        self.find(prefix.as_ref()).is_some()
+    }
+    // every stored key beginning with prefix, in lexicographic order
+    pub fn keys_with_prefix<K>(&self, prefix: K) -> Vec<Vec<u8>>
+    where
+        K: AsRef<[u8]>,
+    {
+        let mut keys = Vec::new();
+        let Some(start) = self.find(prefix.as_ref()) else {
+            return keys;
+        };
+        let mut stack = alloc::vec![(start, prefix.as_ref().to_vec())];
+        while let Some((node, key)) = stack.pop() {
+            // children go on the stack in reverse so the smallest byte is expanded first
+            for (byte, child) in self.children(node).collect::<Vec<_>>().into_iter().rev() {
+                let mut extended = key.clone();
+                extended.push(byte);
+                stack.push((child, extended));
+            }
+            if self.nodes[node].terminal {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+    pub fn keys(&self) -> Vec<Vec<u8>> {
+        self.keys_with_prefix([])
+    }
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[ROOT] = Node::default();
+        self.size = 0;
+    }
+}
+
+impl<K> FromIterator<K> for Trie
+where
+    K: AsRef<[u8]>,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+impl<K> Extend<K> for Trie
+where
+    K: AsRef<[u8]>,
+{
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
@@ -0,0 +1,591 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{borrow::Borrow, cmp::Ordering, fmt::Debug, iter::FusedIterator};
+
+// a node holds one, two or three keys in order and, unless it is a leaf, one more child than
+// keys: child i holds whatever lies between key i - 1 and key i
+#[derive(Clone)]
+enum Node<T> {
+    Two {
+        key: T,
+        children: Option<Box<[Node<T>; 2]>>,
+    },
+    Three {
+        keys: [T; 2],
+        children: Option<Box<[Node<T>; 3]>>,
+    },
+    Four {
+        keys: [T; 3],
+        children: Option<Box<[Node<T>; 4]>>,
+    },
+}
+
+impl<T> Node<T> {
+    fn leaf(key: T) -> Self {
+        Node::Two {
+            key,
+            children: None,
+        }
+    }
+    fn keys(&self) -> &[T] {
+        match self {
+            Node::Two { key, .. } => core::slice::from_ref(key),
+            Node::Three { keys, .. } => keys,
+            Node::Four { keys, .. } => keys,
+        }
+    }
+    fn children(&self) -> &[Node<T>] {
+        match self {
+            Node::Two { children, .. } => children.as_deref().map_or(&[], |c| c),
+            Node::Three { children, .. } => children.as_deref().map_or(&[], |c| c),
+            Node::Four { children, .. } => children.as_deref().map_or(&[], |c| c),
+        }
+    }
+    fn is_four(&self) -> bool {
+        matches!(self, Node::Four { .. })
+    }
+    // a four-node comes apart into two two-nodes and the middle key that goes up between them
+    fn split(self) -> (Node<T>, T, Node<T>) {
+        let Node::Four {
+            keys: [a, b, c],
+            children,
+        } = self
+        else {
+            unreachable!()
+        };
+        match children.map(|children| *children) {
+            None => (Node::leaf(a), b, Node::leaf(c)),
+            Some([w, x, y, z]) => (
+                Node::Two {
+                    key: a,
+                    children: Some(Box::new([w, x])),
+                },
+                b,
+                Node::Two {
+                    key: c,
+                    children: Some(Box::new([y, z])),
+                },
+            ),
+        }
+    }
+    // the node as plain lists and back, for the steps that move keys and children around
+    fn into_parts(self) -> (Vec<T>, Vec<Node<T>>) {
+        fn flatten<T, const N: usize>(children: Option<Box<[Node<T>; N]>>) -> Vec<Node<T>> {
+            children.map_or(Vec::new(), |children| Vec::from(*children))
+        }
+        match self {
+            Node::Two { key, children } => (alloc::vec![key], flatten(children)),
+            Node::Three { keys, children } => (Vec::from(keys), flatten(children)),
+            Node::Four { keys, children } => (Vec::from(keys), flatten(children)),
+        }
+    }
+    fn from_parts(keys: Vec<T>, children: Vec<Node<T>>) -> Self {
+        debug_assert!(children.is_empty() || children.len() == keys.len() + 1);
+        let count = keys.len();
+        let mut keys = keys.into_iter();
+        match count {
+            1 => Node::Two {
+                key: keys.next().unwrap(),
+                children: children.try_into().ok(),
+            },
+            2 => Node::Three {
+                keys: core::array::from_fn(|_| keys.next().unwrap()),
+                children: children.try_into().ok(),
+            },
+            3 => Node::Four {
+                keys: core::array::from_fn(|_| keys.next().unwrap()),
+                children: children.try_into().ok(),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Ord,
+{
+    // the node is never a four-node here: the root gets split before the walk starts and each
+    // child before the walk goes into it, so a key coming up from below always has room
+    fn insert(self, key: T) -> (Node<T>, bool) {
+        let (mut keys, mut children) = self.into_parts();
+        let mut at = match keys.binary_search(&key) {
+            Ok(_) => return (Node::from_parts(keys, children), false),
+            Err(at) => at,
+        };
+        if children.is_empty() {
+            keys.insert(at, key);
+            return (Node::from_parts(keys, children), true);
+        }
+        if children[at].is_four() {
+            let (left, middle, right) = children.remove(at).split();
+            let order = key.cmp(&middle);
+            keys.insert(at, middle);
+            children.splice(at..at, [left, right]);
+            match order {
+                Ordering::Equal => return (Node::from_parts(keys, children), false),
+                Ordering::Less => {}
+                Ordering::Greater => at += 1,
+            }
+        }
+        let (child, inserted) = children.remove(at).insert(key);
+        children.insert(at, child);
+        (Node::from_parts(keys, children), inserted)
+    }
+}
+
+// a search tree whose nodes hold one to three keys and whose leaves all sit at the same depth.
+// insertion is top-down: any four-node met on the way down is split first, its middle key
+// moving up into the parent, so the new key always lands in a leaf with room for it and the
+// tree only ever grows taller by splitting the root
+#[derive(Clone)]
+pub struct TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    root: Option<Node<T>>,
+    size: usize,
+}
+
+impl<T> Default for TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            size: 0,
+        }
+    }
+    pub fn from<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T> TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    // levels of nodes, which is the same along every path down
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut at = self.root.as_ref();
+        while let Some(node) = at {
+            height += 1;
+            at = node.children().first();
+        }
+        height
+    }
+    // how many two-, three- and four-nodes the tree holds
+    pub fn node_counts(&self) -> [usize; 3] {
+        let mut counts = [0; 3];
+        let mut stack: Vec<&Node<T>> = self.root.iter().collect();
+        while let Some(node) = stack.pop() {
+            counts[node.keys().len() - 1] += 1;
+            stack.extend(node.children());
+        }
+        counts
+    }
+    // false when an equal key is already in it, though four-nodes on the way down get split
+    // either way
+    pub fn insert(&mut self, key: T) -> bool {
+        let root = match self.root.take() {
+            None => {
+                self.root = Some(Node::leaf(key));
+                self.size += 1;
+                return true;
+            }
+            Some(root) if root.is_four() => {
+                let (left, middle, right) = root.split();
+                Node::Two {
+                    key: middle,
+                    children: Some(Box::new([left, right])),
+                }
+            }
+            Some(root) => root,
+        };
+        let (root, inserted) = root.insert(key);
+        self.root = Some(root);
+        self.size += inserted as usize;
+        inserted
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|key| {
+            self.insert(key);
+        });
+    }
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+    pub fn get<Q>(&self, key: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut at = self.root.as_ref();
+        while let Some(node) = at {
+            let keys = node.keys();
+            match keys.binary_search_by(|probe| probe.borrow().cmp(key)) {
+                Ok(found) => return Some(&keys[found]),
+                Err(below) => at = node.children().get(below),
+            }
+        }
+        None
+    }
+    pub fn first(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(child) = node.children().first() {
+            node = child;
+        }
+        node.keys().first()
+    }
+    pub fn last(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(child) = node.children().last() {
+            node = child;
+        }
+        node.keys().last()
+    }
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.size,
+        };
+        if let Some(root) = &self.root {
+            iter.descend(root);
+        }
+        iter
+    }
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+    // the same keys as a red-black tree: a two-node becomes a black node, a three-node a black
+    // node with its smaller key as a red left child, and a four-node a black node between two
+    // red children. the black height comes out as this tree's height
+    pub fn into_red_black(self) -> RedBlackTree<T> {
+        RedBlackTree {
+            root: self.root.map(RedBlackNode::from_node),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Debug for TwoThreeFourTree<T>
+where
+    T: Ord + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        TwoThreeFourTree::extend(self, iter);
+    }
+}
+
+impl<T> FromIterator<T> for TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter)
+    }
+}
+
+// each frame is a node and the index of the next of its keys to yield; child i has been
+// walked by the time key i comes up
+pub struct Iter<'a, T> {
+    stack: Vec<(&'a Node<T>, usize)>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn descend(&mut self, mut node: &'a Node<T>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children().first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let (node, at) = *frame;
+            if at == node.keys().len() {
+                self.stack.pop();
+                continue;
+            }
+            frame.1 += 1;
+            if let Some(child) = node.children().get(at + 1) {
+                self.descend(child);
+            }
+            self.remaining -= 1;
+            return Some(&node.keys()[at]);
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a TwoThreeFourTree<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+#[derive(Clone)]
+struct RedBlackNode<T> {
+    data: T,
+    color: Color,
+    left: Option<Box<RedBlackNode<T>>>,
+    right: Option<Box<RedBlackNode<T>>>,
+}
+
+type Link<T> = Option<Box<RedBlackNode<T>>>;
+
+impl<T> RedBlackNode<T> {
+    fn new(data: T, color: Color, left: Link<T>, right: Link<T>) -> Box<Self> {
+        Box::new(Self {
+            data,
+            color,
+            left,
+            right,
+        })
+    }
+    // a node's keys as one black node with up to two red children, its children hanging below
+    // those in order
+    fn from_node(node: Node<T>) -> Box<Self> {
+        fn lower<T, const N: usize>(children: Option<Box<[Node<T>; N]>>) -> [Link<T>; N] {
+            match children {
+                Some(children) => children.map(|child| Some(RedBlackNode::from_node(child))),
+                None => core::array::from_fn(|_| None),
+            }
+        }
+        use Color::{Black, Red};
+        match node {
+            Node::Two { key, children } => {
+                let [a, b] = lower(children);
+                Self::new(key, Black, a, b)
+            }
+            Node::Three {
+                keys: [x, y],
+                children,
+            } => {
+                let [a, b, c] = lower(children);
+                Self::new(y, Black, Some(Self::new(x, Red, a, b)), c)
+            }
+            Node::Four {
+                keys: [x, y, z],
+                children,
+            } => {
+                let [a, b, c, d] = lower(children);
+                Self::new(
+                    y,
+                    Black,
+                    Some(Self::new(x, Red, a, b)),
+                    Some(Self::new(z, Red, c, d)),
+                )
+            }
+        }
+    }
+    // the other way: a black node takes in its red children, which become its neighbouring
+    // keys, and their children become its own
+    fn into_node(self) -> Node<T> {
+        let RedBlackNode {
+            data, left, right, ..
+        } = self;
+        let (mut keys, mut children) = (Vec::with_capacity(3), Vec::with_capacity(4));
+        let mut absorb = |link: Link<T>, keys: &mut Vec<T>| match link {
+            Some(node) if node.color == Color::Red => {
+                let node = *node;
+                children.extend(node.left.map(|node| node.into_node()));
+                keys.push(node.data);
+                children.extend(node.right.map(|node| node.into_node()));
+            }
+            Some(node) => children.push(node.into_node()),
+            None => {}
+        };
+        absorb(left, &mut keys);
+        keys.push(data);
+        absorb(right, &mut keys);
+        Node::from_parts(keys, children)
+    }
+}
+
+// the binary form of a 2-3-4 tree, made by TwoThreeFourTree::into_red_black and turned back by
+// into_two_three_four; there to show the two are the same tree drawn differently, so it has no
+// insertion of its own
+#[derive(Clone)]
+pub struct RedBlackTree<T> {
+    root: Link<T>,
+    size: usize,
+}
+
+impl<T> RedBlackTree<T> {
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn height(&self) -> usize {
+        fn height<T>(link: &Link<T>) -> usize {
+            link.as_ref()
+                .map_or(0, |node| 1 + height(&node.left).max(height(&node.right)))
+        }
+        height(&self.root)
+    }
+    // black nodes on every path from the root down to an empty link, or None if the paths
+    // disagree, the root is red, or a red node has a red child
+    pub fn black_height(&self) -> Option<usize> {
+        fn check<T>(link: &Link<T>, parent: Color) -> Option<usize> {
+            let Some(node) = link else {
+                return Some(0);
+            };
+            if node.color == Color::Red && parent == Color::Red {
+                return None;
+            }
+            let left = check(&node.left, node.color)?;
+            let right = check(&node.right, node.color)?;
+            (left == right).then_some(left + (node.color == Color::Black) as usize)
+        }
+        match &self.root {
+            Some(root) if root.color == Color::Red => None,
+            root => check(root, Color::Black),
+        }
+    }
+    pub fn color<Q>(&self, key: &Q) -> Option<Color>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match key.cmp(node.data.borrow()) {
+                Ordering::Equal => return Some(node.color),
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+            };
+        }
+        None
+    }
+    pub fn iter(&self) -> RedBlackIter<'_, T> {
+        let mut iter = RedBlackIter {
+            stack: Vec::new(),
+            remaining: self.size,
+        };
+        iter.descend(&self.root);
+        iter
+    }
+    pub fn into_two_three_four(self) -> TwoThreeFourTree<T>
+    where
+        T: Ord,
+    {
+        TwoThreeFourTree {
+            root: self.root.map(|node| node.into_node()),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Debug for RedBlackTree<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+pub struct RedBlackIter<'a, T> {
+    stack: Vec<&'a RedBlackNode<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> RedBlackIter<'a, T> {
+    fn descend(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for RedBlackIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.descend(&node.right);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for RedBlackIter<'_, T> {}
+
+impl<T> FusedIterator for RedBlackIter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a RedBlackTree<T> {
+    type Item = &'a T;
+    type IntoIter = RedBlackIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,387 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+use crate::hash::map::HashMap;
+
+// universes this small fit one word, which ends the recursion
+const LEAF_BITS: u32 = 6;
+
+enum Removal {
+    Absent,
+    Removed,
+    // the key was the last one, and whoever holds the node should drop it
+    Emptied,
+}
+
+// a node over keys of some number of bits. a branch splits each key into its high half, which
+// picks a cluster, and its low half, which the cluster holds; the summary holds the high halves
+// of the clusters that exist. the smallest key is kept only in min and never passed down, so
+// inserting into an empty cluster just creates it, leaving one recursive step per level. only
+// nonempty clusters exist, kept in a hash map, so space follows the keys rather than the
+// universe
+#[derive(Clone)]
+enum Node {
+    Leaf(u64),
+    Branch {
+        min: u64,
+        max: u64,
+        summary: Option<Box<Node>>,
+        clusters: HashMap<u64, Node>,
+    },
+}
+
+impl Node {
+    fn singleton(bits: u32, key: u64) -> Self {
+        match bits <= LEAF_BITS {
+            true => Node::Leaf(1 << key),
+            false => Node::Branch {
+                min: key,
+                max: key,
+                summary: None,
+                clusters: HashMap::new(),
+            },
+        }
+    }
+    fn min(&self) -> u64 {
+        match self {
+            Node::Leaf(mask) => mask.trailing_zeros() as u64,
+            Node::Branch { min, .. } => *min,
+        }
+    }
+    fn max(&self) -> u64 {
+        match self {
+            Node::Leaf(mask) => 63 - mask.leading_zeros() as u64,
+            Node::Branch { max, .. } => *max,
+        }
+    }
+    fn contains(&self, bits: u32, key: u64) -> bool {
+        match self {
+            Node::Leaf(mask) => mask >> key & 1 == 1,
+            Node::Branch {
+                min, max, clusters, ..
+            } => {
+                let (high, low) = split(bits, key);
+                key == *min
+                    || key == *max
+                    || clusters
+                        .get(&high)
+                        .is_some_and(|cluster| cluster.contains(bits / 2, low))
+            }
+        }
+    }
+    fn insert(&mut self, bits: u32, mut key: u64) -> bool {
+        match self {
+            Node::Leaf(mask) => {
+                let inserted = *mask >> key & 1 == 0;
+                *mask |= 1 << key;
+                inserted
+            }
+            Node::Branch {
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                if key == *min || key == *max {
+                    return false;
+                }
+                // a new smallest key takes min's place and the old one goes down instead
+                if key < *min {
+                    core::mem::swap(&mut key, min);
+                }
+                let (high, low) = split(bits, key);
+                let inserted = match clusters.get_mut(&high) {
+                    Some(cluster) => cluster.insert(bits / 2, low),
+                    None => {
+                        clusters.insert(high, Node::singleton(bits / 2, low));
+                        match summary {
+                            Some(summary) => {
+                                summary.insert(bits - bits / 2, high);
+                            }
+                            None => {
+                                *summary = Some(Box::new(Node::singleton(bits - bits / 2, high)))
+                            }
+                        }
+                        true
+                    }
+                };
+                *max = (*max).max(key);
+                inserted
+            }
+        }
+    }
+    fn remove(&mut self, bits: u32, mut key: u64) -> Removal {
+        match self {
+            Node::Leaf(mask) => match *mask >> key & 1 == 1 {
+                false => Removal::Absent,
+                true => {
+                    *mask &= !(1 << key);
+                    match *mask {
+                        0 => Removal::Emptied,
+                        _ => Removal::Removed,
+                    }
+                }
+            },
+            Node::Branch {
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                if min == max {
+                    return match key == *min {
+                        true => Removal::Emptied,
+                        false => Removal::Absent,
+                    };
+                }
+                // the smallest key below takes min's place, and then that key goes from below
+                if key == *min {
+                    let high = summary.as_ref().unwrap().min();
+                    key = join(bits, high, clusters[&high].min());
+                    *min = key;
+                }
+                let (high, low) = split(bits, key);
+                let Some(cluster) = clusters.get_mut(&high) else {
+                    return Removal::Absent;
+                };
+                match cluster.remove(bits / 2, low) {
+                    Removal::Absent => return Removal::Absent,
+                    Removal::Removed => {}
+                    Removal::Emptied => {
+                        clusters.remove(&high);
+                        let summary_bits = bits - bits / 2;
+                        if let Removal::Emptied =
+                            summary.as_mut().unwrap().remove(summary_bits, high)
+                        {
+                            *summary = None;
+                        }
+                    }
+                }
+                if key == *max {
+                    *max = match summary {
+                        Some(summary) => {
+                            let high = summary.max();
+                            join(bits, high, clusters[&high].max())
+                        }
+                        None => *min,
+                    };
+                }
+                Removal::Removed
+            }
+        }
+    }
+    fn successor(&self, bits: u32, key: u64) -> Option<u64> {
+        match self {
+            Node::Leaf(mask) => {
+                let above = mask.checked_shr(key as u32 + 1).unwrap_or(0);
+                (above != 0).then(|| key + 1 + above.trailing_zeros() as u64)
+            }
+            Node::Branch {
+                min,
+                summary,
+                clusters,
+                ..
+            } => {
+                if key < *min {
+                    return Some(*min);
+                }
+                let (high, low) = split(bits, key);
+                if let Some(cluster) = clusters.get(&high)
+                    && low < cluster.max()
+                {
+                    return Some(join(bits, high, cluster.successor(bits / 2, low)?));
+                }
+                let next = summary.as_ref()?.successor(bits - bits / 2, high)?;
+                Some(join(bits, next, clusters[&next].min()))
+            }
+        }
+    }
+    fn predecessor(&self, bits: u32, key: u64) -> Option<u64> {
+        match self {
+            Node::Leaf(mask) => {
+                let below = mask & ((1 << key) - 1);
+                (below != 0).then(|| 63 - below.leading_zeros() as u64)
+            }
+            Node::Branch {
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                if key > *max {
+                    return Some(*max);
+                }
+                let (high, low) = split(bits, key);
+                if let Some(cluster) = clusters.get(&high)
+                    && low > cluster.min()
+                {
+                    return Some(join(bits, high, cluster.predecessor(bits / 2, low)?));
+                }
+                let previous = summary
+                    .as_ref()
+                    .and_then(|summary| summary.predecessor(bits - bits / 2, high));
+                match previous {
+                    Some(previous) => Some(join(bits, previous, clusters[&previous].max())),
+                    None => (key > *min).then_some(*min),
+                }
+            }
+        }
+    }
+}
+
+// the high half of a key picks its cluster, the low half, bits / 2 wide, is its place there
+fn split(bits: u32, key: u64) -> (u64, u64) {
+    let low = bits / 2;
+    (key >> low, key & ((1 << low) - 1))
+}
+
+fn join(bits: u32, high: u64, low: u64) -> u64 {
+    high << (bits / 2) | low
+}
+
+// an ordered set of integers below 2^bits with insert, remove, predecessor and successor each
+// taking O(log log U) steps for a universe of U = 2^bits keys, against O(log n) for a search
+// tree: every step halves the bits left to look at. clusters come from the crate's hash map, so
+// the steps are expected rather than worst-case constant time
+#[derive(Clone)]
+pub struct VebTree {
+    root: Option<Node>,
+    bits: u32,
+    size: usize,
+}
+
+impl Default for VebTree {
+    fn default() -> Self {
+        Self::new(u64::BITS)
+    }
+}
+
+impl VebTree {
+    // keys below 2^bits, for bits from 1 to 64
+    pub fn new(bits: u32) -> Self {
+        assert!(
+            (1..=u64::BITS).contains(&bits),
+            "a universe of {} bits, only 1 to 64 are supported",
+            bits
+        );
+        Self {
+            root: None,
+            bits,
+            size: 0,
+        }
+    }
+    pub fn from<I>(bits: u32, iter: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut tree = Self::new(bits);
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl VebTree {
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+    // panics for a key outside the universe, as does remove
+    pub fn insert(&mut self, key: u64) -> bool {
+        self.check(key);
+        let inserted = match &mut self.root {
+            Some(root) => root.insert(self.bits, key),
+            None => {
+                self.root = Some(Node::singleton(self.bits, key));
+                true
+            }
+        };
+        self.size += inserted as usize;
+        inserted
+    }
+    pub fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        iter.into_iter().for_each(|key| {
+            self.insert(key);
+        });
+    }
+    pub fn remove(&mut self, key: u64) -> bool {
+        self.check(key);
+        let Some(root) = &mut self.root else {
+            return false;
+        };
+        match root.remove(self.bits, key) {
+            Removal::Absent => return false,
+            Removal::Removed => {}
+            Removal::Emptied => self.root = None,
+        }
+        self.size -= 1;
+        true
+    }
+    // false for any key outside the universe
+    pub fn contains(&self, key: u64) -> bool {
+        self.in_universe(key)
+            && self
+                .root
+                .as_ref()
+                .is_some_and(|root| root.contains(self.bits, key))
+    }
+    pub fn first(&self) -> Option<u64> {
+        self.root.as_ref().map(Node::min)
+    }
+    pub fn last(&self) -> Option<u64> {
+        self.root.as_ref().map(Node::max)
+    }
+    // the largest key below the given one, which may lie outside the universe
+    pub fn predecessor(&self, key: u64) -> Option<u64> {
+        let root = self.root.as_ref()?;
+        match self.in_universe(key) {
+            true => root.predecessor(self.bits, key),
+            false => Some(root.max()),
+        }
+    }
+    // the smallest key above the given one
+    pub fn successor(&self, key: u64) -> Option<u64> {
+        match self.in_universe(key) {
+            true => self.root.as_ref()?.successor(self.bits, key),
+            false => None,
+        }
+    }
+    // ascending, one successor step per key
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        core::iter::successors(self.first(), |&key| self.successor(key))
+    }
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+    fn in_universe(&self, key: u64) -> bool {
+        key.checked_shr(self.bits).unwrap_or(0) == 0
+    }
+    fn check(&self, key: u64) {
+        assert!(
+            self.in_universe(key),
+            "key {} out of range for a {}-bit universe",
+            key,
+            self.bits
+        );
+    }
+}
+
+impl Debug for VebTree {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<u64> for VebTree {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        VebTree::extend(self, iter);
+    }
+}
@@ -0,0 +1,40 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    bdd::{Bdd, Ref},
+    visualize::{DotStyle, DotWriter, ToDot},
+};
+
+impl Bdd {
+    // the graphs of the given functions together, each shared node drawn once. a node shows
+    // the variable it tests, with a dashed edge to its false side and a solid one to its true
+    // side; annotate adds each node's ref
+    pub fn to_dot_of(&self, roots: &[Ref], style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["node [shape=circle]"]);
+        for at in self.reachable(roots) {
+            let id = at.0 as usize;
+            let node = self.nodes[id];
+            if at.is_constant() {
+                dot.node(id, if at == Ref::TRUE { "1" } else { "0" });
+                continue;
+            }
+            let var = self.order[node.level as usize];
+            match style.annotate {
+                true => dot.node(id, &format!("x{}\n#{}", var, id)),
+                false => dot.node(id, &format!("x{}", var)),
+            }
+            dot.edge(id, node.low.0 as usize, "style=dashed");
+            dot.edge(id, node.high.0 as usize, "");
+        }
+        dot.finish()
+    }
+}
+
+// the whole node table, which holds every function built so far and whatever intermediate
+// results they were made from
+impl ToDot for Bdd {
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let all: Vec<Ref> = (0..self.nodes.len() as u32).map(Ref).collect();
+        self.to_dot_of(&all, style)
+    }
+}
@@ -0,0 +1,37 @@
+use alloc::{format, string::String};
+
+use crate::{
+    graph::{Graph, TextWeight},
+    visualize::{DotStyle, DotWriter, ToDot, escape},
+};
+
+// every vertex by its number, isolated ones included, and every edge once; weights become edge
+// labels and unweighted graphs get none. annotate adds each edge's id
+impl<W> ToDot for Graph<W>
+where
+    W: TextWeight,
+{
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let attrs = ["node [shape=circle]"];
+        let mut dot = match self.is_directed() {
+            true => DotWriter::new(style, &attrs),
+            false => DotWriter::undirected(style, &attrs),
+        };
+        for vertex in self.vertices() {
+            dot.node(vertex, &format!("{}", vertex));
+        }
+        for (id, (from, to, weight)) in self.edges().enumerate() {
+            let label = match (weight.format(), style.annotate) {
+                (Some(weight), true) => format!("{}\n#{}", weight, id),
+                (Some(weight), false) => weight,
+                (None, true) => format!("#{}", id),
+                (None, false) => String::new(),
+            };
+            match label.is_empty() {
+                true => dot.edge(from, to, ""),
+                false => dot.edge(from, to, &format!("label=\"{}\"", escape(&label))),
+            }
+        }
+        dot.finish()
+    }
+}
@@ -0,0 +1,43 @@
+use alloc::{format, string::String};
+use core::fmt::Debug;
+
+use crate::{
+    linked_list::{doubly, singly},
+    visualize::{DotStyle, DotWriter, ToDot, label},
+};
+
+impl<T: Clone + Debug> ToDot for singly::LinkedList<T> {
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["rankdir=LR", "node [shape=box]"]);
+        for (i, item) in self.iter().enumerate() {
+            if style.annotate {
+                dot.node(i, &format!("[{}] {}", i, label(item)));
+            } else {
+                dot.node(i, &label(item));
+            }
+            if i > 0 {
+                dot.edge(i - 1, i, "");
+            }
+        }
+        dot.finish()
+    }
+}
+
+impl<T: Clone + Debug> ToDot for doubly::LinkedList<T> {
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["rankdir=LR", "node [shape=box]"]);
+        for (i, item) in self.iter().enumerate() {
+            let item = label(item);
+            if style.annotate {
+                dot.node(i, &format!("[{}] {}", i, item));
+            } else {
+                dot.node(i, &item);
+            }
+            if i > 0 {
+                dot.edge(i - 1, i, "");
+                dot.edge(i, i - 1, "style=dashed");
+            }
+        }
+        dot.finish()
+    }
+}
@@ -0,0 +1,87 @@
+pub mod bdd;
+pub mod graph;
+pub mod linked_list;
+pub mod tree;
+
+use alloc::{format, string::String};
+use core::fmt::Debug;
+
+pub struct DotStyle {
+    pub name: String,
+    pub color: Option<String>,
+    pub annotate: bool,
+}
+
+impl Default for DotStyle {
+    fn default() -> Self {
+        Self {
+            name: String::from("G"),
+            color: None,
+            annotate: false,
+        }
+    }
+}
+
+pub trait ToDot {
+    fn to_dot(&self) -> String {
+        self.to_dot_with(&DotStyle::default())
+    }
+    fn to_dot_with(&self, style: &DotStyle) -> String;
+}
+
+pub(crate) struct DotWriter {
+    out: String,
+    arrow: &'static str,
+}
+
+impl DotWriter {
+    pub(crate) fn new(style: &DotStyle, attrs: &[&str]) -> Self {
+        Self::open("digraph", "->", style, attrs)
+    }
+    // a graph block, whose edges are drawn as lines without arrowheads
+    pub(crate) fn undirected(style: &DotStyle, attrs: &[&str]) -> Self {
+        Self::open("graph", "--", style, attrs)
+    }
+    fn open(kind: &str, arrow: &'static str, style: &DotStyle, attrs: &[&str]) -> Self {
+        let mut out = format!("{} \"{}\" {{\n", kind, escape(&style.name));
+        for attr in attrs {
+            out.push_str(&format!("    {};\n", attr));
+        }
+        if let Some(color) = &style.color {
+            out.push_str(&format!(
+                "    node [style=filled, fillcolor=\"{}\"];\n",
+                escape(color)
+            ));
+        }
+        Self { out, arrow }
+    }
+    pub(crate) fn node(&mut self, id: usize, label: &str) {
+        self.out
+            .push_str(&format!("    n{} [label=\"{}\"];\n", id, escape(label)));
+    }
+    pub(crate) fn edge(&mut self, from: usize, to: usize, attrs: &str) {
+        if attrs.is_empty() {
+            self.out
+                .push_str(&format!("    n{} {} n{};\n", from, self.arrow, to));
+        } else {
+            self.out.push_str(&format!(
+                "    n{} {} n{} [{}];\n",
+                from, self.arrow, to, attrs
+            ));
+        }
+    }
+    pub(crate) fn finish(mut self) -> String {
+        self.out.push_str("}\n");
+        self.out
+    }
+}
+
+pub(crate) fn label<T: Debug>(data: &T) -> String {
+    format!("{:?}", data)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
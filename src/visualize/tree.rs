@@ -0,0 +1,89 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Debug;
+
+use crate::{
+    tree::{avl::AVLTree, heap::BinaryHeap, quadtree::QuadTree},
+    visualize::{DotStyle, DotWriter, ToDot, label},
+};
+
+impl<T> ToDot for AVLTree<T>
+where
+    T: Debug + Ord,
+{
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["node [shape=circle]"]);
+        let mut stack = Vec::new();
+        let mut next_id = 0;
+        if let Some(root) = &self.root {
+            stack.push((root, next_id));
+            next_id += 1;
+        }
+        while let Some((node, id)) = stack.pop() {
+            if style.annotate {
+                dot.node(
+                    id,
+                    &format!(
+                        "{}\nh={} bf={}",
+                        label(&node.data),
+                        node.height,
+                        node.balance_factor()
+                    ),
+                );
+            } else {
+                dot.node(id, &label(&node.data));
+            }
+            for (child, side) in [(&node.left, "L"), (&node.right, "R")] {
+                if let Some(child) = child {
+                    dot.edge(id, next_id, &format!("label=\"{}\"", side));
+                    stack.push((child, next_id));
+                    next_id += 1;
+                }
+            }
+        }
+        dot.finish()
+    }
+}
+
+impl<T> ToDot for BinaryHeap<T>
+where
+    T: Debug + Clone + Ord + PartialOrd,
+{
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["node [shape=circle]"]);
+        for (i, item) in self.iter_unsorted().enumerate() {
+            if style.annotate {
+                dot.node(i, &format!("{}\n[{}]", label(item), i));
+            } else {
+                dot.node(i, &label(item));
+            }
+            if i > 0 {
+                dot.edge((i - 1) / 2, i, "");
+            }
+        }
+        dot.finish()
+    }
+}
+
+// one box per node, labelled with the region it covers; leaves also give their point count, or
+// with annotate the points themselves
+impl ToDot for QuadTree {
+    fn to_dot_with(&self, style: &DotStyle) -> String {
+        let mut dot = DotWriter::new(style, &["node [shape=box]"]);
+        for (id, node) in self.nodes.iter().enumerate() {
+            let region = format!("{:?}..{:?}", node.bounds.min, node.bounds.max);
+            match node.children {
+                Some(first) => {
+                    dot.node(id, &region);
+                    for (child, side) in (first..first + 4).zip(["SW", "SE", "NW", "NE"]) {
+                        dot.edge(id, child, &format!("label=\"{}\"", side));
+                    }
+                }
+                None if style.annotate => {
+                    dot.node(id, &format!("{}\n{}", region, label(&node.points)))
+                }
+                None => dot.node(id, &format!("{}\n{} points", region, node.points.len())),
+            }
+        }
+        dot.finish()
+    }
+}
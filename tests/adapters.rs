@@ -0,0 +1,130 @@
+use std::{cell::Cell, collections::VecDeque};
+
+use dsa_rust::{
+    adt::{
+        Queue, Stack,
+        adapters::{QueueFromStacks, StackFromQueues},
+        min_stack::MinQueue,
+    },
+    collections::VecStack,
+    generate::Rng,
+    linked_list::{doubly, singly},
+};
+
+thread_local! {
+    static OPERATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// a stack that counts every push and pop, so the amortized bound can be seen from outside
+#[derive(Default)]
+struct Counting {
+    inner: VecStack<u32>,
+}
+
+impl Stack<u32> for Counting {
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+    fn push(&mut self, data: u32) {
+        OPERATIONS.set(OPERATIONS.get() + 1);
+        self.inner.push(data);
+    }
+    fn pop(&mut self) -> Option<u32> {
+        OPERATIONS.set(OPERATIONS.get() + 1);
+        self.inner.pop()
+    }
+    fn peek(&self) -> Option<u32> {
+        self.inner.peek().copied()
+    }
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+fn queue_matches_model<Q: Queue<u32>>(mut queue: Q, seed: u64) {
+    let mut rng = Rng::new(seed);
+    let mut model = VecDeque::new();
+    for _ in 0..1_000 {
+        match rng.below(5) {
+            0..=1 => assert_eq!(queue.poll(), model.pop_front()),
+            2 if rng.chance(0.02) => {
+                queue.clear();
+                model.clear();
+            }
+            _ => {
+                let item = rng.below(100) as u32;
+                queue.offer(item);
+                model.push_back(item);
+            }
+        }
+        assert_eq!(
+            (queue.head(), queue.tail()),
+            (model.front().copied(), model.back().copied())
+        );
+        assert_eq!(
+            (queue.len(), queue.is_empty()),
+            (model.len(), model.is_empty())
+        );
+    }
+}
+
+fn stack_matches_model<S: Stack<u32>>(mut stack: S, seed: u64) {
+    let mut rng = Rng::new(seed);
+    let mut model = Vec::new();
+    for _ in 0..1_000 {
+        match rng.below(5) {
+            0..=1 => assert_eq!(stack.pop(), model.pop()),
+            _ => {
+                let item = rng.below(100) as u32;
+                stack.push(item);
+                model.push(item);
+            }
+        }
+        assert_eq!(stack.peek(), model.last().copied());
+        assert_eq!(stack.len(), model.len());
+    }
+}
+
+#[test]
+fn queue_from_any_stack() {
+    queue_matches_model(QueueFromStacks::<u32, VecStack<u32>>::new(), 1);
+    queue_matches_model(QueueFromStacks::<u32, singly::LinkedList<u32>>::new(), 2);
+    queue_matches_model(QueueFromStacks::<u32, Counting>::default(), 3);
+}
+
+#[test]
+fn stack_from_any_queue() {
+    stack_matches_model(StackFromQueues::<u32, doubly::LinkedList<u32>>::new(), 4);
+    stack_matches_model(StackFromQueues::<u32, MinQueue<u32>>::new(), 5);
+    // adapters compose: a stack from queues that are themselves made of stacks
+    stack_matches_model(
+        StackFromQueues::<u32, QueueFromStacks<u32, VecStack<u32>>>::new(),
+        6,
+    );
+}
+
+#[test]
+fn two_stack_queue_is_amortized_constant() {
+    let mut queue: QueueFromStacks<u32, Counting> = QueueFromStacks::new();
+    let mut rng = Rng::new(7);
+    let (mut offers, mut polls) = (0, 0);
+    OPERATIONS.set(0);
+    for _ in 0..10_000 {
+        if rng.chance(0.6) {
+            queue.offer(offers as u32);
+            offers += 1;
+        } else {
+            queue.poll();
+            polls += 1;
+        }
+    }
+    while queue.poll().is_some() {
+        polls += 1;
+    }
+    // each item is pushed and popped on both stacks, and a poll that empties the inbox makes one
+    // more pop that finds nothing; a single poll can still be long
+    assert!(OPERATIONS.get() <= 4 * offers + 2 * polls);
+}
@@ -0,0 +1,64 @@
+use std::fmt::Debug;
+
+use dsa_rust::{
+    algebra::{Gcd, Group, Max, Min, Monoid, Semigroup, Sum, Xor},
+    generate::Rng,
+};
+
+fn monoid_laws<M>(values: &[M])
+where
+    M: Monoid + PartialEq + Debug,
+{
+    for a in values {
+        assert_eq!(&a.combine(&M::identity()), a);
+        assert_eq!(&M::identity().combine(a), a);
+        for b in values {
+            for c in values {
+                assert_eq!(a.combine(b).combine(c), a.combine(&b.combine(c)));
+            }
+        }
+    }
+}
+
+fn group_laws<M>(values: &[M])
+where
+    M: Group + PartialEq + Debug,
+{
+    monoid_laws(values);
+    for a in values {
+        assert_eq!(a.combine(&a.inverse()), M::identity());
+        assert_eq!(a.inverse().combine(a), M::identity());
+    }
+}
+
+fn samples(seed: u64) -> Vec<i64> {
+    let mut rng = Rng::new(seed);
+    let mut values: Vec<i64> = (0..12).map(|_| rng.range(0..100) as i64 - 50).collect();
+    values.extend([0, i64::MAX, i64::MIN]);
+    values
+}
+
+#[test]
+fn wrappers_obey_their_laws() {
+    let values = samples(5);
+    group_laws(&values.iter().map(|&v| Sum(v)).collect::<Vec<_>>());
+    group_laws(&values.iter().map(|&v| Xor(v)).collect::<Vec<_>>());
+    monoid_laws(&values.iter().map(|&v| Min(v)).collect::<Vec<_>>());
+    monoid_laws(&values.iter().map(|&v| Max(v)).collect::<Vec<_>>());
+    monoid_laws(&[0u32, 1, 6, 9, 12, 35, 1 << 20].map(Gcd));
+    group_laws(&[0.5f64, -2.0, 8.25, 0.0].map(Sum));
+}
+
+#[test]
+fn combining() {
+    assert_eq!(Sum(3).combine(&Sum(4)), Sum(7));
+    assert_eq!(Sum(u8::MAX).combine(&Sum(1)), Sum(0));
+    assert_eq!(Min("b").combine(&Min("a")), Min("a"));
+    assert_eq!(Max(2).combine(&Max(9)), Max(9));
+    assert_eq!(Gcd(12u64).combine(&Gcd(18)), Gcd(6));
+    assert_eq!(Gcd(0u64).combine(&Gcd(7)), Gcd(7));
+    assert_eq!(Xor(0b1010).combine(&Xor(0b0110)), Xor(0b1100));
+    assert_eq!(Min::<i32>::identity(), Min(i32::MAX));
+    assert_eq!(Max::<u8>::identity(), Max(0));
+    assert_eq!(Sum(5u32).inverse().combine(&Sum(8)), Sum(3));
+}
@@ -0,0 +1,115 @@
+use dsa_rust::graph::{Graph, NegativeCycle, floyd_warshall};
+
+// bellman-ford from one source, as the reference
+fn single_source(graph: &Graph<i64>, source: usize) -> Vec<Option<i64>> {
+    let mut distance = vec![None; graph.vertex_count()];
+    distance[source] = Some(0);
+    for _ in 0..graph.vertex_count() {
+        for (from, to, &weight) in graph.edges() {
+            let mut relax = |a: usize, b: usize| {
+                if let Some(d) = distance[a]
+                    && distance[b].is_none_or(|current| d + weight < current)
+                {
+                    distance[b] = Some(d + weight);
+                }
+            };
+            relax(from, to);
+            if !graph.is_directed() {
+                relax(to, from);
+            }
+        }
+    }
+    distance
+}
+
+fn random_dag(vertices: usize, edges: usize, seed: u64) -> Graph<i64> {
+    let mut graph = Graph::directed(vertices);
+    let mut state = seed;
+    for _ in 0..edges {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let (a, b) = (
+            (state >> 33) as usize % vertices,
+            (state >> 45) as usize % vertices,
+        );
+        // edges only run upward, so negative weights cannot close a cycle
+        if a != b {
+            graph.add_weighted_edge(a.min(b), a.max(b), (state >> 20) as i64 % 21 - 8);
+        }
+    }
+    graph
+}
+
+#[test]
+fn distances_and_paths_match_bellman_ford() {
+    for seed in 0..20 {
+        let graph = random_dag(15, 40, seed);
+        let paths = graph.floyd_warshall().unwrap();
+        for from in graph.vertices() {
+            let reference = single_source(&graph, from);
+            for to in graph.vertices() {
+                assert_eq!(
+                    paths.distance(from, to),
+                    reference[to],
+                    "seed {} {} -> {}",
+                    seed,
+                    from,
+                    to
+                );
+                match paths.path(from, to) {
+                    Some(path) => {
+                        assert_eq!((path[0], *path.last().unwrap()), (from, to));
+                        let length: i64 = path
+                            .windows(2)
+                            .map(|hop| graph.adjacency_matrix()[(hop[0], hop[1])].unwrap())
+                            .sum();
+                        assert_eq!(Some(length), reference[to]);
+                    }
+                    None => assert_eq!(reference[to], None),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn matrices_and_parallel_edges() {
+    let mut graph: Graph<u32> = Graph::undirected(4);
+    graph.extend([(0, 1, 5), (0, 1, 2), (1, 2, 2), (0, 2, 9)]);
+    let weights = graph.adjacency_matrix();
+    assert_eq!(
+        (weights[(0, 1)], weights[(1, 0)], weights[(0, 3)]),
+        (Some(2), Some(2), None)
+    );
+    let paths = floyd_warshall(&weights).unwrap();
+    assert_eq!(paths.distance(0, 2), Some(4));
+    assert_eq!(paths.path(2, 0), Some(vec![2, 1, 0]));
+    assert_eq!((paths.path(0, 3), paths.path(3, 3)), (None, Some(vec![3])));
+    assert_eq!(paths.next_hops()[(0, 2)], Some(1));
+    assert_eq!(paths.distances()[(3, 3)], Some(0));
+}
+
+#[test]
+fn negative_cycles_are_reported() {
+    let mut graph: Graph<i64> = Graph::directed(5);
+    graph.extend([(0, 1, 1), (1, 2, -2), (2, 3, -1), (3, 1, 2), (3, 4, 1)]);
+    let NegativeCycle { vertices } = graph.floyd_warshall().unwrap_err();
+    assert!(!vertices.is_empty() && vertices.iter().all(|v| (1..=3).contains(v)));
+
+    let mut looped: Graph<i64> = Graph::directed(2);
+    looped.add_weighted_edge(1, 1, -1);
+    assert_eq!(
+        looped.floyd_warshall(),
+        Err(NegativeCycle { vertices: vec![1] })
+    );
+    assert_eq!(
+        NegativeCycle { vertices: vec![1] }.to_string(),
+        "negative cycle through vertices [1]"
+    );
+
+    // an undirected negative edge can be walked back and forth
+    let mut undirected: Graph<i64> = Graph::undirected(2);
+    undirected.add_weighted_edge(0, 1, -3);
+    assert!(undirected.floyd_warshall().is_err());
+}
@@ -0,0 +1,74 @@
+// The arena tree must agree with the Box tree on every operation; what differs is where the
+// nodes live, which shows up as slot reuse and a capacity that survives clear.
+
+use dsa_rust::{
+    arena::Arena,
+    tree::{arena_avl::ArenaAVLTree, avl::AVLTree},
+};
+
+#[test]
+fn arena_reuses_freed_slots() {
+    let mut arena = Arena::new();
+    let a = arena.alloc("a");
+    let b = arena.alloc("b");
+    let c = arena.alloc("c");
+    assert_eq!((arena.len(), arena[b]), (3, "b"));
+    assert_eq!(arena.free(b), Some("b"));
+    assert_eq!(arena.free(b), None);
+    assert!(!arena.contains(b) && arena.get(b).is_none());
+
+    // the most recently freed slot is handed out first
+    arena.free(a);
+    assert_eq!(arena.alloc("d"), a);
+    assert_eq!(arena.alloc("e"), b);
+    assert_eq!(arena.alloc("f").index(), 3);
+    arena[c] = "C";
+    let values: Vec<_> = arena.iter().map(|(_, value)| *value).collect();
+    assert_eq!(values, ["d", "e", "C", "f"]);
+
+    let capacity = arena.capacity();
+    arena.clear();
+    assert!(arena.is_empty() && arena.get(a).is_none());
+    assert_eq!(arena.capacity(), capacity);
+}
+
+#[test]
+fn arena_tree_matches_box_tree() {
+    let mut boxed = AVLTree::new();
+    let mut arena = ArenaAVLTree::new();
+    let steps: u32 = if cfg!(miri) { 300 } else { 2_000 };
+    for step in 0..steps {
+        let key = step.wrapping_mul(2_654_435_761) % 257;
+        if step % 3 == 2 {
+            assert_eq!(boxed.remove(&key), arena.remove(&key));
+        } else {
+            assert_eq!(boxed.insert(key), arena.insert(key));
+        }
+        assert_eq!(boxed.size(), arena.size());
+    }
+    arena.validate().unwrap();
+    assert!(arena.iter().eq(boxed.iter()));
+    assert_eq!(arena.first(), boxed.first());
+    assert_eq!(arena.last(), boxed.last());
+    for k in 0..arena.size() {
+        assert_eq!(arena.kth_smallest(k), boxed.kth_smallest(k));
+    }
+    for key in [0, 100, 256, 300] {
+        assert_eq!(arena.rank(&key), boxed.rank(&key));
+        assert_eq!(arena.contains(&key), boxed.contains(&key));
+    }
+}
+
+#[test]
+fn arena_tree_clear_keeps_its_slots() {
+    let mut tree = ArenaAVLTree::from((0..1_000).map(|key| key.to_string()));
+    assert_eq!(tree.take("500"), Some("500".to_string()));
+    assert_eq!(tree.take("500"), None);
+    let capacity = tree.capacity();
+    tree.clear();
+    assert!(tree.is_empty() && tree.iter().next().is_none());
+    tree.extend((0..1_000).map(|key| key.to_string()));
+    tree.validate().unwrap();
+    assert_eq!(tree.capacity(), capacity);
+    assert_eq!(tree.clone(), tree);
+}
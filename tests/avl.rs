@@ -0,0 +1,231 @@
+use std::collections::BTreeSet;
+
+use dsa_rust::{generate::Rng, instrument, tree::avl::AVLTree};
+
+#[test]
+fn sorted_input_builds_a_full_tree() {
+    for n in [0, 1, 2, 3, 10, 127, 128, 1_000] {
+        let tree = AVLTree::from_sorted_iter(0..n);
+        assert!(tree.validate().is_ok(), "n = {}", n);
+        assert_eq!(tree.size(), n as usize);
+        assert!(tree.iter().copied().eq(0..n));
+        // midpoint splitting never leaves a level half done except the last
+        assert_eq!(
+            tree.height(),
+            (usize::BITS - (n as usize).leading_zeros()) as usize
+        );
+        assert_eq!(
+            tree.kth_smallest(n as usize / 2),
+            (n > 0).then_some(&(n / 2))
+        );
+    }
+    let tree = AVLTree::from_sorted_vec(vec![1, 1, 2, 3, 3, 3]);
+    assert_eq!(tree.size(), 3);
+    assert!(tree.validate().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "ascending")]
+fn unsorted_input_is_rejected() {
+    AVLTree::from_sorted_vec(vec![1, 3, 2]);
+}
+
+#[test]
+fn collecting_takes_the_bulk_path_for_sorted_input() {
+    let (sorted, bulk) = instrument::measure(|| (0..512).collect::<AVLTree<u32>>());
+    let (scrambled, one_by_one) = instrument::measure(|| (0..512).rev().collect::<AVLTree<u32>>());
+    assert_eq!(sorted, scrambled);
+    assert!(sorted.validate().is_ok() && scrambled.validate().is_ok());
+    assert!(sorted.height() <= scrambled.height());
+    if instrument::enabled() {
+        assert_eq!((bulk.comparisons, bulk.rotations), (0, 0));
+        assert!(one_by_one.rotations > 0);
+    }
+
+    let mut grown = AVLTree::from(vec![1, 2, 3]);
+    grown.extend([0, 4]);
+    assert!(grown.validate().is_ok());
+    assert!(grown.iter().copied().eq(0..5));
+}
+
+#[test]
+fn set_algebra_matches_btree_set() {
+    let evens: Vec<u32> = (0..300).step_by(2).collect();
+    let threes: Vec<u32> = (0..300).step_by(3).collect();
+    let (a, b) = (
+        BTreeSet::from_iter(evens.iter().copied()),
+        BTreeSet::from_iter(threes.iter().copied()),
+    );
+    let tree = |keys: &[u32]| AVLTree::from_iter(keys.iter().copied().rev());
+
+    let union = tree(&evens).union(tree(&threes));
+    assert!(union.iter().eq(a.union(&b)));
+    let intersection = tree(&evens).intersection(tree(&threes));
+    assert!(intersection.iter().eq(a.intersection(&b)));
+    let difference = tree(&evens).difference(tree(&threes));
+    assert!(difference.iter().eq(a.difference(&b)));
+    for result in [&union, &intersection, &difference] {
+        assert!(result.validate().is_ok());
+    }
+
+    let empty = AVLTree::new();
+    assert_eq!(tree(&evens).union(AVLTree::new()), tree(&evens));
+    assert_eq!(tree(&evens).intersection(AVLTree::new()), empty);
+    assert_eq!(AVLTree::new().difference(tree(&evens)), empty);
+    assert_eq!(tree(&evens).difference(tree(&evens)), empty);
+}
+
+#[test]
+fn order_statistics_match_a_sorted_vec() {
+    let mut rng = Rng::new(1545);
+    let mut tree = AVLTree::new();
+    let mut set = BTreeSet::new();
+    for round in 0..2_000 {
+        let key = rng.below(500) as u32;
+        if rng.chance(0.3) {
+            assert_eq!(tree.remove(&key), set.remove(&key));
+        } else {
+            assert_eq!(tree.insert(key), set.insert(key));
+        }
+        if round % 50 != 0 {
+            continue;
+        }
+        let sorted: Vec<u32> = set.iter().copied().collect();
+        for (k, key) in sorted.iter().enumerate() {
+            assert_eq!(tree.kth_smallest(k), Some(key));
+        }
+        // past the end, by one and by far
+        assert_eq!(tree.kth_smallest(sorted.len()), None);
+        assert_eq!(tree.kth_smallest(usize::MAX), None);
+        // every key in range, present or not, and one either side of it
+        for probe in 0..=501 {
+            let below = sorted.partition_point(|&key| key < probe);
+            assert_eq!(tree.rank(&probe), below, "rank of {}", probe);
+            let found = sorted.binary_search(&probe).ok();
+            assert_eq!(tree.index_of(&probe), found, "index of {}", probe);
+        }
+    }
+
+    let empty = AVLTree::<u32>::new();
+    assert_eq!(empty.kth_smallest(0), None);
+    assert_eq!(empty.rank(&7), 0);
+    assert_eq!(empty.index_of(&7), None);
+}
+
+#[test]
+fn split_and_join_keep_balance() {
+    let n = 1_000u32;
+    let scattered = |seed: u32| (0..n).map(move |key| key.wrapping_mul(seed) % (2 * n));
+    for key in [0, 1, 499, 500, 998, 1_999, 5_000] {
+        let tree = AVLTree::from_iter(scattered(7_919));
+        let model = BTreeSet::from_iter(scattered(7_919));
+        let (below, found, above) = tree.split(&key);
+        assert_eq!(found, model.get(&key).copied());
+        assert!(below.iter().eq(model.range(..key)));
+        assert!(above.iter().eq(model.range(key + 1..)));
+        assert!(below.validate().is_ok() && above.validate().is_ok());
+
+        let joined = AVLTree::join(below, above);
+        assert!(joined.validate().is_ok());
+        assert_eq!(joined.size(), model.len() - found.is_some() as usize);
+    }
+
+    // very different heights meet in the middle of the taller tree
+    let joined = AVLTree::join(AVLTree::from_iter([0]), AVLTree::from_sorted_iter(1..4_096));
+    assert!(joined.validate().is_ok());
+    assert!(joined.iter().copied().eq(0..4_096));
+    let joined = AVLTree::join(
+        AVLTree::from_sorted_iter(0..4_095),
+        AVLTree::from_iter([4_095]),
+    );
+    assert!(joined.validate().is_ok() && joined.size() == 4_096);
+    assert_eq!(
+        AVLTree::join(AVLTree::new(), AVLTree::new()),
+        AVLTree::<u32>::new()
+    );
+
+    // uneven operands exercise the split recursion on both sides
+    for (small, large) in [
+        (scattered(31), scattered(7_919)),
+        (scattered(7_919), scattered(31)),
+    ] {
+        let (small, large): (Vec<u32>, Vec<u32>) = (small.take(40).collect(), large.collect());
+        let (a, b) = (
+            BTreeSet::from_iter(small.clone()),
+            BTreeSet::from_iter(large.clone()),
+        );
+        let tree = |keys: &Vec<u32>| AVLTree::from_iter(keys.iter().copied());
+        for (result, expected) in [
+            (
+                tree(&small).union(tree(&large)),
+                a.union(&b).copied().collect::<Vec<_>>(),
+            ),
+            (
+                tree(&large).union(tree(&small)),
+                a.union(&b).copied().collect(),
+            ),
+            (
+                tree(&small).intersection(tree(&large)),
+                a.intersection(&b).copied().collect(),
+            ),
+            (
+                tree(&large).difference(tree(&small)),
+                b.difference(&a).copied().collect(),
+            ),
+            (
+                tree(&small).difference(tree(&large)),
+                a.difference(&b).copied().collect(),
+            ),
+        ] {
+            assert!(result.validate().is_ok());
+            assert!(result.iter().eq(expected.iter()));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "below every item")]
+fn join_rejects_overlapping_trees() {
+    AVLTree::join(AVLTree::from_iter([1, 5]), AVLTree::from_iter([3, 9]));
+}
+
+// slow outside release builds: cargo test --release --test avl -- --ignored
+#[test]
+#[ignore]
+fn ten_million_sequential_inserts_stay_balanced() {
+    let n = 10_000_000u32;
+    let mut tree = AVLTree::new();
+    for key in 0..n {
+        assert!(tree.insert(key));
+    }
+    assert_eq!(tree.size(), n as usize);
+    assert!(tree.validate().is_ok());
+    assert!(tree.is_balanced());
+    let bound = 1.44 * (n as f64).log2();
+    assert!(
+        (tree.height() as f64) <= bound,
+        "height {} above {}",
+        tree.height(),
+        bound
+    );
+    assert_eq!((tree.first(), tree.last()), (Some(&0), Some(&(n - 1))));
+}
+
+#[test]
+fn ascii_drawing_after_a_rotation() {
+    // 6 unbalances the root's right side and a left rotation lifts 4 to the top
+    let mut tree = AVLTree::new();
+    for key in 1..=6 {
+        tree.insert(key);
+    }
+    let expected = "\
+4 (h=3, bf=0)
++-- L: 2 (h=2, bf=0)
+|   +-- L: 1 (h=1, bf=0)
+|   `-- R: 3 (h=1, bf=0)
+`-- R: 5 (h=2, bf=-1)
+    `-- R: 6 (h=1, bf=0)
+";
+    assert_eq!(tree.to_ascii(), expected);
+    assert_eq!(AVLTree::<i32>::new().to_ascii(), "");
+}
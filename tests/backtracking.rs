@@ -0,0 +1,97 @@
+use dsa_rust::{
+    algorithms::backtracking::{
+        Frame, backtrack, backtrack_with, combinations, n_queens, permutations, queens, subsets,
+    },
+    linked_list::doubly::LinkedList,
+};
+
+#[test]
+fn queens_counts_and_boards() {
+    let counts: Vec<usize> = (0..=8).map(|n| queens(n).count()).collect();
+    assert_eq!(counts, vec![1, 1, 0, 0, 2, 10, 4, 40, 92]);
+    assert_eq!(n_queens(4), vec![vec![1, 3, 0, 2], vec![2, 0, 3, 1]]);
+    for board in n_queens(6) {
+        for (r1, &c1) in board.iter().enumerate() {
+            for (r2, &c2) in board.iter().enumerate().skip(r1 + 1) {
+                assert!(c1 != c2 && r2 - r1 != c1.abs_diff(c2));
+            }
+        }
+    }
+}
+
+#[test]
+fn permutations_in_lexicographic_order() {
+    let all: Vec<Vec<char>> = permutations(&['a', 'b', 'c']).collect();
+    let words: Vec<String> = all.iter().map(|p| p.iter().collect()).collect();
+    assert_eq!(words, ["abc", "acb", "bac", "bca", "cab", "cba"]);
+    assert_eq!(permutations(&[1, 2, 3, 4, 5]).count(), 120);
+    assert_eq!(
+        permutations::<u8>(&[]).collect::<Vec<_>>(),
+        vec![Vec::<u8>::new()]
+    );
+}
+
+#[test]
+fn combinations_and_subsets() {
+    let items = [1, 2, 3, 4];
+    let pairs: Vec<Vec<i32>> = combinations(&items, 2).collect();
+    assert_eq!(
+        pairs,
+        vec![
+            vec![1, 2],
+            vec![1, 3],
+            vec![1, 4],
+            vec![2, 3],
+            vec![2, 4],
+            vec![3, 4]
+        ]
+    );
+    assert_eq!(
+        combinations(&items, 0).collect::<Vec<_>>(),
+        vec![Vec::<i32>::new()]
+    );
+    assert_eq!(combinations(&items, 5).count(), 0);
+    assert_eq!(combinations(&[0; 10], 4).count(), 210);
+
+    let all: Vec<Vec<i32>> = subsets(&[1, 2, 3]).collect();
+    assert_eq!(all.len(), 8);
+    assert_eq!(all[0], Vec::<i32>::new());
+    assert_eq!(all[7], vec![1, 2, 3]);
+    assert!(all.contains(&vec![1, 3]));
+}
+
+#[test]
+fn the_driver_prunes_and_stays_lazy() {
+    // sequences of 1s and 2s summing to 6; the constraint cuts off any that overshoot
+    let sums = |state: &[u32]| (state.iter().sum::<u32>() < 6).then_some([1, 2]);
+    let fits = |state: &[u32], &next: &u32| state.iter().sum::<u32>() + next <= 6;
+    assert_eq!(backtrack(Vec::new(), sums, fits).count(), 13);
+    // the first solution is found without visiting the rest of the tree
+    let calls = std::cell::Cell::new(0);
+    let counted = |state: &[u32]| {
+        calls.set(calls.get() + 1);
+        sums(state)
+    };
+    let first = backtrack(Vec::new(), counted, fits).next();
+    assert_eq!((first, calls.get()), (Some(vec![1; 6]), 7));
+
+    // a state that is already complete is the only solution
+    let done = backtrack(vec![7], |_: &[u8]| None::<[u8; 0]>, |_: &[u8], _: &u8| true);
+    assert_eq!(done.collect::<Vec<_>>(), vec![vec![7]]);
+}
+
+#[test]
+fn any_stack_will_do() {
+    let on_doubly = backtrack_with::<LinkedList<Frame<usize>>, _, _, _, _>(
+        Vec::new(),
+        |placed: &[usize]| (placed.len() < 8).then_some(0..8),
+        |placed: &[usize], &col| {
+            let row = placed.len();
+            placed
+                .iter()
+                .enumerate()
+                .all(|(r, &c)| c != col && row - r != c.abs_diff(col))
+        },
+    );
+    assert!(on_doubly.eq(queens(8)));
+}
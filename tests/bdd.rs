@@ -0,0 +1,233 @@
+use dsa_rust::{
+    bdd::{Bdd, Ref},
+    generate::Rng,
+    visualize::{DotStyle, ToDot},
+};
+
+// a random formula, built in the diagram and evaluated directly side by side
+enum Formula {
+    Var(usize),
+    Not(Box<Formula>),
+    And(Box<Formula>, Box<Formula>),
+    Or(Box<Formula>, Box<Formula>),
+    Xor(Box<Formula>, Box<Formula>),
+    Ite(Box<Formula>, Box<Formula>, Box<Formula>),
+}
+
+impl Formula {
+    fn random(rng: &mut Rng, vars: usize, depth: usize) -> Self {
+        if depth == 0 || rng.chance(0.15) {
+            return Formula::Var(rng.below(vars));
+        }
+        let kind = rng.below(5);
+        let mut sub = || Box::new(Formula::random(rng, vars, depth - 1));
+        match kind {
+            0 => Formula::Not(sub()),
+            1 => Formula::And(sub(), sub()),
+            2 => Formula::Or(sub(), sub()),
+            3 => Formula::Xor(sub(), sub()),
+            _ => Formula::Ite(sub(), sub(), sub()),
+        }
+    }
+    fn eval(&self, assignment: &[bool]) -> bool {
+        match self {
+            Formula::Var(v) => assignment[*v],
+            Formula::Not(f) => !f.eval(assignment),
+            Formula::And(f, g) => f.eval(assignment) && g.eval(assignment),
+            Formula::Or(f, g) => f.eval(assignment) || g.eval(assignment),
+            Formula::Xor(f, g) => f.eval(assignment) != g.eval(assignment),
+            Formula::Ite(f, g, h) => match f.eval(assignment) {
+                true => g.eval(assignment),
+                false => h.eval(assignment),
+            },
+        }
+    }
+    fn build(&self, bdd: &mut Bdd) -> Ref {
+        match self {
+            Formula::Var(v) => bdd.var(*v),
+            Formula::Not(f) => {
+                let f = f.build(bdd);
+                bdd.not(f)
+            }
+            Formula::And(f, g) => {
+                let (f, g) = (f.build(bdd), g.build(bdd));
+                bdd.and(f, g)
+            }
+            Formula::Or(f, g) => {
+                let (f, g) = (f.build(bdd), g.build(bdd));
+                bdd.or(f, g)
+            }
+            Formula::Xor(f, g) => {
+                let (f, g) = (f.build(bdd), g.build(bdd));
+                bdd.xor(f, g)
+            }
+            Formula::Ite(f, g, h) => {
+                let (f, g, h) = (f.build(bdd), g.build(bdd), h.build(bdd));
+                bdd.ite(f, g, h)
+            }
+        }
+    }
+}
+
+fn assignments(vars: usize) -> impl Iterator<Item = Vec<bool>> {
+    (0..1u32 << vars).map(move |bits| (0..vars).map(|v| bits >> v & 1 == 1).collect())
+}
+
+#[test]
+fn agrees_with_truth_tables() {
+    let mut rng = Rng::new(41);
+    let vars = 7;
+    for _ in 0..60 {
+        let mut order: Vec<usize> = (0..vars).collect();
+        rng.shuffle(&mut order);
+        let mut bdd = Bdd::with_order(order);
+        let formula = Formula::random(&mut rng, vars, 6);
+        let f = formula.build(&mut bdd);
+        let mut count = 0;
+        for assignment in assignments(vars) {
+            let expected = formula.eval(&assignment);
+            assert_eq!(bdd.eval(f, &assignment), expected);
+            count += expected as u128;
+        }
+        assert_eq!(bdd.sat_count(f), count);
+        match bdd.any_sat(f) {
+            Some(witness) => assert!(formula.eval(&witness)),
+            None => assert_eq!((count, f), (0, Ref::FALSE)),
+        }
+        // a second formula for the same function lands on the same ref
+        let twice = Formula::Not(Box::new(Formula::Not(Box::new(formula))));
+        assert_eq!(twice.build(&mut bdd), f);
+    }
+}
+
+#[test]
+fn equal_functions_share_a_ref() {
+    let mut bdd = Bdd::new(3);
+    let (a, b, c) = (bdd.var(0), bdd.var(1), bdd.var(2));
+    let ab = bdd.and(a, b);
+    let ac = bdd.and(a, c);
+    let left = bdd.or(ab, ac);
+    let b_or_c = bdd.or(b, c);
+    assert_eq!(left, bdd.and(a, b_or_c));
+
+    let not_a = bdd.not(a);
+    assert_eq!(bdd.or(a, not_a), Ref::TRUE);
+    assert_eq!(bdd.and(a, not_a), Ref::FALSE);
+    assert_eq!(bdd.xor(b, b), Ref::FALSE);
+    assert_eq!(bdd.not(not_a), a);
+    let not_b = bdd.not(b);
+    let nand = bdd.not(ab);
+    assert_eq!(nand, bdd.or(not_a, not_b));
+    assert_eq!(bdd.implies(a, b), bdd.or(not_a, b));
+    assert_eq!(bdd.constant(true), Ref::TRUE);
+    assert_eq!(bdd.decision(a), Some((0, Ref::FALSE, Ref::TRUE)));
+    assert_eq!(bdd.decision(Ref::TRUE), None);
+}
+
+// (x0 & x1) | (x2 & x3) | (x4 & x5) | ...
+fn pairs(bdd: &mut Bdd, pairs: usize) -> Ref {
+    let mut f = Ref::FALSE;
+    for p in 0..pairs {
+        let (a, b) = (bdd.var(2 * p), bdd.var(2 * p + 1));
+        let both = bdd.and(a, b);
+        f = bdd.or(f, both);
+    }
+    f
+}
+
+#[test]
+fn size_depends_on_the_variable_order() {
+    let n = 6;
+    let mut good = Bdd::new(2 * n);
+    let f = pairs(&mut good, n);
+    // one node per variable and the two terminals
+    assert_eq!(good.node_count(f), 2 * n + 2);
+
+    let interleaved = (0..n)
+        .map(|p| 2 * p)
+        .chain((0..n).map(|p| 2 * p + 1))
+        .collect();
+    let mut bad = Bdd::with_order(interleaved);
+    let g = pairs(&mut bad, n);
+    assert!(bad.node_count(g) >= 1 << n);
+    assert_eq!(good.sat_count(f), bad.sat_count(g));
+}
+
+#[test]
+fn counts_queen_placements() {
+    for (n, solutions) in [(4, 2), (5, 10), (6, 4)] {
+        let mut bdd = Bdd::new(n * n);
+        let square = |row: usize, col: usize| row * n + col;
+        let mut board = Ref::TRUE;
+        for row in 0..n {
+            let mut some = Ref::FALSE;
+            for col in 0..n {
+                let here = bdd.var(square(row, col));
+                some = bdd.or(some, here);
+                // a queen here rules out every square it attacks later on the board
+                for (r, c) in (0..n).flat_map(|r| (0..n).map(move |c| (r, c))) {
+                    let attacks = (r, c) != (row, col)
+                        && (r == row || c == col || r.abs_diff(row) == c.abs_diff(col));
+                    if attacks && square(r, c) > square(row, col) {
+                        let there = bdd.var(square(r, c));
+                        let both = bdd.and(here, there);
+                        let neither = bdd.not(both);
+                        board = bdd.and(board, neither);
+                    }
+                }
+            }
+            board = bdd.and(board, some);
+        }
+        assert_eq!(bdd.sat_count(board), solutions, "{} queens", n);
+        let witness = bdd.any_sat(board).unwrap();
+        assert_eq!(witness.iter().filter(|&&queen| queen).count(), n);
+    }
+}
+
+#[test]
+fn sat_counts_free_variables() {
+    let mut bdd = Bdd::new(100);
+    let x = bdd.var(40);
+    assert_eq!(bdd.sat_count(x), 1 << 99);
+    assert_eq!(bdd.sat_count(Ref::TRUE), 1 << 100);
+    assert_eq!(bdd.sat_count(Ref::FALSE), 0);
+    assert_eq!(bdd.any_sat(Ref::FALSE), None);
+    let witness = bdd.any_sat(x).unwrap();
+    assert!(witness[40] && witness.iter().filter(|&&set| set).count() == 1);
+}
+
+#[test]
+fn draws_shared_nodes_once() {
+    let mut bdd = Bdd::new(3);
+    let (a, b, c) = (bdd.var(0), bdd.var(1), bdd.var(2));
+    let b_or_c = bdd.or(b, c);
+    let f = bdd.and(a, b_or_c);
+    let g = bdd.xor(a, b_or_c);
+    let dot = bdd.to_dot_of(&[f, g], &DotStyle::default());
+    assert!(dot.starts_with("digraph \"G\" {\n"));
+    // the two roots, b | c and its negation with two nodes each, and the terminals
+    let drawn = dot.lines().filter(|line| line.contains("[label=")).count();
+    assert_eq!(drawn, 8);
+    assert_eq!(dot.matches("style=dashed").count(), drawn - 2);
+    assert_eq!(bdd.node_count(f) + bdd.node_count(g), 5 + 7);
+    assert!(dot.contains("[label=\"x2\"]"));
+
+    let annotated = bdd.to_dot_with(&DotStyle {
+        annotate: true,
+        ..DotStyle::default()
+    });
+    assert!(annotated.contains("[label=\"x0\\n#"));
+    assert_eq!(annotated.matches("[label=").count(), bdd.size());
+}
+
+#[test]
+#[should_panic(expected = "variable 3 out of range for 3 variables")]
+fn rejects_unknown_variables() {
+    Bdd::new(3).var(3);
+}
+
+#[test]
+#[should_panic(expected = "does not list each of the 3 variables once")]
+fn rejects_orders_that_are_not_permutations() {
+    Bdd::with_order(vec![0, 2, 2]);
+}
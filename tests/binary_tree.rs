@@ -0,0 +1,194 @@
+use dsa_rust::{
+    generate::Rng,
+    tree::binary::{BinaryTree, ParseTreeError, ReconstructError},
+};
+
+fn leaf<T>(data: T) -> BinaryTree<T> {
+    BinaryTree::leaf(data)
+}
+
+//       1
+//      / \
+//     2   3
+//    / \   \
+//   4   5   6
+fn sample() -> BinaryTree<i32> {
+    BinaryTree::node(
+        1,
+        BinaryTree::node(2, leaf(4), leaf(5)),
+        BinaryTree::node(3, BinaryTree::new(), leaf(6)),
+    )
+}
+
+fn values(refs: Vec<&i32>) -> Vec<i32> {
+    refs.into_iter().copied().collect()
+}
+
+// a random shape over 0..size, built by hanging each new value off a random empty spot
+fn random_tree(rng: &mut Rng, size: i32) -> BinaryTree<i32> {
+    let mut tree = BinaryTree::new();
+    for value in 0..size {
+        let mut at = &mut tree;
+        while !at.is_empty() {
+            at = match rng.chance(0.5) {
+                true => at.left_mut().unwrap(),
+                false => at.right_mut().unwrap(),
+            };
+        }
+        *at = leaf(value);
+    }
+    tree
+}
+
+#[test]
+fn construction_and_traversals() {
+    let mut tree = sample();
+    assert_eq!((tree.size(), tree.height()), (6, 3));
+    assert_eq!(values(tree.preorder()), [1, 2, 4, 5, 3, 6]);
+    assert_eq!(values(tree.inorder()), [4, 2, 5, 1, 3, 6]);
+    assert_eq!(values(tree.postorder()), [4, 5, 2, 6, 3, 1]);
+    assert_eq!(values(tree.level_order()), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(tree.left().and_then(BinaryTree::root), Some(&2));
+    assert!(tree.right().unwrap().left().unwrap().is_empty());
+
+    let old = tree.set_left(leaf(7));
+    assert_eq!(old.size(), 3);
+    *tree.root_mut().unwrap() = 10;
+    tree.right_mut().unwrap().set_left(old);
+    assert_eq!(values(tree.preorder()), [10, 7, 3, 2, 4, 5, 6]);
+    assert!(BinaryTree::<u8>::new().preorder().is_empty());
+    assert_eq!(BinaryTree::<u8>::default().height(), 0);
+}
+
+#[test]
+fn rebuilds_from_preorder_and_inorder() {
+    let mut rng = Rng::new(13);
+    for size in [0, 1, 2, 5, 40, 200] {
+        let tree = random_tree(&mut rng, size);
+        let rebuilt =
+            BinaryTree::from_preorder_inorder(&values(tree.preorder()), &values(tree.inorder()))
+                .unwrap();
+        assert_eq!(rebuilt, tree);
+    }
+    assert_eq!(
+        BinaryTree::from_preorder_inorder(&[1, 2], &[1]),
+        Err(ReconstructError::LengthMismatch {
+            preorder: 2,
+            inorder: 1
+        })
+    );
+    assert_eq!(
+        BinaryTree::from_preorder_inorder(&[1, 1], &[1, 1]),
+        Err(ReconstructError::Duplicate)
+    );
+    // 3 would have to be the root of the left part [2], which cannot hold it
+    assert_eq!(
+        BinaryTree::from_preorder_inorder(&[1, 3, 2], &[2, 1, 3]),
+        Err(ReconstructError::Inconsistent)
+    );
+    assert_eq!(
+        BinaryTree::from_preorder_inorder(&[1, 2], &[1, 9]),
+        Err(ReconstructError::Inconsistent)
+    );
+}
+
+#[test]
+fn symmetry_and_inversion() {
+    let mirror = BinaryTree::node(
+        1,
+        BinaryTree::node(2, leaf(3), leaf(4)),
+        BinaryTree::node(2, leaf(4), leaf(3)),
+    );
+    assert!(mirror.is_symmetric());
+    assert!(BinaryTree::<i32>::new().is_symmetric());
+    assert!(leaf(0).is_symmetric());
+    assert!(!sample().is_symmetric());
+    // same values, different shape
+    let lopsided = BinaryTree::node(1, leaf(2), BinaryTree::node(2, BinaryTree::new(), leaf(3)));
+    assert!(!lopsided.is_symmetric());
+
+    let mut tree = sample();
+    tree.invert();
+    assert_eq!(values(tree.inorder()), [6, 3, 1, 5, 2, 4]);
+    tree.invert();
+    assert_eq!(tree, sample());
+
+    // a tree beside its mirror image is symmetric
+    let mut rng = Rng::new(4);
+    let half = random_tree(&mut rng, 30);
+    let mut flipped = half.clone();
+    flipped.invert();
+    assert!(BinaryTree::node(-1, half, flipped).is_symmetric());
+}
+
+#[test]
+fn max_path_sums() {
+    assert_eq!(sample().max_path_sum(), Some(17));
+    //    -10
+    //    /  \
+    //   9    20
+    //       /  \
+    //      15   7
+    let tree = BinaryTree::node(-10, leaf(9), BinaryTree::node(20, leaf(15), leaf(7)));
+    assert_eq!(tree.max_path_sum(), Some(42));
+    // every value negative: the best path is the single largest node
+    let negative = BinaryTree::node(-3, leaf(-1), leaf(-2));
+    assert_eq!(negative.max_path_sum(), Some(-1));
+    assert_eq!(BinaryTree::<i64>::new().max_path_sum(), None);
+}
+
+#[test]
+fn level_order_strings_round_trip() {
+    let tree = sample();
+    assert_eq!(tree.level_order_string(), "1,2,3,4,5,#,6");
+    assert_eq!(format!("{:?}", tree), "BinaryTree [1, 2, 3, 4, 5, #, 6]");
+    let parsed: BinaryTree<i32> = BinaryTree::parse_level_order("1, 2, 3, 4, 5, #, 6").unwrap();
+    assert_eq!(parsed, tree);
+
+    let mut rng = Rng::new(21);
+    for size in [0, 1, 3, 50] {
+        let tree = random_tree(&mut rng, size);
+        let text = tree.level_order_string();
+        assert_eq!(BinaryTree::parse_level_order(&text), Ok(tree));
+    }
+    assert_eq!(BinaryTree::<u8>::new().level_order_string(), "");
+    assert!(BinaryTree::<u8>::parse_level_order("#").unwrap().is_empty());
+    assert!(BinaryTree::<u8>::parse_level_order(" ").unwrap().is_empty());
+    let chain = BinaryTree::<u8>::parse_level_order("1,#,2,#,3").unwrap();
+    assert_eq!(chain.height(), 3);
+}
+
+#[test]
+fn level_order_errors() {
+    assert_eq!(
+        BinaryTree::<u8>::parse_level_order("1,x,2"),
+        Err(ParseTreeError::BadValue { at: 1 })
+    );
+    assert_eq!(
+        BinaryTree::<u8>::parse_level_order("1,#,#,4"),
+        Err(ParseTreeError::Trailing { at: 3 })
+    );
+    assert_eq!(
+        ParseTreeError::Trailing { at: 3 }.to_string(),
+        "token 3 has no parent to hang from"
+    );
+}
+
+// dropping and cleaning up never recurse, so a tree as deep as it is large is fine
+#[test]
+fn deep_trees() {
+    let mut tree = BinaryTree::new();
+    for value in 0..100_000i64 {
+        tree = BinaryTree::node(value, tree, BinaryTree::new());
+    }
+    assert_eq!(tree.height(), 100_000);
+    assert_eq!(tree.max_path_sum(), Some((0..100_000).sum()));
+    tree.invert();
+    assert!(!tree.is_symmetric());
+}
+
+#[test]
+#[should_panic(expected = "an empty tree has no children to set")]
+fn empty_trees_have_no_children() {
+    BinaryTree::new().set_right(leaf(1));
+}
@@ -0,0 +1,98 @@
+use dsa_rust::collections::BitVec;
+
+#[test]
+fn bit_vec_basics() {
+    let mut bits = BitVec::new();
+    (0..130).for_each(|i| bits.push(i % 3 == 0));
+    assert_eq!(bits.len(), 130);
+    assert_eq!(
+        (bits.get(63), bits.get(64), bits.get(129), bits.get(130)),
+        (Some(true), Some(false), Some(true), None)
+    );
+    assert_eq!(bits.iter().next_back(), Some(true));
+    assert_eq!(bits.iter().filter(|&bit| bit).count(), 44);
+    assert_eq!(bits.pop(), Some(true));
+    (0..65).for_each(|_| {
+        bits.pop();
+    });
+    assert_eq!(bits, (0..64).map(|i| i % 3 == 0).collect());
+    assert_eq!(
+        format!(
+            "{:?}",
+            "10110".chars().map(|c| c == '1').collect::<BitVec>()
+        ),
+        "10110"
+    );
+}
+
+fn pattern(len: usize, every: usize) -> BitVec {
+    (0..len).map(|i| i % every == 0).collect()
+}
+
+#[test]
+fn set_toggle_and_repeat() {
+    let mut bits = BitVec::repeat(false, 100);
+    assert!(!bits.any());
+    bits.set(3, true);
+    bits.set(99, true);
+    bits.toggle(64);
+    bits.toggle(3);
+    assert_eq!(bits.iter_ones().collect::<Vec<_>>(), vec![64, 99]);
+    assert_eq!((bits.count_ones(), bits.count_zeros()), (2, 98));
+    let full = BitVec::repeat(true, 70);
+    assert_eq!(full.count_ones(), 70);
+    assert_eq!(full, (0..70).map(|_| true).collect());
+}
+
+#[test]
+#[should_panic(expected = "bit 8 out of range for 8 bits")]
+fn set_checks_bounds() {
+    BitVec::repeat(false, 8).set(8, true);
+}
+
+#[test]
+fn bitwise_ops_match_per_bit() {
+    let (a, b) = (pattern(150, 2), pattern(150, 3));
+    let check = |result: BitVec, op: fn(bool, bool) -> bool| {
+        assert_eq!(result.len(), 150);
+        for i in 0..150 {
+            assert_eq!(result.get(i), Some(op(i % 2 == 0, i % 3 == 0)), "bit {}", i);
+        }
+    };
+    check(&a & &b, |x, y| x & y);
+    check(&a | &b, |x, y| x | y);
+    check(&a ^ &b, |x, y| x ^ y);
+    check(!&a, |x, _| !x);
+    // the tail past len stays clear, so 150 bits give 150 - 75 ones and not 192 - 75
+    assert_eq!((!a.clone()).count_ones(), 75);
+    let mut c = a.clone();
+    c |= &b;
+    c &= &!&b;
+    assert_eq!(c, &a & &!&b);
+    c ^= &c.clone();
+    assert!(!c.any());
+}
+
+#[test]
+#[should_panic(expected = "bitwise ops need bit vectors of the same length")]
+fn bitwise_ops_need_equal_lengths() {
+    let _ = pattern(10, 2) & pattern(11, 2);
+}
+
+#[test]
+fn rank_and_select_are_inverse() {
+    let bits = pattern(1000, 7);
+    let ones: Vec<usize> = bits.iter_ones().collect();
+    assert_eq!(ones.len(), bits.count_ones());
+    for (k, &index) in ones.iter().enumerate() {
+        assert_eq!(bits.select(k), Some(index));
+        assert_eq!(bits.rank(index), k);
+    }
+    assert_eq!(bits.select(ones.len()), None);
+    assert_eq!(bits.rank(1000), ones.len());
+    assert_eq!(bits.rank(0), 0);
+    for i in 0..=1000 {
+        assert_eq!(bits.rank(i), ones.iter().filter(|&&one| one < i).count());
+    }
+    assert_eq!(BitVec::new().iter_ones().next(), None);
+}
@@ -0,0 +1,154 @@
+use std::thread;
+
+use dsa_rust::tree::{avl::AVLTree, bst::BST};
+
+// inserting sorted keys costs n^2 / 2 comparisons, so the deep cases stay modest
+const DEEP: u32 = if cfg!(miri) { 200 } else { 5_000 };
+
+// runs work on a thread whose stack is far too small for anything that recurses once per level
+fn on_small_stack<F>(work: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(work)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn sorted_input_degenerates_into_a_list() {
+    let bst = BST::from(0..64);
+    let avl = AVLTree::from(0..64);
+    assert_eq!(bst.height(), 64);
+    assert_eq!(bst.depth(&0), Some(0));
+    assert_eq!(bst.depth(&63), Some(63));
+    assert_eq!(bst.depth(&64), None);
+    assert!(bst.iter().eq(avl.iter()));
+
+    // the same keys in an order that splits each range at its middle keep it perfectly full
+    let mut keys = Vec::new();
+    let mut ranges = vec![(0, 63)];
+    while let Some((low, high)) = ranges.pop() {
+        if low < high {
+            let mid = (low + high) / 2;
+            keys.push(mid);
+            ranges.extend([(mid + 1, high), (low, mid)]);
+        }
+    }
+    let full = BST::from(keys);
+    assert_eq!(full.height(), 6);
+    assert_eq!(BST::<u32>::new().height(), 0);
+    assert!(full.validate().is_ok() && bst.validate().is_ok());
+}
+
+#[test]
+fn queries_match_their_avl_answers() {
+    let keys: Vec<u32> = (0..200).map(|key| key * 7_919 % 1_000).collect();
+    let mut bst: BST<u32> = keys.iter().copied().collect();
+    let mut avl: AVLTree<u32> = keys.iter().copied().collect();
+    for probe in [0, 3, 500, 999, 1_000] {
+        assert_eq!(bst.get_floor(&probe), avl.get_floor(&probe));
+        assert_eq!(bst.get_ceil(&probe), avl.get_ceil(&probe));
+        assert_eq!(bst.rank(&probe), avl.rank(&probe));
+        assert_eq!(bst.index_of(&probe), avl.index_of(&probe));
+        assert_eq!(bst.range_count(probe..), avl.range_count(probe..));
+        assert!(bst.range(..probe).rev().eq(avl.range(..probe).rev()));
+    }
+    assert_eq!(bst.kth_smallest(42), avl.kth_smallest(42));
+    assert_eq!((bst.first(), bst.last()), (avl.first(), avl.last()));
+
+    for key in keys.iter().step_by(3) {
+        assert_eq!(bst.take(key), Some(*key));
+        avl.remove(key);
+        assert!(!bst.remove(key));
+        // a miss walks the whole path, so its sizes have to be put back
+        assert!(!bst.insert(keys[1]));
+        assert!(bst.validate().is_ok());
+    }
+    assert_eq!(bst.size(), avl.size());
+    assert!(bst.iter().eq(avl.iter()));
+
+    *bst.get_mut(&keys[1]).unwrap() = keys[1];
+    let evens: Vec<u32> = bst.drain_filter(|key| key % 2 == 0).collect();
+    assert!(evens.iter().all(|key| key % 2 == 0) && evens.is_sorted());
+    assert!(bst.iter().all(|key| key % 2 == 1));
+    assert!(bst.validate().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "no longer matches its key")]
+fn get_mut_rejects_a_changed_key() {
+    let mut bst = BST::from([1, 2, 3]);
+    *bst.get_mut(&2).unwrap() = 5;
+}
+
+#[test]
+fn degenerate_trees_never_recurse() {
+    on_small_stack(|| {
+        let mut tree = BST::from((0..DEEP).rev());
+        assert_eq!(tree.height(), DEEP as usize);
+        assert!(tree.validate().is_ok());
+
+        let copy = tree.clone();
+        assert_eq!(copy, tree);
+        assert_eq!(copy.height(), DEEP as usize);
+        assert_eq!(tree.iter_postorder().next(), Some(&0));
+        assert_eq!(tree.iter_preorder().next(), Some(&(DEEP - 1)));
+        assert_eq!(tree.iter_level_order().count(), DEEP as usize);
+        assert_eq!(tree.kth_smallest(0), Some(&0));
+        assert_eq!(tree.rank(&(DEEP - 1)), DEEP as usize - 1);
+
+        // drain_filter puts what it keeps back in preorder, so the list shape survives
+        let odd = tree.drain_filter(|key| key % 2 == 1).count();
+        assert_eq!(odd, DEEP as usize / 2);
+        assert_eq!(tree.height(), DEEP as usize - odd);
+
+        // dropping part way through must not recurse into the spine the iterator still owns
+        let mut iter = copy.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+        assert!(tree.remove(&0));
+        drop(tree);
+    });
+}
+
+#[test]
+fn shape_diagnostics_tell_the_trees_apart() {
+    let n = 127;
+    let list = BST::from(0..n);
+    let avl = AVLTree::from(0..n);
+    assert_eq!((list.height(), list.leaf_count()), (n as usize, 1));
+    assert_eq!(
+        list.internal_path_length(),
+        (n as usize) * (n as usize - 1) / 2
+    );
+    assert!(!list.is_balanced());
+
+    // 127 sorted inserts fill an AVL tree completely: seven levels, 64 leaves
+    assert_eq!((avl.height(), avl.leaf_count()), (7, 64));
+    assert_eq!(
+        avl.internal_path_length(),
+        (1..7).map(|depth| depth << depth).sum()
+    );
+    assert!(avl.is_balanced());
+
+    let balanced = BST::from(avl.iter_preorder().copied());
+    assert_eq!(balanced.height(), avl.height());
+    assert_eq!(balanced.internal_path_length(), avl.internal_path_length());
+    assert!(balanced.is_balanced());
+    assert!(BST::from([2, 1, 3, 4]).is_balanced() && !BST::from([2, 1, 3, 4, 5]).is_balanced());
+
+    let empty = AVLTree::<u32>::new();
+    assert_eq!(
+        (
+            empty.height(),
+            empty.leaf_count(),
+            empty.internal_path_length()
+        ),
+        (0, 0, 0)
+    );
+    assert!(empty.is_balanced() && BST::<u32>::new().is_balanced());
+}
@@ -0,0 +1,170 @@
+// The shared contract is checked once per policy through the Cache trait; only the choice of
+// victim differs between policies, so each gets its own eviction test on top.
+
+use std::{cell::RefCell, rc::Rc};
+
+use dsa_rust::prelude::*;
+
+fn exercise(cache: &mut dyn Cache<i32, i32>) {
+    assert!(cache.is_empty());
+    assert_eq!(cache.capacity(), 3);
+    assert_eq!(cache.put(1, 10), None);
+    assert_eq!(cache.put(2, 20), None);
+    assert_eq!(cache.put(1, 11), Some(10));
+    assert_eq!(cache.len(), 2);
+
+    assert_eq!(cache.get(&1), Some(&11));
+    assert_eq!(cache.get(&3), None);
+    assert_eq!(cache.peek(&2), Some(&20));
+    assert!(cache.contains(&2));
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 1, 0));
+
+    for key in 3..10 {
+        cache.put(key, key * 10);
+        assert!(cache.len() <= 3);
+    }
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.stats().evictions, 6);
+    assert!(cache.contains(&9));
+
+    assert_eq!(cache.remove(&9), Some(90));
+    assert_eq!(cache.remove(&9), None);
+    assert_eq!(cache.len(), 2);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&8), None);
+}
+
+macro_rules! check_cache {
+    ($name:ident, $ty:ident) => {
+        #[test]
+        fn $name() {
+            exercise(&mut $ty::new(3));
+
+            let evicted = Rc::new(RefCell::new(Vec::new()));
+            let sink = Rc::clone(&evicted);
+            let mut cache = $ty::with_eviction_callback(1, move |key, value| {
+                sink.borrow_mut().push((key, value))
+            });
+            cache.put(1, 10);
+            cache.put(2, 20);
+            cache.remove(&2);
+            cache.put(3, 30);
+            assert_eq!(*evicted.borrow(), [(1, 10)]);
+
+            let mut cache = $ty::new(4);
+            (0..4).for_each(|key| {
+                cache.put(key, key);
+            });
+            cache.set_capacity(2);
+            assert_eq!(cache.len(), 2);
+            assert_eq!(cache.stats().evictions, 2);
+        }
+    };
+}
+
+check_cache!(lru_contract, LruCache);
+check_cache!(lfu_contract, LfuCache);
+check_cache!(fifo_contract, FifoCache);
+
+#[test]
+fn lru_evicts_least_recent() {
+    let mut cache = LruCache::new(2);
+    cache.put(1, 'a');
+    cache.put(2, 'b');
+    cache.get(&1);
+    cache.put(3, 'c');
+    assert!(!cache.contains(&2));
+    assert_eq!(cache.peek_lru(), Some((&1, &'a')));
+    // peeking leaves recency alone, so 1 is still next to go
+    cache.peek(&1);
+    cache.put(4, 'd');
+    assert!(!cache.contains(&1));
+}
+
+#[test]
+fn lfu_evicts_least_frequent_then_least_recent() {
+    let mut cache = LfuCache::new(3);
+    cache.put(1, 'a');
+    cache.put(2, 'b');
+    cache.put(3, 'c');
+    cache.get(&1);
+    cache.get(&1);
+    cache.get(&3);
+    assert_eq!(cache.uses(&1), Some(3));
+    cache.put(4, 'd');
+    assert!(!cache.contains(&2));
+    // a newcomer starts out as the rarest entry, so it is the next victim
+    cache.put(5, 'e');
+    assert!(!cache.contains(&4));
+    cache.get(&5);
+    cache.put(6, 'f');
+    assert!(!cache.contains(&3) && cache.contains(&5));
+    assert_eq!(cache.uses(&6), Some(1));
+}
+
+#[test]
+fn lfu_finds_the_next_rarest_after_emptying_the_lowest_bucket() {
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let log = Rc::clone(&evicted);
+    let mut cache = LfuCache::with_eviction_callback(5, move |key, _| log.borrow_mut().push(key));
+    // key k is used k times
+    for key in 1..=5 {
+        cache.put(key, ());
+        for _ in 1..key {
+            cache.get(&key);
+        }
+    }
+    // removing the only one-use entry leaves two uses as the lowest count
+    cache.remove(&1);
+    cache.set_capacity(2);
+    assert_eq!(*evicted.borrow(), [2, 3]);
+    cache.set_capacity(1);
+    assert_eq!(*evicted.borrow(), [2, 3, 4]);
+    assert_eq!(cache.uses(&5), Some(5));
+    // a full cache evicts its only entry, however often used, to make room
+    cache.put(6, ());
+    assert_eq!(*evicted.borrow(), [2, 3, 4, 5]);
+    cache.remove(&6);
+    assert!(cache.is_empty());
+    cache.put(7, ());
+    assert_eq!(cache.uses(&7), Some(1));
+}
+
+#[test]
+fn fifo_evicts_oldest_regardless_of_use() {
+    let mut cache = FifoCache::new(2);
+    cache.put(1, 'a');
+    cache.put(2, 'b');
+    cache.get(&1);
+    cache.put(1, 'z');
+    assert_eq!(cache.peek_oldest(), Some((&1, &'z')));
+    cache.put(3, 'c');
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.iter().collect::<Vec<_>>(), [(&3, &'c'), (&2, &'b')]);
+}
+
+#[test]
+fn policies_compare_on_the_same_trace() {
+    // a hot key interleaved with a scan longer than the cache: recency and frequency both keep
+    // the hot key resident, while insertion order keeps pushing it out
+    let trace: Vec<i32> = (0..200).flat_map(|i| [0, i % 10 + 1]).collect();
+    let mut caches: [Box<dyn Cache<i32, i32>>; 3] = [
+        Box::new(LruCache::new(4)),
+        Box::new(LfuCache::new(4)),
+        Box::new(FifoCache::new(4)),
+    ];
+    for cache in caches.iter_mut() {
+        for &key in &trace {
+            if cache.get(&key).is_none() {
+                cache.put(key, key);
+            }
+        }
+        assert_eq!(cache.stats().lookups(), trace.len());
+    }
+    let [lru, lfu, fifo] = caches.map(|cache| cache.stats().hit_rate());
+    assert!((lfu - 0.5).abs() < 0.01);
+    assert!((lru - 0.5).abs() < 0.01);
+    assert!(fifo < lru);
+}
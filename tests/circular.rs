@@ -0,0 +1,54 @@
+use dsa_rust::linked_list::circular::CircularList;
+
+// the same elimination on a vec, removing by index
+fn naive_survivor(n: usize, k: usize) -> Option<usize> {
+    let mut people: Vec<usize> = (1..=n).collect();
+    let mut at = 0;
+    while people.len() > 1 {
+        at = (at + k - 1) % people.len();
+        people.remove(at);
+    }
+    people.pop()
+}
+
+#[test]
+fn josephus_survivors() {
+    assert_eq!(CircularList::from(1..=7).survivor(3), Some(4));
+    // one person survives any step, and a step of one takes everyone in order
+    assert_eq!(CircularList::from([9]).survivor(5), Some(9));
+    assert_eq!(CircularList::from(1..=7).survivor(1), Some(7));
+    assert_eq!(CircularList::<usize>::new().survivor(2), None);
+    for n in 1..20 {
+        for k in 1..10 {
+            assert_eq!(
+                CircularList::from(1..=n).survivor(k),
+                naive_survivor(n, k),
+                "n={} k={}",
+                n,
+                k
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "josephus step must be at least 1")]
+fn josephus_rejects_a_zero_step() {
+    CircularList::from(1..=3).survivor(0);
+}
+
+#[test]
+fn iter_cycle_wraps_around() {
+    let list = CircularList::from([1, 2, 3]);
+    let twice: Vec<i32> = list.iter_cycle().take(2 * list.len()).copied().collect();
+    assert_eq!(twice, [1, 2, 3, 1, 2, 3]);
+    assert!(list.iter_cycle().skip(3).take(3).eq(list.iter()));
+
+    // the cycle starts wherever the head has been rotated to
+    let mut rotated = list.clone();
+    rotated.rotate();
+    assert!(rotated.iter_cycle().take(4).copied().eq([2, 3, 1, 2]));
+    let single = CircularList::from(["only"]);
+    assert!(single.iter_cycle().take(3).eq(&["only"; 3]));
+    assert_eq!(CircularList::<i32>::new().iter_cycle().next(), None);
+}
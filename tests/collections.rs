@@ -0,0 +1,53 @@
+use dsa_rust::collections::{Key, SlotMap};
+
+#[test]
+fn slot_map_keys_go_stale_on_removal() {
+    let mut map = SlotMap::new();
+    let a = map.insert("a");
+    let b = map.insert("b");
+    assert_eq!((map.len(), map[a], map[b]), (2, "a", "b"));
+
+    assert_eq!(map.remove(a), Some("a"));
+    assert_eq!(map.remove(a), None);
+    assert!(!map.contains_key(a) && map.get(a).is_none());
+
+    // the freed slot is reused, but under a new generation the old key cannot reach
+    let c = map.insert("c");
+    assert_eq!(c.index(), a.index());
+    assert_ne!(c.generation(), a.generation());
+    assert_eq!(map.get(a), None);
+    assert_eq!(map.get(c), Some(&"c"));
+    *map.get_mut(c).unwrap() = "C";
+    assert_eq!(map[c], "C");
+}
+
+#[test]
+fn slot_map_iterates_occupied_slots() {
+    let mut map: SlotMap<i32> = (0..10).collect();
+    let keys: Vec<Key> = map.keys().collect();
+    for key in keys.iter().step_by(2) {
+        map.remove(*key);
+    }
+    assert_eq!(map.iter().len(), 5);
+    assert!(map.values().copied().eq([1, 3, 5, 7, 9]));
+    for (_, value) in &mut map {
+        *value *= 10;
+    }
+    map.retain(|_, value| *value > 30);
+    assert!(map.values().copied().eq([50, 70, 90]));
+
+    let kept: Vec<Key> = map.keys().collect();
+    map.clear();
+    assert!(map.is_empty());
+    assert!(kept.iter().all(|key| !map.contains_key(*key)));
+    assert!(keys.iter().all(|key| map.get(*key).is_none()));
+}
+
+#[test]
+fn slot_map_values_can_hold_their_own_key() {
+    let mut nodes = SlotMap::new();
+    let root = nodes.insert_with_key(|key| (key, None));
+    let child = nodes.insert_with_key(|key| (key, Some(root)));
+    assert_eq!(nodes[root].0, root);
+    assert_eq!(nodes[child], (child, Some(root)));
+}
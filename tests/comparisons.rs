@@ -0,0 +1,86 @@
+// Equality, ordering and hashing are element-wise for every collection, so the
+// edge cases worth pinning down are empty collections and strict prefixes.
+
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use dsa_rust::prelude::*;
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+macro_rules! check_sequence {
+    ($name:ident, $ty:ty) => {
+        #[test]
+        fn $name() {
+            let empty = <$ty>::default();
+            assert_eq!(empty, <$ty>::default());
+            assert_eq!(hash_of(&empty), hash_of(&<$ty>::default()));
+
+            let short: $ty = [1, 2].into_iter().collect();
+            let long: $ty = [1, 2, 3].into_iter().collect();
+            let other: $ty = [1, 3].into_iter().collect();
+            assert_ne!(short, long);
+            assert_ne!(empty, short);
+            assert_eq!(short, [1, 2].into_iter().collect::<$ty>());
+            assert_eq!(
+                hash_of(&short),
+                hash_of(&[1, 2].into_iter().collect::<$ty>())
+            );
+
+            assert_eq!(empty.cmp(&short), Ordering::Less);
+            assert_eq!(short.cmp(&long), Ordering::Less);
+            assert_eq!(long.cmp(&other), Ordering::Less);
+            assert_eq!(other.partial_cmp(&short), Some(Ordering::Greater));
+        }
+    };
+}
+
+check_sequence!(singly_list, SinglyLinkedList<i32>);
+check_sequence!(doubly_list, DoublyLinkedList<i32>);
+check_sequence!(xor_list, XorLinkedList<i32>);
+check_sequence!(unrolled_list, UnrolledList<i32, 2>);
+check_sequence!(circular_list, CircularList<i32>);
+check_sequence!(avl_tree, AVLTree<i32>);
+check_sequence!(multiset, AVLMultiSet<i32>);
+
+#[test]
+fn avl_equality_ignores_insertion_order() {
+    let ascending = AVLTree::from(0..100);
+    let descending = AVLTree::from((0..100).rev());
+    assert_eq!(ascending, descending);
+    assert_eq!(hash_of(&ascending), hash_of(&descending));
+}
+
+#[test]
+fn multiset_equality_counts_duplicates() {
+    assert_ne!(AVLMultiSet::from([1, 1, 2]), AVLMultiSet::from([1, 2, 2]));
+    assert_eq!(AVLMultiSet::from([2, 1, 1]), AVLMultiSet::from([1, 2, 1]));
+}
+
+#[test]
+fn heaps_compare_as_multisets() {
+    assert_eq!(BinaryHeap::<i32>::default(), BinaryHeap::min());
+    assert_eq!(
+        BinaryHeap::from_iter([3, 1, 2]),
+        BinaryHeap::from_iter([1, 2, 3])
+    );
+    assert_ne!(
+        BinaryHeap::from_iter([1, 2]),
+        BinaryHeap::from_iter([1, 2, 3])
+    );
+
+    let mut small = BoundedHeap::with_capacity_limit(2, |a: &i32, b| a.cmp(b));
+    let mut large = BoundedHeap::with_capacity_limit(2, |a: &i32, b| a.cmp(b));
+    small.extend([5, 1, 3]);
+    large.extend([1, 3]);
+    assert_eq!(small, large);
+    large.clear();
+    assert_ne!(small, large);
+}
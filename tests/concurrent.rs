@@ -0,0 +1,152 @@
+// Queue producers tag each item with their id and a running count, so the consumer can check that
+// nothing was lost or duplicated and that each producer's items kept their order.
+#![cfg(feature = "std")]
+
+use std::{
+    sync::{Arc, Barrier},
+    thread,
+    time::Duration,
+};
+
+use dsa_rust::{
+    adt::{ConcurrentQueue, Stack},
+    concurrent::{BlockingQueue, MpscQueue, TreiberStack},
+};
+
+const PRODUCERS: usize = 4;
+// miri checks every atomic access, so it gets a smaller workload
+const ITEMS: usize = if cfg!(miri) { 100 } else { 5_000 };
+
+fn hammer<Q>(queue: Arc<Q>)
+where
+    Q: ConcurrentQueue<(usize, usize)> + 'static,
+{
+    let barrier = Arc::new(Barrier::new(PRODUCERS));
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|id| {
+            let (queue, barrier) = (Arc::clone(&queue), Arc::clone(&barrier));
+            thread::spawn(move || {
+                barrier.wait();
+                for count in 0..ITEMS {
+                    queue.offer((id, count));
+                }
+            })
+        })
+        .collect();
+
+    let mut next = [0; PRODUCERS];
+    let mut received = 0;
+    while received < PRODUCERS * ITEMS {
+        match queue.poll() {
+            Some((id, count)) => {
+                assert_eq!(count, next[id], "producer {} out of order", id);
+                next[id] += 1;
+                received += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    producers
+        .into_iter()
+        .for_each(|producer| producer.join().unwrap());
+    assert!(queue.is_empty());
+    assert_eq!(queue.poll(), None);
+}
+
+#[test]
+fn blocking_queue_keeps_per_producer_order() {
+    hammer(Arc::new(BlockingQueue::new()));
+}
+
+#[test]
+fn mpsc_queue_keeps_per_producer_order() {
+    hammer(Arc::new(MpscQueue::new()));
+}
+
+#[test]
+fn blocking_queue_waits_for_items() {
+    let queue = Arc::new(BlockingQueue::new());
+    assert_eq!(queue.poll_timeout(Duration::from_millis(10)), None);
+
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            queue.offer(1);
+            queue.offer(2);
+        })
+    };
+    assert_eq!(queue.take(), 1);
+    assert_eq!(queue.poll_timeout(Duration::from_secs(10)), Some(2));
+    producer.join().unwrap();
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn mpsc_queue_drops_unpolled_items() {
+    let item = Arc::new(());
+    let queue = MpscQueue::new();
+    for _ in 0..10 {
+        queue.offer(Arc::clone(&item));
+    }
+    drop(queue.poll());
+    drop(queue);
+    assert_eq!(Arc::strong_count(&item), 1);
+}
+
+#[test]
+fn treiber_stack_loses_nothing_under_contention() {
+    // strings own heap memory, so a peek cloning an item that a pop had already moved out
+    // would read freed memory rather than just a stale value
+    let stack = Arc::new(TreiberStack::new());
+    let workers: Vec<_> = (0..PRODUCERS)
+        .map(|id| {
+            let mut stack = Arc::clone(&stack);
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                for count in 0..ITEMS {
+                    stack.push(format!("{}-{}", id, count));
+                    if count % 3 == 0 {
+                        popped.extend(stack.pop());
+                    }
+                    if let Some(top) = stack.peek() {
+                        assert!(top.contains('-'));
+                    }
+                }
+                popped
+            })
+        })
+        .collect();
+    let mut seen: Vec<String> = workers
+        .into_iter()
+        .flat_map(|worker| worker.join().unwrap())
+        .collect();
+    let stack = Arc::into_inner(stack).unwrap();
+    assert_eq!(stack.len(), PRODUCERS * ITEMS - seen.len());
+    while let Some(item) = stack.pop() {
+        seen.push(item);
+    }
+    seen.sort();
+    let mut expected: Vec<String> = (0..PRODUCERS)
+        .flat_map(|id| (0..ITEMS).map(move |count| format!("{}-{}", id, count)))
+        .collect();
+    expected.sort();
+    assert_eq!(seen, expected);
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn treiber_stack_is_lifo_through_the_stack_trait() {
+    let mut stack = Arc::new(TreiberStack::new());
+    let mut handle = Arc::clone(&stack);
+    for value in 0..5 {
+        handle.push(value);
+    }
+    assert_eq!(Stack::peek(&stack), Some(4));
+    assert_eq!(Stack::len(&stack), 5);
+    assert_eq!(Stack::pop(&mut stack), Some(4));
+    assert_eq!(Stack::pop(&mut handle), Some(3));
+    Stack::clear(&mut handle);
+    assert!(Stack::is_empty(&stack));
+    assert_eq!(Stack::pop(&mut stack), None);
+}
@@ -0,0 +1,169 @@
+// Every node the doubly list allocates must be freed exactly once, whichever way
+// the element leaves the list. Run under `cargo +nightly miri test` to also catch
+// leaks and use-after-free in the pointer handling.
+
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use dsa_rust::linked_list::doubly::LinkedList;
+
+#[derive(Clone)]
+struct Tracked {
+    drops: Rc<Cell<usize>>,
+    panic_on_drop: bool,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+        if self.panic_on_drop {
+            panic!("tracked element panicked on drop");
+        }
+    }
+}
+
+fn tracked_list(drops: &Rc<Cell<usize>>, n: usize) -> LinkedList<Tracked> {
+    LinkedList::from((0..n).map(|_| Tracked {
+        drops: drops.clone(),
+        panic_on_drop: false,
+    }))
+}
+
+#[test]
+fn pops_reclaim_single_element() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = tracked_list(&drops, 1);
+    drop(list.pop_head());
+    assert_eq!(drops.get(), 1);
+    assert_eq!(list.len(), 0);
+    assert!(list.peek_head().is_none() && list.peek_tail().is_none());
+
+    let mut list = tracked_list(&drops, 1);
+    drop(list.pop_tail());
+    assert_eq!(drops.get(), 2);
+    assert_eq!(list.len(), 0);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn pops_keep_size_in_sync() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = tracked_list(&drops, 10);
+    for remaining in (0..10).rev() {
+        if remaining % 2 == 0 {
+            list.pop_head();
+        } else {
+            list.pop_tail();
+        }
+        assert_eq!(list.len(), remaining);
+        assert_eq!(list.iter().count(), remaining);
+    }
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn drop_and_clear_free_every_node() {
+    let drops = Rc::new(Cell::new(0));
+    drop(tracked_list(&drops, 100));
+    assert_eq!(drops.get(), 100);
+
+    let mut list = tracked_list(&drops, 100);
+    list.clear();
+    assert_eq!(drops.get(), 200);
+    assert!(list.is_empty());
+    drop(list);
+    assert_eq!(drops.get(), 200);
+}
+
+#[test]
+fn partially_consumed_into_iter_frees_the_rest() {
+    let drops = Rc::new(Cell::new(0));
+    let mut iter = tracked_list(&drops, 10).into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(drops.get(), 2);
+    drop(iter);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn cursor_edits_free_removed_nodes() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = tracked_list(&drops, 10);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    drop(cursor.remove_current());
+    assert_eq!(drops.get(), 1);
+    cursor.move_next();
+    let split = cursor.split_after();
+    assert_eq!(list.len(), 3);
+    assert_eq!(split.len(), 6);
+    drop(split);
+    assert_eq!(drops.get(), 7);
+    drop(list);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn panicking_element_does_not_leak_the_rest() {
+    let drops = Rc::new(Cell::new(0));
+    let mut list = tracked_list(&drops, 5);
+    list.get_mut(1).unwrap().panic_on_drop = true;
+    let result = panic::catch_unwind(AssertUnwindSafe(move || drop(list)));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn long_list_drops_without_recursion() {
+    let list = LinkedList::from(0..if cfg!(miri) { 1_000 } else { 1_000_000 });
+    drop(list);
+}
+
+#[test]
+fn remove_first_keeps_both_directions_linked() {
+    let mut list = LinkedList::from([1, 2, 3, 2, 4]);
+    let check = |list: &LinkedList<i32>, expected: &[i32]| {
+        assert!(list.iter().eq(expected));
+        assert!(list.iter().rev().eq(expected.iter().rev()));
+        assert_eq!(
+            (list.peek_head(), list.peek_tail()),
+            (expected.first(), expected.last())
+        );
+        assert_eq!(list.len(), expected.len());
+    };
+    assert!(list.remove_first(&1));
+    check(&list, &[2, 3, 2, 4]);
+    assert!(list.remove_first(&2));
+    check(&list, &[3, 2, 4]);
+    assert!(list.remove_first(&4));
+    check(&list, &[3, 2]);
+    list.push_tail(5);
+    list.push_head(0);
+    check(&list, &[0, 3, 2, 5]);
+    assert!(!list.remove_first(&9));
+    check(&list, &[0, 3, 2, 5]);
+    for item in [3, 0, 5, 2] {
+        assert!(list.remove_first(&item));
+    }
+    check(&list, &[]);
+    assert!(!list.remove_first(&0));
+    assert_eq!(list.pop_tail(), None);
+}
+
+#[test]
+fn remove_first_frees_only_the_removed_node() {
+    // each element holds a count on live, so its strong count tracks the elements still alive
+    let live = Rc::new(());
+    let mut list = LinkedList::from((0..4).map(|i| (i, Rc::clone(&live))));
+    let probe = |i| (i, Rc::clone(&live));
+    assert!(list.remove_first(&probe(2)));
+    assert_eq!(Rc::strong_count(&live), 4);
+    assert!(!list.remove_first(&probe(7)));
+    assert_eq!(Rc::strong_count(&live), 4);
+    drop(list);
+    assert_eq!(Rc::strong_count(&live), 1);
+}
@@ -0,0 +1,87 @@
+use dsa_rust::algorithms::dp::{
+    Edit, edit_distance, fibonacci, knapsack, longest_common_subsequence,
+};
+
+#[test]
+fn fibonacci_until_it_overflows() {
+    let firsts: Vec<u64> = (0..10).map(|n| fibonacci(n).unwrap()).collect();
+    assert_eq!(firsts, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    assert_eq!(fibonacci(93), Some(12_200_160_415_121_876_738));
+    assert_eq!(fibonacci(94), None);
+}
+
+#[test]
+fn knapsack_picks_the_best_subset() {
+    let items = [(1, 1), (3, 4), (4, 5), (5, 7)];
+    assert_eq!(knapsack(&items, 7), (9, vec![1, 2]));
+    assert_eq!(knapsack(&items, 0), (0, vec![]));
+    assert_eq!(knapsack(&[], 10), (0, vec![]));
+    assert_eq!(knapsack(&items, 100), (17, vec![0, 1, 2, 3]));
+
+    // every subset of a small set, checked against the solver
+    let items = [(5, 10), (4, 40), (6, 30), (3, 50), (2, 7), (7, 33)];
+    for capacity in 0..=27 {
+        let (value, chosen) = knapsack(&items, capacity);
+        let weight: usize = chosen.iter().map(|&i| items[i].0).sum();
+        assert!(weight <= capacity);
+        assert_eq!(chosen.iter().map(|&i| items[i].1).sum::<u64>(), value);
+        let brute = (0u32..1 << items.len())
+            .filter_map(|mask| {
+                let picked = items.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1);
+                let (w, v) = picked.fold((0, 0), |(w, v), (_, item)| (w + item.0, v + item.1));
+                (w <= capacity).then_some(v)
+            })
+            .max();
+        assert_eq!(Some(value), brute);
+    }
+}
+
+#[test]
+fn lcs_rebuilds_a_common_subsequence() {
+    let (length, lcs) = longest_common_subsequence(b"AGGTAB", b"GXTXAYB");
+    assert_eq!((length, lcs), (4, b"GTAB".to_vec()));
+    let (length, lcs) = longest_common_subsequence(b"ABCBDAB", b"BDCABA");
+    assert_eq!(length, 4);
+    let is_subsequence = |of: &[u8]| {
+        let mut rest = of.iter();
+        lcs.iter().all(|c| rest.any(|x| x == c))
+    };
+    assert!(is_subsequence(b"ABCBDAB") && is_subsequence(b"BDCABA"));
+    assert_eq!(longest_common_subsequence::<u8>(b"abc", b""), (0, vec![]));
+}
+
+fn apply(script: &[Edit<char>]) -> (String, String) {
+    let (mut from, mut to) = (String::new(), String::new());
+    for edit in script {
+        match *edit {
+            Edit::Keep(c) => (from.push(c), to.push(c)),
+            Edit::Replace(a, b) => (from.push(a), to.push(b)),
+            Edit::Insert(c) => ((), to.push(c)),
+            Edit::Delete(c) => (from.push(c), ()),
+        };
+    }
+    (from, to)
+}
+
+#[test]
+fn edit_distance_with_a_script() {
+    for (from, to, distance) in [
+        ("kitten", "sitting", 3),
+        ("flaw", "lawn", 2),
+        ("", "abc", 3),
+        ("abc", "", 3),
+        ("same", "same", 0),
+        ("intention", "execution", 5),
+    ] {
+        let a: Vec<char> = from.chars().collect();
+        let b: Vec<char> = to.chars().collect();
+        let (cost, script) = edit_distance(&a, &b);
+        assert_eq!(cost, distance, "{} -> {}", from, to);
+        let paid = script
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Keep(_)))
+            .count();
+        assert_eq!(paid, cost);
+        assert_eq!(apply(&script), (from.to_string(), to.to_string()));
+    }
+}
@@ -0,0 +1,32 @@
+use dsa_rust::{
+    linked_list::{doubly, singly},
+    tree::avl::AVLTree,
+};
+
+// long enough that a recursive drop would overflow the default test thread stack
+const NODES: u32 = if cfg!(miri) { 1_000 } else { 5_000_000 };
+
+#[test]
+fn long_singly_list_drops_without_recursion() {
+    let list = singly::LinkedList::from(0..NODES);
+    assert_eq!(list.len(), NODES as usize);
+    drop(list);
+}
+
+#[test]
+fn long_doubly_list_drops_without_recursion() {
+    let list = doubly::LinkedList::from(0..NODES);
+    assert_eq!(list.len(), NODES as usize);
+    drop(list);
+}
+
+#[test]
+fn large_avl_tree_drops_without_recursion() {
+    let tree = AVLTree::from_sorted_iter(0..NODES);
+    assert_eq!(tree.size(), NODES as usize);
+    drop(tree);
+    // clear takes the same iterative path on a tree that stays alive
+    let mut tree = AVLTree::from_sorted_iter(0..NODES);
+    tree.clear();
+    assert!(tree.is_empty());
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use dsa_rust::applications::event_queue::EventQueue;
+
+#[test]
+fn events_fire_in_time_order_with_ties_first_come() {
+    let mut queue = EventQueue::new();
+    for (at, name) in [(30, "c"), (10, "a"), (20, "b1"), (20, "b2"), (40, "d")] {
+        queue.schedule(at, name);
+    }
+    assert_eq!(
+        (queue.size(), queue.now(), queue.peek_time()),
+        (5, 0, Some(10))
+    );
+    assert_eq!(queue.next_event(), Some((10, "a")));
+    assert_eq!(queue.now(), 10);
+
+    // the clock lands on the requested time, not on the last event before it
+    assert_eq!(
+        queue.advance_to(35),
+        vec![(20, "b1"), (20, "b2"), (30, "c")]
+    );
+    assert_eq!(queue.now(), 35);
+    assert!(queue.advance_to(39).is_empty());
+    assert_eq!(queue.next_event(), Some((40, "d")));
+    assert_eq!((queue.next_event(), queue.now()), (None, 40));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn cancel_and_reschedule_through_ids() {
+    let mut queue: EventQueue<&str> = EventQueue::starting_at(100);
+    let timeout = queue.schedule(150, "timeout");
+    let reply = queue.schedule(120, "reply");
+    let retry = queue.schedule(130, "retry");
+    assert_eq!(
+        (queue.get(timeout), queue.time_of(timeout)),
+        (Some(&"timeout"), Some(150))
+    );
+
+    // the reply arrives, so the timeout is called off and the retry pushed back behind a
+    // new event at the same time
+    assert_eq!(queue.next_event(), Some((120, "reply")));
+    assert_eq!(queue.cancel(timeout), Some("timeout"));
+    let log = queue.schedule(140, "log");
+    assert_eq!(queue.reschedule(retry, 140), Some(130));
+    assert_eq!(queue.advance_to(200), vec![(140, "log"), (140, "retry")]);
+
+    // ids that fired or were cancelled stay dead
+    for id in [timeout, reply, retry, log] {
+        assert!(!queue.contains(id));
+        assert_eq!(
+            (
+                queue.cancel(id),
+                queue.reschedule(id, 300),
+                queue.time_of(id)
+            ),
+            (None, None, None)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot schedule an event in the past")]
+fn scheduling_behind_the_clock_panics() {
+    let mut queue = EventQueue::new();
+    queue.schedule(5u64, ());
+    queue.next_event();
+    queue.schedule(4, ());
+}
+
+#[test]
+#[should_panic(expected = "time only moves forward")]
+fn the_clock_never_runs_backwards() {
+    let mut queue: EventQueue<()> = EventQueue::starting_at(10);
+    queue.advance_to(9);
+}
+
+// a single-teller bank: arrivals every 3 ticks, each service taking 5, so the line builds up and
+// every departure is scheduled from inside the loop that fires events
+#[test]
+fn drives_a_queueing_simulation() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Event {
+        Arrive(u32),
+        Depart(u32),
+    }
+    let mut queue = EventQueue::new();
+    (0..6).for_each(|customer| {
+        queue.schedule(3 * customer as u64, Event::Arrive(customer));
+    });
+    let (mut line, mut busy, mut departures) = (Vec::new(), false, Vec::new());
+    while let Some((now, event)) = queue.next_event() {
+        match event {
+            Event::Arrive(customer) if !busy => {
+                busy = true;
+                queue.schedule(now + 5, Event::Depart(customer));
+            }
+            Event::Arrive(customer) => line.push(customer),
+            Event::Depart(customer) => {
+                departures.push((now, customer));
+                match line.is_empty() {
+                    true => busy = false,
+                    false => {
+                        queue.schedule(now + 5, Event::Depart(line.remove(0)));
+                    }
+                }
+            }
+        }
+    }
+    let expected: Vec<(u64, u32)> = (0..6)
+        .map(|customer| (5 * (customer as u64 + 1), customer))
+        .collect();
+    assert_eq!(departures, expected);
+    assert_eq!(queue.now(), 30);
+}
+
+#[test]
+fn any_ordered_time_type_works() {
+    let mut queue = EventQueue::new();
+    queue.schedule(Duration::from_millis(250), "quarter");
+    queue.schedule(Duration::from_millis(100), "tenth");
+    assert_eq!(
+        queue.advance_to(Duration::from_millis(200)),
+        vec![(Duration::from_millis(100), "tenth")]
+    );
+    assert_eq!(queue.now(), Duration::from_millis(200));
+    let debug = format!("{:?}", queue);
+    assert!(debug.contains("quarter") && debug.contains("now"));
+    queue.clear();
+    assert!(queue.is_empty() && queue.peek_time().is_none());
+    assert_eq!(queue.now(), Duration::from_millis(200));
+}
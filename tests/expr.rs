@@ -0,0 +1,115 @@
+use dsa_rust::{
+    applications::expr::{
+        ExprError, Operator, Pending, Token, eval_postfix, eval_postfix_with, evaluate,
+        is_balanced, to_postfix, to_postfix_with, unbalanced_at,
+    },
+    linked_list::doubly::LinkedList,
+};
+
+fn postfix(infix: &str) -> String {
+    let tokens = to_postfix(infix).unwrap();
+    tokens
+        .iter()
+        .map(|token| token.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn shunting_yard_orders_by_precedence() {
+    assert_eq!(postfix("1 + 2 * 3"), "1 2 3 * +");
+    assert_eq!(postfix("(1 + 2) * 3"), "1 2 + 3 *");
+    assert_eq!(postfix("8 - 3 - 2"), "8 3 - 2 -");
+    assert_eq!(postfix("2 ^ 3 ^ 2"), "2 3 2 ^ ^");
+    assert_eq!(postfix("-2 ^ 2"), "2 2 ^ neg");
+    assert_eq!(postfix("4 * -(1 - 3)"), "4 1 3 - neg *");
+    assert_eq!(
+        to_postfix("12+3").unwrap(),
+        vec![
+            Token::Number(12),
+            Token::Number(3),
+            Token::Operator(Operator::Add)
+        ]
+    );
+}
+
+#[test]
+fn evaluates_infix() {
+    assert_eq!(evaluate("1 + 2 * 3"), Ok(7));
+    assert_eq!(evaluate("(1 + 2) * 3"), Ok(9));
+    assert_eq!(evaluate("100 / 7 / 2"), Ok(7));
+    assert_eq!(evaluate("2 ^ 3 ^ 2"), Ok(512));
+    assert_eq!(evaluate("-2 ^ 2"), Ok(-4));
+    assert_eq!(evaluate("--3"), Ok(3));
+    assert_eq!(evaluate("((((5))))"), Ok(5));
+    assert_eq!(evaluate("  7 -\t-7 "), Ok(14));
+}
+
+#[test]
+fn reports_where_input_goes_wrong() {
+    assert_eq!(
+        evaluate("1 + x"),
+        Err(ExprError::UnexpectedChar { at: 4, found: 'x' })
+    );
+    assert_eq!(evaluate("1 2"), Err(ExprError::UnexpectedToken { at: 2 }));
+    assert_eq!(
+        evaluate("1 * / 2"),
+        Err(ExprError::UnexpectedToken { at: 4 })
+    );
+    assert_eq!(evaluate("()"), Err(ExprError::UnexpectedToken { at: 1 }));
+    assert_eq!(
+        evaluate("(1 + 2"),
+        Err(ExprError::UnbalancedParen { at: 0 })
+    );
+    assert_eq!(
+        evaluate("1 + 2)"),
+        Err(ExprError::UnbalancedParen { at: 5 })
+    );
+    assert_eq!(evaluate("1 +"), Err(ExprError::MissingOperand));
+    assert_eq!(evaluate(""), Err(ExprError::MissingOperand));
+    assert_eq!(evaluate("1 / (2 - 2)"), Err(ExprError::DivisionByZero));
+    assert_eq!(evaluate("2 ^ -1"), Err(ExprError::NegativeExponent));
+    assert_eq!(evaluate("2 ^ 63"), Err(ExprError::Overflow));
+    assert_eq!(evaluate("99999999999999999999"), Err(ExprError::Overflow));
+    assert_eq!(
+        ExprError::UnbalancedParen { at: 3 }.to_string(),
+        "parenthesis at 3 has no partner"
+    );
+}
+
+#[test]
+fn postfix_checks_its_operands() {
+    let add = Token::Operator(Operator::Add);
+    assert_eq!(
+        eval_postfix(&[Token::Number(1), add]),
+        Err(ExprError::MissingOperand)
+    );
+    assert_eq!(eval_postfix(&[]), Err(ExprError::MissingOperand));
+    assert_eq!(
+        eval_postfix(&[Token::Number(1), Token::Number(2), Token::Number(3), add]),
+        Err(ExprError::ExtraOperands { left: 1 })
+    );
+    assert_eq!(
+        eval_postfix(&[Token::Number(i64::MIN), Token::Operator(Operator::Negate)]),
+        Err(ExprError::Overflow)
+    );
+}
+
+#[test]
+fn any_stack_will_do() {
+    let tokens = to_postfix_with::<LinkedList<Pending>>("(6 - 2) * 5 ^ 2").unwrap();
+    assert_eq!(tokens, to_postfix("(6 - 2) * 5 ^ 2").unwrap());
+    assert_eq!(eval_postfix_with::<LinkedList<i64>>(&tokens), Ok(100));
+}
+
+#[test]
+fn balanced_brackets() {
+    assert!(is_balanced(""));
+    assert!(is_balanced("fn main() { let v = [1, (2)]; }"));
+    assert!(is_balanced("{[()()]}"));
+    assert!(!is_balanced("(]"));
+    assert_eq!(unbalanced_at("([)]"), Some(2));
+    assert_eq!(unbalanced_at("a)"), Some(1));
+    assert_eq!(unbalanced_at("{ ( [ ]"), Some(2));
+    assert_eq!(unbalanced_at("é{"), Some(2));
+}
@@ -0,0 +1,72 @@
+use dsa_rust::{
+    algebra::{Max, Sum, Xor},
+    generate::Rng,
+    ranges::FenwickTree,
+};
+
+#[test]
+fn range_sums_match_a_scan_under_updates() {
+    let mut rng = Rng::new(17);
+    let mut items: Vec<i64> = (0..50).map(|_| rng.below(100) as i64 - 50).collect();
+    let mut tree = FenwickTree::from(items.iter().map(|&v| Sum(v)));
+    for _ in 0..400 {
+        let index = rng.below(items.len());
+        let delta = rng.below(100) as i64 - 50;
+        if rng.chance(0.5) {
+            items[index] += delta;
+            tree.add(index, &Sum(delta));
+        } else {
+            items[index] = delta;
+            tree.set(index, Sum(delta));
+        }
+        let start = rng.below(items.len() + 1);
+        let end = rng.range(start..items.len() + 1);
+        assert_eq!(tree.query(start..end), Sum(items[start..end].iter().sum()));
+        assert_eq!(tree.prefix(end), Sum(items[..end].iter().sum()));
+    }
+    for (index, &item) in items.iter().enumerate() {
+        assert_eq!(tree.get(index), Sum(item));
+    }
+}
+
+#[test]
+fn built_and_added_agree() {
+    let items = [3u32, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+    let built = FenwickTree::from(items.map(Sum));
+    let mut added = FenwickTree::new(items.len());
+    for (index, item) in items.iter().enumerate() {
+        added.add(index, &Sum(*item));
+    }
+    for end in 0..=items.len() {
+        assert_eq!(built.prefix(end), added.prefix(end));
+    }
+    assert_eq!(format!("{:?}", built), format!("{:?}", items.map(Sum)));
+}
+
+// sums wrap, so ranges come out exact even when a prefix on the way overflows
+#[test]
+fn wrapping_prefixes() {
+    let tree = FenwickTree::from([u8::MAX, 200, 3, 4].map(Sum));
+    assert_eq!(tree.query(2..), Sum(7));
+    assert_eq!(tree.query(1..3), Sum(203));
+}
+
+#[test]
+fn other_monoids() {
+    let xors = FenwickTree::from([0b0001u8, 0b0010, 0b0100, 0b1000].map(Xor));
+    assert_eq!(xors.query(1..3), Xor(0b0110));
+    // max is no group, but prefixes still work and only ever grow
+    let mut maxes = FenwickTree::from([4, 1, 7, 2].map(Max));
+    assert_eq!(maxes.prefix(2), Max(4));
+    maxes.add(1, &Max(6));
+    assert_eq!(maxes.prefix(2), Max(6));
+    assert_eq!(maxes.prefix(4), Max(7));
+    assert_eq!(maxes.prefix(0), Max(i32::MIN));
+    assert!(FenwickTree::<Sum<i8>>::new(0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "index 2 out of range for 2 items")]
+fn add_checks_bounds() {
+    FenwickTree::<Sum<i32>>::new(2).add(2, &Sum(1));
+}
@@ -0,0 +1,145 @@
+use dsa_rust::{
+    generate::{
+        Mix, Op, Rng, Shape, bst_keys, erdos_renyi, grid, operations, random_dag, random_tree,
+        weighted,
+    },
+    graph::Graph,
+    tree::{bst::BST, lca::parents_from},
+};
+
+// kahn's algorithm: every vertex gets removed only if there is no cycle
+fn is_acyclic(graph: &Graph) -> bool {
+    let mut indegree = vec![0; graph.vertex_count()];
+    graph.edges().for_each(|(_, to, _)| indegree[to] += 1);
+    let mut ready: Vec<usize> = graph.vertices().filter(|&v| indegree[v] == 0).collect();
+    let mut removed = 0;
+    while let Some(vertex) = ready.pop() {
+        removed += 1;
+        for next in graph.neighbors(vertex) {
+            indegree[next] -= 1;
+            if indegree[next] == 0 {
+                ready.push(next);
+            }
+        }
+    }
+    removed == graph.vertex_count()
+}
+
+#[test]
+fn rng_is_seeded_and_in_range() {
+    let draws = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..64).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(draws(7), draws(7));
+    assert_ne!(draws(7), draws(8));
+    assert_eq!(Rng::default(), Rng::new(0));
+
+    let mut rng = Rng::new(1);
+    let mut counts = [0usize; 6];
+    for _ in 0..60_000 {
+        counts[rng.below(6)] += 1;
+        let value = rng.range(10..13);
+        assert!((10..13).contains(&value));
+        let unit = rng.unit();
+        assert!((0.0..1.0).contains(&unit));
+    }
+    assert!(counts.iter().all(|&count| (9_000..11_000).contains(&count)));
+    assert!(!rng.chance(0.0) && rng.chance(1.0));
+
+    let mut items: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..100).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..100).collect::<Vec<_>>());
+    assert_eq!(rng.choose::<u32>(&[]), None);
+    assert!(rng.choose(&items).is_some_and(|item| *item < 100));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = Rng::new(3);
+    let sparse = erdos_renyi(&mut rng, 200, 0.05, false);
+    // expected 995 edges with a standard deviation near 31
+    assert!((850..1_150).contains(&sparse.edge_count()));
+    assert!(sparse.edges().all(|(from, to, _)| from < to));
+    assert_eq!(erdos_renyi(&mut rng, 6, 1.0, true).edge_count(), 30);
+    assert_eq!(erdos_renyi(&mut rng, 6, 0.0, false).edge_count(), 0);
+
+    let lattice = grid(3, 4);
+    assert_eq!((lattice.vertex_count(), lattice.edge_count()), (12, 17));
+    assert_eq!(
+        (lattice.degree(0), lattice.degree(5), lattice.degree(11)),
+        (2, 4, 2)
+    );
+    assert!(lattice.is_bipartite().is_ok());
+
+    for seed in 0..20 {
+        let dag = random_dag(&mut Rng::new(seed), 30, 0.3);
+        assert!(dag.is_directed() && is_acyclic(&dag));
+    }
+    let complete = random_dag(&mut rng, 10, 1.0);
+    assert_eq!(complete.edge_count(), 45);
+    assert!(is_acyclic(&complete));
+
+    // n - 1 edges reaching every vertex from any one of them makes a tree
+    let tree = random_tree(&mut rng, 40);
+    assert_eq!(tree.edge_count(), 39);
+    let parents = parents_from(&tree, 17);
+    assert_eq!(parents.iter().filter(|parent| parent.is_none()).count(), 1);
+
+    let costs = weighted(&mut rng, &lattice, 9);
+    assert_eq!(costs.edge_count(), lattice.edge_count());
+    assert!(
+        costs
+            .edges()
+            .zip(lattice.edges())
+            .all(|(a, b)| (a.0, a.1) == (b.0, b.1))
+    );
+    assert!(costs.edges().all(|(.., &weight)| (1..=9).contains(&weight)));
+
+    // the same seed builds the same graph
+    let again = |seed| erdos_renyi(&mut Rng::new(seed), 50, 0.1, true).edge_list();
+    assert_eq!(again(11), again(11));
+}
+
+#[test]
+fn key_orders_shape_the_tree() {
+    let mut rng = Rng::new(5);
+    let height = |keys: Vec<usize>| {
+        let mut tree = BST::new();
+        keys.into_iter().for_each(|key| {
+            tree.insert(key);
+        });
+        assert_eq!(tree.size(), 127);
+        tree.height()
+    };
+    let balanced = height(bst_keys(&mut rng, 127, Shape::Balanced));
+    assert_eq!(balanced, 7);
+    for shape in [Shape::Sorted, Shape::Reversed, Shape::ZigZag] {
+        assert_eq!(height(bst_keys(&mut rng, 127, shape)), 127);
+    }
+    let random = height(bst_keys(&mut rng, 127, Shape::Random));
+    assert!(random > balanced && random < 60);
+    assert_eq!(bst_keys(&mut rng, 0, Shape::Balanced), vec![]);
+}
+
+#[test]
+fn operation_sequences_follow_the_mix() {
+    let mut rng = Rng::new(9);
+    let ops = operations(&mut rng, 10_000, 32, Mix::default());
+    let inserts = ops.iter().filter(|op| matches!(op, Op::Insert(_))).count();
+    let removes = ops.iter().filter(|op| matches!(op, Op::Remove(_))).count();
+    assert!((3_700..4_300).contains(&inserts) && (1_700..2_300).contains(&removes));
+    assert!(ops.iter().all(|op| match *op {
+        Op::Insert(key) | Op::Remove(key) | Op::Contains(key) => key < 32,
+    }));
+
+    let lookups = Mix {
+        insert: 0,
+        remove: 0,
+        contains: 1,
+    };
+    let ops = operations(&mut rng, 100, 8, lookups);
+    assert!(ops.iter().all(|op| matches!(op, Op::Contains(_))));
+}
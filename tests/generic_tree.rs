@@ -0,0 +1,131 @@
+use dsa_rust::tree::generic::{NodeId, Tree};
+
+//        a
+//      / | \
+//     b  c  d
+//    / \    |
+//   e   f   g
+fn sample() -> (Tree<&'static str>, Vec<NodeId>) {
+    let mut tree = Tree::with_root("a");
+    let a = tree.root().unwrap();
+    let b = tree.add_child(a, "b");
+    let c = tree.add_child(a, "c");
+    let d = tree.add_child(a, "d");
+    let e = tree.add_child(b, "e");
+    let f = tree.add_child(b, "f");
+    let g = tree.add_child(d, "g");
+    (tree, vec![a, b, c, d, e, f, g])
+}
+
+fn names(tree: &Tree<&'static str>, ids: impl Iterator<Item = NodeId>) -> String {
+    ids.map(|id| *tree.get(id).unwrap()).collect()
+}
+
+#[test]
+fn navigation() {
+    let (tree, ids) = sample();
+    let [a, b, c, d, e, _, g] = ids[..] else {
+        unreachable!()
+    };
+    assert_eq!(tree.len(), 7);
+    assert_eq!(tree.parent(a), None);
+    assert_eq!(tree.parent(e), Some(b));
+    assert_eq!(names(&tree, tree.children(a)), "bcd");
+    assert_eq!(tree.children(c).len(), 0);
+    assert_eq!(names(&tree, tree.children(b).rev()), "fe");
+    assert_eq!((tree.depth(a), tree.depth(d), tree.depth(g)), (0, 1, 2));
+}
+
+#[test]
+fn traversals() {
+    let (tree, ids) = sample();
+    assert_eq!(names(&tree, tree.depth_first(ids[0])), "abefcdg");
+    assert_eq!(names(&tree, tree.breadth_first(ids[0])), "abcdefg");
+    assert_eq!(names(&tree, tree.depth_first(ids[1])), "bef");
+    assert_eq!(names(&tree, tree.breadth_first(ids[3])), "dg");
+}
+
+#[test]
+fn map_and_fold_over_subtrees() {
+    let (tree, ids) = sample();
+    let upper = tree.map(ids[0], |name| name.to_uppercase());
+    let root = upper.root().unwrap();
+    let order: String = upper
+        .depth_first(root)
+        .map(|id| upper.get(id).unwrap().as_str())
+        .collect();
+    assert_eq!(order, "ABEFCDG");
+
+    // height, leaf count, and a bracketed rendering, each in one bottom-up pass
+    let height = tree.fold(ids[0], |_, kids: Vec<usize>| {
+        1 + kids.into_iter().max().unwrap_or(0)
+    });
+    let leaves = tree.fold(ids[0], |_, kids: Vec<usize>| {
+        kids.iter().sum::<usize>().max(1)
+    });
+    let text = tree.fold(ids[0], |name, kids: Vec<String>| match kids.is_empty() {
+        true => name.to_string(),
+        false => format!("{}({})", name, kids.join(" ")),
+    });
+    assert_eq!((height, leaves), (3, 4));
+    assert_eq!(text, "a(b(e f) c d(g))");
+    assert_eq!(tree.fold(ids[6], |_, kids: Vec<u8>| kids.len() as u8), 0);
+}
+
+#[test]
+fn removing_subtrees() {
+    let (mut tree, ids) = sample();
+    let b = ids[1];
+    let removed = tree.remove_subtree(b).unwrap();
+    assert_eq!(tree.len(), 4);
+    assert!(!tree.contains(b) && !tree.contains(ids[4]));
+    assert_eq!(tree.get(ids[5]), None);
+    assert_eq!(names(&tree, tree.depth_first(ids[0])), "acdg");
+    assert!(tree.remove_subtree(b).is_none());
+
+    let root = removed.root().unwrap();
+    assert_eq!(names(&removed, removed.depth_first(root)), "bef");
+    assert_eq!(removed.parent(root), None);
+
+    // the old slots get reused, and the stale ids still miss
+    let h = tree.add_child(ids[3], "h");
+    assert!(!tree.contains(b));
+    assert_eq!(names(&tree, tree.children(ids[3])), "gh");
+    *tree.get_mut(h).unwrap() = "i";
+    assert_eq!(names(&tree, tree.breadth_first(ids[0])), "acdgi");
+
+    let whole = tree.remove_subtree(ids[0]).unwrap();
+    assert!(tree.is_empty() && tree.root().is_none());
+    assert_eq!(whole.len(), 5);
+    tree.add_root("z");
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn debug_indents_by_depth() {
+    let mut tree = Tree::with_root(1);
+    let root = tree.root().unwrap();
+    let two = tree.add_child(root, 2);
+    tree.add_child(two, 3);
+    tree.add_child(root, 4);
+    assert_eq!(
+        format!("{:?}", tree),
+        "Tree [\n  1\n    2\n      3\n    4\n]"
+    );
+    tree.clear();
+    assert_eq!(format!("{:?}", tree), "Tree []");
+}
+
+#[test]
+#[should_panic(expected = "the tree already has a root")]
+fn one_root() {
+    Tree::with_root(0).add_root(1);
+}
+
+#[test]
+#[should_panic(expected = "is not in the tree")]
+fn stale_ids_panic_on_navigation() {
+    let (mut tree, ids) = sample();
+    tree.remove_subtree(ids[3]);
+    tree.add_child(ids[6], "x");
+}
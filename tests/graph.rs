@@ -0,0 +1,285 @@
+use dsa_rust::{
+    graph::{Graph, VertexOrder},
+    visualize::{DotStyle, ToDot},
+};
+
+fn undirected(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+    let mut graph = Graph::undirected(vertices);
+    graph.extend(edges.iter().copied());
+    graph
+}
+
+fn is_odd_cycle(graph: &Graph, cycle: &[usize]) -> bool {
+    cycle.len() % 2 == 1
+        && (0..cycle.len()).all(|i| graph.has_edge(cycle[i], cycle[(i + 1) % cycle.len()]))
+}
+
+#[test]
+fn adjacency_basics() {
+    let mut graph: Graph<u32> = Graph::directed(3);
+    let id = graph.add_weighted_edge(0, 1, 7);
+    graph.add_weighted_edge(1, 2, 3);
+    graph.add_weighted_edge(1, 2, 9);
+    assert_eq!(graph.edge(id), Some((0, 1, &7)));
+    assert!(graph.has_edge(0, 1) && !graph.has_edge(1, 0));
+    assert_eq!((graph.degree(1), graph.edge_count()), (2, 3));
+    assert_eq!(graph.incident(1), &[(2, 1), (2, 2)]);
+    let v = graph.add_vertex();
+    graph.add_edge(v, v);
+    assert_eq!((graph.vertex_count(), graph.degree(v)), (4, 1));
+
+    let loops = undirected(2, &[(0, 0), (0, 1)]);
+    assert_eq!((loops.degree(0), loops.degree(1)), (2, 1));
+    assert_eq!(loops.neighbors(0).collect::<Vec<_>>(), vec![0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "edge 0 -> 5 out of range for 2 vertices")]
+fn edges_need_both_ends() {
+    Graph::<()>::undirected(2).add_edge(0, 5);
+}
+
+#[test]
+fn bipartite_partitions() {
+    // an even cycle with a pendant path and an isolated vertex
+    let graph = undirected(8, &[(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 6)]);
+    let [left, right] = graph.is_bipartite().unwrap();
+    assert_eq!(left, vec![0, 2, 4, 6, 7]);
+    assert_eq!(right, vec![1, 3, 5]);
+    assert!(
+        graph
+            .edges()
+            .all(|(a, b, _)| left.contains(&a) != left.contains(&b))
+    );
+    assert_eq!(undirected(0, &[]).is_bipartite(), Ok([vec![], vec![]]));
+}
+
+#[test]
+fn odd_cycle_witness() {
+    let triangle = undirected(3, &[(0, 1), (1, 2), (2, 0)]);
+    let cycle = triangle.is_bipartite().unwrap_err();
+    assert!(is_odd_cycle(&triangle, &cycle), "{:?}", cycle);
+
+    // a 7-cycle hanging off a tree, reached only from a second component
+    let mut edges = vec![(0, 1), (1, 2)];
+    edges.extend((3..10).map(|v| (v, if v == 9 { 3 } else { v + 1 })));
+    edges.push((2, 12));
+    let graph = undirected(13, &edges);
+    let cycle = graph.is_bipartite().unwrap_err();
+    assert_eq!(cycle.len(), 7);
+    assert!(is_odd_cycle(&graph, &cycle), "{:?}", cycle);
+
+    assert_eq!(
+        undirected(2, &[(0, 1), (1, 1)]).is_bipartite(),
+        Err(vec![1])
+    );
+}
+
+#[test]
+fn greedy_coloring_orders() {
+    // a crown graph: natural order alternates sides badly and needs n colors, bfs needs two
+    let n = 5;
+    let mut crown: Graph = Graph::undirected(2 * n);
+    for i in 0..n {
+        for j in 0..n {
+            if i != j {
+                crown.add_edge(2 * i, 2 * j + 1);
+            }
+        }
+    }
+    let natural = crown.greedy_coloring(VertexOrder::Natural);
+    let bfs = crown.greedy_coloring(VertexOrder::BreadthFirst);
+    assert_eq!((natural.count, bfs.count), (n, 2));
+    assert!(crown.is_proper_coloring(&natural.colors) && crown.is_proper_coloring(&bfs.colors));
+
+    let wheel = undirected(
+        6,
+        &[
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 1),
+        ],
+    );
+    let coloring = wheel.greedy_coloring(VertexOrder::DegreeDescending);
+    assert_eq!((coloring.colors[0], coloring.count), (0, 4));
+    assert!(wheel.is_proper_coloring(&coloring.colors));
+    assert!(!wheel.is_proper_coloring(&[0; 6]));
+
+    let with_loop = undirected(2, &[(0, 0), (0, 1)]);
+    assert_eq!(
+        with_loop.greedy_coloring(VertexOrder::Natural).colors,
+        vec![0, 1]
+    );
+}
+
+// connected components with one vertex or one edge left out, for brute-force checks
+fn components_without(graph: &Graph, vertex: Option<usize>, edge: Option<usize>) -> usize {
+    let mut seen = vec![false; graph.vertex_count()];
+    let mut count = 0;
+    for start in graph.vertices().filter(|&v| Some(v) != vertex) {
+        if seen[start] {
+            continue;
+        }
+        count += 1;
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(at) = stack.pop() {
+            for &(next, id) in graph.incident(at) {
+                if Some(next) != vertex && Some(id) != edge && !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+    }
+    count
+}
+
+fn random_graph(vertices: usize, edges: usize, seed: u64) -> Graph {
+    let mut graph = Graph::undirected(vertices);
+    let mut state = seed;
+    for _ in 0..edges {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        graph.add_edge(
+            (state >> 33) as usize % vertices,
+            (state >> 45) as usize % vertices,
+        );
+    }
+    graph
+}
+
+#[test]
+fn cut_vertices_and_bridges_match_brute_force() {
+    for seed in 0..40 {
+        let graph = random_graph(12, 10 + seed as usize % 10, seed);
+        let result = graph.biconnectivity();
+        let base = components_without(&graph, None, None);
+        let cut: Vec<usize> = graph
+            .vertices()
+            .filter(|&v| {
+                components_without(&graph, Some(v), None) > base - usize::from(graph.degree(v) == 0)
+            })
+            .collect();
+        assert_eq!(result.articulation_points, cut, "seed {}", seed);
+        let bridges: Vec<usize> = (0..graph.edge_count())
+            .filter(|&id| components_without(&graph, None, Some(id)) > base)
+            .collect();
+        assert_eq!(result.bridges, bridges, "seed {}", seed);
+
+        // every edge but a self-loop lands in exactly one component
+        let mut covered: Vec<usize> = result
+            .components
+            .iter()
+            .flat_map(|c| c.edges.clone())
+            .collect();
+        covered.sort();
+        let expected: Vec<usize> = graph
+            .edges()
+            .enumerate()
+            .filter(|(_, (a, b, _))| a != b)
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(covered, expected, "seed {}", seed);
+    }
+}
+
+#[test]
+fn biconnected_components_of_a_bowtie() {
+    // two triangles sharing vertex 2, a bridge 4-5, a doubled edge 5-6 and an isolated 7
+    let graph = undirected(
+        8,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 2),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+        ],
+    );
+    let result = graph.biconnectivity();
+    assert_eq!(result.articulation_points, vec![2, 4, 5]);
+    assert_eq!(result.bridges, vec![6]);
+    let mut blocks: Vec<Vec<usize>> = result
+        .components
+        .iter()
+        .map(|c| c.vertices.clone())
+        .collect();
+    blocks.sort();
+    assert_eq!(
+        blocks,
+        vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5], vec![5, 6]]
+    );
+    assert_eq!(graph.bridges(), vec![6]);
+    assert_eq!(graph.articulation_points(), vec![2, 4, 5]);
+    assert_eq!(graph.biconnected_components().len(), 4);
+
+    // a long path, deep enough that a recursive search would be at risk
+    let path = undirected(
+        100_000,
+        &(0..99_999).map(|v| (v, v + 1)).collect::<Vec<_>>(),
+    );
+    let result = path.biconnectivity();
+    assert_eq!(
+        (result.articulation_points.len(), result.bridges.len()),
+        (99_998, 99_999)
+    );
+}
+
+#[test]
+fn dot_export() {
+    let mut roads = Graph::directed(4);
+    roads.add_weighted_edge(0, 1, 7u32);
+    roads.add_weighted_edge(1, 2, 3);
+    roads.add_weighted_edge(2, 0, 12);
+    let expected = "\
+digraph \"G\" {
+    node [shape=circle];
+    n0 [label=\"0\"];
+    n1 [label=\"1\"];
+    n2 [label=\"2\"];
+    n3 [label=\"3\"];
+    n0 -> n1 [label=\"7\"];
+    n1 -> n2 [label=\"3\"];
+    n2 -> n0 [label=\"12\"];
+}
+";
+    assert_eq!(roads.to_dot(), expected);
+
+    // undirected and unweighted: lines without labels, each edge once
+    let triangle = undirected(3, &[(0, 1), (1, 2), (2, 0)]);
+    let expected = "\
+graph \"G\" {
+    node [shape=circle];
+    n0 [label=\"0\"];
+    n1 [label=\"1\"];
+    n2 [label=\"2\"];
+    n0 -- n1;
+    n1 -- n2;
+    n2 -- n0;
+}
+";
+    assert_eq!(triangle.to_dot(), expected);
+
+    let annotated = roads.to_dot_with(&DotStyle {
+        annotate: true,
+        ..DotStyle::default()
+    });
+    assert!(annotated.contains("n2 -> n0 [label=\"12\\n#2\"];"));
+    assert_eq!(
+        Graph::<()>::undirected(0).to_dot(),
+        "graph \"G\" {\n    node [shape=circle];\n}\n"
+    );
+}
@@ -0,0 +1,146 @@
+use dsa_rust::graph::{Graph, GraphFormatError};
+
+fn sorted_edges<W: Clone + PartialOrd>(graph: &Graph<W>) -> Vec<(usize, usize, W)> {
+    let mut edges: Vec<_> = graph
+        .edges()
+        .map(|(from, to, weight)| (from, to, weight.clone()))
+        .collect();
+    edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    edges
+}
+
+#[test]
+fn edge_list_round_trips() {
+    let text = "\
+        # a small road map\n\
+        undirected 5\n\
+        0 1 2.5\n\
+        1 2 4   # toll road\n\
+        \n\
+        2 2 1\n\
+        3 4 0.25\n";
+    let graph: Graph<f64> = Graph::parse_edge_list(text).unwrap();
+    assert!(!graph.is_directed());
+    assert_eq!((graph.vertex_count(), graph.edge_count()), (5, 4));
+    assert_eq!(graph.edge(1), Some((1, 2, &4.0)));
+
+    let written = graph.edge_list();
+    assert_eq!(written, "undirected 5\n0 1 2.5\n1 2 4\n2 2 1\n3 4 0.25\n");
+    let again: Graph<f64> = Graph::parse_edge_list(&written).unwrap();
+    assert_eq!(sorted_edges(&again), sorted_edges(&graph));
+    assert_eq!(again.edge_list(), written);
+}
+
+#[test]
+fn unweighted_graphs_write_bare_pairs() {
+    let mut graph = Graph::directed(4);
+    graph.extend([(0, 1), (1, 2), (2, 0), (3, 3), (0, 1)]);
+    let written = graph.edge_list();
+    assert_eq!(written, "directed 4\n0 1\n1 2\n2 0\n3 3\n0 1\n");
+    let again: Graph = Graph::parse_edge_list(&written).unwrap();
+    assert_eq!(sorted_edges(&again), sorted_edges(&graph));
+    assert!(again.is_directed() && again.has_edge(2, 0) && !again.has_edge(0, 2));
+
+    // a weight on an unweighted graph, or a missing one on a weighted graph, is malformed
+    assert!(matches!(
+        Graph::<()>::parse_edge_list("directed 2\n0 1 5\n"),
+        Err(GraphFormatError::BadEdge { line: 2 })
+    ));
+    assert!(matches!(
+        Graph::<u32>::parse_edge_list("directed 2\n0 1\n"),
+        Err(GraphFormatError::BadEdge { line: 2 })
+    ));
+}
+
+#[test]
+fn adjacency_list_round_trips() {
+    let mut graph: Graph<u32> = Graph::undirected(4);
+    for (from, to, weight) in [(0, 1, 3), (0, 2, 1), (2, 3, 8), (1, 1, 2), (3, 0, 5)] {
+        graph.add_weighted_edge(from, to, weight);
+    }
+    let written = graph.adjacency_list();
+    assert_eq!(
+        written,
+        "undirected 4\n0: 1:3 2:1 3:5\n1: 0:3 1:2\n2: 0:1 3:8\n3: 2:8 0:5\n"
+    );
+    let again: Graph<u32> = Graph::parse_adjacency_list(&written).unwrap();
+    // each undirected edge comes back once, stored from its lower end
+    let mut normalized: Vec<_> = sorted_edges(&graph)
+        .into_iter()
+        .map(|(from, to, weight)| (from.min(to), from.max(to), weight))
+        .collect();
+    normalized.sort();
+    assert_eq!(sorted_edges(&again), normalized);
+    // neighbours can come back in another order, the edges themselves do not change
+    let twice: Graph<u32> = Graph::parse_adjacency_list(&again.adjacency_list()).unwrap();
+    assert_eq!(sorted_edges(&twice), normalized);
+
+    let mut directed = Graph::directed(3);
+    directed.extend([(0, 1), (1, 0), (2, 1)]);
+    let written = directed.adjacency_list();
+    assert_eq!(written, "directed 3\n0: 1\n1: 0\n2: 1\n");
+    let again: Graph = Graph::parse_adjacency_list(&written).unwrap();
+    assert_eq!(sorted_edges(&again), sorted_edges(&directed));
+
+    // vertices with no line just have no outgoing edges
+    let sparse: Graph = Graph::parse_adjacency_list("directed 5\n4: 0 1\n").unwrap();
+    assert_eq!((sparse.edge_count(), sparse.degree(4)), (2, 2));
+}
+
+#[test]
+fn errors_name_the_line() {
+    let parse = |text: &str| Graph::<u32>::parse_edge_list(text).unwrap_err();
+    assert!(matches!(parse(""), GraphFormatError::MissingHeader));
+    assert!(matches!(
+        parse("# only a comment\n"),
+        GraphFormatError::MissingHeader
+    ));
+    assert!(matches!(
+        parse("sideways 3\n"),
+        GraphFormatError::BadHeader { line: 1 }
+    ));
+    assert!(matches!(
+        parse("\ndirected\n"),
+        GraphFormatError::BadHeader { line: 2 }
+    ));
+    assert!(matches!(
+        parse("directed 3\n0 x 1\n"),
+        GraphFormatError::BadEdge { line: 2 }
+    ));
+    assert!(matches!(
+        parse("directed 3\n0 1 1 1\n"),
+        GraphFormatError::BadEdge { line: 2 }
+    ));
+    assert!(matches!(
+        parse("directed 3\n\n0 1 1\n1 3 1\n"),
+        GraphFormatError::VertexOutOfRange { line: 4, vertex: 3 }
+    ));
+    let error = Graph::<()>::parse_adjacency_list("undirected 2\n0 1\n").unwrap_err();
+    assert!(matches!(error, GraphFormatError::BadEdge { line: 2 }));
+    assert_eq!(error.to_string(), "line 2: malformed edge");
+}
+
+#[test]
+fn readers_and_writers() {
+    let mut graph: Graph<i64> = Graph::directed(3);
+    graph.add_weighted_edge(0, 1, -4);
+    graph.add_weighted_edge(1, 2, 7);
+
+    let mut buffer = Vec::new();
+    graph.to_edge_list(&mut buffer).unwrap();
+    let again: Graph<i64> = Graph::from_edge_list(buffer.as_slice()).unwrap();
+    assert_eq!(sorted_edges(&again), sorted_edges(&graph));
+
+    let path = std::env::temp_dir().join(format!("dsa-rust-graph-{}.txt", std::process::id()));
+    graph
+        .to_adjacency_list(std::fs::File::create(&path).unwrap())
+        .unwrap();
+    let loaded: Graph<i64> =
+        Graph::from_adjacency_list(std::fs::File::open(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(sorted_edges(&loaded), sorted_edges(&graph));
+
+    // bytes that are not utf-8 surface as an io error
+    let error = Graph::<i64>::from_edge_list(&[0xff, 0xfe][..]).unwrap_err();
+    assert!(matches!(error, GraphFormatError::Io(_)));
+}
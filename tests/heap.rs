@@ -0,0 +1,402 @@
+use std::cell::Cell;
+
+use dsa_rust::{
+    generate::Rng,
+    tree::heap::{BinaryHeap, BoundedHeap, top_k},
+};
+
+#[test]
+fn implicit_tree_navigation() {
+    let heap = BinaryHeap::from(|a: &u32, b: &u32| a.cmp(b), (1..=10).rev());
+    let buffer: Vec<u32> = heap.iter_unsorted().copied().collect();
+    assert_eq!(Some(&buffer[0]), heap.peek());
+    for i in 0..heap.size() {
+        let (left, right) = heap.children_of(i);
+        for child in [left, right].into_iter().flatten() {
+            assert_eq!(heap.parent_of(child), Some(i));
+            assert!(heap.get(i) <= heap.get(child));
+        }
+        assert_eq!(left.is_some(), 2 * i + 1 < heap.size());
+        assert_eq!(right.is_some(), 2 * i + 2 < heap.size());
+    }
+    assert_eq!(heap.parent_of(0), None);
+    assert_eq!(heap.children_of(4), (Some(9), None));
+    assert_eq!(heap.get(10), None);
+
+    let levels: Vec<&[u32]> = heap.level_iter().collect();
+    assert_eq!(
+        levels.iter().map(|level| level.len()).collect::<Vec<_>>(),
+        vec![1, 2, 4, 3]
+    );
+    assert_eq!(levels.concat(), buffer);
+    assert_eq!(heap.depth(), levels.len());
+    assert_eq!(BinaryHeap::<u32>::min().level_iter().count(), 0);
+    assert_eq!(BinaryHeap::<u32>::min().depth(), 0);
+    let full = BinaryHeap::from(|a: &u32, b: &u32| a.cmp(b), 0..7);
+    assert_eq!(full.level_iter().last().map(<[u32]>::len), Some(4));
+    assert_eq!(full.depth(), 3);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of range for a heap of 3")]
+fn navigation_checks_the_index() {
+    let heap = BinaryHeap::from(|a: &i32, b: &i32| a.cmp(b), [1, 2, 3]);
+    heap.children_of(3);
+}
+
+#[test]
+fn tree_string_follows_the_buffer() {
+    let mut heap = BinaryHeap::min();
+    heap.extend([5, 3, 8, 1, 4, 7]);
+    assert_eq!(
+        heap.to_tree_string(),
+        "[0] 1\n\
+         +-- L: [1] 3\n\
+         |   +-- L: [3] 5\n\
+         |   `-- R: [4] 4\n\
+         `-- R: [2] 7\n    \
+             `-- L: [5] 8\n"
+    );
+    assert_eq!(BinaryHeap::<i32>::min().to_tree_string(), "");
+}
+
+#[test]
+fn random_interleavings_keep_the_heap_valid() {
+    for seed in 0..32 {
+        let mut rng = Rng::new(seed);
+        let mut heap = match seed % 2 {
+            0 => BinaryHeap::min(),
+            _ => BinaryHeap::max(),
+        };
+        let mut model = Vec::new();
+        for _ in 0..300 {
+            let key = rng.below(50) as i32;
+            match rng.below(9) {
+                0..=2 => {
+                    heap.offer(key);
+                    model.push(key);
+                }
+                3 => {
+                    let batch: Vec<i32> = (0..rng.below(5)).map(|_| rng.below(50) as i32).collect();
+                    model.extend(&batch);
+                    heap.extend(batch);
+                }
+                4..=5 => {
+                    if let Some(top) = heap.poll() {
+                        let at = model.iter().position(|&item| item == top).unwrap();
+                        model.swap_remove(at);
+                    }
+                }
+                6 => {
+                    if let Some(at) = model.iter().position(|&item| item == key) {
+                        model.swap_remove(at);
+                        assert!(heap.remove(&key));
+                    }
+                }
+                7 => {
+                    let out = heap.push_pop(key);
+                    model.push(key);
+                    let at = model.iter().position(|&item| item == out).unwrap();
+                    model.swap_remove(at);
+                }
+                _ => {
+                    heap.retain(|item| item % 7 != 0);
+                    model.retain(|item| item % 7 != 0);
+                }
+            }
+            assert!(heap.is_valid_heap(), "seed {}", seed);
+            assert_eq!(heap.size(), model.len());
+        }
+    }
+}
+
+#[test]
+fn outside_mutation_breaks_the_invariant() {
+    let heap = BinaryHeap::from(
+        |a: &Cell<i32>, b: &Cell<i32>| a.cmp(b),
+        (0..8).map(Cell::new),
+    );
+    assert!(heap.is_valid_heap());
+    // the ordering is read through a Cell, so a shared reference is enough to break it
+    heap.iter_unsorted().last().unwrap().set(-1);
+    assert!(!heap.is_valid_heap());
+    assert!(BinaryHeap::<i32>::min().is_valid_heap());
+}
+
+#[cfg(all(feature = "verify", debug_assertions))]
+#[test]
+#[should_panic(expected = "BinaryHeap invariant violated: index 7 orders before its parent 3")]
+fn verify_catches_a_broken_heap_on_the_next_operation() {
+    let mut heap = BinaryHeap::from(
+        |a: &Cell<i32>, b: &Cell<i32>| a.cmp(b),
+        (0..8).map(Cell::new),
+    );
+    heap.iter_unsorted().last().unwrap().set(-1);
+    // offering at the end does not touch index 7, so the damage is still there to find
+    heap.offer(Cell::new(100));
+}
+
+#[test]
+fn changing_the_comparator_rebuilds_in_place() {
+    let mut heap: BinaryHeap<i32> = BinaryHeap::min();
+    heap.extend([4, 9, 1, 7, 3, 8]);
+    heap.set_comparator(|a, b| b.cmp(a));
+    assert!(heap.is_valid_heap());
+    assert_eq!(heap.peek(), Some(&9));
+
+    // by distance from 5, ties going to the smaller value
+    let heap = heap.into_with_cmp(|a, b| (a - 5).abs().cmp(&(b - 5).abs()).then(a.cmp(b)));
+    assert_eq!(heap.into_sorted_vec(), vec![4, 3, 7, 8, 1, 9]);
+}
+
+#[test]
+fn reheapify_repairs_outside_mutation() {
+    let mut heap = BinaryHeap::from(
+        |a: &Cell<i32>, b: &Cell<i32>| a.cmp(b),
+        (0..50).map(Cell::new),
+    );
+    for (i, item) in heap.iter_unsorted().enumerate() {
+        item.set((i as i32 * 37) % 101);
+    }
+    heap.reheapify();
+    assert!(heap.is_valid_heap());
+    let mut expected: Vec<i32> = (0..50).map(|i| (i * 37) % 101).collect();
+    expected.sort();
+    let drained: Vec<i32> = std::iter::from_fn(|| heap.poll())
+        .map(Cell::into_inner)
+        .collect();
+    assert_eq!(drained, expected);
+}
+
+#[test]
+fn handles_follow_their_elements() {
+    let mut heap = BinaryHeap::min();
+    heap.offer(5);
+    let a = heap.offer_with_handle(7);
+    let b = heap.offer_with_handle(3);
+    heap.extend([9, 1]);
+    assert_eq!(
+        (heap.get_by_handle(a), heap.get_by_handle(b)),
+        (Some(&7), Some(&3))
+    );
+    assert_eq!(heap.get(heap.position_of(a).unwrap()), Some(&7));
+
+    // decrease-key to the top, then increase-key to the bottom
+    assert_eq!(heap.update(a, 0), Some(7));
+    assert_eq!((heap.peek(), heap.position_of(a)), (Some(&0), Some(0)));
+    assert_eq!(heap.update(a, 10), Some(0));
+    assert_eq!(heap.peek(), Some(&1));
+    assert!(heap.is_valid_heap());
+
+    assert_eq!(heap.remove_handle(b), Some(3));
+    assert!(!heap.contains_handle(b));
+    assert_eq!(
+        (
+            heap.remove_handle(b),
+            heap.update(b, 4),
+            heap.get_by_handle(b)
+        ),
+        (None, None, None)
+    );
+    assert_eq!(heap.into_sorted_vec(), vec![1, 5, 9, 10]);
+}
+
+#[test]
+fn handles_go_stale_however_the_element_leaves() {
+    let mut heap = BinaryHeap::min();
+    let top = heap.offer_with_handle(1);
+    let odd = heap.offer_with_handle(3);
+    let kept = heap.offer_with_handle(4);
+    let drained = heap.offer_with_handle(6);
+    assert_eq!(heap.poll(), Some(1));
+    heap.retain(|item| item % 2 == 0);
+    assert_eq!(
+        heap.drain_filter(|&item| item > 5).collect::<Vec<_>>(),
+        vec![6]
+    );
+    assert!(
+        ![top, odd, drained]
+            .into_iter()
+            .any(|handle| heap.contains_handle(handle))
+    );
+    assert_eq!(heap.get_by_handle(kept), Some(&4));
+
+    // replace_top and push_pop swap out the top, and the handle with it
+    assert_eq!(heap.replace_top(8), Some(4));
+    assert!(!heap.contains_handle(kept));
+    let low = heap.offer_with_handle(2);
+    assert_eq!(heap.push_pop(5), 2);
+    assert!(!heap.contains_handle(low));
+
+    // handles from the appended heap belong to it, and clear retires every handle of self
+    let mut other = BinaryHeap::min();
+    let foreign = other.offer_with_handle(0);
+    let mine = heap.offer_with_handle(7);
+    heap.append(&mut other);
+    assert!(!other.contains_handle(foreign) && heap.get_by_handle(mine) == Some(&7));
+    assert_eq!(heap.peek(), Some(&0));
+    heap.clear();
+    assert!(!heap.contains_handle(mine));
+    let fresh = heap.offer_with_handle(1);
+    assert_ne!(fresh, mine);
+    assert_eq!(heap.get_by_handle(mine), None);
+}
+
+#[test]
+fn random_handle_operations_match_a_model() {
+    // (priority, id) keeps every element distinct, so a polled value says which entry left
+    for seed in 0..24 {
+        let mut rng = Rng::new(seed);
+        let mut heap = BinaryHeap::min();
+        let mut live: Vec<(usize, u32, _)> = Vec::new();
+        let mut dead = Vec::new();
+        let mut untracked = 0usize;
+        for id in 0..400 {
+            let priority = rng.below(100) as u32;
+            match rng.below(8) {
+                0..=2 => live.push((id, priority, heap.offer_with_handle((priority, id)))),
+                3 => {
+                    heap.offer((priority, id));
+                    untracked += 1;
+                }
+                4 if !live.is_empty() => {
+                    let at = rng.below(live.len());
+                    let (entry, old, handle) = live[at];
+                    assert_eq!(heap.update(handle, (priority, entry)), Some((old, entry)));
+                    live[at].1 = priority;
+                }
+                5 if !live.is_empty() => {
+                    let (entry, old, handle) = live.swap_remove(rng.below(live.len()));
+                    assert_eq!(heap.remove_handle(handle), Some((old, entry)));
+                    dead.push(handle);
+                }
+                _ => match heap.poll() {
+                    Some((_, entry)) => match live.iter().position(|&(id, ..)| id == entry) {
+                        Some(at) => dead.push(live.swap_remove(at).2),
+                        None => untracked -= 1,
+                    },
+                    None => assert!(live.is_empty() && untracked == 0),
+                },
+            }
+            assert!(heap.is_valid_heap(), "seed {}", seed);
+            assert_eq!(heap.size(), live.len() + untracked);
+            for &(entry, priority, handle) in &live {
+                assert_eq!(heap.get_by_handle(handle), Some(&(priority, entry)));
+            }
+            assert!(dead.iter().all(|&handle| !heap.contains_handle(handle)));
+        }
+    }
+}
+
+#[test]
+fn replace_top_and_push_pop() {
+    let mut heap = BinaryHeap::from(|a: &i32, b: &i32| a.cmp(b), [5, 3, 8]);
+    // replace_top always takes the top out, even when the new item would come first
+    assert_eq!(heap.replace_top(1), Some(3));
+    assert_eq!(heap.peek(), Some(&1));
+    assert_eq!(heap.replace_top(9), Some(1));
+    assert_eq!(heap.clone().into_sorted_vec(), vec![5, 8, 9]);
+    // push_pop only swaps when the new item does not come first itself
+    assert_eq!(heap.push_pop(2), 2);
+    assert_eq!(heap.push_pop(6), 5);
+    assert_eq!(heap.into_sorted_vec(), vec![6, 8, 9]);
+
+    let mut empty = BinaryHeap::max();
+    assert_eq!(empty.push_pop(4), 4);
+    assert!(empty.is_empty());
+    assert_eq!(empty.replace_top(4), None);
+    assert_eq!(empty.poll(), Some(4));
+}
+
+#[test]
+fn remove_and_retain() {
+    let mut heap = BinaryHeap::from(|a: &i32, b: &i32| b.cmp(a), [4, 9, 1, 7, 4, 2]);
+    assert!(heap.remove(&4));
+    assert!(heap.is_valid_heap());
+    assert!(!heap.remove(&5));
+    assert!(heap.remove(&9));
+    assert_eq!(heap.peek(), Some(&7));
+    assert_eq!(heap.clone().into_sorted_vec(), vec![7, 4, 2, 1]);
+    heap.retain(|item| item % 2 == 0);
+    assert!(heap.is_valid_heap());
+    assert_eq!(heap.clone().into_sorted_vec(), vec![4, 2]);
+    heap.retain(|_| false);
+    assert!(heap.is_empty());
+    assert!(!heap.remove(&4));
+}
+
+#[test]
+fn merging_takes_the_receivers_comparator() {
+    // equivalent comparators that are different functions
+    let built = BinaryHeap::from(|a: &i32, b: &i32| a.cmp(b), [6, 2, 9]);
+    let merged = BinaryHeap::min().merge(built);
+    assert_eq!(merged.into_sorted_vec(), vec![2, 6, 9]);
+
+    // opposite orders: the result follows self, whichever side is larger
+    let mut min = BinaryHeap::from(|a: &i32, b: &i32| a.cmp(b), [5, 1]);
+    let mut max = BinaryHeap::from(|a: &i32, b: &i32| b.cmp(a), [3, 8, 4, 7]);
+    min.append(&mut max);
+    assert!(max.is_empty());
+    assert!(min.is_valid_heap());
+    assert_eq!(min.peek(), Some(&1));
+    assert_eq!(min.into_sorted_vec(), vec![1, 3, 4, 5, 7, 8]);
+
+    let small_max = BinaryHeap::from(|a: &i32, b: &i32| b.cmp(a), [2]);
+    let big_min = BinaryHeap::from(|a: &i32, b: &i32| a.cmp(b), [9, 4, 6, 1]);
+    let mut merged = small_max.merge(big_min);
+    assert!(merged.is_valid_heap());
+    let drained: Vec<i32> = std::iter::from_fn(|| merged.poll()).collect();
+    assert_eq!(drained, vec![9, 6, 4, 2, 1]);
+}
+
+#[test]
+fn top_k_matches_sort_and_truncate() {
+    let mut rng = Rng::new(1539);
+    let orders: [fn(&i32, &i32) -> std::cmp::Ordering; 2] = [|a, b| a.cmp(b), |a, b| b.cmp(a)];
+    for round in 0..200 {
+        // few distinct values, so duplicates are common
+        let items: Vec<i32> = (0..rng.below(40)).map(|_| rng.below(12) as i32).collect();
+        let k = rng.below(items.len() + 5);
+        let cmp = orders[round % 2];
+        let mut expected = items.clone();
+        expected.sort_by(cmp);
+        expected.truncate(k);
+        assert_eq!(
+            top_k(items.iter().copied(), k, cmp),
+            expected,
+            "k = {} of {:?}",
+            k,
+            items
+        );
+    }
+    assert_eq!(
+        top_k([3, 1, 2], 0, |a: &i32, b: &i32| a.cmp(b)),
+        Vec::<i32>::new()
+    );
+    assert_eq!(
+        top_k([3, 1, 2], 10, |a: &i32, b: &i32| b.cmp(a)),
+        vec![3, 2, 1]
+    );
+    assert_eq!(
+        top_k([4, 4, 1, 4], 2, |a: &i32, b: &i32| b.cmp(a)),
+        vec![4, 4]
+    );
+}
+
+#[test]
+fn bounded_heap_keeps_the_best_and_hands_back_the_rest() {
+    let mut heap = BoundedHeap::with_capacity_limit(3, |a: &i32, b: &i32| a.cmp(b));
+    assert_eq!(heap.offer(5), None);
+    assert_eq!(heap.offer(9), None);
+    assert_eq!(heap.offer(1), None);
+    assert!(heap.is_full());
+    assert_eq!(heap.peek_worst(), Some(&9));
+    assert_eq!(heap.offer(3), Some(9));
+    assert_eq!(heap.offer(7), Some(7));
+    assert_eq!(heap.size(), 3);
+    assert_eq!(heap.into_sorted_vec(), vec![1, 3, 5]);
+
+    let mut none = BoundedHeap::with_capacity_limit(0, |a: &i32, b: &i32| a.cmp(b));
+    assert_eq!(none.offer(1), Some(1));
+    assert!(none.is_empty());
+}
@@ -0,0 +1,133 @@
+use dsa_rust::{
+    algebra::{AddEach, Max, SetEach, Sum},
+    generate::{Rng, random_tree},
+    tree::{hld::HeavyLight, lca::parents_from},
+};
+
+// a vertex and everything above it
+fn climb(parents: &[Option<usize>], mut vertex: usize) -> Vec<usize> {
+    let mut path = vec![vertex];
+    while let Some(parent) = parents[vertex] {
+        path.push(parent);
+        vertex = parent;
+    }
+    path
+}
+
+// the vertices on the path, found by climbing from both ends to the first vertex they share
+fn naive_path(parents: &[Option<usize>], u: usize, v: usize) -> Option<Vec<usize>> {
+    let (up, vp) = (climb(parents, u), climb(parents, v));
+    let meet = up.iter().position(|vertex| vp.contains(vertex))?;
+    let back = vp.iter().position(|&vertex| vertex == up[meet]).unwrap();
+    let mut path = up[..=meet].to_vec();
+    path.extend(vp[..back].iter().rev());
+    Some(path)
+}
+
+fn naive_subtree(parents: &[Option<usize>], vertex: usize) -> Vec<usize> {
+    (0..parents.len())
+        .filter(|&other| climb(parents, other).contains(&vertex))
+        .collect()
+}
+
+#[test]
+fn path_sums_and_adds_match_a_walk() {
+    let mut rng = Rng::new(97);
+    for size in [1, 2, 7, 60, 300] {
+        let tree = random_tree(&mut rng, size);
+        let root = rng.below(size);
+        let parents = parents_from(&tree, root);
+        let mut values: Vec<i64> = (0..size).map(|_| rng.below(100) as i64).collect();
+        let mut hld: HeavyLight<Sum<i64>, AddEach<i64>> =
+            HeavyLight::from_graph(&tree, root, values.iter().map(|&v| Sum(v)));
+        assert_eq!(hld.len(), size);
+        for _ in 0..200 {
+            let (u, v) = (rng.below(size), rng.below(size));
+            let path = naive_path(&parents, u, v).unwrap();
+            assert_eq!(
+                hld.lca(u, v),
+                path.iter()
+                    .copied()
+                    .min_by_key(|&w| climb(&parents, w).len())
+            );
+            match rng.below(3) {
+                0 => {
+                    let delta = rng.below(20) as i64 - 10;
+                    path.iter().for_each(|&w| values[w] += delta);
+                    assert!(hld.path_update(u, v, &AddEach(delta)));
+                }
+                1 => {
+                    let delta = rng.below(20) as i64 - 10;
+                    naive_subtree(&parents, u)
+                        .iter()
+                        .for_each(|&w| values[w] += delta);
+                    hld.subtree_update(u, &AddEach(delta));
+                }
+                _ => {
+                    values[u] = rng.below(100) as i64;
+                    hld.set(u, Sum(values[u]));
+                }
+            }
+            let (u, v) = (rng.below(size), rng.below(size));
+            let path = naive_path(&parents, u, v).unwrap();
+            assert_eq!(
+                hld.path_query(u, v),
+                Some(Sum(path.iter().map(|&w| values[w]).sum()))
+            );
+            let below: i64 = naive_subtree(&parents, v).iter().map(|&w| values[w]).sum();
+            assert_eq!(hld.subtree_query(v), Sum(below));
+            assert_eq!(hld.get(v), Sum(values[v]));
+        }
+    }
+}
+
+// a path makes one long heavy chain; a star makes every edge light
+#[test]
+fn path_maxima_with_assignments_on_extreme_shapes() {
+    let chain: Vec<_> = (0..50).map(|v: usize| v.checked_sub(1)).collect();
+    let star: Vec<_> = (0..50).map(|v| (v > 0).then_some(0)).collect();
+    for parents in [chain, star] {
+        let mut values: Vec<u32> = (0..50).collect();
+        let mut hld: HeavyLight<Max<u32>, SetEach<u32>> =
+            HeavyLight::new(&parents, values.iter().map(|&v| Max(v)));
+        let mut rng = Rng::new(3);
+        for _ in 0..100 {
+            let (u, v) = (rng.below(50), rng.below(50));
+            let value = rng.below(100) as u32;
+            for w in naive_path(&parents, u, v).unwrap() {
+                values[w] = value;
+            }
+            hld.path_update(u, v, &SetEach(Some(value)));
+            let (u, v) = (rng.below(50), rng.below(50));
+            let expected = naive_path(&parents, u, v)
+                .unwrap()
+                .iter()
+                .map(|&w| values[w])
+                .max();
+            assert_eq!(hld.path_query(u, v), expected.map(Max));
+        }
+    }
+}
+
+#[test]
+fn forests_keep_their_trees_apart() {
+    // 0 - 1 - 2    3 - 4
+    let parents = [None, Some(0), Some(1), None, Some(3)];
+    let mut hld: HeavyLight<Sum<i32>, AddEach<i32>> =
+        HeavyLight::new(&parents, [1, 2, 3, 4, 5].map(Sum));
+    assert_eq!(hld.path_query(2, 0), Some(Sum(6)));
+    assert_eq!(hld.path_query(2, 4), None);
+    assert!(!hld.path_update(0, 3, &AddEach(100)));
+    assert_eq!(hld.subtree_query(3), Sum(9));
+    assert_eq!(hld.lca(1, 4), None);
+    assert_eq!(hld.lca(2, 1), Some(1));
+    assert!(hld.path_update(4, 4, &AddEach(1)));
+    assert_eq!(hld.path_query(3, 4), Some(Sum(10)));
+    assert!(HeavyLight::<Sum<i32>>::new(&[], []).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "2 values given for 3 vertices")]
+fn needs_a_value_per_vertex() {
+    HeavyLight::<Sum<i32>>::new(&[None, Some(0), Some(0)], [Sum(1), Sum(2)]);
+}
@@ -0,0 +1,131 @@
+// Counts only exist with the instrument feature: `cargo test --features instrument`. The bounds
+// checked here are the textbook ones, so a regression to linear behaviour shows up as a failure.
+#![cfg(feature = "instrument")]
+
+use std::thread;
+
+use dsa_rust::{
+    hash::map::HashMap,
+    instrument::{self, BigO, Complexity, Instrumented, OpCounts},
+    linked_list::singly::LinkedList,
+    tree::{arena_avl::ArenaAVLTree, avl::AVLTree, heap::BinaryHeap},
+};
+
+fn log2(n: usize) -> u64 {
+    (usize::BITS - n.leading_zeros()) as u64
+}
+
+#[test]
+fn tree_operations_stay_logarithmic() {
+    let n = 1_024;
+    let mut tree = Instrumented::new(AVLTree::new());
+    // sorted input is the worst case for an unbalanced tree; rotations keep it shallow
+    tree.run(|tree| tree.extend(0..n));
+    let built = tree.stats();
+    assert_eq!(built.allocations, n as u64);
+    assert!(built.rotations > 0);
+    assert!(built.comparisons <= n as u64 * 2 * log2(n));
+
+    tree.reset_stats();
+    for key in [0, n / 2, n - 1, n + 7] {
+        tree.run(|tree| tree.contains(&key));
+        // a balanced tree is at most 1.44 log2(n) deep
+        assert!(tree.stats().comparisons <= 2 * log2(n));
+        tree.reset_stats();
+    }
+    assert_eq!(tree.inner().size(), n);
+}
+
+#[test]
+fn arena_tree_grows_its_buffer_instead_of_boxing_nodes() {
+    let ((), boxed) = instrument::measure(|| drop(AVLTree::from(0..1_000)));
+    let ((), arena) = instrument::measure(|| drop(ArenaAVLTree::from(0..1_000)));
+    assert_eq!(boxed.allocations, 1_000);
+    assert!(arena.allocations <= log2(1_000) + 1);
+    // the same balancing runs in both, so the rest of the work matches
+    assert_eq!(boxed.rotations, arena.rotations);
+    assert_eq!(boxed.comparisons, arena.comparisons);
+}
+
+#[test]
+fn heap_sifts_are_logarithmic() {
+    let n = 4_096;
+    let mut heap = Instrumented::new(BinaryHeap::min());
+    heap.run(|heap| heap.extend((0..n).rev()));
+    let offered = heap.stats();
+    assert!(offered.swaps <= n as u64 * log2(n));
+    assert!(offered.comparisons >= offered.swaps);
+
+    heap.reset_stats();
+    assert_eq!(heap.run(|heap| heap.poll()), Some(0));
+    assert!(heap.stats().comparisons <= 2 * log2(n));
+    assert!(heap.stats().swaps <= log2(n));
+}
+
+#[test]
+fn counts_are_per_thread() {
+    instrument::reset();
+    let (list, spent) = instrument::measure(|| LinkedList::from(0..10));
+    assert_eq!(spent.allocations, 10);
+    assert_eq!(instrument::counts().allocations, 10);
+    let elsewhere = thread::spawn(instrument::counts).join().unwrap();
+    assert_eq!(elsewhere, OpCounts::default());
+
+    let mut sorted = Instrumented::new(list);
+    sorted.run(|list| list.sort());
+    assert!(sorted.stats().comparisons > 0 && sorted.stats().allocations == 0);
+    assert!(instrument::enabled());
+}
+
+#[test]
+fn report_lines_counts_up_with_documented_bounds() {
+    assert_eq!(AVLTree::<u32>::bound("insert"), Some(BigO::Logarithmic));
+    assert_eq!(HashMap::<u32, u32>::bound("get"), Some(BigO::Constant));
+    assert_eq!(BinaryHeap::<u32>::bound("heapify"), None);
+    assert_eq!(BigO::Linearithmic.at(1_024), 10_240);
+    assert_eq!(BigO::Logarithmic.at(0), 1);
+
+    let mut tree = Instrumented::new(AVLTree::new());
+    for key in 0..256 {
+        tree.run_op("insert", |tree| tree.insert(key));
+    }
+    tree.run_op("contains", |tree| tree.contains(&17));
+    tree.run_op("rebuild", |tree| *tree = tree.iter().copied().collect());
+    let report = tree.report();
+    assert_eq!((report.name, report.size), ("AVLTree", 256));
+    assert_eq!(report.rows.len(), AVLTree::<u32>::OPERATIONS.len() + 1);
+
+    let insert = &report.rows[0];
+    assert_eq!(
+        (insert.operation, insert.bound, insert.calls),
+        ("insert", Some(BigO::Logarithmic), 256)
+    );
+    assert_eq!(insert.counts.allocations, 256);
+    let unmeasured = report
+        .rows
+        .iter()
+        .find(|row| row.operation == "rank")
+        .unwrap();
+    assert_eq!(
+        (unmeasured.calls, unmeasured.counts),
+        (0, OpCounts::default())
+    );
+    let rebuild = report.rows.last().unwrap();
+    assert_eq!(
+        (rebuild.operation, rebuild.bound, rebuild.calls),
+        ("rebuild", None, 1)
+    );
+
+    let table = report.to_string();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines[0], "AVLTree (n = 256)");
+    assert!(lines[1].starts_with("operation") && lines[1].contains("cmp/call"));
+    assert!(lines[2].starts_with("insert") && lines[2].contains("O(log n)"));
+    assert!(lines[2].contains("1.00"));
+    assert!(lines.last().unwrap().starts_with("rebuild       -"));
+    // every row lines up under the header
+    assert!(lines[1..].iter().all(|line| line.len() == lines[1].len()));
+
+    tree.reset_stats();
+    assert!(tree.report().rows.iter().all(|row| row.calls == 0));
+}
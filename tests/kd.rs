@@ -0,0 +1,92 @@
+use dsa_rust::tree::kd::KdTree;
+
+fn squared(a: &[i64; 2], b: &[i64; 2]) -> i64 {
+    (a[0] - b[0]).pow(2) + (a[1] - b[1]).pow(2)
+}
+
+fn cloud(count: u64) -> Vec<[i64; 2]> {
+    (0..count)
+        .map(|i| {
+            let mix = i
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            [
+                (mix >> 20) as i64 % 200 - 100,
+                (mix >> 40) as i64 % 200 - 100,
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn nearest_matches_a_scan() {
+    let points = cloud(400);
+    let built = KdTree::from(points.clone());
+    let mut inserted = KdTree::new();
+    inserted.extend(points.iter().copied());
+    assert_eq!((built.size(), inserted.size()), (400, 400));
+    // a median build stays near log2(400)
+    assert!(built.height() <= 10, "height {}", built.height());
+    for target in cloud(60).iter().map(|p| [p[1] + 3, p[0] - 7]) {
+        let best = points.iter().map(|p| squared(p, &target)).min();
+        for tree in [&built, &inserted] {
+            assert_eq!(tree.nearest(&target).map(|p| squared(p, &target)), best);
+            let near: Vec<i64> = tree
+                .k_nearest(&target, 7)
+                .iter()
+                .map(|p| squared(p, &target))
+                .collect();
+            let mut scan: Vec<i64> = points.iter().map(|p| squared(p, &target)).collect();
+            scan.sort();
+            assert_eq!(near, scan[..7]);
+        }
+    }
+}
+
+#[test]
+fn range_query_matches_a_scan() {
+    let points = cloud(300);
+    let tree: KdTree<2, i64> = points.iter().copied().collect();
+    for (min, max) in [
+        ([-50, -50], [50, 50]),
+        ([0, -100], [10, 100]),
+        ([7, 7], [7, 7]),
+        ([90, 90], [-90, -90]),
+    ] {
+        let mut found: Vec<[i64; 2]> = tree.range_query(&min, &max).into_iter().copied().collect();
+        let mut scan: Vec<[i64; 2]> = points
+            .iter()
+            .filter(|p| (0..2).all(|axis| min[axis] <= p[axis] && p[axis] <= max[axis]))
+            .copied()
+            .collect();
+        found.sort();
+        scan.sort();
+        assert_eq!(found, scan);
+    }
+}
+
+#[test]
+fn duplicates_floats_and_edges() {
+    let mut tree = KdTree::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [-1.5, 0.0, 9.0]]);
+    tree.insert([1.0, 2.0, 3.0]);
+    assert_eq!(tree.size(), 4);
+    assert!(tree.contains(&[1.0, 2.0, 3.0]) && !tree.contains(&[1.0, 2.0, 3.5]));
+    assert_eq!(
+        tree.k_nearest(&[1.0, 2.0, 2.0], 3),
+        vec![&[1.0, 2.0, 3.0]; 3]
+    );
+    assert_eq!(tree.nearest(&[-2.0, 0.0, 8.0]), Some(&[-1.5, 0.0, 9.0]));
+    assert_eq!(tree.k_nearest(&[0.0; 3], 10).len(), 4);
+    assert!(tree.k_nearest(&[0.0; 3], 0).is_empty());
+
+    // unsigned coordinates measure gaps without underflowing
+    let grid: KdTree<2, u32> = (0..10)
+        .flat_map(|x| (0..10).map(move |y| [x * 3, y * 3]))
+        .collect();
+    assert_eq!(grid.nearest(&[1, 29]), Some(&[0, 27]));
+    assert!(grid.contains(&[27, 0]));
+
+    let empty: KdTree<1, i32> = KdTree::new();
+    assert_eq!((empty.nearest(&[4]), empty.height()), (None, 0));
+    assert!(empty.range_query(&[0], &[10]).is_empty());
+}
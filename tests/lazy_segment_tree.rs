@@ -0,0 +1,97 @@
+use dsa_rust::{
+    algebra::{AddEach, Max, Min, SetEach, Sum},
+    generate::Rng,
+    ranges::LazySegmentTree,
+};
+
+fn range(rng: &mut Rng, len: usize) -> (usize, usize) {
+    let start = rng.below(len + 1);
+    (start, rng.range(start..len + 1))
+}
+
+#[test]
+fn range_adds_match_a_scan() {
+    let mut rng = Rng::new(61);
+    let mut items: Vec<i64> = (0..45).map(|_| rng.below(200) as i64 - 100).collect();
+    let mut sums = LazySegmentTree::<_, AddEach<i64>>::from(items.iter().map(|&v| Sum(v)));
+    let mut maxes = LazySegmentTree::<_, AddEach<i64>>::from(items.iter().map(|&v| Max(v)));
+    for _ in 0..500 {
+        let (start, end) = range(&mut rng, items.len());
+        let delta = rng.below(50) as i64 - 25;
+        items[start..end].iter_mut().for_each(|item| *item += delta);
+        sums.update(start..end, &AddEach(delta));
+        maxes.update(start..end, &AddEach(delta));
+        let (start, end) = range(&mut rng, items.len());
+        let slice = &items[start..end];
+        assert_eq!(sums.query(start..end), Sum(slice.iter().sum()));
+        assert_eq!(
+            maxes.query(start..end),
+            Max(slice.iter().copied().max().unwrap_or(i64::MIN))
+        );
+    }
+    assert_eq!(
+        sums.to_vec(),
+        items.iter().map(|&v| Sum(v)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn assignments_then_points() {
+    let mut rng = Rng::new(62);
+    let mut items = [u32::MAX; 33];
+    let mut tree = LazySegmentTree::<Min<u32>, SetEach<u32>>::new(items.len());
+    assert_eq!(tree.query(..), Min(u32::MAX));
+    for _ in 0..400 {
+        let (start, end) = range(&mut rng, items.len());
+        let value = rng.below(1000) as u32;
+        if rng.chance(0.2) && start < items.len() {
+            items[start] = value;
+            tree.set(start, Min(value));
+        } else {
+            items[start..end].fill(value);
+            tree.update(start..end, &SetEach(Some(value)));
+        }
+        let index = rng.below(items.len());
+        assert_eq!(tree.get(index), Min(items[index]));
+        let (start, end) = range(&mut rng, items.len());
+        assert_eq!(
+            tree.query(start..end),
+            Min(items[start..end].iter().copied().min().unwrap_or(u32::MAX))
+        );
+    }
+}
+
+// a later assignment overrides an earlier pending add, and an add after an assignment builds
+// on the assigned value
+#[test]
+fn pending_actions_compose_in_order() {
+    let mut tree = LazySegmentTree::<Sum<i32>, SetEach<i32>>::from([1, 2, 3, 4].map(Sum));
+    tree.update(.., &SetEach(Some(5)));
+    tree.update(1..3, &SetEach(None));
+    tree.update(2.., &SetEach(Some(1)));
+    assert_eq!(tree.query(..), Sum(12));
+    assert_eq!(format!("{:?}", tree), "[Sum(5), Sum(5), Sum(1), Sum(1)]");
+
+    let mut adds = LazySegmentTree::<Sum<u8>, AddEach<u8>>::new(3);
+    adds.update(.., &AddEach(2));
+    adds.update(..1, &AddEach(3));
+    adds.set(2, Sum(10));
+    assert_eq!(adds.to_vec(), [Sum(5), Sum(2), Sum(10)]);
+    assert_eq!((adds.len(), adds.is_empty()), (3, false));
+}
+
+#[test]
+fn point_updates_only() {
+    let mut tree: LazySegmentTree<Sum<i64>> = LazySegmentTree::from([3, 1, 4].map(Sum));
+    tree.set(1, Sum(10));
+    assert_eq!(tree.query(..), Sum(17));
+    let mut empty: LazySegmentTree<Sum<i64>> = LazySegmentTree::new(0);
+    assert_eq!(empty.query(..), Sum(0));
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "range end 4 out of range for 3 items")]
+fn update_checks_bounds() {
+    LazySegmentTree::<Sum<i32>, AddEach<i32>>::new(3).update(..=3, &AddEach(1));
+}
@@ -0,0 +1,115 @@
+use dsa_rust::{
+    generate::{Rng, grid, random_tree},
+    tree::lca::{Backend, Lca, parents_from},
+};
+
+const BACKENDS: [Backend; 2] = [Backend::BinaryLifting, Backend::EulerTour];
+
+// the slow answers, straight from the parent links
+fn ancestors(parents: &[Option<usize>], mut vertex: usize) -> Vec<usize> {
+    let mut path = vec![vertex];
+    while let Some(parent) = parents[vertex] {
+        path.push(parent);
+        vertex = parent;
+    }
+    path
+}
+
+fn naive_lca(parents: &[Option<usize>], u: usize, v: usize) -> Option<usize> {
+    let above_v = ancestors(parents, v);
+    ancestors(parents, u)
+        .into_iter()
+        .find(|vertex| above_v.contains(vertex))
+}
+
+fn check(parents: &[Option<usize>], lca: &Lca, rng: &mut Rng, queries: usize) {
+    for _ in 0..queries {
+        let (u, v) = (rng.below(parents.len()), rng.below(parents.len()));
+        let expected = naive_lca(parents, u, v);
+        assert_eq!(lca.lca(u, v), expected, "lca({}, {})", u, v);
+        assert_eq!(lca.lca(v, u), expected);
+        let up = ancestors(parents, u);
+        assert_eq!(lca.depth(u), up.len() - 1);
+        let k = rng.below(up.len() + 2);
+        assert_eq!(lca.kth_ancestor(u, k), up.get(k).copied());
+        let distance = expected.map(|a| lca.depth(u) + lca.depth(v) - 2 * lca.depth(a));
+        assert_eq!(lca.distance(u, v), distance);
+    }
+}
+
+#[test]
+fn random_trees_match_a_naive_climb() {
+    let mut rng = Rng::new(73);
+    for size in [1, 2, 3, 10, 100, 400] {
+        let tree = random_tree(&mut rng, size);
+        let root = rng.below(size);
+        let parents = parents_from(&tree, root);
+        for backend in BACKENDS {
+            let lca = Lca::from_graph(&tree, root, backend);
+            assert_eq!((lca.len(), lca.backend()), (size, backend));
+            assert_eq!(lca.root(rng.below(size)), root);
+            check(&parents, &lca, &mut rng, 200);
+        }
+    }
+}
+
+// a path is the deepest shape there is, so every lifting level gets used
+#[test]
+fn deep_paths() {
+    let mut rng = Rng::new(8);
+    let parents: Vec<_> = (0..300).map(|v: usize| v.checked_sub(1)).collect();
+    for backend in BACKENDS {
+        let lca = Lca::new(&parents, backend);
+        assert_eq!(lca.lca(299, 150), Some(150));
+        assert_eq!(lca.kth_ancestor(299, 299), Some(0));
+        assert_eq!(lca.kth_ancestor(299, 300), None);
+        assert_eq!(lca.distance(17, 256), Some(239));
+        check(&parents, &lca, &mut rng, 200);
+    }
+}
+
+#[test]
+fn forests_have_no_common_ancestor_across_trees() {
+    //   0       4
+    //  / \      |
+    // 1   2     5
+    //     |
+    //     3
+    let parents = [None, Some(0), Some(0), Some(2), None, Some(4)];
+    for backend in BACKENDS {
+        let lca = Lca::new(&parents, backend);
+        assert_eq!(lca.lca(1, 3), Some(0));
+        assert_eq!(lca.lca(3, 3), Some(3));
+        assert_eq!(lca.lca(2, 3), Some(2));
+        assert_eq!(lca.lca(3, 5), None);
+        assert_eq!(lca.distance(1, 3), Some(3));
+        assert_eq!(lca.distance(0, 4), None);
+        assert_eq!(
+            (lca.root(5), lca.parent(5), lca.parent(4)),
+            (4, Some(4), None)
+        );
+        assert_eq!(lca.kth_ancestor(5, 1), Some(4));
+    }
+    assert!(Lca::new(&[], Backend::EulerTour).is_empty());
+}
+
+// breadth-first parents on a grid keep the shortest edge count to the root as the depth
+#[test]
+fn trees_from_graphs_with_cycles() {
+    let lca = Lca::from_graph(&grid(4, 5), 0, Backend::default());
+    assert_eq!(lca.depth(19), 7);
+    assert_eq!(lca.root(12), 0);
+    assert_eq!(lca.distance(0, 19), Some(7));
+}
+
+#[test]
+#[should_panic(expected = "parents form a cycle through vertex 1")]
+fn rejects_cycles() {
+    Lca::new(&[None, Some(2), Some(1)], Backend::BinaryLifting);
+}
+
+#[test]
+#[should_panic(expected = "vertex 3 out of range for 3 vertices")]
+fn checks_vertices() {
+    Lca::new(&[None, Some(0), Some(0)], Backend::EulerTour).lca(0, 3);
+}
@@ -0,0 +1,57 @@
+use dsa_rust::collections::Matrix;
+
+#[test]
+fn indexing_is_row_major() {
+    let mut grid = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!((grid.rows(), grid.cols(), grid.is_square()), (2, 3, false));
+    assert_eq!(grid[(1, 0)], 4);
+    grid[(0, 2)] = 30;
+    assert_eq!(grid.row(0), &[1, 2, 30]);
+    assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![30, 6]);
+    assert_eq!((grid.get(2, 0), grid.get(0, 3)), (None, None));
+    assert_eq!(grid.clone().into_vec(), vec![1, 2, 30, 4, 5, 6]);
+    assert_eq!(Matrix::from_vec(2, 3, vec![1, 2, 30, 4, 5, 6]), grid);
+    assert_eq!(grid.map(|x| x % 2).iter().sum::<i32>(), 2);
+}
+
+#[test]
+#[should_panic(expected = "(2, 0) out of range for a 2x3 matrix")]
+fn index_checks_bounds() {
+    let grid: Matrix<u8> = Matrix::new(2, 3);
+    let _ = grid[(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "every row needs the same length")]
+fn ragged_rows_are_rejected() {
+    Matrix::from(vec![vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn transpose_and_multiply() {
+    let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    let t = a.transpose();
+    assert_eq!(t, Matrix::from([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(t.transpose(), a);
+    assert_eq!(&a * &t, Matrix::from([[14, 32], [32, 77]]));
+    let identity = Matrix::from_fn(3, 3, |r, c| u8::from(r == c) as i32);
+    assert_eq!(a.multiply(&identity), a);
+
+    let fib = Matrix::from([[1u64, 1], [1, 0]]);
+    let power = (1..10).fold(fib.clone(), |acc, _| &acc * &fib);
+    assert_eq!(power[(0, 1)], 55);
+}
+
+#[test]
+#[should_panic(expected = "cannot multiply 2x3 by 2x3")]
+fn multiply_checks_shapes() {
+    let a: Matrix<i32> = Matrix::new(2, 3);
+    let _ = &a * &a;
+}
+
+#[test]
+fn display_aligns_columns() {
+    let grid = Matrix::from([[1, -20], [300, 4]]);
+    assert_eq!(grid.to_string(), "  1 -20\n300   4\n");
+    assert_eq!(format!("{:?}", grid), "[[1, -20], [300, 4]]");
+}
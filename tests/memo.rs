@@ -0,0 +1,118 @@
+use dsa_rust::{
+    algorithms::{
+        dp,
+        memo::{Memo, Memoized, fibonacci, grid_paths},
+    },
+    cache::LruCache,
+    collections::Matrix,
+    generate::Rng,
+    hash::map::HashMap,
+};
+
+#[test]
+fn fibonacci_matches_the_table_version() {
+    for n in 0..=93 {
+        assert_eq!(fibonacci(n), dp::fibonacci(n as usize), "fib({})", n);
+    }
+    assert_eq!(fibonacci(94), None);
+}
+
+#[test]
+fn each_value_is_worked_out_once() {
+    let mut fib = Memoized::new(|fib, n: u64| match n {
+        0 | 1 => n,
+        _ => fib(n - 1) + fib(n - 2),
+    });
+    assert_eq!(fib.call(80), 23_416_728_348_467_685);
+    // 0 to 80 once each, and every fib(n - 2) after the first found waiting
+    let stats = fib.stats();
+    assert_eq!((stats.misses, stats.hits, stats.evictions), (81, 78, 0));
+    assert_eq!(fib.memo().len(), 81);
+    fib.call(80);
+    assert_eq!(fib.stats().hits, 79);
+    fib.clear();
+    assert!(fib.memo().is_empty());
+}
+
+#[test]
+fn a_small_lru_is_enough_for_fibonacci() {
+    let mut fib = Memoized::bounded(3, |fib, n: u64| match n {
+        0 | 1 => n,
+        _ => fib(n - 1) + fib(n - 2),
+    });
+    assert_eq!(fib.call(90), 2_880_067_194_370_816_120);
+    assert_eq!(fib.stats().misses, 91);
+    assert_eq!(fib.memo().len(), 3);
+    assert!(fib.stats().evictions > 0);
+
+    // with room for only two, fib(n - 2) keeps falling out and the runs multiply
+    let mut cramped = Memoized::bounded(2, |fib, n: u64| match n {
+        0 | 1 => n,
+        _ => fib(n - 1) + fib(n - 2),
+    });
+    assert_eq!(cramped.call(20), 6_765);
+    assert!(cramped.stats().misses > 1_000);
+}
+
+// the same recursion with no memo at all
+fn naive_paths(open: &Matrix<bool>, row: usize, col: usize) -> u128 {
+    if !open[(row, col)] {
+        return 0;
+    }
+    if (row, col) == (open.rows() - 1, open.cols() - 1) {
+        return 1;
+    }
+    let down = if row + 1 < open.rows() {
+        naive_paths(open, row + 1, col)
+    } else {
+        0
+    };
+    let right = if col + 1 < open.cols() {
+        naive_paths(open, row, col + 1)
+    } else {
+        0
+    };
+    down + right
+}
+
+#[test]
+fn grid_paths_count_monotone_routes() {
+    // an open grid has C(rows + cols - 2, rows - 1) paths
+    assert_eq!(grid_paths(&Matrix::filled(3, 3, true)), 6);
+    assert_eq!(grid_paths(&Matrix::filled(1, 9, true)), 1);
+    assert_eq!(grid_paths(&Matrix::filled(17, 17, true)), 601_080_390);
+    assert_eq!(grid_paths(&Matrix::filled(0, 0, true)), 0);
+    let mut closed = Matrix::filled(4, 4, true);
+    closed[(3, 3)] = false;
+    assert_eq!(grid_paths(&closed), 0);
+
+    let mut rng = Rng::new(42);
+    for _ in 0..40 {
+        let (rows, cols) = (rng.range(1..8), rng.range(1..8));
+        let open = Matrix::from_fn(rows, cols, |_, _| rng.chance(0.75));
+        assert_eq!(grid_paths(&open), naive_paths(&open, 0, 0));
+    }
+}
+
+#[test]
+fn takes_any_memo() {
+    // a memo handed in is used and returned as it was filled
+    let mut memo: HashMap<(u64, u64), u64> = HashMap::new();
+    memo.remember((10, 3), 0);
+    let mut choose = Memoized::with_memo(memo, |choose, (n, k): (u64, u64)| match k {
+        0 => 1,
+        _ if k == n => 1,
+        _ => choose((n - 1, k - 1)) + choose((n - 1, k)),
+    });
+    assert_eq!(choose.call((6, 3)), 20);
+    // the planted wrong answer is trusted, as a memo must be
+    assert_eq!(choose.call((11, 3)), 45);
+
+    let mut small: Memoized<_, u64, u64, LruCache<u64, u64>> =
+        Memoized::with_memo(LruCache::new(8), |steps, n: u64| match n {
+            0..=2 => n,
+            _ => steps(n - 1) + steps(n - 2),
+        });
+    assert_eq!(small.call(30), 1_346_269);
+    assert_eq!(small.memo().capacity(), 8);
+}
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use dsa_rust::{
+    adt::{
+        Queue, Stack,
+        min_stack::{MinQueue, MinStack},
+    },
+    generate::Rng,
+};
+
+#[test]
+fn min_stack_matches_a_scan() {
+    let mut rng = Rng::new(31);
+    let mut stack = MinStack::new();
+    let mut model: Vec<u32> = Vec::new();
+    for _ in 0..2_000 {
+        match rng.below(3) {
+            0 => assert_eq!(stack.pop(), model.pop()),
+            _ => {
+                let item = rng.below(40) as u32;
+                stack.push(item);
+                model.push(item);
+            }
+        }
+        assert_eq!(stack.min(), model.iter().min());
+        assert_eq!((stack.peek(), stack.len()), (model.last(), model.len()));
+    }
+    assert!(stack.iter().eq(model.iter().rev()));
+}
+
+#[test]
+fn equal_minima_survive_popping_one_copy() {
+    let mut stack: MinStack<i32> = [5, 2, 7, 2, 9].into_iter().collect();
+    assert_eq!(stack.min(), Some(&2));
+    assert_eq!((stack.pop(), stack.pop()), (Some(9), Some(2)));
+    assert_eq!(stack.min(), Some(&2));
+    assert_eq!((stack.pop(), stack.pop()), (Some(7), Some(2)));
+    assert_eq!(stack.min(), Some(&5));
+    stack.clear();
+    assert_eq!(stack.min(), None);
+    assert_eq!(stack.pop(), None);
+
+    // through the trait the stack is interchangeable with any other
+    let mut generic: Box<dyn Stack<i32>> = Box::new(MinStack::with_capacity(4));
+    generic.push(3);
+    assert_eq!((generic.peek(), generic.len()), (Some(3), 1));
+}
+
+#[test]
+fn min_queue_matches_a_scan() {
+    let mut rng = Rng::new(37);
+    let mut queue = MinQueue::new();
+    let mut model: VecDeque<u32> = VecDeque::new();
+    for _ in 0..2_000 {
+        match rng.below(5) {
+            0..=1 => assert_eq!(queue.poll(), model.pop_front()),
+            _ => {
+                let item = rng.below(40) as u32;
+                queue.offer(item);
+                model.push_back(item);
+            }
+        }
+        assert_eq!(queue.min(), model.iter().min());
+        assert_eq!((queue.head(), queue.tail()), (model.front(), model.back()));
+        assert_eq!(queue.len(), model.len());
+    }
+    assert!(queue.iter().eq(model.iter()));
+}
+
+#[test]
+fn sliding_window_minimum() {
+    // the textbook use: offer the new item and poll the oldest, reading min in between
+    let items = [4, 2, 12, 11, -5, 3, 8, 1, 9];
+    let mut window = MinQueue::new();
+    let mut minima = Vec::new();
+    for (i, &item) in items.iter().enumerate() {
+        window.offer(item);
+        if i >= 3 {
+            window.poll();
+        }
+        if i >= 2 {
+            minima.push(*window.min().unwrap());
+        }
+    }
+    assert_eq!(minima, vec![2, 2, -5, -5, -5, 1, 1]);
+
+    let mut queue: MinQueue<char> = "queue".chars().collect();
+    assert_eq!(format!("{:?}", queue), "['q', 'u', 'e', 'u', 'e']");
+    assert_eq!(Queue::poll(&mut queue), Some('q'));
+    assert_eq!(
+        (Queue::head(&queue), Queue::tail(&queue)),
+        (Some('u'), Some('e'))
+    );
+    Queue::clear(&mut queue);
+    assert!(queue.is_empty() && queue.min().is_none());
+}
@@ -0,0 +1,412 @@
+// Model checking against std: random operation sequences are applied to a crate structure and
+// to the std collection with the same semantics, and every observable result has to agree. A
+// failing sequence is shrunk by deleting ever smaller chunks of operations for as long as it
+// keeps failing, so the report is a short reproduction rather than hundreds of steps.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap as StdBinaryHeap, VecDeque},
+    fmt::Debug,
+    ops::Bound::{Excluded, Unbounded},
+    panic::{self, AssertUnwindSafe},
+};
+
+use dsa_rust::{
+    generate::Rng,
+    linked_list::{doubly, singly},
+    tree::{avl::AVLTree, bst::BST, heap::BinaryHeap},
+};
+
+const RUNS: u64 = if cfg!(miri) { 2 } else { 64 };
+const STEPS: usize = if cfg!(miri) { 40 } else { 300 };
+const KEYS: u32 = 48;
+
+fn key(rng: &mut Rng) -> u32 {
+    rng.below(KEYS as usize) as u32
+}
+
+// a crate panic counts as a failure too, so it gets shrunk like a wrong answer
+fn outcome<O, R>(run: &R, ops: &[O]) -> Result<(), String>
+where
+    R: Fn(&[O]) -> Result<(), String>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(|| run(ops))) {
+        Ok(result) => result,
+        Err(payload) => Err(payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .map_or("panicked".to_string(), |msg| format!("panicked: {}", msg))),
+    }
+}
+
+fn shrink<O, R>(mut ops: Vec<O>, mut err: String, run: &R) -> (Vec<O>, String)
+where
+    O: Clone,
+    R: Fn(&[O]) -> Result<(), String>,
+{
+    let mut chunk = ops.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < ops.len() {
+            let mut candidate = ops.clone();
+            candidate.drain(start..(start + chunk).min(ops.len()));
+            match outcome(run, &candidate) {
+                Err(smaller) => (ops, err) = (candidate, smaller),
+                Ok(()) => start += chunk,
+            }
+        }
+        chunk /= 2;
+    }
+    (ops, err)
+}
+
+fn find_failure<O, G, R>(generate: G, run: R) -> Option<(u64, Vec<O>, String)>
+where
+    O: Clone,
+    G: Fn(&mut Rng) -> O,
+    R: Fn(&[O]) -> Result<(), String>,
+{
+    (1..=RUNS).find_map(|seed| {
+        let mut rng = Rng::new(seed);
+        let ops: Vec<O> = (0..STEPS).map(|_| generate(&mut rng)).collect();
+        let err = outcome(&run, &ops).err()?;
+        let (ops, err) = shrink(ops, err, &run);
+        Some((seed, ops, err))
+    })
+}
+
+fn check<O, G, R>(generate: G, run: R)
+where
+    O: Clone + Debug,
+    G: Fn(&mut Rng) -> O,
+    R: Fn(&[O]) -> Result<(), String>,
+{
+    if let Some((seed, ops, err)) = find_failure(generate, run) {
+        panic!(
+            "seed {} still fails with {} ops: {}\n{:#?}",
+            seed,
+            ops.len(),
+            err,
+            ops
+        );
+    }
+}
+
+macro_rules! agree {
+    ($step:expr, $actual:expr, $expected:expr) => {{
+        let (actual, expected) = ($actual, $expected);
+        if actual != expected {
+            return Err(format!(
+                "step {}: {} gave {:?}, model gave {:?}",
+                $step,
+                stringify!($actual),
+                actual,
+                expected
+            ));
+        }
+    }};
+}
+
+#[test]
+fn shrinking_finds_a_minimal_case() {
+    // fails as soon as a 3 and a 7 have both been seen, so two operations are enough
+    let failure = find_failure(
+        |rng| rng.below(10),
+        |ops: &[usize]| match ops.contains(&3) && ops.contains(&7) {
+            true => Err("saw 3 and 7".to_string()),
+            false => Ok(()),
+        },
+    );
+    let (_, mut ops, err) = failure.unwrap();
+    ops.sort();
+    assert_eq!((ops, err.as_str()), (vec![3, 7], "saw 3 and 7"));
+
+    let failure = find_failure(
+        |rng| rng.below(10),
+        |ops: &[usize]| {
+            assert!(ops.len() < 5, "too long");
+            Ok(())
+        },
+    );
+    let (_, ops, err) = failure.unwrap();
+    assert_eq!((ops.len(), err.as_str()), (5, "panicked: too long"));
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SetOp {
+    Insert(u32),
+    Remove(u32),
+    Contains(u32),
+    Floor(u32),
+    Ceil(u32),
+    Successor(u32),
+    Predecessor(u32),
+    Rank(u32),
+    Kth(u32),
+    Extremes,
+}
+
+fn set_op(rng: &mut Rng) -> SetOp {
+    match rng.below(10) {
+        0..=3 => SetOp::Insert(key(rng)),
+        4..=5 => SetOp::Remove(key(rng)),
+        6 => SetOp::Contains(key(rng)),
+        7 => match rng.below(4) {
+            0 => SetOp::Floor(key(rng)),
+            1 => SetOp::Ceil(key(rng)),
+            2 => SetOp::Successor(key(rng)),
+            _ => SetOp::Predecessor(key(rng)),
+        },
+        8 => match rng.below(2) {
+            0 => SetOp::Rank(key(rng)),
+            _ => SetOp::Kth(key(rng)),
+        },
+        _ => SetOp::Extremes,
+    }
+}
+
+// the balanced and unbalanced trees answer every query the same way, only their shape differs
+macro_rules! set_model {
+    ($name:ident, $tree:ty) => {
+        #[test]
+        fn $name() {
+            check(set_op, |ops| {
+                let mut tree = <$tree>::new();
+                let mut model = BTreeSet::new();
+                for (step, &op) in ops.iter().enumerate() {
+                    match op {
+                        SetOp::Insert(key) => agree!(step, tree.insert(key), model.insert(key)),
+                        SetOp::Remove(key) => agree!(step, tree.remove(&key), model.remove(&key)),
+                        SetOp::Contains(key) => {
+                            agree!(step, tree.contains(&key), model.contains(&key))
+                        }
+                        SetOp::Floor(key) => {
+                            agree!(step, tree.get_floor(&key), model.range(..=key).next_back())
+                        }
+                        SetOp::Ceil(key) => {
+                            agree!(step, tree.get_ceil(&key), model.range(key..).next())
+                        }
+                        SetOp::Successor(key) => agree!(
+                            step,
+                            tree.successor(&key),
+                            model.range((Excluded(key), Unbounded)).next()
+                        ),
+                        SetOp::Predecessor(key) => {
+                            agree!(step, tree.predecessor(&key), model.range(..key).next_back())
+                        }
+                        SetOp::Rank(key) => {
+                            agree!(step, tree.rank(&key), model.range(..key).count())
+                        }
+                        SetOp::Kth(k) => {
+                            agree!(
+                                step,
+                                tree.kth_smallest(k as usize),
+                                model.iter().nth(k as usize)
+                            )
+                        }
+                        SetOp::Extremes => agree!(
+                            step,
+                            (tree.first(), tree.last()),
+                            (model.first(), model.last())
+                        ),
+                    }
+                    agree!(step, tree.size(), model.len());
+                    if let Err(err) = tree.validate() {
+                        return Err(format!("step {}: {}", step, err));
+                    }
+                }
+                agree!(
+                    ops.len(),
+                    tree.iter().collect::<Vec<_>>(),
+                    model.iter().collect::<Vec<_>>()
+                );
+                Ok(())
+            });
+        }
+    };
+}
+
+set_model!(avl_tree_matches_btree_set, AVLTree<u32>);
+set_model!(bst_matches_btree_set, BST<u32>);
+
+#[derive(Debug, Clone, Copy)]
+enum HeapOp {
+    Offer(u32),
+    Poll,
+    Peek,
+    PushPop(u32),
+    ReplaceTop(u32),
+}
+
+#[test]
+fn binary_heap_matches_std_heap() {
+    check(
+        |rng| match rng.below(8) {
+            0..=2 => HeapOp::Offer(key(rng)),
+            3..=4 => HeapOp::Poll,
+            5 => HeapOp::Peek,
+            6 => HeapOp::PushPop(key(rng)),
+            _ => HeapOp::ReplaceTop(key(rng)),
+        },
+        |ops| {
+            // the crate's min heap against std's max heap over reversed keys
+            let mut heap = BinaryHeap::min();
+            let mut model = StdBinaryHeap::new();
+            for (step, &op) in ops.iter().enumerate() {
+                match op {
+                    HeapOp::Offer(key) => {
+                        heap.offer(key);
+                        model.push(Reverse(key));
+                    }
+                    HeapOp::Poll => agree!(step, heap.poll(), model.pop().map(|key| key.0)),
+                    HeapOp::Peek => agree!(step, heap.peek(), model.peek().map(|key| &key.0)),
+                    HeapOp::PushPop(key) => {
+                        model.push(Reverse(key));
+                        agree!(step, heap.push_pop(key), model.pop().unwrap().0)
+                    }
+                    HeapOp::ReplaceTop(key) => {
+                        let top = model.pop().map(|key| key.0);
+                        model.push(Reverse(key));
+                        agree!(step, heap.replace_top(key), top)
+                    }
+                }
+                agree!(step, heap.size(), model.len());
+            }
+            let mut drained = Vec::new();
+            while let Some(key) = heap.poll() {
+                drained.push(key);
+            }
+            agree!(
+                ops.len(),
+                drained,
+                model
+                    .into_sorted_vec()
+                    .into_iter()
+                    .rev()
+                    .map(|key| key.0)
+                    .collect::<Vec<_>>()
+            );
+            Ok(())
+        },
+    );
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ListOp {
+    PushHead(u32),
+    PushTail(u32),
+    PopHead,
+    PopTail,
+    Get(u32),
+    Set(u32, u32),
+    Remove(u32),
+    Reverse,
+    Rotate(u32),
+    SplitSwap(u32),
+    RetainEven,
+    Sort,
+}
+
+fn list_op(rng: &mut Rng) -> ListOp {
+    match rng.below(16) {
+        0..=2 => ListOp::PushHead(key(rng)),
+        3..=5 => ListOp::PushTail(key(rng)),
+        6 => ListOp::PopHead,
+        7 => ListOp::PopTail,
+        8 => ListOp::Get(key(rng)),
+        9 => ListOp::Set(key(rng), key(rng)),
+        10 => ListOp::Remove(key(rng)),
+        11 => ListOp::Reverse,
+        12 => ListOp::Rotate(key(rng)),
+        13 => ListOp::SplitSwap(key(rng)),
+        14 => ListOp::RetainEven,
+        _ => ListOp::Sort,
+    }
+}
+
+// both lists share their method names apart from taking the head, which singly calls pop
+macro_rules! list_model {
+    ($name:ident, $list:ty, $pop_head:ident) => {
+        #[test]
+        fn $name() {
+            check(list_op, |ops| {
+                let mut list = <$list>::new();
+                let mut model = VecDeque::new();
+                for (step, &op) in ops.iter().enumerate() {
+                    match op {
+                        ListOp::PushHead(item) => {
+                            list.push_head(item);
+                            model.push_front(item);
+                        }
+                        ListOp::PushTail(item) => {
+                            list.push_tail(item);
+                            model.push_back(item);
+                        }
+                        ListOp::PopHead => agree!(step, list.$pop_head(), model.pop_front()),
+                        ListOp::PopTail => agree!(step, list.pop_tail(), model.pop_back()),
+                        ListOp::Get(index) => {
+                            agree!(step, list.get(index as usize), model.get(index as usize))
+                        }
+                        ListOp::Set(index, item) => {
+                            let expected = model.get_mut(index as usize).map(|slot| *slot = item);
+                            agree!(step, list.set(index as usize, item), expected)
+                        }
+                        ListOp::Remove(index) => {
+                            agree!(
+                                step,
+                                list.remove(index as usize),
+                                model.remove(index as usize)
+                            )
+                        }
+                        ListOp::Reverse => {
+                            list.reverse();
+                            model.make_contiguous().reverse();
+                        }
+                        ListOp::Rotate(k) => {
+                            list.rotate_left(k as usize);
+                            if !model.is_empty() {
+                                model.rotate_left(k as usize % model.len());
+                            }
+                        }
+                        ListOp::SplitSwap(index) => {
+                            // moving the tail in front of the head is a rotation done by hand
+                            let index = (index as usize).min(list.len());
+                            let mut back = list.split_off(index);
+                            back.append(core::mem::take(&mut list));
+                            list = back;
+                            model.rotate_left(index);
+                        }
+                        ListOp::RetainEven => {
+                            list.retain(|item| item % 2 == 0);
+                            model.retain(|item| item % 2 == 0);
+                        }
+                        ListOp::Sort => {
+                            list.sort();
+                            model.make_contiguous().sort();
+                        }
+                    }
+                    agree!(step, list.len(), model.len());
+                    agree!(
+                        step,
+                        (list.first(), list.last()),
+                        (model.front(), model.back())
+                    );
+                }
+                agree!(
+                    ops.len(),
+                    list.iter().collect::<Vec<_>>(),
+                    model.iter().collect::<Vec<_>>()
+                );
+                Ok(())
+            });
+        }
+    };
+}
+
+list_model!(singly_list_matches_vec_deque, singly::LinkedList<u32>, pop);
+list_model!(
+    doubly_list_matches_vec_deque,
+    doubly::LinkedList<u32>,
+    pop_head
+);
@@ -0,0 +1,154 @@
+// Sharing is observed through element addresses: two versions share a node exactly when they
+// hand out references to the same item.
+
+use std::ptr;
+
+use dsa_rust::{
+    persistent::{List, OrderedMap, Vector},
+    tree::avl::AVLTree,
+};
+
+#[test]
+fn list_versions_share_their_tails() {
+    let base = List::from([2, 3, 4]);
+    let left = base.push_front(1);
+    let right = base.push_front(10);
+    assert_eq!(left, List::from([1, 2, 3, 4]));
+    assert_eq!(right, List::from([10, 2, 3, 4]));
+    assert_eq!(base, List::from([2, 3, 4]));
+    for index in 0..3 {
+        assert!(ptr::eq(
+            base.get(index).unwrap(),
+            left.get(index + 1).unwrap()
+        ));
+        assert!(ptr::eq(
+            left.get(index + 1).unwrap(),
+            right.get(index + 1).unwrap()
+        ));
+    }
+
+    let rest = left.pop_front().unwrap();
+    assert!(rest.ptr_eq(&base));
+    assert!(!rest.ptr_eq(&List::from([2, 3, 4])));
+    assert_eq!(rest, List::from([2, 3, 4]));
+
+    let joined = List::from([0]).append(&base);
+    assert!(ptr::eq(joined.get(1).unwrap(), base.first().unwrap()));
+    assert_eq!(base.reverse(), List::from([4, 3, 2]));
+}
+
+#[test]
+fn list_drops_long_chains() {
+    let len = if cfg!(miri) { 5_000 } else { 200_000 };
+    let long = List::from(0..len);
+    let shared = long.pop_front().unwrap();
+    drop(long);
+    assert_eq!(shared.len(), len - 1);
+    assert_eq!(shared.first(), Some(&1));
+}
+
+#[test]
+fn vector_update_copies_only_its_path() {
+    let base = Vector::from(0..2_000);
+    let changed = base.update(1_000, -1).unwrap();
+    assert_eq!(base[1_000], 1_000);
+    assert_eq!(changed[1_000], -1);
+    assert_eq!(base.update(2_000, 0), None);
+
+    // the updated leaf holds indices 992..1024; everything outside it is still shared
+    for index in (0..2_000).filter(|index| !(992..1024).contains(index)) {
+        assert!(ptr::eq(&base[index], &changed[index]));
+    }
+    for index in 992..1024 {
+        assert!(!ptr::eq(&base[index], &changed[index]));
+    }
+}
+
+#[test]
+fn vector_versions_survive_pushes_and_pops() {
+    let mut versions = vec![Vector::new()];
+    for value in 0..1_500 {
+        versions.push(versions.last().unwrap().push_back(value));
+    }
+    let shrunk = (0..700).fold(versions[1_500].clone(), |vector, _| {
+        vector.pop_back().unwrap()
+    });
+    // walking every version is quadratic, too slow for miri, so it samples them instead
+    let step = if cfg!(miri) { 50 } else { 1 };
+    for (len, version) in versions.iter().enumerate().step_by(step) {
+        assert_eq!(version.len(), len);
+        assert!(version.iter().copied().eq(0..len as i32));
+    }
+    assert!(shrunk.iter().copied().eq(0..800));
+    assert!(ptr::eq(&shrunk[0], &versions[1_500][0]));
+    assert_eq!(versions[0].pop_back(), None);
+    assert_eq!(shrunk.last(), Some(&799));
+}
+
+#[test]
+fn map_snapshots_are_unaffected_by_later_versions() {
+    let mut map = OrderedMap::new();
+    let mut snapshots = Vec::new();
+    for key in 0..100 {
+        snapshots.push(map.snapshot());
+        map = map.insert(key, key * 2);
+    }
+    for (len, snapshot) in snapshots.iter().enumerate() {
+        assert_eq!(snapshot.len(), len);
+        assert!(snapshot.keys().copied().eq(0..len as i32));
+        snapshot.validate().unwrap();
+    }
+
+    let replaced = map.insert(50, -1);
+    assert_eq!((map[&50], replaced[&50]), (100, -1));
+    let removed = map.remove(&50);
+    assert!(!removed.contains_key(&50) && map.contains_key(&50));
+    assert!(map.remove(&1_000).ptr_eq(&map));
+    assert_eq!(map.snapshot(), map);
+}
+
+#[test]
+fn map_updates_copy_only_the_search_path() {
+    let map = OrderedMap::from((0..1_024).map(|key| (key, key)));
+    // a balanced tree of 1024 keys is at most 1.44 * log2(1024) levels deep
+    let path = 15;
+    for key in [0, 511, 1_023] {
+        let changed = map.insert(key, -1);
+        let copied = (0..1_024)
+            .filter(|key| !ptr::eq(&map[key], &changed[key]))
+            .count();
+        assert!(copied <= path, "{} values copied", copied);
+
+        let removed = map.remove(&key);
+        removed.validate().unwrap();
+        let copied = (0..1_024)
+            .filter(|other| other != &key && !ptr::eq(&map[other], &removed[other]))
+            .count();
+        // rotations while rebalancing may copy one or two nodes beside each one on the path
+        assert!(copied <= 3 * path, "{} values copied", copied);
+    }
+}
+
+#[test]
+fn map_matches_mutable_tree() {
+    // the same operations applied in place and persistently leave the same keys behind,
+    // but only the persistent side still has every intermediate version
+    let mut tree = AVLTree::new();
+    let mut versions = vec![OrderedMap::new()];
+    for step in 0..500u32 {
+        let key = step.wrapping_mul(2_654_435_761) % 97;
+        let last = versions.last().unwrap();
+        let next = if step % 3 == 2 {
+            tree.remove(&key);
+            last.remove(&key)
+        } else {
+            tree.insert(key);
+            last.insert(key, step)
+        };
+        versions.push(next);
+    }
+    let last = versions.last().unwrap();
+    assert!(last.keys().eq(tree.iter()));
+    assert_eq!(last.len(), tree.size());
+    assert!(versions[1].len() == 1 && versions[2].len() == 2);
+}
@@ -0,0 +1,88 @@
+use dsa_rust::tree::heap::{IndexedHeap, PriorityQueue};
+
+// a payload with no ordering at all, which a plain BinaryHeap could not hold
+#[derive(Debug, Clone, PartialEq)]
+struct Task {
+    name: &'static str,
+}
+
+#[test]
+fn only_priorities_are_compared() {
+    let mut queue = PriorityQueue::min();
+    for (priority, name) in [(3, "write"), (1, "plan"), (4, "ship"), (2, "build")] {
+        queue.offer(priority, Task { name });
+    }
+    assert_eq!(queue.size(), 4);
+    assert_eq!(queue.peek(), Some(&Task { name: "plan" }));
+    assert_eq!(queue.peek_with_priority().map(|(p, _)| *p), Some(1));
+    assert_eq!(queue.poll_with_priority(), Some((1, Task { name: "plan" })));
+    assert_eq!(queue.poll().map(|task| task.name), Some("build"));
+    let rest: Vec<(i32, &str)> = queue.into_iter().map(|(p, task)| (p, task.name)).collect();
+    assert_eq!(rest, [(3, "write"), (4, "ship")]);
+}
+
+#[test]
+fn comparators_and_collecting() {
+    let mut queue: PriorityQueue<u32, char> = PriorityQueue::max();
+    queue.extend([(1, 'a'), (9, 'z'), (5, 'm')]);
+    assert_eq!(queue.poll(), Some('z'));
+    assert_eq!(queue.clone().into_sorted_vec(), [(5, 'm'), (1, 'a')]);
+
+    let by_distance = PriorityQueue::from(
+        |a: &i32, b: &i32| a.abs().cmp(&b.abs()),
+        [(-7, 'x'), (2, 'y'), (-1, 'w')],
+    );
+    assert!(by_distance.into_iter().map(|(_, v)| v).eq(['w', 'y', 'x']));
+
+    let collected: PriorityQueue<u8, ()> = (0..100).rev().map(|p| (p, ())).collect();
+    assert!(collected.into_iter().map(|(p, _)| p).eq(0..100));
+
+    let mut empty = PriorityQueue::<u8, String>::default();
+    assert!(empty.is_empty() && empty.poll_with_priority().is_none() && empty.peek().is_none());
+    empty.offer(1, String::from("one"));
+    assert_eq!(format!("{:?}", empty), r#"{1: "one"}"#);
+    empty.clear();
+    assert_eq!(empty.iter_unsorted().len(), 0);
+}
+
+#[test]
+fn indexed_heap_moves_ids_both_ways() {
+    let mut heap = IndexedHeap::min();
+    heap.extend([(0, 50), (1, 20), (2, 40), (3, 10), (7, 30)]);
+    assert_eq!((heap.size(), heap.peek()), (5, Some((3, &10))));
+    assert_eq!(heap.change_priority(2, 5), Some(40));
+    assert_eq!(heap.peek(), Some((2, &5)));
+    assert_eq!(heap.change_priority(2, 60), Some(5));
+    assert_eq!(heap.insert(1, 1), Some(20));
+    assert_eq!(heap.change_priority(4, 0), None);
+    assert!(!heap.contains(4));
+    assert_eq!(heap.remove(3), Some(10));
+    assert_eq!((heap.remove(3), heap.priority(3)), (None, None));
+    let order: Vec<(usize, i32)> = std::iter::from_fn(|| heap.poll()).collect();
+    assert_eq!(order, vec![(1, 1), (7, 30), (0, 50), (2, 60)]);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn indexed_heap_matches_a_scan() {
+    let mut heap = IndexedHeap::max();
+    let mut model: Vec<Option<u32>> = vec![None; 64];
+    for step in 0..4000u32 {
+        let mix = step.wrapping_mul(2_654_435_761);
+        let id = (mix >> 8) as usize % model.len();
+        let priority = (mix >> 16) % 1000;
+        match mix % 4 {
+            0 | 1 => assert_eq!(heap.insert(id, priority), model[id].replace(priority)),
+            2 => assert_eq!(heap.remove(id), model[id].take()),
+            _ => {
+                let best = model.iter().flatten().max().copied();
+                let polled = heap.poll();
+                assert_eq!(polled.map(|(_, p)| p), best);
+                if let Some((id, _)) = polled {
+                    assert_eq!(model[id].take(), best);
+                }
+            }
+        }
+        assert_eq!(heap.size(), model.iter().flatten().count());
+    }
+}
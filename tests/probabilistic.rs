@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+
+use dsa_rust::{
+    generate::Rng,
+    probabilistic::{CountMinSketch, HyperLogLog},
+};
+
+// skewed so a few keys are heavy and most are rare: key k turns up about 1 / (k + 1) as often
+fn skewed_stream(rng: &mut Rng, len: usize, keys: usize) -> Vec<u32> {
+    (0..len)
+        .map(|_| {
+            let key = (keys as f64).powf(rng.unit()) as usize - 1;
+            key.min(keys - 1) as u32
+        })
+        .collect()
+}
+
+fn counts(stream: &[u32]) -> HashMap<u32, u64> {
+    let mut counts = HashMap::new();
+    for &item in stream {
+        *counts.entry(item).or_default() += 1;
+    }
+    counts
+}
+
+#[test]
+fn count_min_never_undercounts() {
+    let mut rng = Rng::new(1582);
+    for (width, depth) in [(8, 2), (64, 4), (1024, 5)] {
+        let stream = skewed_stream(&mut rng, 20_000, 2_000);
+        let mut sketch = CountMinSketch::new(width, depth);
+        for item in &stream {
+            sketch.add(item);
+        }
+        assert_eq!(sketch.total(), stream.len() as u64);
+        for (item, count) in counts(&stream) {
+            assert!(
+                sketch.estimate(&item) >= count,
+                "{} seen {} times",
+                item,
+                count
+            );
+        }
+    }
+}
+
+#[test]
+fn count_min_stays_within_its_error_bound() {
+    let mut rng = Rng::new(7);
+    let (epsilon, delta) = (0.001, 0.01);
+    let stream = skewed_stream(&mut rng, 50_000, 5_000);
+    let mut sketch = CountMinSketch::with_error(epsilon, delta);
+    for item in &stream {
+        sketch.add(item);
+    }
+    let slack = (epsilon * stream.len() as f64) as u64;
+    let counts = counts(&stream);
+    let over = counts
+        .iter()
+        .filter(|&(item, &count)| sketch.estimate(item) > count + slack)
+        .count();
+    // at most delta of them, with room for an unlucky sample
+    assert!(
+        over as f64 <= 2.0 * delta * counts.len() as f64,
+        "{} over",
+        over
+    );
+}
+
+#[test]
+fn count_min_merge_is_the_combined_stream() {
+    let mut rng = Rng::new(99);
+    let (left, right) = (
+        skewed_stream(&mut rng, 5_000, 500),
+        skewed_stream(&mut rng, 8_000, 900),
+    );
+    let sketch = |items: &[u32]| {
+        let mut sketch = CountMinSketch::new(256, 4);
+        for item in items {
+            sketch.add(item);
+        }
+        sketch
+    };
+    let mut merged = sketch(&left);
+    merged.merge(&sketch(&right));
+    let combined: Vec<u32> = left.iter().chain(&right).copied().collect();
+    assert_eq!(merged, sketch(&combined));
+}
+
+#[test]
+fn hyperloglog_stays_within_three_standard_errors() {
+    let mut rng = Rng::new(41);
+    for precision in [10, 12, 14] {
+        let sigma = 1.04 / ((1u64 << precision) as f64).sqrt();
+        for cardinality in [10, 300, 5_000, 50_000, 200_000] {
+            let mut hll = HyperLogLog::new(precision);
+            let mut seen = HashSet::new();
+            while seen.len() < cardinality {
+                let item = rng.next_u64();
+                hll.add(&item);
+                // every item added twice, which must not count
+                hll.add(&item);
+                seen.insert(item);
+            }
+            let error = (hll.estimate() - cardinality as f64).abs() / cardinality as f64;
+            assert!(
+                error <= 3.0 * sigma,
+                "precision {}, {} distinct: off by {:.4} against 3 sigma {:.4}",
+                precision,
+                cardinality,
+                error,
+                3.0 * sigma
+            );
+        }
+    }
+    let empty = HyperLogLog::new(12);
+    assert!(empty.is_empty());
+    assert_eq!(empty.estimate(), 0.0);
+}
+
+#[test]
+fn hyperloglog_merge_is_the_union() {
+    let mut rng = Rng::new(5);
+    let shared: Vec<u64> = (0..4_000).map(|_| rng.next_u64()).collect();
+    let left: Vec<u64> = (0..10_000)
+        .map(|_| rng.next_u64())
+        .chain(shared.clone())
+        .collect();
+    let right: Vec<u64> = (0..6_000).map(|_| rng.next_u64()).chain(shared).collect();
+    let sketch = |items: &[u64]| {
+        let mut hll = HyperLogLog::new(12);
+        for item in items {
+            hll.add(item);
+        }
+        hll
+    };
+    let mut merged = sketch(&left);
+    merged.merge(&sketch(&right));
+    let union: HashSet<u64> = left.iter().chain(&right).copied().collect();
+    assert_eq!(merged, sketch(&union.iter().copied().collect::<Vec<_>>()));
+    let error = (merged.estimate() - union.len() as f64).abs() / union.len() as f64;
+    assert!(error <= 3.0 * 1.04 / 64.0);
+}
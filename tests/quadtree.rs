@@ -0,0 +1,108 @@
+use dsa_rust::{
+    tree::quadtree::{QuadTree, Rect},
+    visualize::{DotStyle, ToDot},
+};
+
+fn scatter(count: u64) -> Vec<[f64; 2]> {
+    (0..count)
+        .map(|i| {
+            let mix = i
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            [
+                ((mix >> 20) % 1000) as f64 / 10.0,
+                ((mix >> 40) % 1000) as f64 / 10.0,
+            ]
+        })
+        .collect()
+}
+
+fn sorted(mut points: Vec<[f64; 2]>) -> Vec<[f64; 2]> {
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    points
+}
+
+#[test]
+fn queries_match_a_scan() {
+    let points = scatter(500);
+    let mut tree = QuadTree::new(Rect::new([0.0, 0.0], [100.0, 100.0]), 4);
+    tree.extend(points.iter().copied());
+    assert_eq!(tree.size(), 500);
+    assert!(tree.node_count() > 1 && tree.depth() >= 3);
+
+    for area in [
+        Rect::new([10.0, 20.0], [40.0, 35.5]),
+        Rect::new([50.0, 50.0], [50.0, 100.0]),
+        Rect::new([-5.0, -5.0], [200.0, 0.5]),
+    ] {
+        let found = sorted(tree.query_rect(&area).into_iter().copied().collect());
+        let scan = sorted(
+            points
+                .iter()
+                .filter(|p| area.contains(p))
+                .copied()
+                .collect(),
+        );
+        assert_eq!(found, scan);
+    }
+    for (center, radius) in [
+        ([50.0, 50.0], 12.5),
+        ([0.0, 100.0], 30.0),
+        ([33.3, 66.6], 0.0),
+    ] {
+        let found = sorted(
+            tree.query_circle(&center, radius)
+                .into_iter()
+                .copied()
+                .collect(),
+        );
+        let inside = |p: &&[f64; 2]| {
+            (p[0] - center[0]).powi(2) + (p[1] - center[1]).powi(2) <= radius * radius
+        };
+        let scan = sorted(points.iter().filter(inside).copied().collect());
+        assert_eq!(found, scan);
+    }
+    assert!(points.iter().all(|p| tree.contains(p)));
+    assert_eq!(sorted(tree.iter().copied().collect()), sorted(points));
+}
+
+#[test]
+fn bounds_capacity_and_piles() {
+    let mut tree = QuadTree::new(Rect::new([-1.0, -1.0], [1.0, 1.0]), 2);
+    assert!(!tree.insert([1.5, 0.0]));
+    assert!(tree.insert([1.0, 1.0]) && tree.insert([-1.0, -1.0]));
+    assert_eq!(tree.node_count(), 1);
+    assert!(tree.insert([0.0, 0.0]));
+    assert_eq!(tree.node_count(), 5);
+    assert!(tree.contains(&[0.0, 0.0]) && !tree.contains(&[0.5, 0.5]));
+
+    // identical points cannot be separated, so the split stops at the depth limit
+    (0..10).for_each(|_| {
+        tree.insert([0.25, 0.25]);
+    });
+    assert_eq!(tree.size(), 13);
+    assert!(tree.depth() <= 32);
+    assert_eq!(
+        tree.query_rect(&Rect::new([0.2, 0.2], [0.3, 0.3])).len(),
+        10
+    );
+
+    tree.clear();
+    assert!(tree.is_empty() && tree.node_count() == 1);
+    assert_eq!(tree.bounds(), Rect::new([-1.0, -1.0], [1.0, 1.0]));
+}
+
+#[test]
+fn dot_shows_the_subdivision() {
+    let mut tree = QuadTree::new(Rect::new([0.0, 0.0], [4.0, 4.0]), 1);
+    tree.extend([[1.0, 1.0], [3.0, 3.0]]);
+    let dot = tree.to_dot();
+    assert!(dot.contains("n0 [label=\"[0.0, 0.0]..[4.0, 4.0]\"];"));
+    assert!(dot.contains("n0 -> n4 [label=\"NE\"];"));
+    assert!(dot.contains("n1 [label=\"[0.0, 0.0]..[2.0, 2.0]\\n1 points\"];"));
+    let annotated = tree.to_dot_with(&DotStyle {
+        annotate: true,
+        ..DotStyle::default()
+    });
+    assert!(annotated.contains("[[3.0, 3.0]]"));
+}
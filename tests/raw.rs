@@ -0,0 +1,107 @@
+// The xor list rebuilds each neighbour from the link the node stores, so every walk, edit and
+// handed-out reference goes through that decoding. These tests are small enough to run under
+// `cargo +nightly miri test --test raw`, with -Zmiri-strict-provenance to check that no pointer
+// is ever made from a bare integer.
+
+use std::{cell::Cell, rc::Rc};
+
+use dsa_rust::linked_list::raw::LinkedList;
+
+#[test]
+fn pushes_and_pops_at_both_ends() {
+    let mut list = LinkedList::new();
+    assert_eq!((list.pop_head(), list.pop_tail()), (None, None));
+    list.push_tail(2);
+    list.push_head(1);
+    list.push_tail(3);
+    list.push_head(0);
+    assert_eq!(list.len(), 4);
+    assert!(list.iter().copied().eq(0..4));
+    assert!(list.iter().rev().copied().eq((0..4).rev()));
+    assert_eq!((list.peek_head(), list.peek_tail()), (Some(&0), Some(&3)));
+    assert_eq!(list.pop_tail(), Some(3));
+    assert_eq!(list.pop_head(), Some(0));
+    // the freed slots are reused without disturbing the links around them
+    list.push_tail(4);
+    list.push_head(-1);
+    assert_eq!(Vec::from(list.clone()), [-1, 1, 2, 4]);
+    while list.pop_tail().is_some() {}
+    assert!(list.is_empty());
+    assert_eq!((list.peek_head(), list.peek_tail()), (None, None));
+    list.push_head(7);
+    assert_eq!((list.first(), list.last()), (Some(&7), Some(&7)));
+}
+
+#[test]
+fn reverse_swaps_the_ends() {
+    let mut list = LinkedList::from(0..5);
+    list.reverse();
+    assert!(list.iter().copied().eq((0..5).rev()));
+    list.push_head(5);
+    list.push_tail(-1);
+    assert_eq!(list.pop_head(), Some(5));
+    assert!(list.iter().rev().copied().eq(-1..5));
+    list.reverse();
+    assert!(list.iter().copied().eq(-1..5));
+    let mut single = LinkedList::from([1]);
+    single.reverse();
+    assert_eq!(Vec::from(single), [1]);
+}
+
+#[test]
+fn mutable_access_reaches_every_node() {
+    let mut list = LinkedList::from(0..6);
+    *list.get_mut(0).unwrap() = 10;
+    *list.get_mut(4).unwrap() = 14;
+    list[5] = 15;
+    assert_eq!(list.get_mut(6), None);
+    *list.peek_head_mut().unwrap() += 100;
+    *list.peek_tail_mut().unwrap() += 100;
+    assert_eq!(Vec::from(list.clone()), [110, 1, 2, 3, 14, 115]);
+
+    // references from both ends of the same IterMut are live together
+    let mut iter = list.iter_mut();
+    let (front, back) = (iter.next().unwrap(), iter.next_back().unwrap());
+    let middle: Vec<&mut i32> = iter.collect();
+    *front = 0;
+    *back = 5;
+    for item in middle {
+        *item *= 2;
+    }
+    assert_eq!(Vec::from(list.clone()), [0, 2, 4, 6, 28, 5]);
+    for item in &mut list {
+        *item += 1;
+    }
+    assert!(list.iter().copied().eq([1, 3, 5, 7, 29, 6]));
+}
+
+#[derive(Clone)]
+struct Counted(Rc<Cell<usize>>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn every_element_is_dropped_once() {
+    let drops = Rc::new(Cell::new(0));
+    let list = LinkedList::from((0..8).map(|_| Counted(drops.clone())));
+    drop(list);
+    assert_eq!(drops.get(), 8);
+
+    drops.set(0);
+    let mut list = LinkedList::from((0..8).map(|_| Counted(drops.clone())));
+    drop(list.pop_head());
+    drop(list.pop_tail());
+    assert_eq!(drops.get(), 2);
+    list.clear();
+    assert_eq!(drops.get(), 8);
+    list.push_tail(Counted(drops.clone()));
+    let mut iter = LinkedList::from((0..4).map(|_| Counted(drops.clone()))).into_iter();
+    drop(iter.next_back());
+    drop(iter);
+    drop(list);
+    assert_eq!(drops.get(), 13);
+}
@@ -0,0 +1,87 @@
+use dsa_rust::{
+    adt::{BoundedQueue, Queue},
+    collections::RingBuffer,
+};
+
+#[test]
+fn wraps_around_without_moving_anything() {
+    let mut ring = RingBuffer::with_capacity(3);
+    assert!(ring.is_empty() && !ring.is_full());
+    assert_eq!(
+        (ring.try_push(1), ring.try_push(2), ring.try_push(3)),
+        (Ok(()), Ok(()), Ok(()))
+    );
+    assert_eq!(ring.try_push(4), Err(4));
+    assert!(ring.is_full() && ring.remaining_capacity() == 0);
+
+    // each round pushes past the physical end, so head and tail lap the buffer several times
+    for next in 4..20 {
+        assert_eq!(ring.pop(), Some(next - 3));
+        ring.try_push(next).unwrap();
+        assert_eq!(
+            (ring.front(), ring.back()),
+            (Some(&(next - 2)), Some(&next))
+        );
+    }
+    assert!(ring.iter().copied().eq([17, 18, 19]));
+    assert!(ring.iter().rev().copied().eq([19, 18, 17]));
+    assert_eq!((ring.get(1), ring.get(3)), (Some(&18), None));
+    assert_eq!(format!("{:?}", ring), "[17, 18, 19]");
+
+    assert_eq!(ring.push_overwrite(20), Some(17));
+    assert!(ring.iter().copied().eq([18, 19, 20]));
+    ring.clear();
+    assert_eq!(
+        (ring.len(), ring.pop(), ring.push_overwrite(1)),
+        (0, None, None)
+    );
+    assert_eq!(ring.iter().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "offer on a full queue of capacity 2")]
+fn offer_panics_when_full() {
+    let mut ring = RingBuffer::with_capacity(2);
+    Queue::offer(&mut ring, 'a');
+    Queue::offer(&mut ring, 'b');
+    Queue::offer(&mut ring, 'c');
+}
+
+// a producer that only knows the trait: whatever try_offer refuses waits locally, so the
+// consumer's pace, not the producer's, sets how much is in flight
+fn pump<Q>(queue: &mut Q, work: &mut Vec<u32>, done: &mut Vec<u32>, per_tick: usize) -> usize
+where
+    Q: BoundedQueue<u32>,
+{
+    let mut refusals = 0;
+    while let Some(item) = work.pop() {
+        if let Err(item) = queue.try_offer(item) {
+            work.push(item);
+            refusals += 1;
+            for _ in 0..per_tick {
+                done.extend(queue.poll());
+            }
+        }
+        assert!(queue.len() <= queue.capacity());
+    }
+    while let Some(item) = queue.poll() {
+        done.push(item);
+    }
+    refusals
+}
+
+#[test]
+fn producers_back_off_when_the_queue_is_full() {
+    let mut ring = RingBuffer::with_capacity(4);
+    let mut work: Vec<u32> = (0..50).rev().collect();
+    let mut done = Vec::new();
+    let refusals = pump(&mut ring, &mut work, &mut done, 2);
+    assert_eq!(done, (0..50).collect::<Vec<_>>());
+    // four fit, then every two freed slots make room for two more
+    assert_eq!(refusals, 23);
+
+    assert_eq!(BoundedQueue::remaining_capacity(&ring), 4);
+    assert!(!BoundedQueue::is_full(&ring));
+    ring.try_offer(7).unwrap();
+    assert_eq!((Queue::head(&ring), Queue::tail(&ring)), (Some(7), Some(7)));
+}
@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+use dsa_rust::{
+    adt::Deque,
+    applications::{
+        palindrome::{is_palindrome, is_palindrome_with},
+        round_robin::RoundRobin,
+    },
+    generate::Rng,
+    linked_list::doubly::LinkedList,
+};
+
+#[test]
+fn deques_agree_at_both_ends() {
+    let mut rng = Rng::new(29);
+    let mut list = LinkedList::new();
+    let mut ring = VecDeque::new();
+    for _ in 0..2_000 {
+        let value = rng.below(100);
+        match rng.below(4) {
+            0 => {
+                Deque::push_front(&mut list, value);
+                Deque::push_front(&mut ring, value);
+            }
+            1 => {
+                Deque::push_back(&mut list, value);
+                Deque::push_back(&mut ring, value);
+            }
+            2 => assert_eq!(Deque::pop_front(&mut list), Deque::pop_front(&mut ring)),
+            _ => assert_eq!(Deque::pop_back(&mut list), Deque::pop_back(&mut ring)),
+        }
+        assert_eq!(Deque::len(&list), Deque::len(&ring));
+        assert_eq!(Deque::front(&list), Deque::front(&ring));
+        assert_eq!(Deque::back(&list), Deque::back(&ring));
+    }
+    Deque::clear(&mut list);
+    assert!(Deque::is_empty(&list));
+}
+
+#[test]
+fn palindromes() {
+    assert!(is_palindrome("racecar".chars()));
+    assert!(is_palindrome("abba".chars()));
+    assert!(is_palindrome("".chars()));
+    assert!(is_palindrome(['x']));
+    assert!(!is_palindrome("ab".chars()));
+    assert!(!is_palindrome("abcba!".chars()));
+    assert!(is_palindrome([1, 2, 3, 2, 1]));
+    assert!(!is_palindrome_with::<VecDeque<_>, _, _>([1, 2, 3, 1]));
+    assert!(is_palindrome_with::<VecDeque<_>, _, _>(
+        "never odd or even".split(' ').flat_map(str::chars)
+    ));
+}
+
+#[test]
+fn every_task_gets_a_turn_in_order() {
+    let mut tasks = RoundRobin::from(["a", "b", "c"]);
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(tasks.current(), None);
+    let turns: Vec<_> = tasks.by_ref().take(7).collect();
+    assert_eq!(turns, ["a", "b", "c", "a", "b", "c", "a"]);
+    assert_eq!(tasks.current(), Some(&"a"));
+    assert_eq!(tasks.len(), 3);
+}
+
+#[test]
+fn removing_the_current_task_moves_on() {
+    let mut tasks = RoundRobin::from([1, 2, 3]);
+    assert_eq!(tasks.next(), Some(1));
+    assert_eq!(tasks.next(), Some(2));
+    assert_eq!(tasks.remove_current(), Some(2));
+    assert_eq!(tasks.remove_current(), None);
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks.next(), Some(3));
+    assert_eq!(tasks.next(), Some(1));
+    assert_eq!(tasks.next(), Some(3));
+    tasks.remove_current();
+    tasks.next();
+    tasks.remove_current();
+    assert!(tasks.is_empty());
+    assert_eq!(tasks.next(), None);
+    assert_eq!(tasks.current(), None);
+}
+
+#[test]
+fn added_tasks_wait_behind_the_line() {
+    let mut tasks = RoundRobin::new();
+    tasks.add('a');
+    tasks.add('b');
+    assert_eq!(tasks.next(), Some('a'));
+    tasks.add('c');
+    let turns: String = tasks.by_ref().take(4).collect();
+    assert_eq!(turns, "bcab");
+    tasks.clear();
+    assert!(tasks.is_empty());
+    assert_eq!(tasks.size_hint(), (0, Some(0)));
+}
+
+// remaining work per task: each turn does one unit, and finished tasks drop out
+#[test]
+fn runs_a_workload_to_completion() {
+    let mut tasks: RoundRobin<(char, u32), VecDeque<_>> = RoundRobin::default();
+    for task in [('x', 3), ('y', 1), ('z', 2)] {
+        tasks.add(task);
+    }
+    let mut order = String::new();
+    while let Some((name, left)) = tasks.next() {
+        order.push(name);
+        tasks.remove_current();
+        if left > 1 {
+            tasks.add((name, left - 1));
+        }
+    }
+    assert_eq!(order, "xyzxzx");
+}
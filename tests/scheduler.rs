@@ -0,0 +1,70 @@
+use dsa_rust::applications::scheduler::Scheduler;
+
+#[test]
+fn runs_by_deadline_then_by_arrival() {
+    let mut scheduler = Scheduler::new();
+    let report = scheduler.schedule("report", 30);
+    scheduler.schedule("backup", 10);
+    scheduler.schedule("email", 30);
+    scheduler.schedule("deploy", 20);
+    assert_eq!(scheduler.size(), 4);
+    assert_eq!(
+        scheduler.peek_next().map(|(_, p, task)| (*p, *task)),
+        Some((10, "backup"))
+    );
+    assert_eq!(scheduler.priority(report), Some(&30));
+
+    let order: Vec<(u32, &str)> = std::iter::from_fn(|| scheduler.run_next())
+        .map(|(_, priority, task)| (priority, task))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (10, "backup"),
+            (20, "deploy"),
+            (30, "report"),
+            (30, "email")
+        ]
+    );
+    assert!(scheduler.is_empty());
+}
+
+#[test]
+fn reprioritize_is_decrease_key() {
+    let mut scheduler = Scheduler::new();
+    let ids: Vec<_> = (0..10).map(|n| scheduler.schedule(n, 100 + n)).collect();
+    assert_eq!(scheduler.reprioritize(ids[7], 1), Some(107));
+    assert_eq!(scheduler.reprioritize(ids[0], 500), Some(100));
+    assert_eq!(
+        scheduler.run_next().map(|(id, _, task)| (id, task)),
+        Some((ids[7], 7))
+    );
+    assert_eq!(scheduler.reprioritize(ids[7], 0), None);
+    let rest: Vec<u32> = std::iter::from_fn(|| scheduler.run_next())
+        .map(|(.., task)| task)
+        .collect();
+    assert_eq!(rest, vec![1, 2, 3, 4, 5, 6, 8, 9, 0]);
+}
+
+#[test]
+fn cancelled_ids_stay_dead() {
+    let mut scheduler = Scheduler::highest_first();
+    let low = scheduler.schedule("low", 1);
+    let high = scheduler.schedule("high", 9);
+    assert_eq!(scheduler.cancel(high), Some("high"));
+    assert_eq!(scheduler.cancel(high), None);
+    // the freed slot goes to the next task, but the old id does not reach it
+    let next = scheduler.schedule("next", 5);
+    assert_ne!(next, high);
+    assert!(!scheduler.contains(high));
+    assert_eq!(scheduler.get(high), None);
+    assert_eq!(scheduler.reprioritize(high, 0), None);
+    *scheduler.get_mut(low).unwrap() = "raised";
+    scheduler.reprioritize(low, 8);
+    assert_eq!(
+        scheduler.run_next().map(|(_, p, task)| (p, task)),
+        Some((8, "raised"))
+    );
+    scheduler.clear();
+    assert!(!scheduler.contains(next) && scheduler.run_next().is_none());
+}
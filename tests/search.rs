@@ -0,0 +1,72 @@
+// Every search is checked against a linear scan over a slice with runs of duplicates, including
+// targets below, between and above the stored values.
+
+use dsa_rust::search::*;
+
+const VALUES: [i32; 10] = [1, 3, 3, 3, 5, 8, 8, 13, 21, 21];
+
+fn first_match(slice: &[i32], target: i32) -> Option<usize> {
+    slice.iter().position(|&x| x == target)
+}
+
+#[test]
+fn bounds_match_linear_scan() {
+    for target in -1..=23 {
+        let lower = VALUES.iter().take_while(|&&x| x < target).count();
+        let upper = VALUES.iter().take_while(|&&x| x <= target).count();
+        assert_eq!(lower_bound(&VALUES, &target), lower);
+        assert_eq!(upper_bound(&VALUES, &target), upper);
+        assert_eq!(equal_range(&VALUES, &target), lower..upper);
+        assert_eq!(lower_bound_by(&VALUES, |x| x.cmp(&target)), lower);
+        assert_eq!(upper_bound_by(&VALUES, |x| x.cmp(&target)), upper);
+    }
+}
+
+#[test]
+fn point_searches_find_first_match() {
+    for target in -1..=23 {
+        let expected = first_match(&VALUES, target);
+        assert_eq!(binary_search(&VALUES, &target), expected);
+        assert_eq!(exponential_search(&VALUES, &target), expected);
+    }
+}
+
+#[test]
+fn interpolation_search_finds_some_match() {
+    for target in -1..=23 {
+        match interpolation_search(&VALUES, target) {
+            Some(i) => assert_eq!(VALUES[i], target),
+            None => assert_eq!(first_match(&VALUES, target), None),
+        }
+    }
+    let uniform: Vec<u64> = (0..1000).map(|x| x * 7).collect();
+    assert_eq!(interpolation_search(&uniform, 693), Some(99));
+    assert_eq!(interpolation_search(&uniform, 694), None);
+    assert_eq!(interpolation_search(&[4u8; 5], 4), Some(0));
+}
+
+#[test]
+fn empty_slices() {
+    let empty: [i32; 0] = [];
+    assert_eq!(lower_bound(&empty, &1), 0);
+    assert_eq!(upper_bound(&empty, &1), 0);
+    assert_eq!(equal_range(&empty, &1), 0..0);
+    assert_eq!(binary_search(&empty, &1), None);
+    assert_eq!(exponential_search(&empty, &1), None);
+    assert_eq!(interpolation_search(&empty, 1), None);
+}
+
+#[test]
+fn ternary_search_finds_peaks() {
+    let peak = ternary_search(-10.0, 10.0, 1e-9, |x| -(x - 2.5) * (x - 2.5));
+    assert!((peak - 2.5).abs() < 1e-6);
+    assert_eq!(ternary_search_int(-100, 100, |x| -(x - 17).abs()), 17);
+    assert_eq!(ternary_search_int(0, 10, |x| x), 10);
+    assert_eq!(ternary_search_int(0, 10, |x| -x), 0);
+    assert_eq!(ternary_search_int(5, 5, |x| x), 5);
+    // two equal peaks side by side resolve to the smaller argument
+    assert_eq!(
+        ternary_search_int(0, 9, |x| [0, 1, 2, 3, 7, 7, 3, 2, 1, 0][x as usize]),
+        4
+    );
+}
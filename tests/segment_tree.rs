@@ -0,0 +1,90 @@
+use dsa_rust::{
+    algebra::{Max, Min, Monoid, Semigroup, Sum},
+    generate::Rng,
+    ranges::SegmentTree,
+};
+
+// string concatenation: a monoid that is not commutative, so any query that folds out of order
+// shows up as a scrambled string
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Concat(String);
+
+impl Semigroup for Concat {
+    fn combine(&self, other: &Self) -> Self {
+        Concat(format!("{}{}", self.0, other.0))
+    }
+}
+
+impl Monoid for Concat {
+    fn identity() -> Self {
+        Concat(String::new())
+    }
+}
+
+#[test]
+fn queries_match_a_scan_under_updates() {
+    let mut rng = Rng::new(41);
+    let mut items: Vec<i64> = (0..37).map(|_| rng.below(1000) as i64).collect();
+    let mut sums = SegmentTree::from(items.iter().map(|&v| Sum(v)));
+    let mut mins = SegmentTree::from(items.iter().map(|&v| Min(v)));
+    for _ in 0..300 {
+        let index = rng.below(items.len());
+        let value = rng.below(1000) as i64;
+        items[index] = value;
+        sums.set(index, Sum(value));
+        mins.update(index, |_| Min(value));
+        let start = rng.below(items.len() + 1);
+        let end = rng.range(start..items.len() + 1);
+        let slice = &items[start..end];
+        assert_eq!(sums.query(start..end), Sum(slice.iter().sum()));
+        assert_eq!(
+            mins.query(start..end),
+            Min(slice.iter().copied().min().unwrap_or(i64::MAX))
+        );
+    }
+    assert_eq!(sums.query(..), Sum(items.iter().sum()));
+}
+
+#[test]
+fn folds_in_order() {
+    let words = ["a", "b", "c", "d", "e", "f", "g"];
+    let mut tree = SegmentTree::from(words.iter().map(|w| Concat(w.to_string())));
+    for start in 0..=words.len() {
+        for end in start..=words.len() {
+            assert_eq!(tree.query(start..end).0, words[start..end].concat());
+        }
+    }
+    tree.update(3, |item| item.combine(&Concat("!".into())));
+    assert_eq!(tree.query(2..=4).0, "cd!e");
+}
+
+#[test]
+fn accessors_and_empty() {
+    let mut tree = SegmentTree::<Max<u8>>::new(4);
+    assert_eq!((tree.len(), tree.is_empty()), (4, false));
+    assert_eq!(tree.query(..), Max(0));
+    tree.set(2, Max(9));
+    assert_eq!(tree.get(2), Some(&Max(9)));
+    assert_eq!(tree.get(4), None);
+    assert_eq!(tree.iter().map(|m| m.0).collect::<Vec<_>>(), [0, 0, 9, 0]);
+    assert_eq!(
+        format!("{:?}", tree.clone()),
+        "[Max(0), Max(0), Max(9), Max(0)]"
+    );
+
+    let empty = SegmentTree::<Sum<i32>>::from([]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.query(..), Sum(0));
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of range for 3 items")]
+fn set_checks_bounds() {
+    SegmentTree::<Sum<i32>>::new(3).set(3, Sum(1));
+}
+
+#[test]
+#[should_panic(expected = "range end 4 out of range for 3 items")]
+fn query_checks_bounds() {
+    SegmentTree::<Sum<i32>>::new(3).query(1..4);
+}
@@ -0,0 +1,112 @@
+// These checks happen at compile time: a regression in either direction stops this file from
+// building, so each test body is only there to give the assertions a home.
+
+use std::{cell::Cell, rc::Rc};
+
+use dsa_rust::{
+    concurrent::{MpscQueue, TreiberStack},
+    linked_list::{circular, doubly, raw, singly, unrolled},
+    prelude::*,
+};
+
+fn send<T: Send>() {}
+fn sync<T: Sync>() {}
+
+// resolving the method is ambiguous, and so fails to compile, exactly when T implements the
+// trait, because then both impls apply
+macro_rules! assert_not_impl {
+    ($ty:ty, $marker:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn check() {}
+        }
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+        impl<T: ?Sized + $marker> AmbiguousIfImpl<u8> for T {}
+        let _ = <$ty as AmbiguousIfImpl<_>>::check;
+    }};
+}
+
+macro_rules! assert_thread_safe {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            send::<$ty>();
+            sync::<$ty>();
+        )*
+    };
+}
+
+#[test]
+fn owning_collections_follow_their_elements() {
+    assert_thread_safe!(
+        SinglyLinkedList<i32>,
+        DoublyLinkedList<i32>,
+        XorLinkedList<i32>,
+        CircularList<i32>,
+        UnrolledList<i32, 4>,
+        AVLTree<i32>,
+        AVLMultiSet<i32>,
+        BinaryHeap<i32>,
+        BoundedHeap<i32>,
+        Trie,
+        HashMap<i32, i32>,
+        singly::IntoIter<i32>,
+        doubly::IntoIter<i32>,
+        raw::IntoIter<i32>,
+        circular::IntoIter<i32>,
+        unrolled::IntoIter<i32, 4>,
+    );
+
+    // Cell is Send but not Sync, Rc is neither
+    send::<SinglyLinkedList<Cell<i32>>>();
+    send::<DoublyLinkedList<Cell<i32>>>();
+    send::<CircularList<Cell<i32>>>();
+    send::<UnrolledList<Cell<i32>, 4>>();
+    assert_not_impl!(DoublyLinkedList<Cell<i32>>, Sync);
+    assert_not_impl!(SinglyLinkedList<Cell<i32>>, Sync);
+    assert_not_impl!(SinglyLinkedList<Rc<i32>>, Send);
+    assert_not_impl!(DoublyLinkedList<Rc<i32>>, Send);
+    assert_not_impl!(XorLinkedList<Rc<i32>>, Send);
+    assert_not_impl!(CircularList<Rc<i32>>, Send);
+    assert_not_impl!(UnrolledList<Rc<i32>, 4>, Send);
+}
+
+#[test]
+fn borrowing_iterators_follow_references() {
+    assert_thread_safe!(
+        singly::Iter<'static, i32>,
+        doubly::Iter<'static, i32>,
+        doubly::IterMut<'static, i32>,
+        doubly::CursorMut<'static, i32>,
+        raw::Iter<'static, i32>,
+        raw::IterMut<'static, i32>,
+        circular::Iter<'static, i32>,
+        circular::IterMut<'static, i32>,
+        circular::Cycle<'static, i32>,
+        unrolled::Iter<'static, i32, 4>,
+        unrolled::IterMut<'static, i32, 4>,
+    );
+
+    // a shared iterator over Cell items is a &Cell, which must not leave its thread, while a
+    // unique one is a &mut Cell, which may
+    assert_not_impl!(doubly::Iter<'static, Cell<i32>>, Send);
+    assert_not_impl!(circular::Cycle<'static, Cell<i32>>, Send);
+    send::<doubly::IterMut<'static, Cell<i32>>>();
+    send::<unrolled::IterMut<'static, Cell<i32>, 4>>();
+    assert_not_impl!(doubly::IterMut<'static, Cell<i32>>, Sync);
+}
+
+#[test]
+fn shared_structures_are_exactly_what_they_claim() {
+    assert_thread_safe!(MpscQueue<i32>, TreiberStack<i32>);
+    assert_not_impl!(MpscQueue<Rc<i32>>, Send);
+    assert_not_impl!(TreiberStack<Rc<i32>>, Sync);
+
+    // Rc sharing makes every persistent version single-threaded
+    assert_not_impl!(PersistentList<i32>, Send);
+    assert_not_impl!(PersistentVector<i32>, Sync);
+    assert_not_impl!(OrderedMap<i32, i32>, Send);
+
+    // eviction callbacks are not required to be Send, so neither are the caches holding them
+    assert_not_impl!(LruCache<i32, i32>, Send);
+    assert_not_impl!(LfuCache<i32, i32>, Send);
+    assert_not_impl!(FifoCache<i32, i32>, Sync);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use dsa_rust::{
+    linked_list::{doubly, singly},
+    tree::{avl::AVLTree, heap::BinaryHeap, multiset::AVLMultiSet},
+};
+
+const ITEMS: [i32; 8] = [5, -3, 9, 0, 9, 12, -7, 4];
+
+#[test]
+fn lists_round_trip_in_order() {
+    let singly = singly::LinkedList::from(ITEMS);
+    let json = serde_json::to_string(&singly).unwrap();
+    assert_eq!(json, "[5,-3,9,0,9,12,-7,4]");
+    let back: singly::LinkedList<i32> = serde_json::from_str(&json).unwrap();
+    assert!(back.iter().eq(singly.iter()));
+
+    let doubly = doubly::LinkedList::from(ITEMS);
+    let back: doubly::LinkedList<i32> =
+        serde_json::from_str(&serde_json::to_string(&doubly).unwrap()).unwrap();
+    assert!(back.iter().eq(doubly.iter()));
+    assert!(back.iter().rev().eq(ITEMS.iter().rev()));
+}
+
+#[test]
+fn trees_round_trip_sorted() {
+    let tree = AVLTree::from_iter(ITEMS);
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_eq!(json, "[-7,-3,0,4,5,9,12]");
+    let back: AVLTree<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, tree);
+    assert!(back.validate().is_ok());
+
+    let multiset = AVLMultiSet::from(ITEMS);
+    let json = serde_json::to_string(&multiset).unwrap();
+    assert_eq!(json, "[-7,-3,0,4,5,9,9,12]");
+    let back: AVLMultiSet<i32> = serde_json::from_str(&json).unwrap();
+    assert!(back.iter().eq(multiset.iter()));
+    assert_eq!(back.count(&9), 2);
+}
+
+#[test]
+fn heaps_keep_their_layout_under_the_same_comparator() {
+    let min = BinaryHeap::from_iter(ITEMS);
+    let json = serde_json::to_string(&min).unwrap();
+    // the storage as it stands, not a sorted copy
+    assert_eq!(
+        json,
+        serde_json::to_string(&min.clone().into_vec()).unwrap()
+    );
+    let back: BinaryHeap<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.clone().into_vec(), min.clone().into_vec());
+    assert_eq!(back.into_sorted_vec(), min.into_sorted_vec());
+
+    let max = BinaryHeap::from(|a: &i32, b: &i32| b.cmp(a), ITEMS);
+    let json = serde_json::to_string(&max).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let back = BinaryHeap::from_serialized(|a: &i32, b: &i32| b.cmp(a), &mut deserializer).unwrap();
+    assert!(back.is_valid_heap());
+    assert_eq!(back.clone().into_vec(), max.clone().into_vec());
+    assert_eq!(back.peek(), Some(&12));
+    assert_eq!(back.into_sorted_vec(), [12, 9, 9, 5, 4, 0, -3, -7]);
+}
+
+#[test]
+fn heaps_read_without_a_comparator_are_min_heaps() {
+    let max = BinaryHeap::from(|a: &i32, b: &i32| b.cmp(a), ITEMS);
+    let back: BinaryHeap<i32> =
+        serde_json::from_str(&serde_json::to_string(&max).unwrap()).unwrap();
+    assert!(back.is_valid_heap());
+    assert_eq!(back.peek(), Some(&-7));
+    assert_eq!(back.into_sorted_vec(), [-7, -3, 0, 4, 5, 9, 9, 12]);
+}
@@ -0,0 +1,169 @@
+// The singly list hands out references into nodes it reaches through raw pointers, and keeps a
+// second pointer to its last node. These tests drive every path that writes through either
+// pointer while references from the other are live; run them under
+// `cargo +nightly miri test --test singly_miri` to check the aliasing, or as ordinary tests in CI.
+
+use dsa_rust::{
+    adt::{Queue, Stack},
+    linked_list::singly::LinkedList,
+    string::aho_corasick::{AhoCorasick, Match},
+    tree::avl::AVLTree,
+};
+
+#[test]
+fn tail_survives_head_and_middle_edits() {
+    let mut list = LinkedList::new();
+    list.push_tail(2);
+    list.push_head(1);
+    list.push_tail(3);
+    assert_eq!(list.last(), Some(&3));
+    *list.get_mut(2).unwrap() = 30;
+    assert_eq!(list.last(), Some(&30));
+    list.insert(2, 40);
+    list.push_tail(50);
+    assert_eq!(Vec::from(list.clone()), [1, 2, 30, 40, 50]);
+    assert_eq!(list.pop_tail(), Some(50));
+    assert_eq!(list.remove(3), Some(40));
+    list.push_tail(60);
+    assert_eq!(list.last(), Some(&60));
+    while list.pop().is_some() {}
+    list.push_tail(7);
+    assert_eq!((list.first(), list.last()), (Some(&7), Some(&7)));
+}
+
+#[test]
+fn iter_mut_then_append_through_tail() {
+    let mut list = LinkedList::from(0..8);
+    list.iter_mut().for_each(|item| *item *= 10);
+    let mut refs: Vec<&mut i32> = list.iter_mut().collect();
+    *refs[7] += 1;
+    list.push_tail(80);
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [0, 10, 20, 30, 40, 50, 60, 71, 80]
+    );
+    assert_eq!(list.middle(), Some(&40));
+    assert_eq!(list.nth_from_end(0), Some(&80));
+}
+
+#[test]
+fn split_append_and_rotate_keep_tails_apart() {
+    let mut front = LinkedList::from(0..10);
+    let mut back = front.split_off(6);
+    front.push_tail(100);
+    back.push_tail(200);
+    assert_eq!(Vec::from(front.clone()), [0, 1, 2, 3, 4, 5, 100]);
+    assert_eq!(Vec::from(back.clone()), [6, 7, 8, 9, 200]);
+    front.append(back);
+    front.push_tail(300);
+    front.rotate_left(3);
+    front.push_tail(-1);
+    front.reverse();
+    front.push_tail(-2);
+    assert_eq!(front.last(), Some(&-2));
+    assert_eq!(front.len(), 15);
+    let (left, right) = front.split_at(15);
+    assert!(right.is_empty() && left.len() == 15);
+}
+
+#[test]
+fn filtering_relinks_the_tail() {
+    let mut list = LinkedList::from(0..20);
+    list.retain(|item| item % 3 != 2);
+    list.push_tail(99);
+    let odd: Vec<_> = list.drain_filter(|item| item % 2 == 1).collect();
+    assert_eq!(odd, [1, 3, 7, 9, 13, 15, 19, 99]);
+    list.push_tail(-1);
+    assert_eq!(Vec::from(list), [0, 4, 6, 10, 12, 16, 18, -1]);
+
+    let mut all = LinkedList::from(["a".to_string(), "b".to_string()]);
+    all.retain(|_| false);
+    all.push_tail("c".to_string());
+    assert_eq!((all.first(), all.len()), (Some(&"c".to_string()), 1));
+}
+
+#[test]
+fn sorting_and_merging_relink_the_tail() {
+    let mut list = LinkedList::from([5, 1, 4, 2, 3]);
+    list.sort();
+    list.push_tail(6);
+    let merged = list.merge(LinkedList::from([0, 7]), |a, b| a.cmp(b));
+    assert_eq!(merged.last(), Some(&7));
+    assert!(merged.into_iter().eq(0..8));
+}
+
+#[test]
+fn adt_views_do_not_free_nodes() {
+    let mut queue = LinkedList::new();
+    for item in 0..4 {
+        Queue::offer(&mut queue, vec![item]);
+    }
+    assert_eq!(Queue::tail(&queue), Some(vec![3]));
+    assert_eq!(Queue::tail(&queue), Some(vec![3]));
+    assert_eq!(Queue::head(&queue), Some(vec![0]));
+    assert_eq!(Queue::poll(&mut queue), Some(vec![0]));
+    Stack::push(&mut queue, vec![-1]);
+    assert_eq!(Stack::peek(&queue), Some(vec![-1]));
+    assert_eq!(Queue::len(&queue), 4);
+}
+
+#[test]
+fn dependents_of_the_singly_queue() {
+    let tree = AVLTree::from([4, 2, 6, 1, 3, 5, 7]);
+    assert!(tree.iter_level_order().copied().eq([4, 2, 6, 1, 3, 5, 7]));
+
+    let matcher = AhoCorasick::new(["he", "she", "hers"]);
+    let matches: Vec<Match> = matcher.find_iter(b"ushers").collect();
+    assert_eq!(matches.len(), 3);
+    assert!(matcher.is_match(b"hers"));
+}
+
+#[test]
+fn lists_built_normally_have_no_cycle() {
+    assert!(!LinkedList::<i32>::new().has_cycle());
+    for len in 1..6 {
+        let mut list = LinkedList::from(0..len);
+        assert!(!list.has_cycle());
+        list.reverse();
+        list.push_head(-1);
+        assert!(!list.has_cycle());
+    }
+}
+
+#[cfg(feature = "verify")]
+#[test]
+fn has_cycle_finds_a_tail_linked_back() {
+    for len in 1..8 {
+        for target in 0..len {
+            let mut list = LinkedList::from(0..len);
+            // safety: only has_cycle runs before the cycle is broken
+            unsafe { list.make_cycle(target) };
+            assert!(list.has_cycle(), "{} nodes looping back to {}", len, target);
+            list.break_cycle();
+            assert!(!list.has_cycle());
+            assert!(list.iter().copied().eq(0..len));
+        }
+    }
+}
+
+#[test]
+fn remove_first_relinks_head_middle_and_tail() {
+    let mut list = LinkedList::from([1, 2, 3, 2, 4]);
+    assert!(list.remove_first(&1));
+    assert_eq!(list.first(), Some(&2));
+    // only the first of two equal values goes
+    assert!(list.remove_first(&2));
+    assert_eq!(Vec::from(list.clone()), [3, 2, 4]);
+    assert!(list.remove_first(&4));
+    assert_eq!((list.last(), list.len()), (Some(&2), 2));
+    // the tail moved back, so appending lands after the new last node
+    list.push_tail(5);
+    assert_eq!(Vec::from(list.clone()), [3, 2, 5]);
+    assert!(!list.remove_first(&9));
+    assert_eq!(list.len(), 3);
+    assert!(list.remove_first(&3) && list.remove_first(&2) && list.remove_first(&5));
+    assert!(list.is_empty() && list.last().is_none());
+    assert!(!list.remove_first(&3));
+    list.push_tail(6);
+    assert_eq!((list.first(), list.last()), (Some(&6), Some(&6)));
+}
@@ -0,0 +1,68 @@
+use dsa_rust::{
+    algebra::{Gcd, Min},
+    ranges::{SparseTable, sparse_table::gcd},
+};
+
+fn values(len: usize) -> Vec<u64> {
+    (0..len as u64)
+        .map(|i| i.wrapping_mul(2_654_435_761) % 1000)
+        .collect()
+}
+
+#[test]
+fn every_range_matches_a_scan() {
+    let items = values(70);
+    let min = SparseTable::min(&items);
+    let max = SparseTable::max(&items);
+    for start in 0..=items.len() {
+        for end in start..=items.len() {
+            let slice = &items[start..end];
+            assert_eq!(min.query(start..end), slice.iter().min().copied());
+            assert_eq!(max.query(start..end), slice.iter().max().copied());
+        }
+    }
+    assert_eq!(
+        (min.len(), min.get(3), min.get(70)),
+        (70, Some(&items[3]), None)
+    );
+}
+
+#[test]
+fn range_forms_and_gcd() {
+    let items = [12, 18, 24, 36, 7, 14, 28];
+    let table = SparseTable::new(&items, gcd);
+    assert_eq!(table.query(..4), Some(6));
+    assert_eq!(table.query(0..=2), Some(6));
+    assert_eq!(table.query(4..), Some(7));
+    assert_eq!(table.query(..), Some(1));
+    assert_eq!(table.query(3..3), None);
+    assert_eq!(table.query(2..=2), Some(24));
+
+    let bits = SparseTable::new(&[0b1100u8, 0b0110, 0b0011], |a, b| a | b);
+    assert_eq!(bits.query(0..2), Some(0b1110));
+}
+
+#[test]
+fn empty_and_single() {
+    let empty = SparseTable::<i32, _>::min(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.query(..), None);
+    let one = SparseTable::max(&["only"]);
+    assert_eq!(one.query(..), Some("only"));
+    assert_eq!(format!("{:?}", one), "[\"only\"]");
+}
+
+#[test]
+#[should_panic(expected = "range end 5 out of range for 4 items")]
+fn query_checks_bounds() {
+    SparseTable::min(&[1, 2, 3, 4]).query(2..5);
+}
+
+#[test]
+fn over_idempotent_semigroups() {
+    let gcds = SparseTable::over(&[12u64, 18, 24, 7].map(Gcd));
+    assert_eq!(gcds.query(..3), Some(Gcd(6)));
+    assert_eq!(gcds.query(..), Some(Gcd(1)));
+    let mins = SparseTable::over(&["pear", "fig", "plum"].map(Min));
+    assert_eq!(mins.query(1..), Some(Min("fig")));
+}
@@ -0,0 +1,61 @@
+use dsa_rust::{
+    adt::LocalStack,
+    applications::expr::{Pending, eval_postfix_with, to_postfix_with},
+    collections::VecStack,
+    linked_list::{doubly, singly},
+};
+
+// the same script against every stack that can lend out its items
+fn exercise<S>(mut stack: S)
+where
+    S: LocalStack<String> + Default,
+{
+    stack.reserve(8);
+    for word in ["bottom", "middle", "top"] {
+        stack.push(word.to_string());
+    }
+    assert!(
+        stack
+            .iter()
+            .map(String::as_str)
+            .eq(["top", "middle", "bottom"])
+    );
+    stack.peek_mut().unwrap().push_str("most");
+    assert_eq!(stack.peek().as_deref(), Some("topmost"));
+    assert_eq!(stack.pop().as_deref(), Some("topmost"));
+    assert_eq!(stack.iter().count(), stack.len());
+    stack.clear();
+    assert!(stack.peek_mut().is_none() && stack.iter().next().is_none());
+}
+
+#[test]
+fn every_local_stack_behaves_alike() {
+    exercise(VecStack::new());
+    exercise(singly::LinkedList::new());
+    exercise(doubly::LinkedList::new());
+}
+
+#[test]
+fn vec_stack_keeps_its_capacity() {
+    let mut stack = VecStack::with_capacity(16);
+    let capacity = stack.capacity();
+    assert!(capacity >= 16);
+    (0..16).for_each(|item| stack.push(item));
+    assert_eq!(stack.capacity(), capacity);
+    assert_eq!((stack.peek(), stack.len()), (Some(&15), 16));
+
+    // collected in order means the last item is on top
+    let collected: VecStack<i32> = (1..=3).collect();
+    assert_eq!(format!("{:?}", collected), "[3, 2, 1]");
+    assert!(collected.iter().rev().copied().eq(1..=3));
+    assert_eq!(collected.clone().into_vec(), vec![1, 2, 3]);
+    stack.clear();
+    stack.shrink_to_fit();
+    assert!(stack.is_empty() && stack.capacity() <= capacity);
+}
+
+#[test]
+fn plugs_into_the_generic_algorithms() {
+    let postfix = to_postfix_with::<VecStack<Pending>>("2 * (3 + 4) ^ 2").unwrap();
+    assert_eq!(eval_postfix_with::<VecStack<i64>>(&postfix), Ok(98));
+}
@@ -0,0 +1,88 @@
+use dsa_rust::streaming::{MedianTracker, MonotonicDeque, WindowAggregator};
+
+#[test]
+fn median_follows_a_sorted_copy() {
+    let mut tracker = MedianTracker::new();
+    let mut sorted = Vec::new();
+    assert_eq!((tracker.median(), tracker.median_pair()), (None, None));
+    for step in 0..500u32 {
+        let item = step.wrapping_mul(2_654_435_761) % 97;
+        tracker.insert(item);
+        let at = sorted.partition_point(|&x| x < item);
+        sorted.insert(at, item);
+
+        let lower = &sorted[(sorted.len() - 1) / 2];
+        let upper = &sorted[sorted.len() / 2];
+        assert_eq!(tracker.median(), Some(lower));
+        assert_eq!(tracker.median_pair(), Some((lower, upper)));
+        assert_eq!(tracker.size(), sorted.len());
+    }
+}
+
+#[test]
+fn pairs_and_duplicates() {
+    let mut tracker: MedianTracker<i32> = [5, 1, 9, 3].into_iter().collect();
+    assert_eq!(tracker.median_pair(), Some((&3, &5)));
+    tracker.extend([7]);
+    assert_eq!(tracker.median_pair(), Some((&5, &5)));
+
+    let same = MedianTracker::from([4; 6]);
+    assert_eq!(same.median_pair(), Some((&4, &4)));
+    tracker.clear();
+    assert!(tracker.is_empty() && tracker.median().is_none());
+}
+
+#[test]
+fn monotonic_deque_tracks_window_extremes() {
+    let window = 7;
+    let items: Vec<i64> = (0..400)
+        .map(|step: i64| (step * 7_919) % 101 - 50)
+        .collect();
+    let mut deque = MonotonicDeque::new(window);
+    assert_eq!((deque.max(), deque.min()), (None, None));
+    for (end, &item) in items.iter().enumerate() {
+        deque.push(item);
+        assert_eq!(deque.pop_expired(), usize::from(end >= window));
+        let start = (end + 1).saturating_sub(window);
+        let slice = &items[start..=end];
+        assert_eq!(deque.max(), slice.iter().max());
+        assert_eq!(deque.min(), slice.iter().min());
+        assert!(deque.iter().eq(slice));
+    }
+
+    // a burst is answered over everything pushed until the window is trimmed
+    deque.extend([1_000, -1_000, 0]);
+    assert_eq!((deque.max(), deque.min()), (Some(&1_000), Some(&-1_000)));
+    assert_eq!(deque.pop_expired(), 3);
+    assert_eq!(deque.len(), window);
+    deque.clear();
+    assert!(deque.is_empty() && deque.max().is_none());
+    deque.push(5);
+    assert_eq!((deque.pop_front(), deque.pop_front()), (Some(5), None));
+}
+
+#[test]
+fn aggregator_folds_in_order_without_an_inverse() {
+    // concatenation is associative but not commutative, so any reordering would show
+    let mut window = WindowAggregator::new(4, |a: &String, b: &String| format!("{}{}", a, b));
+    assert_eq!(window.aggregate(), None);
+    let letters: Vec<String> = ('a'..='z').map(String::from).collect();
+    for (end, letter) in letters.iter().enumerate() {
+        window.push(letter.clone());
+        window.pop_expired();
+        let start = (end + 1).saturating_sub(4);
+        assert_eq!(window.aggregate(), Some(letters[start..=end].concat()));
+        assert_eq!(window.len(), end + 1 - start);
+    }
+
+    // max has no inverse at all, which is what rules out a plain running total
+    let mut highest = WindowAggregator::new(3, |a: &u32, b: &u32| *a.max(b));
+    highest.extend([9, 1, 2]);
+    assert_eq!(highest.aggregate(), Some(9));
+    highest.push(3);
+    highest.pop_expired();
+    assert_eq!(highest.aggregate(), Some(3));
+    assert_eq!(highest.pop_front(), Some(1));
+    highest.clear();
+    assert!(highest.is_empty() && highest.aggregate().is_none());
+}
@@ -0,0 +1,273 @@
+use std::ops::Range;
+
+use dsa_rust::{
+    generate::Rng,
+    string::{
+        aho_corasick::{AhoCorasick, Match},
+        common_prefix_len, failure_table, kmp_find, kmp_find_all, longest_common_prefix,
+        rabin_karp_find, rabin_karp_find_all,
+        suffix_array::SuffixArray,
+        z_array, z_find_all,
+    },
+};
+
+// every start where the pattern fits and matches, the empty pattern at each of the len + 1 gaps
+fn naive_find_all(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&start| text[start..].starts_with(pattern))
+        .collect()
+}
+
+fn check(text: &[u8], pattern: &[u8]) {
+    let expected = naive_find_all(text, pattern);
+    assert_eq!(
+        kmp_find_all(text, pattern),
+        expected,
+        "kmp {:?} in {:?}",
+        pattern,
+        text
+    );
+    assert_eq!(
+        rabin_karp_find_all(text, pattern),
+        expected,
+        "rabin-karp {:?} in {:?}",
+        pattern,
+        text
+    );
+    assert_eq!(
+        z_find_all(text, pattern),
+        expected,
+        "z {:?} in {:?}",
+        pattern,
+        text
+    );
+    assert_eq!(kmp_find(text, pattern), expected.first().copied());
+    assert_eq!(rabin_karp_find(text, pattern), expected.first().copied());
+}
+
+#[test]
+fn searches_match_the_naive_scan() {
+    let mut rng = Rng::new(1576);
+    for _ in 0..2_000 {
+        // two or three letters make repeats and overlaps common
+        let alphabet = rng.range(2..4) as u8;
+        let text: Vec<u8> = (0..rng.below(40))
+            .map(|_| b'a' + rng.below(alphabet as usize) as u8)
+            .collect();
+        let pattern: Vec<u8> = (0..rng.below(6))
+            .map(|_| b'a' + rng.below(alphabet as usize) as u8)
+            .collect();
+        check(&text, &pattern);
+        // and a pattern known to occur, cut from the text
+        if !text.is_empty() {
+            let start = rng.below(text.len());
+            let end = rng.range(start..text.len()) + 1;
+            check(&text, &text[start..end]);
+        }
+    }
+}
+
+#[test]
+fn edge_cases() {
+    // the empty pattern matches at every gap, the empty text included
+    check(b"abc", b"");
+    assert_eq!(kmp_find_all(b"abc", b""), [0, 1, 2, 3]);
+    check(b"", b"");
+    assert_eq!(z_find_all(b"", b""), [0]);
+    // a pattern longer than the text never matches
+    check(b"ab", b"abc");
+    check(b"", b"a");
+    assert_eq!(rabin_karp_find(b"ab", b"abc"), None);
+    // overlapping matches are all reported
+    check(b"aaaaa", b"aa");
+    assert_eq!(z_find_all(b"aaaaa", b"aa"), [0, 1, 2, 3]);
+    check(b"abababa", b"aba");
+    assert_eq!(rabin_karp_find_all(b"abababa", b"aba"), [0, 2, 4]);
+    check(b"abc", b"abc");
+}
+
+#[test]
+fn non_ascii_bytes() {
+    let text = "naïve café, naïveté".as_bytes();
+    check(text, "ï".as_bytes());
+    assert_eq!(kmp_find_all(text, "ïve".as_bytes()), [2, 16]);
+    // the tail byte of a multi-byte char matches on its own, as bytes do
+    check(text, &"é".as_bytes()[1..]);
+    let raw = [0xff, 0x00, 0xff, 0xff, 0x80, 0xff, 0x00, 0xff];
+    check(&raw, &[0xff, 0x00, 0xff]);
+    assert_eq!(rabin_karp_find_all(&raw, &[0xff, 0x00, 0xff]), [0, 5]);
+    check(&raw, &[0x80]);
+    check(&raw, &[0xff, 0xff]);
+}
+
+#[test]
+fn tables_match_their_definitions() {
+    let mut rng = Rng::new(77);
+    for _ in 0..300 {
+        let s: Vec<u8> = (0..rng.below(30))
+            .map(|_| b'a' + rng.below(2) as u8)
+            .collect();
+        let z: Vec<usize> = (0..s.len())
+            .map(|i| common_prefix_len(&s, &s[i..]))
+            .collect();
+        assert_eq!(z_array(&s), z);
+        let failure: Vec<usize> = (0..s.len())
+            .map(|i| {
+                let prefix = &s[..=i];
+                (0..=i)
+                    .rev()
+                    .find(|&k| prefix.ends_with(&prefix[..k]))
+                    .unwrap_or(0)
+            })
+            .collect();
+        assert_eq!(failure_table(&s), failure);
+    }
+}
+
+#[test]
+fn common_prefixes_stop_at_char_boundaries() {
+    assert_eq!(longest_common_prefix(&["flower", "flow", "flight"]), "fl");
+    assert_eq!(longest_common_prefix(&[]), "");
+    assert_eq!(longest_common_prefix(&["alone"]), "alone");
+    // é and è share their first byte, which is not a prefix on its own
+    assert_eq!(longest_common_prefix(&["café", "cafè"]), "caf");
+}
+
+// the suffix array and lcp straight from their definitions
+fn check_suffix_array(text: &[u8]) {
+    let array = SuffixArray::new(text);
+    let mut suffixes: Vec<usize> = (0..text.len()).collect();
+    suffixes.sort_by_key(|&start| &text[start..]);
+    assert_eq!(array.suffixes(), suffixes, "suffixes of {:?}", text);
+    let lcp: Vec<usize> = (0..suffixes.len())
+        .map(|i| match i {
+            0 => 0,
+            _ => common_prefix_len(&text[suffixes[i - 1]..], &text[suffixes[i]..]),
+        })
+        .collect();
+    assert_eq!(array.lcp(), lcp, "lcp of {:?}", text);
+    assert_eq!((array.len(), array.text()), (text.len(), text));
+}
+
+#[test]
+fn suffix_arrays_match_sorted_suffixes() {
+    let mut rng = Rng::new(1577);
+    for _ in 0..500 {
+        let alphabet = rng.range(1..5);
+        let text: Vec<u8> = (0..rng.below(60))
+            .map(|_| b'a' + rng.below(alphabet) as u8)
+            .collect();
+        check_suffix_array(&text);
+        let array = SuffixArray::new(&text);
+        let pattern: Vec<u8> = (0..rng.range(1..4))
+            .map(|_| b'a' + rng.below(alphabet) as u8)
+            .collect();
+        let expected = naive_find_all(&text, &pattern);
+        assert_eq!(array.find_all(&pattern), expected);
+        assert_eq!(array.count_occurrences(&pattern), expected.len());
+    }
+    // bytes past ascii sort as the unsigned values they are
+    check_suffix_array(&[0xff, 0x00, 0x80, 0xff, 0x00]);
+    check_suffix_array("banana bandana".as_bytes());
+}
+
+#[test]
+fn suffix_array_edge_cases() {
+    let empty = SuffixArray::new(b"");
+    assert!(empty.is_empty());
+    assert!(empty.suffixes().is_empty() && empty.lcp().is_empty());
+    assert_eq!(empty.find_all(b""), [0]);
+    assert_eq!(empty.count_occurrences(b"a"), 0);
+
+    let single = SuffixArray::new(b"x");
+    assert_eq!((single.suffixes(), single.lcp()), (&[0][..], &[0][..]));
+    assert_eq!(single.find_all(b"x"), [0]);
+    assert_eq!(single.find_all(b"xx"), [] as [usize; 0]);
+
+    // every suffix of a run is a prefix of the next longer one
+    let run = SuffixArray::new(&[7; 9]);
+    assert!(run.suffixes().iter().copied().eq((0..9).rev()));
+    assert!(run.lcp().iter().copied().eq(0..9));
+    assert_eq!(run.count_occurrences(&[7; 3]), 7);
+    check_suffix_array(&[7; 9]);
+}
+
+// every occurrence of every pattern, ordered the way find_iter promises: by end, then longest
+// first, then in the order the patterns were given
+fn naive_find_patterns(text: &[u8], patterns: &[Vec<u8>]) -> Vec<Match> {
+    let mut matches: Vec<Match> = patterns
+        .iter()
+        .enumerate()
+        .flat_map(|(pattern, p)| {
+            naive_find_all(text, p).into_iter().map(move |start| Match {
+                pattern,
+                start,
+                end: start + p.len(),
+            })
+        })
+        .collect();
+    matches.sort_by_key(|m| (m.end, m.start, m.pattern));
+    matches
+}
+
+fn check_patterns(text: &[u8], patterns: &[Vec<u8>]) {
+    let automaton = AhoCorasick::new(patterns);
+    let expected = naive_find_patterns(text, patterns);
+    assert_eq!(
+        automaton.find_iter(text).collect::<Vec<_>>(),
+        expected,
+        "{:?} in {:?}",
+        patterns,
+        text
+    );
+    assert_eq!(automaton.is_match(text), !expected.is_empty());
+    assert_eq!(automaton.pattern_count(), patterns.len());
+}
+
+#[test]
+fn aho_corasick_matches_a_naive_scan_per_pattern() {
+    let mut rng = Rng::new(1578);
+    for _ in 0..1_000 {
+        let alphabet = rng.range(2..4);
+        let word = |rng: &mut Rng, lens: Range<usize>| -> Vec<u8> {
+            let len = rng.range(lens);
+            (0..len).map(|_| b'a' + rng.below(alphabet) as u8).collect()
+        };
+        let text = word(&mut rng, 0..40);
+        let mut patterns: Vec<Vec<u8>> =
+            (0..rng.range(1..6)).map(|_| word(&mut rng, 1..5)).collect();
+        // a prefix of one pattern and a repeat of another
+        let cut = patterns[0].len() - 1;
+        if cut > 0 {
+            patterns.push(patterns[0][..cut].to_vec());
+        }
+        if rng.chance(0.5) {
+            patterns.push(patterns[rng.below(patterns.len())].clone());
+        }
+        check_patterns(&text, &patterns);
+    }
+}
+
+#[test]
+fn aho_corasick_edge_cases() {
+    let words = |list: &[&str]| {
+        list.iter()
+            .map(|w| w.as_bytes().to_vec())
+            .collect::<Vec<_>>()
+    };
+    // overlapping and nested within one another
+    check_patterns(b"ushers", &words(&["he", "she", "his", "hers"]));
+    check_patterns(b"aaaa", &words(&["a", "aa", "aaa"]));
+    // prefixes of each other, found at the same start
+    check_patterns(b"abcabc", &words(&["abc", "ab", "a", "bc"]));
+    // duplicates each report every occurrence under their own index
+    let twice = AhoCorasick::new(["ab", "ab"]);
+    let found: Vec<usize> = twice.find_iter(b"abab").map(|m| m.pattern).collect();
+    assert_eq!(found, [0, 1, 0, 1]);
+    check_patterns(b"abab", &words(&["ab", "b", "ab"]));
+    // the empty pattern at every position, and no patterns at all
+    check_patterns(b"ab", &words(&["", "b"]));
+    check_patterns(b"", &words(&["", "a"]));
+    check_patterns(b"abc", &[]);
+    check_patterns("héllo".as_bytes(), &words(&["é", "llo", "h"]));
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use dsa_rust::{
+    generate::{Rng, Shape, bst_keys},
+    tree::threaded::ThreadedBST,
+};
+
+#[test]
+fn walks_in_order_like_a_btree_set() {
+    let mut rng = Rng::new(55);
+    let mut tree = ThreadedBST::new();
+    let mut model = BTreeSet::new();
+    for _ in 0..2_000 {
+        let key = rng.below(500);
+        assert_eq!(tree.insert(key), model.insert(key));
+        if rng.chance(0.05) {
+            assert!(tree.iter().eq(model.iter()));
+        }
+    }
+    assert!(tree.iter().eq(model.iter()));
+    assert_eq!(tree.iter().len(), model.len());
+    assert_eq!(tree.size(), model.len());
+    assert_eq!((tree.first(), tree.last()), (model.first(), model.last()));
+    for probe in 0..510 {
+        assert_eq!(tree.contains(&probe), model.contains(&probe));
+        assert_eq!(tree.successor(&probe), model.range(probe + 1..).next());
+    }
+}
+
+#[test]
+fn every_shape_threads_correctly() {
+    for shape in [
+        Shape::Balanced,
+        Shape::Sorted,
+        Shape::Reversed,
+        Shape::ZigZag,
+        Shape::Random,
+    ] {
+        let keys = bst_keys(&mut Rng::new(2), 300, shape);
+        let tree: ThreadedBST<_> = keys.iter().copied().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert!(tree.iter().copied().eq(sorted), "{:?}", shape);
+    }
+}
+
+// sorted input makes a right spine: every right link is a child, and the walk follows them
+// without a stack however long the spine gets
+#[test]
+fn degenerate_trees() {
+    let up = ThreadedBST::from(0..5_000);
+    assert_eq!(up.height(), 5_000);
+    assert!(up.iter().copied().eq(0..5_000));
+    let down = ThreadedBST::from((0..5_000).rev());
+    assert!(down.iter().copied().eq(0..5_000));
+    assert_eq!(down.successor(&4_999), None);
+}
+
+#[test]
+fn small_trees() {
+    let mut tree = ThreadedBST::new();
+    assert!(tree.is_empty());
+    assert_eq!(tree.iter().next(), None);
+    assert_eq!((tree.first(), tree.height()), (None, 0));
+    tree.extend(["m", "c", "x", "a", "e"]);
+    assert!(!tree.insert("e"));
+    assert_eq!(tree.get("c"), Some(&"c"));
+    assert_eq!(tree.successor("d"), Some(&"e"));
+    assert_eq!(tree.successor("e"), Some(&"m"));
+    assert_eq!(format!("{:?}", tree), r#"{"a", "c", "e", "m", "x"}"#);
+    assert_eq!(tree.height(), 3);
+    let copy = tree.clone();
+    tree.clear();
+    assert!(tree.is_empty());
+    assert_eq!((&copy).into_iter().count(), 5);
+}
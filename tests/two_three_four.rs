@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+
+use dsa_rust::{
+    generate::Rng,
+    tree::two_three_four::{Color, TwoThreeFourTree},
+};
+
+#[test]
+fn acts_like_a_btree_set() {
+    let mut rng = Rng::new(38);
+    let mut tree = TwoThreeFourTree::new();
+    let mut model = BTreeSet::new();
+    for _ in 0..3_000 {
+        let key = rng.below(800);
+        assert_eq!(tree.insert(key), model.insert(key));
+    }
+    assert!(tree.iter().eq(model.iter()));
+    assert_eq!(tree.iter().len(), model.len());
+    assert_eq!(tree.size(), model.len());
+    assert_eq!((tree.first(), tree.last()), (model.first(), model.last()));
+    for probe in 0..810 {
+        assert_eq!(tree.contains(&probe), model.contains(&probe));
+    }
+    let [twos, threes, fours] = tree.node_counts();
+    assert_eq!(twos + 2 * threes + 3 * fours, model.len());
+}
+
+#[test]
+fn stays_shallow_on_sorted_input() {
+    let tree = TwoThreeFourTree::from(0..4_095);
+    // every node holding at least one key bounds it by log2, and every node full by log4
+    assert!(tree.height() <= 12);
+    assert!(tree.height() >= 6);
+    assert!(tree.iter().copied().eq(0..4_095));
+}
+
+#[test]
+fn grows_by_splitting_the_root() {
+    let mut tree = TwoThreeFourTree::new();
+    assert_eq!(tree.height(), 0);
+    tree.extend([2, 1, 3]);
+    assert_eq!((tree.height(), tree.node_counts()), (1, [0, 0, 1]));
+    // the full root splits before 4 goes in, leaving 2 above 1 and 3 4
+    tree.insert(4);
+    assert_eq!((tree.height(), tree.node_counts()), (2, [2, 1, 0]));
+    assert!(!tree.insert(2));
+    assert_eq!(tree.size(), 4);
+    assert_eq!(format!("{:?}", tree), "{1, 2, 3, 4}");
+}
+
+#[test]
+fn converts_to_a_valid_red_black_tree() {
+    let mut rng = Rng::new(39);
+    for round in 0..30 {
+        let keys: Vec<u32> = (0..rng.below(300))
+            .map(|_| rng.below(1_000) as u32)
+            .collect();
+        let tree: TwoThreeFourTree<u32> = keys.iter().copied().collect();
+        let (height, [_, threes, fours]) = (tree.height(), tree.node_counts());
+        let expected: Vec<u32> = tree.iter().copied().collect();
+
+        let red_black = tree.into_red_black();
+        assert_eq!(red_black.black_height(), Some(height), "round {}", round);
+        assert!(red_black.height() <= 2 * height);
+        assert!(red_black.iter().copied().eq(expected.iter().copied()));
+        let reds = expected
+            .iter()
+            .filter(|key| red_black.color(*key) == Some(Color::Red))
+            .count();
+        assert_eq!(reds, threes + 2 * fours);
+
+        let back = red_black.into_two_three_four();
+        assert_eq!(back.height(), height);
+        assert_eq!(back.node_counts()[1..], [threes, fours]);
+        assert!(back.iter().copied().eq(expected.iter().copied()));
+    }
+}
+
+#[test]
+fn conversion_colors_each_node_kind() {
+    let tree = TwoThreeFourTree::from([1, 2, 3, 4]);
+    let red_black = tree.clone().into_red_black();
+    // 2 is a two-node over the three-node 3 4, which leans left as black 4 over red 3
+    assert_eq!(red_black.color(&2), Some(Color::Black));
+    assert_eq!(red_black.color(&1), Some(Color::Black));
+    assert_eq!(red_black.color(&4), Some(Color::Black));
+    assert_eq!(red_black.color(&3), Some(Color::Red));
+    assert_eq!(red_black.color(&5), None);
+    assert_eq!(format!("{:?}", red_black), "{1, 2, 3, 4}");
+
+    let empty = TwoThreeFourTree::<i32>::new().into_red_black();
+    assert!(empty.is_empty());
+    assert_eq!(empty.black_height(), Some(0));
+    assert!(empty.into_two_three_four().is_empty());
+}
@@ -0,0 +1,138 @@
+use dsa_rust::{
+    collections::{Strategy, UnionFind},
+    generate::Rng,
+};
+
+// the naive model: every element carries its set label, a union relabels one whole side
+fn relabel(labels: &mut [usize], a: usize, b: usize) -> bool {
+    let (from, to) = (labels[b], labels[a]);
+    labels
+        .iter_mut()
+        .filter(|label| **label == from)
+        .for_each(|label| *label = to);
+    from != to
+}
+
+fn all_variants(elements: usize) -> [UnionFind; 4] {
+    [
+        UnionFind::new(elements),
+        UnionFind::by_rank(elements),
+        UnionFind::with_rollback(elements, Strategy::BySize),
+        UnionFind::with_rollback(elements, Strategy::ByRank),
+    ]
+}
+
+#[test]
+fn every_variant_matches_the_model() {
+    let mut rng = Rng::new(17);
+    for mut sets in all_variants(60) {
+        let mut labels: Vec<usize> = (0..60).collect();
+        for _ in 0..400 {
+            let (a, b) = (rng.below(60), rng.below(60));
+            assert_eq!(sets.union(a, b), relabel(&mut labels, a, b));
+            let (c, d) = (rng.below(60), rng.below(60));
+            assert_eq!(sets.same(c, d), labels[c] == labels[d]);
+            let size = labels.iter().filter(|&&label| label == labels[c]).count();
+            assert_eq!(sets.set_size(c), size);
+        }
+        let mut distinct = labels.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(sets.set_count(), distinct.len());
+        assert_eq!(sets.sets().concat().len(), 60);
+    }
+}
+
+#[test]
+fn strategies_pick_the_surviving_root() {
+    // a pair against a singleton: both strategies keep the pair's root on top
+    for mut sets in all_variants(4) {
+        sets.union(0, 1);
+        sets.union(2, 0);
+        assert_eq!(sets.root(2), sets.root(0));
+        assert_eq!((sets.depth(0), sets.depth(2)), (0, 1));
+    }
+
+    // sizes and ranks disagree once a wide shallow set meets a narrow tall one
+    let build = |strategy| {
+        let mut sets = UnionFind::with_rollback(8, strategy);
+        // 0 <- 1, 2 <- 3, then 0 <- 2: a tall set of four with rank 2
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(0, 2);
+        // a star of four with rank 1
+        sets.union(4, 5);
+        sets.union(4, 6);
+        sets.union(4, 7);
+        sets.union(4, 0);
+        sets
+    };
+    // equal sizes: the tie goes to the first argument
+    assert_eq!(build(Strategy::BySize).root(0), 4);
+    assert_eq!(build(Strategy::ByRank).root(4), 0);
+    assert_eq!(build(Strategy::ByRank).strategy(), Strategy::ByRank);
+}
+
+#[test]
+fn rollback_restores_earlier_states() {
+    let mut rng = Rng::new(23);
+    for strategy in [Strategy::BySize, Strategy::ByRank] {
+        let mut sets = UnionFind::with_rollback(40, strategy);
+        assert!(!sets.compresses_paths() && !sets.rollback());
+        let mut saved = Vec::new();
+        for _ in 0..5 {
+            saved.push((sets.checkpoint(), sets.sets(), sets.depth_stats()));
+            for _ in 0..12 {
+                sets.union(rng.below(40), rng.below(40));
+            }
+        }
+        while let Some((checkpoint, partition, depths)) = saved.pop() {
+            sets.rollback_to(checkpoint);
+            assert_eq!(sets.sets(), partition);
+            assert_eq!(sets.set_count(), partition.len());
+            assert_eq!(sets.depth_stats(), depths);
+        }
+        assert_eq!(sets.set_count(), 40);
+    }
+
+    let mut sets = UnionFind::with_rollback(3, Strategy::BySize);
+    assert!(sets.union(0, 1) && !sets.union(1, 0));
+    assert_eq!(sets.checkpoint(), 1);
+    let added = sets.add();
+    sets.union(added, 2);
+    assert!(sets.rollback() && sets.rollback() && !sets.rollback());
+    assert_eq!((sets.len(), sets.set_count()), (4, 4));
+}
+
+#[test]
+#[should_panic(expected = "rollback needs a union-find built with_rollback")]
+fn compressing_sets_cannot_roll_back() {
+    let mut sets = UnionFind::new(2);
+    sets.union(0, 1);
+    sets.rollback();
+}
+
+#[test]
+fn depth_statistics() {
+    let mut sets = UnionFind::with_rollback(8, Strategy::ByRank);
+    for (a, b) in [(0, 1), (2, 3), (0, 2), (4, 5), (6, 7), (4, 6), (0, 4)] {
+        sets.union(a, b);
+    }
+    // a binomial tree of eight: one root, then 3, 3 and 1 elements per level
+    let stats = sets.depth_stats();
+    assert_eq!(stats.histogram, vec![1, 3, 3, 1]);
+    assert_eq!((stats.max, stats.total), (3, 12));
+    assert_eq!(stats.mean(), 1.5);
+    assert_eq!(sets.depth(7), 3);
+
+    // compression flattens whatever find walks through
+    let mut flat = UnionFind::by_rank(8);
+    for (a, b) in [(0, 1), (2, 3), (0, 2), (4, 5), (6, 7), (4, 6), (0, 4)] {
+        flat.union(a, b);
+    }
+    (0..8).for_each(|element| {
+        flat.find(element);
+    });
+    assert_eq!(flat.depth_stats().histogram, vec![1, 7]);
+    assert_eq!(UnionFind::default().depth_stats().mean(), 0.0);
+}
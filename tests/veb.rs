@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+
+use dsa_rust::{generate::Rng, tree::veb::VebTree};
+
+fn check_against_model(bits: u32, keys: u64, seed: u64) {
+    let mut rng = Rng::new(seed);
+    let mut tree = VebTree::new(bits);
+    let mut model = BTreeSet::new();
+    for _ in 0..4_000 {
+        let key = rng.next_u64() % keys;
+        match rng.chance(0.6) {
+            true => assert_eq!(tree.insert(key), model.insert(key)),
+            false => assert_eq!(tree.remove(key), model.remove(&key)),
+        }
+        assert_eq!(tree.size(), model.len());
+        assert_eq!(
+            (tree.first(), tree.last()),
+            (model.first().copied(), model.last().copied())
+        );
+        let probe = rng.next_u64() % keys;
+        assert_eq!(tree.contains(probe), model.contains(&probe));
+        assert_eq!(
+            tree.successor(probe),
+            model.range(probe + 1..).next().copied()
+        );
+        assert_eq!(
+            tree.predecessor(probe),
+            model.range(..probe).next_back().copied()
+        );
+    }
+    assert!(tree.iter().eq(model.iter().copied()));
+}
+
+#[test]
+fn matches_a_btree_set_in_a_word_sized_universe() {
+    check_against_model(6, 64, 1);
+}
+
+#[test]
+fn matches_a_btree_set_in_small_universes() {
+    for bits in [1, 3, 7, 10, 13] {
+        check_against_model(bits, 1 << bits, bits as u64);
+    }
+}
+
+#[test]
+fn matches_a_btree_set_across_a_wide_universe() {
+    check_against_model(64, u64::MAX, 2);
+    // dense in one corner of a 40-bit universe, so clusters fill and empty
+    check_against_model(40, 3_000, 3);
+}
+
+#[test]
+fn drains_to_empty() {
+    let mut rng = Rng::new(4);
+    let mut keys: Vec<u64> = (0..500).map(|_| rng.next_u64() >> 32).collect();
+    let mut tree = VebTree::from(32, keys.iter().copied());
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(tree.size(), keys.len());
+    rng.shuffle(&mut keys);
+    for &key in &keys {
+        assert!(tree.remove(key));
+        assert!(!tree.remove(key));
+    }
+    assert!(tree.is_empty());
+    assert_eq!((tree.first(), tree.successor(0)), (None, None));
+    tree.insert(7);
+    assert_eq!(format!("{:?}", tree), "{7}");
+}
+
+#[test]
+fn edges_of_the_universe() {
+    let mut tree = VebTree::default();
+    assert_eq!(tree.bits(), 64);
+    tree.extend([0, u64::MAX, 1 << 63]);
+    assert_eq!(tree.successor(0), Some(1 << 63));
+    assert_eq!(tree.successor(u64::MAX), None);
+    assert_eq!(tree.predecessor(0), None);
+    assert_eq!(tree.predecessor(u64::MAX), Some(1 << 63));
+
+    let small = VebTree::from(8, [3, 200]);
+    assert!(!small.contains(1_000));
+    assert_eq!(small.predecessor(1_000), Some(200));
+    assert_eq!(small.successor(1_000), None);
+}
+
+#[test]
+#[should_panic(expected = "key 256 out of range for a 8-bit universe")]
+fn rejects_keys_outside_the_universe() {
+    VebTree::new(8).insert(256);
+}
+
+#[test]
+#[should_panic(expected = "a universe of 0 bits")]
+fn rejects_an_empty_universe() {
+    VebTree::new(0);
+}